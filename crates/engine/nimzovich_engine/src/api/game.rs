@@ -7,9 +7,11 @@ use alloc::vec::Vec;
 
 use crate::bitset::BitSet;
 use crate::board::{init_bitboards, init_board};
+#[cfg(feature = "search")]
+use crate::constants::MAX_SEARCH_THREADS;
 use crate::constants::{MAX_DEPTH, TTE_SIZE};
 #[cfg(feature = "search")]
-use crate::hash::init_zobrist;
+use crate::hash::{init_zobrist, SharedTT};
 use crate::move_gen::init_move_tables;
 use crate::types::*;
 use crate::utils;
@@ -57,12 +59,20 @@ fn new_game_with_tt_capacity(tt_capacity: usize) -> Game {
         tt: vec![TTE::default(); tt_capacity],
         #[cfg(feature = "search")]
         tt_capacity,
+        #[cfg(feature = "search")]
+        shared_tt: None,
+        #[cfg(feature = "search")]
+        search_threads: 1,
 
         #[cfg(feature = "search")]
         zobrist_table: [[0u64; 64]; 12],
         #[cfg(feature = "search")]
         zobrist_black_turn: 0,
         #[cfg(feature = "search")]
+        zobrist_castling: [0; 4],
+        #[cfg(feature = "search")]
+        zobrist_en_passant: [0; 8],
+        #[cfg(feature = "search")]
         current_hash: 0,
 
         max_depth_so_far: 0,
@@ -90,6 +100,10 @@ fn new_game_with_tt_capacity(tt_capacity: usize) -> Game {
         hash_history: Vec::new(),
         #[cfg(feature = "search")]
         search_deadline: None,
+        #[cfg(feature = "search")]
+        search_node_limit: None,
+        #[cfg(feature = "search")]
+        late_move_reductions: false,
 
         // Bitboards
         white_pawns: BitSet::default(),
@@ -138,8 +152,12 @@ pub fn set_tt_size_mb(game: &mut Game, mb: usize) {
     let capacity = raw.next_power_of_two() / 2; // floor to previous power of two
     let capacity = capacity.max(1);
 
-    game.tt.resize_with(capacity, TTE::default);
-    game.tt.shrink_to_fit();
+    if game.shared_tt.is_some() {
+        game.shared_tt = Some(std::sync::Arc::new(SharedTT::new(capacity)));
+    } else {
+        game.tt.resize_with(capacity, TTE::default);
+        game.tt.shrink_to_fit();
+    }
 
     game.tt_capacity = capacity;
     game.cache_size_bytes = entry_size * capacity;
@@ -150,6 +168,39 @@ pub fn set_tt_size_mb(game: &mut Game, mb: usize) {
     );
 }
 
+/// Search with `threads` threads from now on (Lazy SMP), clamped to the
+/// machine's cores; 1 goes back to a single-threaded search.
+///
+/// More than one thread moves the transposition table into a shared,
+/// per-bucket-locked table the threads search through together, so the
+/// memory use stays the same. Switching either way starts from an empty
+/// table; setting the current count again keeps it. WebAssembly has no
+/// threads to spare and always stays at 1.
+#[cfg(feature = "search")]
+pub fn set_search_threads(game: &mut Game, threads: usize) {
+    let threads = if cfg!(target_arch = "wasm32") {
+        1
+    } else {
+        let cores = std::thread::available_parallelism().map_or(1, |n| n.get());
+        threads.clamp(1, cores.min(MAX_SEARCH_THREADS))
+    };
+    if threads == game.search_threads {
+        return;
+    }
+
+    let shared = threads > 1;
+    if shared != game.shared_tt.is_some() {
+        if shared {
+            game.tt = Vec::new();
+            game.shared_tt = Some(std::sync::Arc::new(SharedTT::new(game.tt_capacity)));
+        } else {
+            game.shared_tt = None;
+            game.tt = vec![TTE::default(); game.tt_capacity];
+        }
+    }
+    game.search_threads = threads;
+}
+
 /// Reset the game to starting position
 #[cfg(feature = "std")]
 pub fn reset_game(game: &mut Game) {
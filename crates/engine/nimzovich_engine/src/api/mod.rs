@@ -19,6 +19,10 @@ pub use game::{
     set_game_from_fen, set_tt_size_mb,
 };
 pub use moves::{do_move, do_move_with_promo, is_legal_move, is_legal_move_unchecked};
-pub use state::get_game_state;
+#[cfg(feature = "std")]
+pub use moves::legal_moves_for_fen;
+pub use state::{get_game_state, get_game_status, GameStatus};
+#[cfg(feature = "search")]
+pub use game::set_search_threads;
 #[cfg(feature = "search")]
-pub use state::reply;
+pub use state::{reply, reply_bounded, reply_limited};
@@ -372,6 +372,44 @@ mod tests {
         }
         assert_eq!(executed, expected, "all {} plies should execute", expected);
     }
+
+    #[test]
+    fn legal_moves_for_fen_lists_the_twenty_opening_moves_in_order() {
+        //! The start position has 20 legal moves, listed sorted and the same
+        //! way every time
+
+        let fen = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1";
+        let moves = legal_moves_for_fen(fen);
+        assert_eq!(moves.len(), 20);
+
+        let keys: Vec<(i64, i64)> = moves.iter().map(|mv| (mv.src, mv.dst)).collect();
+        let mut sorted = keys.clone();
+        sorted.sort();
+        assert_eq!(keys, sorted);
+        let again: Vec<(i64, i64)> = legal_moves_for_fen(fen)
+            .iter()
+            .map(|mv| (mv.src, mv.dst))
+            .collect();
+        assert_eq!(keys, again);
+    }
+
+    #[test]
+    fn legal_moves_for_fen_skips_pinned_pieces() {
+        //! A knight pinned to its king by a rook has no moves; only the king
+        //! steps aside, and promotions come once per piece
+
+        // Ne2 is pinned by the rook on e8.
+        let moves = legal_moves_for_fen("4r1k1/8/8/8/8/8/4N3/4K3 w - - 0 1");
+        assert!(moves.iter().all(|mv| mv.src != 12), "pinned knight moved");
+        assert_eq!(moves.len(), 4);
+
+        let promotions: Vec<i8> = legal_moves_for_fen("7k/P7/8/8/8/8/8/K7 w - - 0 1")
+            .iter()
+            .filter(|mv| mv.src == 48)
+            .map(|mv| mv.promo)
+            .collect();
+        assert_eq!(promotions, [QUEEN_ID, ROOK_ID, BISHOP_ID, KNIGHT_ID]);
+    }
 }
 
 /// Check if a move is legal
@@ -427,3 +465,32 @@ pub fn is_legal_move_unchecked(game: &mut Game, src: i8, dst: i8, color: Color)
 
     legal
 }
+
+/// Every legal move in the position `fen` describes, for its side to move.
+///
+/// For callers without a [`Game`] of their own: tools, the web client and
+/// tests. Moves are sorted by source square, then destination, then
+/// promotion piece (queen first), so the order is stable. Only `src`, `dst`
+/// and `promo` are filled in; a promotion is listed once per piece.
+#[cfg(feature = "std")]
+pub fn legal_moves_for_fen(fen: &str) -> Vec<Move> {
+    let mut game = crate::api::game::game_from_fen_no_tt(fen);
+    let color = if fen.split_whitespace().nth(1) == Some("b") {
+        COLOR_BLACK
+    } else {
+        COLOR_WHITE
+    };
+
+    let mut moves: Vec<Move> = generate_pseudo_legal_moves(&game, color)
+        .into_iter()
+        .filter(|mv| is_legal_move_unchecked(&mut game, mv.src, mv.dst, color))
+        .map(|mv| Move {
+            src: mv.src as i64,
+            dst: mv.dst as i64,
+            promo: (mv.nxt_dir_idx >> 4) as i8,
+            ..Move::default()
+        })
+        .collect();
+    moves.sort_by_key(|mv| (mv.src, mv.dst, -mv.promo));
+    moves
+}
@@ -1,7 +1,10 @@
 //! Game state queries and AI move generation
 //!
 //! Functions for querying game state and generating AI moves.
+//! [`get_game_status`] is the single source for every way a game can end on
+//! the board; [`get_game_state`] keeps the older mate/stalemate-only codes.
 
+use super::moves::is_legal_move_unchecked;
 use crate::constants::*;
 use crate::move_gen::*;
 #[cfg(feature = "search")]
@@ -19,6 +22,164 @@ pub async fn reply(game: &mut Game, color: i64) -> Move {
     find_best_move(game, game.secs_per_move, color)
 }
 
+/// Like [`reply`], but synchronous and bounded by both `max_secs` of wall-clock
+/// time and a hard `max_nodes` node limit — whichever is hit first.
+///
+/// # Arguments
+///
+/// * `game` - The game state
+/// * `color` - The color to move (1 for White, -1 for Black)
+/// * `max_secs` - Wall-clock budget in seconds
+/// * `max_nodes` - Maximum nodes to visit
+#[cfg(feature = "search")]
+pub fn reply_bounded(game: &mut Game, color: i64, max_secs: f32, max_nodes: i64) -> Move {
+    find_best_move_bounded(game, max_secs, max_nodes, color)
+}
+
+/// Like [`reply_bounded`], with the node limit and a depth limit both
+/// optional. On one search thread a node budget makes the result
+/// reproducible: the same position and budget give the same move on any
+/// machine, as long as `max_secs` isn't reached first. `max_depth` caps the
+/// iterative deepening for this search only; `game.abs_max_depth` is put
+/// back afterwards.
+///
+/// # Arguments
+///
+/// * `game` - The game state
+/// * `color` - The color to move (1 for White, -1 for Black)
+/// * `max_secs` - Wall-clock budget in seconds
+/// * `max_nodes` - Maximum nodes to visit, if limited
+/// * `max_depth` - Deepest iteration to search, if limited
+#[cfg(feature = "search")]
+pub fn reply_limited(
+    game: &mut Game,
+    color: i64,
+    max_secs: f32,
+    max_nodes: Option<u64>,
+    max_depth: Option<i64>,
+) -> Move {
+    let previous_depth = game.abs_max_depth;
+    if let Some(depth) = max_depth {
+        game.abs_max_depth = depth.max(1);
+    }
+    let mv = match max_nodes {
+        Some(nodes) => {
+            find_best_move_bounded(game, max_secs, nodes.min(i64::MAX as u64) as i64, color)
+        }
+        None => find_best_move(game, max_secs, color),
+    };
+    game.abs_max_depth = previous_depth;
+    mv
+}
+
+/// Where the game stands for the side to move, including every draw rule.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GameStatus {
+    Playing,
+    /// The side to move is mated.
+    Checkmate,
+    /// The side to move has no legal move and is not in check.
+    Stalemate,
+    /// 100 plies without a capture or pawn move.
+    FiftyMoveRule,
+    /// The current position has occurred three times with the same side to
+    /// move. Needs the `search` feature, which keeps the position history.
+    ThreefoldRepetition,
+    /// Neither side can mate: K v K, K+minor v K, or K+B v K+B with both
+    /// bishops on one colour.
+    InsufficientMaterial,
+}
+
+impl GameStatus {
+    /// True for every status except [`GameStatus::Playing`].
+    pub fn is_terminal(self) -> bool {
+        self != GameStatus::Playing
+    }
+
+    /// True for the drawn endings.
+    pub fn is_draw(self) -> bool {
+        self.is_terminal() && self != GameStatus::Checkmate
+    }
+}
+
+/// Full game status for `color` to move.
+///
+/// When several endings hold at once the first in this order wins:
+/// checkmate, stalemate, threefold repetition, fifty-move rule, insufficient
+/// material. So a mate delivered on the hundredth reversible ply still
+/// counts, and a repetition completed on that ply is reported as the
+/// repetition. Repetition is read from `game.hash_history`, which `do_move`
+/// extends and a FEN load resets.
+pub fn get_game_status(game: &mut Game, color: Color) -> GameStatus {
+    match get_game_state(game, color) {
+        STATE_CHECKMATE => return GameStatus::Checkmate,
+        STATE_STALEMATE => return GameStatus::Stalemate,
+        _ => {}
+    }
+    if repetition_count(game) >= 3 {
+        return GameStatus::ThreefoldRepetition;
+    }
+    if game.halfmove_clock >= 100 {
+        return GameStatus::FiftyMoveRule;
+    }
+    if is_insufficient_material(game) {
+        return GameStatus::InsufficientMaterial;
+    }
+    GameStatus::Playing
+}
+
+/// Occurrences of the current position in the history, itself included.
+/// Only positions since the last capture or pawn move can match.
+#[cfg(feature = "search")]
+fn repetition_count(game: &Game) -> usize {
+    let Some((current, earlier)) = game.hash_history.split_last() else {
+        return 0;
+    };
+    let window = (game.halfmove_clock as usize).min(earlier.len());
+    1 + earlier[earlier.len() - window..]
+        .iter()
+        .filter(|h| *h == current)
+        .count()
+}
+
+#[cfg(not(feature = "search"))]
+fn repetition_count(_game: &Game) -> usize {
+    0
+}
+
+fn is_insufficient_material(game: &Game) -> bool {
+    let mut white_minors = 0;
+    let mut black_minors = 0;
+    let mut knights = 0;
+    let mut bishop_square_colors = [None; 2];
+    for (sq, &piece) in game.board.iter().enumerate() {
+        match piece.abs() {
+            0 | KING_ID => {}
+            KNIGHT_ID | BISHOP_ID => {
+                if piece > 0 {
+                    white_minors += 1;
+                } else {
+                    black_minors += 1;
+                }
+                if piece.abs() == KNIGHT_ID {
+                    knights += 1;
+                } else {
+                    bishop_square_colors[(piece < 0) as usize] = Some((sq / 8 + sq % 8) & 1);
+                }
+            }
+            _ => return false,
+        }
+    }
+
+    if white_minors + black_minors <= 1 {
+        return true;
+    }
+    white_minors == 1
+        && black_minors == 1
+        && knights == 0
+        && bishop_square_colors[0] == bishop_square_colors[1]
+}
+
 /// Get current game state (playing, checkmate, stalemate)
 pub fn get_game_state(game: &mut Game, color: Color) -> i32 {
     let in_check = is_in_check(game, color);
@@ -36,22 +197,282 @@ pub fn get_game_state(game: &mut Game, color: Color) -> i32 {
 }
 
 fn has_any_legal_move(game: &mut Game, color: Color) -> bool {
-    let moves = generate_pseudo_legal_moves(game, color);
+    // `is_in_check` reads the bitboards, so each candidate has to go through
+    // `do_move` rather than a bare mailbox swap, or a king step into check
+    // looks legal and stalemate is never seen.
+    generate_pseudo_legal_moves(game, color)
+        .into_iter()
+        .any(|mv| is_legal_move_unchecked(game, mv.src, mv.dst, color))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::game::game_from_fen_no_tt as game_from_fen;
+    use crate::api::moves::{do_move, is_legal_move};
+
+    fn status(fen: &str) -> GameStatus {
+        let mut game = game_from_fen(fen);
+        let color = if fen.contains(" w ") {
+            COLOR_WHITE
+        } else {
+            COLOR_BLACK
+        };
+        get_game_status(&mut game, color)
+    }
+
+    #[test]
+    fn fifty_move_draw_after_100_reversible_plies() {
+        //! Rook and king shuffles that never repeat a position end in a fifty-move draw on ply 100
+
+        let mut game = game_from_fen("r3k3/8/8/8/8/8/8/4K2R w - - 0 1");
+        let mut color = COLOR_WHITE;
+        let mut seen = vec![game.current_hash];
+
+        for ply in 1..=100 {
+            let quiet_moves: Vec<KK> = generate_pseudo_legal_moves(&game, color)
+                .into_iter()
+                .filter(|mv| game.board[mv.dst as usize] == 0)
+                .collect();
+            let mut played = false;
+            for mv in quiet_moves {
+                if !is_legal_move(&mut game, mv.src, mv.dst, color) {
+                    continue;
+                }
+                let mut next = game.clone();
+                do_move(&mut next, mv.src, mv.dst, true);
+                if seen.contains(&next.current_hash)
+                    || get_game_state(&mut next, -color) != STATE_PLAYING
+                {
+                    continue;
+                }
+                seen.push(next.current_hash);
+                game = next;
+                played = true;
+                break;
+            }
+            assert!(played, "no fresh quiet move at ply {ply}");
+            color = -color;
+
+            let expected = if ply < 100 {
+                GameStatus::Playing
+            } else {
+                GameStatus::FiftyMoveRule
+            };
+            assert_eq!(get_game_status(&mut game, color), expected, "ply {ply}");
+        }
+        assert_eq!(game.halfmove_clock, 100);
+    }
+
+    #[test]
+    fn threefold_repetition_needs_the_third_occurrence() {
+        //! Knights out and back twice repeats the start position three times
+
+        let mut game = game_from_fen("4k1n1/8/8/8/8/8/8/4K1N1 w - - 0 1");
+        // g1-f3, g8-f6, f3-g1, f6-g8 returns to the start.
+        let cycle = [(6, 21), (62, 45), (21, 6), (45, 62)];
+        let mut color = COLOR_WHITE;
+        for (i, &(src, dst)) in cycle.iter().chain(cycle.iter()).enumerate() {
+            do_move(&mut game, src, dst, true);
+            color = -color;
+            let expected = if i == 7 {
+                GameStatus::ThreefoldRepetition
+            } else {
+                GameStatus::Playing
+            };
+            assert_eq!(get_game_status(&mut game, color), expected, "ply {}", i + 1);
+        }
+    }
+
+    #[test]
+    fn castling_and_en_passant_rights_tell_positions_apart() {
+        //! A placement with castling or an en-passant capture still on isn't a repeat of the same one without
+
+        // Kings out and back give up castling, so the start placement counts
+        // afresh from ply 4 and the first threefold is the position after
+        // ...Kf8, on ply 10, rather than the start on ply 8.
+        let mut game = game_from_fen("r3k2r/8/8/8/8/8/8/R3K2R w KQkq - 0 1");
+        let shuffle = [(4, 5), (60, 61), (5, 4), (61, 60)];
+        let mut color = COLOR_WHITE;
+        for (i, &(src, dst)) in shuffle.iter().cycle().take(10).enumerate() {
+            do_move(&mut game, src, dst, true);
+            color = -color;
+            let expected = if i == 9 {
+                GameStatus::ThreefoldRepetition
+            } else {
+                GameStatus::Playing
+            };
+            assert_eq!(get_game_status(&mut game, color), expected, "ply {}", i + 1);
+        }
+
+        // After e2-e4 the d4 pawn may take en passant; once the kings have
+        // walked out and back it no longer can. So the placement after e4
+        // reaches a third occurrence only on ply 13, and the first threefold
+        // is the position after ...Kd7, on ply 10, rather than on ply 9.
+        let mut game = game_from_fen("4k3/8/8/8/3p4/8/4P3/4K3 w - - 0 1");
+        do_move(&mut game, 12, 28, true);
+        let shuffle = [(60, 51), (4, 11), (51, 60), (11, 4)];
+        let mut color = COLOR_BLACK;
+        for (i, &(src, dst)) in shuffle.iter().cycle().take(9).enumerate() {
+            do_move(&mut game, src, dst, true);
+            color = -color;
+            let expected = if i == 8 {
+                GameStatus::ThreefoldRepetition
+            } else {
+                GameStatus::Playing
+            };
+            assert_eq!(get_game_status(&mut game, color), expected, "ply {}", i + 2);
+        }
+    }
 
-    for mv in moves {
-        let captured = game.board[mv.dst as usize];
-        game.board[mv.dst as usize] = game.board[mv.src as usize];
-        game.board[mv.src as usize] = 0;
+    #[test]
+    fn checkmate_on_the_hundredth_ply_beats_the_fifty_move_rule() {
+        //! A quiet mating move that takes the clock to 100 ends the game in mate, not a draw
 
-        let legal = !is_in_check(game, color);
+        let mut game = game_from_fen("7k/8/6K1/8/8/8/8/5Q2 w - - 99 80");
+        // Qf1-f8#
+        do_move(&mut game, 5, 61, true);
+        assert_eq!(game.halfmove_clock, 100);
+        assert_eq!(
+            get_game_status(&mut game, COLOR_BLACK),
+            GameStatus::Checkmate
+        );
+    }
+
+    #[test]
+    fn repetition_on_the_hundredth_ply_beats_the_fifty_move_rule() {
+        //! A third occurrence landing on ply 100 is reported as the repetition
+
+        let mut game = game_from_fen("4k1n1/8/8/8/8/8/8/4K1N1 w - - 92 60");
+        let cycle = [(6, 21), (62, 45), (21, 6), (45, 62)];
+        for &(src, dst) in cycle.iter().chain(cycle.iter()) {
+            do_move(&mut game, src, dst, true);
+        }
+        assert_eq!(game.halfmove_clock, 100);
+        assert_eq!(
+            get_game_status(&mut game, COLOR_WHITE),
+            GameStatus::ThreefoldRepetition
+        );
+    }
 
-        game.board[mv.src as usize] = game.board[mv.dst as usize];
-        game.board[mv.dst as usize] = captured;
+    #[test]
+    fn reply_without_a_legal_move_reports_mate_or_stalemate() {
+        //! A side with no move gets back no move, flagged as checkmate or stalemate as appropriate
 
-        if legal {
-            return true;
+        for (fen, expected) in [
+            ("7k/6Q1/6K1/8/8/8/8/8 b - - 0 1", STATE_CHECKMATE),
+            ("7k/5Q2/6K1/8/8/8/8/8 b - - 0 1", STATE_STALEMATE),
+        ] {
+            let mut game = game_from_fen(fen);
+            crate::set_tt_size_mb(&mut game, 1);
+            let mv = reply_bounded(&mut game, COLOR_BLACK, 0.2, 10_000);
+            assert_eq!((mv.src, mv.dst), (0, 0), "{fen}");
+            assert_eq!(mv.state, expected, "{fen}");
         }
     }
 
-    false
+    #[test]
+    fn node_budget_makes_the_reply_repeatable() {
+        //! Two searches of one position with the same node budget play the same move after the same node count; a depth cap stops the deepening
+
+        let fen = "r1bqkbnr/pppp1ppp/2n5/4p3/4P3/5N2/PPPP1PPP/RNBQKB1R w KQkq - 2 3";
+        let search = |max_nodes, max_depth| {
+            let mut game = game_from_fen(fen);
+            crate::set_tt_size_mb(&mut game, 1);
+            let mv = reply_limited(&mut game, COLOR_WHITE, 30.0, max_nodes, max_depth);
+            (
+                (mv.src, mv.dst),
+                game.calls,
+                game.max_depth_so_far,
+                game.abs_max_depth,
+            )
+        };
+
+        let first = search(Some(20_000), None);
+        assert_eq!(search(Some(20_000), None), first);
+        assert!(first.1 <= 20_000);
+
+        let (_, _, depth, restored) = search(None, Some(3));
+        assert_eq!(depth, 3);
+        assert_eq!(restored, MAX_DEPTH as i64);
+    }
+
+    #[test]
+    fn reply_underpromotes_to_a_knight_when_that_mates() {
+        //! f8=N is the only mate here, and the move comes back with its knight and its SAN survives a round trip
+
+        // Black's own pieces box the king in; a queen on f8 gives no check.
+        let fen = "6bn/5Ppk/6pp/8/8/8/8/K7 w - - 0 1";
+        let mut game = game_from_fen(fen);
+        crate::set_tt_size_mb(&mut game, 1);
+        let mv = reply_bounded(&mut game, COLOR_WHITE, 0.5, 50_000);
+        assert_eq!((mv.src, mv.dst, mv.promo), (53, 61, KNIGHT_ID));
+
+        let mut game = game_from_fen(fen);
+        let san = crate::pgn::move_to_san(&mut game, 53, 61, KNIGHT_ID);
+        assert_eq!(san, "f8=N#");
+        assert_eq!(
+            crate::pgn::san_to_move(&mut game, &san),
+            Ok((53, 61, KNIGHT_ID))
+        );
+    }
+
+    #[test]
+    fn insufficient_material_covers_each_dead_ending() {
+        //! Bare kings, a lone minor piece, and same-coloured bishops are dead draws; anything that can still mate is not
+
+        for fen in [
+            "8/8/8/4k3/8/4K3/8/8 w - - 0 1",
+            "8/8/8/4k3/8/4K3/8/2B5 w - - 0 1",
+            "8/8/8/4k3/8/4K3/8/6n1 b - - 0 1",
+            // Bishops on dark squares (c1, f4), then on light ones (f1, f7).
+            "8/8/8/4k3/5b2/4K3/8/2B5 w - - 0 1",
+            "8/5b2/8/4k3/8/4K3/8/5B2 w - - 0 1",
+        ] {
+            assert_eq!(status(fen), GameStatus::InsufficientMaterial, "{}", fen);
+        }
+        for fen in [
+            // Bishops on opposite colours.
+            "8/8/8/4k3/8/4K3/8/2B2b2 w - - 0 1",
+            // Bishop and knight, and two knights, can still mate.
+            "8/8/8/4k3/8/4K3/8/2B3N1 w - - 0 1",
+            "8/8/8/4k3/8/4K3/8/1N4N1 w - - 0 1",
+            "8/8/8/4k3/8/4K3/4P3/8 w - - 0 1",
+        ] {
+            assert_eq!(status(fen), GameStatus::Playing, "{}", fen);
+        }
+    }
+
+    #[test]
+    fn terminal_statuses_from_fen() {
+        //! Mate, stalemate and dead positions are reported; mating material is not a draw
+
+        assert_eq!(
+            status("7k/6Q1/6K1/8/8/8/8/8 b - - 0 1"),
+            GameStatus::Checkmate
+        );
+        assert_eq!(
+            status("7k/5Q2/6K1/8/8/8/8/8 b - - 0 1"),
+            GameStatus::Stalemate
+        );
+        assert_eq!(
+            status("8/8/8/4k3/8/4K3/5N2/8 w - - 0 1"),
+            GameStatus::InsufficientMaterial
+        );
+        assert_eq!(
+            status("8/8/8/4k3/5b2/4K3/8/2B5 w - - 0 1"),
+            GameStatus::InsufficientMaterial
+        );
+        assert_eq!(
+            status("8/8/8/4k3/8/4K3/5R2/8 w - - 99 80"),
+            GameStatus::Playing
+        );
+        assert_eq!(
+            status("8/8/8/4k3/8/4K3/5R2/8 w - - 100 80"),
+            GameStatus::FiftyMoveRule
+        );
+        assert!(GameStatus::FiftyMoveRule.is_draw());
+        assert!(!GameStatus::Checkmate.is_draw());
+        assert!(!GameStatus::Playing.is_terminal());
+    }
 }
@@ -3,7 +3,8 @@
 //! A compact set of mainstream theory lines (UCI moves from the start
 //! position). [`book_move`] returns a book continuation for the game so far,
 //! picking pseudo-randomly between candidate lines per process so the engine
-//! doesn't play the same opening every game.
+//! doesn't play the same opening every game. [`book_move_seeded`] makes that
+//! choice reproducible from a caller-supplied seed.
 //!
 //! Callers MUST validate the returned move for legality before playing it
 //! (`is_legal_move`) — a typo in a line must degrade to "out of book", never
@@ -97,6 +98,50 @@ pub fn book_move(moves_played: &[&str]) -> Option<&'static str> {
     Some(candidates[idx])
 }
 
+/// Like [`book_move`], but the pick is driven by `seed`: the same seed and
+/// moves always give the same continuation, different seeds spread across
+/// the book. Candidates are weighted by how many book lines play them, so
+/// mainstream replies come up more often than sidelines.
+pub fn book_move_seeded(moves_played: &[&str], seed: u64) -> Option<&'static str> {
+    if moves_played.len() >= 16 {
+        return None;
+    }
+
+    let candidates: Vec<&'static str> = BOOK_LINES
+        .iter()
+        .filter_map(|line| {
+            line.split_whitespace()
+                .nth(moves_played.len())
+                .map(|next| (line, next))
+        })
+        .filter(|(line, _)| {
+            line.split_whitespace()
+                .take(moves_played.len())
+                .eq(moves_played.iter().copied())
+        })
+        .map(|(_, next)| next)
+        .collect();
+
+    if candidates.is_empty() {
+        return None;
+    }
+
+    // FNV-1a over the moves, mixed with the seed through a splitmix64
+    // finaliser so neighbouring seeds land on unrelated candidates.
+    let mut h: u64 = 0xcbf2_9ce4_8422_2325;
+    for m in moves_played {
+        for b in m.bytes().chain(core::iter::once(b' ')) {
+            h = (h ^ b as u64).wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+    let mut z = h ^ seed.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^= z >> 31;
+
+    Some(candidates[(z % candidates.len() as u64) as usize])
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -148,5 +193,28 @@ mod tests {
     #[test]
     fn out_of_book_returns_none() {
         assert!(book_move(&["a2a3"]).is_none());
+        assert!(book_move_seeded(&["a2a3"], 7).is_none());
+    }
+
+    #[test]
+    fn seeded_book_repeats_per_seed_and_varies_across_seeds() {
+        //! One seed always opens the same way; different seeds can diverge on move one
+
+        let first = book_move_seeded(&[], 42).expect("book must open");
+        for _ in 0..10 {
+            assert_eq!(book_move_seeded(&[], 42), Some(first));
+        }
+
+        let openings: std::collections::HashSet<&str> = (0..64)
+            .filter_map(|seed| book_move_seeded(&[], seed))
+            .collect();
+        assert!(
+            openings.len() > 1,
+            "64 seeds all opened with {:?}",
+            openings
+        );
+
+        let reply = book_move_seeded(&["d2d4"], 3).expect("book must answer 1.d4");
+        assert!(["d7d5", "g8f6"].contains(&reply));
     }
 }
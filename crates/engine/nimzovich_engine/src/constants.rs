@@ -328,6 +328,9 @@ pub const TTE_SIZE: usize = 1024 * 1024 * 2; // 2M entries for native
 
 pub const TT_TRY: usize = 5;
 
+/// Most threads a parallel search will use.
+pub const MAX_SEARCH_THREADS: usize = 16;
+
 #[allow(dead_code)] // Part of engine's public API - search extension configuration
 pub const V_RATIO: i64 = 8;
 #[allow(dead_code)] // Part of engine's public API - search extension configuration
@@ -2,11 +2,22 @@
 //!
 //! Positions hash to a single `u64` (standard Zobrist: one random bitstring
 //! per piece/square plus a side-to-move bitstring, combined by XOR and
-//! updated incrementally). This replaced a 24/32-byte byte-array scheme whose
+//! updated incrementally). Castling rights and a capturable en-passant
+//! square have bitstrings of their own (see [`rights_hash`]), so repetition
+//! detection doesn't take two positions that differ only in those for the
+//! same one. This replaced a 24/32-byte byte-array scheme whose
 //! per-byte XOR loops and 24-byte key compares were pure overhead — a u64
 //! provides ample collision resistance for a TT with power-of-two indexing.
+//!
+//! A single-threaded search reads and writes `Game::tt` directly. A parallel
+//! search (see `search::parallel`) moves the table into a [`SharedTT`]
+//! instead, where every bucket sits behind its own lock: threads only contend
+//! when they touch the same bucket, and a reader never sees a half-written
+//! entry.
 
+use super::constants::*;
 use super::types::*;
+use std::sync::{Mutex, MutexGuard};
 
 /// Return the current pre-calculated hash
 #[inline]
@@ -32,6 +43,12 @@ pub fn init_zobrist(game: &mut Game) {
         }
     }
     game.zobrist_black_turn = splitmix64(&mut seed);
+    for key in game.zobrist_castling.iter_mut() {
+        *key = splitmix64(&mut seed);
+    }
+    for key in game.zobrist_en_passant.iter_mut() {
+        *key = splitmix64(&mut seed);
+    }
     game.current_hash = compute_full_hash(game);
 }
 
@@ -47,9 +64,57 @@ pub fn compute_full_hash(game: &Game) -> BitBuffer192 {
     if game.move_counter % 2 != 0 {
         hash ^= game.zobrist_black_turn;
     }
+    hash ^ rights_hash(game)
+}
+
+/// The part of the hash for castling rights and en passant.
+///
+/// A castling right counts while its king and rook are unmoved and on their
+/// squares; an en-passant square only while an enemy pawn stands ready to
+/// take on it, as a position is the same for repetition whenever the capture
+/// isn't possible. It depends on the board as well as the flags, so moves
+/// XOR it out before changing anything and back in once they're done.
+pub fn rights_hash(game: &Game) -> u64 {
+    let kings_moved = [game.white_king_has_moved, game.black_king_has_moved];
+    // Short side first, as in `zobrist_castling`.
+    let rooks_moved = [
+        [game.white_rook_7_has_moved, game.white_rook_0_has_moved],
+        [game.black_rook_63_has_moved, game.black_rook_56_has_moved],
+    ];
+    let mut hash = 0;
+    for (side, (king_sq, sign)) in [(4, 1), (60, -1)].into_iter().enumerate() {
+        for (wing, rook_sq) in [king_sq + 3, king_sq - 4].into_iter().enumerate() {
+            if !kings_moved[side]
+                && !rooks_moved[side][wing]
+                && game.board[king_sq] == sign * KING_ID
+                && game.board[rook_sq] == sign * ROOK_ID
+            {
+                hash ^= game.zobrist_castling[side * 2 + wing];
+            }
+        }
+    }
+    if let Some(target) = game.en_passant_target {
+        if en_passant_capturable(game, target) {
+            hash ^= game.zobrist_en_passant[(target % 8) as usize];
+        }
+    }
     hash
 }
 
+/// Whether a pawn of the side to take stands beside the pawn that just
+/// passed `target`.
+fn en_passant_capturable(game: &Game, target: i8) -> bool {
+    let (pawn_sq, taker) = match target / 8 {
+        2 => (target + 8, B_PAWN),
+        5 => (target - 8, W_PAWN),
+        _ => return false,
+    };
+    let file = target % 8;
+    [file - 1, file + 1]
+        .into_iter()
+        .any(|f| (0..8).contains(&f) && game.board[(pawn_sq - file + f) as usize] == taker)
+}
+
 /// Toggle the side-to-move in the current hash
 #[inline]
 pub fn toggle_turn(game: &mut Game) {
@@ -79,11 +144,42 @@ pub fn hash_to_index(hash: &BitBuffer192, capacity: usize) -> usize {
     (*hash as usize) & (capacity - 1)
 }
 
+/// Transposition table shared between the threads of a parallel search.
+pub struct SharedTT {
+    buckets: Box<[Mutex<TTE>]>,
+}
+
+impl SharedTT {
+    /// An empty table of `capacity` buckets (a power of two).
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            buckets: (0..capacity).map(|_| Mutex::new(TTE::default())).collect(),
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.buckets.len()
+    }
+
+    fn bucket(&self, index: usize) -> MutexGuard<'_, TTE> {
+        // A thread that panicked mid-store leaves at worst one stale entry,
+        // which the key check already guards against.
+        self.buckets[index]
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
 /// Probe transposition table (returns copy to avoid borrow issues)
 pub fn tt_probe(game: &Game, hash: &BitBuffer192) -> Option<HashResult> {
     let index = hash_to_index(hash, game.tt_capacity);
-    let tte = &game.tt[index];
+    match &game.shared_tt {
+        Some(shared) => probe_bucket(&shared.bucket(index), hash),
+        None => probe_bucket(&game.tt[index], hash),
+    }
+}
 
+fn probe_bucket(tte: &TTE, hash: &BitBuffer192) -> Option<HashResult> {
     for entry in &tte.h {
         if entry.key == *hash && entry.res.hit > 0 {
             return Some(entry.res);
@@ -96,8 +192,13 @@ pub fn tt_probe(game: &Game, hash: &BitBuffer192) -> Option<HashResult> {
 /// Store position in transposition table
 pub fn tt_store(game: &mut Game, hash: BitBuffer192, result: HashResult, priority: i64) {
     let index = hash_to_index(&hash, game.tt_capacity);
-    let tte = &mut game.tt[index];
+    match &game.shared_tt {
+        Some(shared) => store_bucket(&mut shared.bucket(index), hash, result, priority),
+        None => store_bucket(&mut game.tt[index], hash, result, priority),
+    }
+}
 
+fn store_bucket(tte: &mut TTE, hash: BitBuffer192, result: HashResult, priority: i64) {
     // Find slot to replace (lowest priority)
     let mut min_pri_idx = 0;
     let mut min_pri = i64::MAX;
@@ -39,8 +39,10 @@
 //! - **`hash`** (TODO) - Zobrist hashing, transposition table lookup/storage
 //!
 //! ### AI Engine (Depends on all above)
-//! - **`search`** - Minimax with alpha-beta pruning, iterative deepening
-//! - **`api`** - Public functions: `new_game()`, `reply()`, `do_move()`
+//! - **`search`** - Minimax with alpha-beta pruning, iterative deepening,
+//!   and `reply_incremental()` for node-budgeted slices on hosts that can't
+//!   block (WASM main thread)
+//! - **`api`** - Public functions: `new_game()`, `reply()`, `reply_bounded()`, `do_move()`
 //! - **`error`** - Error types for engine operations
 //!
 //! ## Algorithm Overview: Alpha-Beta Pruning
@@ -80,7 +82,7 @@
 //!
 //! ```rust,ignore
 //! // In src/game/ai/mod.rs (new module)
-//! use chess_engine::{new_game, reply, do_move, Game, Move, STATE_CHECKMATE};
+//! use chess_engine::{new_game, reply, do_move, Game, Move, STATE_CHECKMATE, STATE_STALEMATE};
 //! use bevy::prelude::*;
 //! use bevy::tasks::{AsyncComputeTaskPool, Task};
 //! use std::sync::{Arc, Mutex};
@@ -135,8 +137,13 @@
 //!         // AI move completed!
 //!         commands.remove_resource::<PendingAIMove>();
 //!
-//!         if ai_move.state == STATE_CHECKMATE {
-//!             info!("AI declares checkmate!");
+//!         // No legal move: src == dst and `state` tells mate from stalemate.
+//!         if ai_move.src == ai_move.dst {
+//!             match ai_move.state {
+//!                 STATE_CHECKMATE => info!("AI is checkmated"),
+//!                 STATE_STALEMATE => info!("AI is stalemated — draw"),
+//!                 _ => {}
+//!             }
 //!             return;
 //!         }
 //!
@@ -250,16 +257,20 @@ mod pgn;
 #[cfg(feature = "std")]
 pub mod book;
 
+#[cfg(feature = "std")]
+pub mod opening_book;
+
 // Re-export public API
 #[cfg(feature = "search")]
-pub use api::reply;
+pub use api::{reply, reply_bounded, reply_limited, set_search_threads};
 pub use api::{
-    do_move, do_move_with_promo, get_game_state, is_legal_move, is_legal_move_unchecked,
+    do_move, do_move_with_promo, get_game_state, get_game_status, is_legal_move,
+    is_legal_move_unchecked, GameStatus,
 };
 #[cfg(feature = "std")]
 pub use api::{
-    game_from_fen, game_from_fen_no_tt, game_to_fen, new_game, new_game_no_tt, reset_game,
-    set_game_from_fen, set_tt_size_mb,
+    game_from_fen, game_from_fen_no_tt, game_to_fen, legal_moves_for_fen, new_game,
+    new_game_no_tt, reset_game, set_game_from_fen, set_tt_size_mb,
 };
 pub use error::{ChessEngineError, ChessEngineResult};
 #[cfg(feature = "std")]
@@ -270,6 +281,10 @@ pub use pgn::{
     move_to_san, parse_pgn, parse_pgn_annotated, san_to_move, MoveQuality, ParsedPgnGame,
     PerPlyAnnotation, PgnAssembler, PgnParseError, PgnResult,
 };
+#[cfg(feature = "search")]
+pub use search::{reply_incremental, IncrementalSearch, SearchProgress};
+#[cfg(feature = "search")]
+pub use see::{see, see_value};
 
 // Re-export types
 pub use types::{Board, Color, Game, Move, Position, KK};
@@ -278,8 +293,8 @@ pub use types::{Board, Color, Game, Move, Position, KK};
 pub use constants::{
     BISHOP_ID, BISHOP_VALUE, COLOR_BLACK, COLOR_WHITE, FLAG_CAPTURE, FLAG_EP, FLAG_PLAIN,
     FLAG_PROCAP, FLAG_PROMOTION, KING_ID, KING_VALUE, KING_VALUE_DIV_2, KNIGHT_ID, KNIGHT_VALUE,
-    PAWN_ID, PAWN_VALUE, QUEEN_ID, QUEEN_VALUE, ROOK_ID, ROOK_VALUE, STATE_CHECKMATE,
-    STATE_PLAYING, STATE_STALEMATE, SURE_CHECKMATE,
+    MAX_SEARCH_THREADS, PAWN_ID, PAWN_VALUE, QUEEN_ID, QUEEN_VALUE, ROOK_ID, ROOK_VALUE,
+    STATE_CHECKMATE, STATE_PLAYING, STATE_STALEMATE, SURE_CHECKMATE,
 };
 
 // Re-export on-chain validation API
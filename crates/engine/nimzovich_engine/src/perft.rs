@@ -261,6 +261,45 @@ mod tests {
         assert_eq!(perft(&mut g, 4, fen_color(fen)), 43_238);
     }
 
+    // ── Position 4 and its mirror: castling rights, promotions, checks ──────
+    // The mirrored position has the colours swapped, so both counts agree.
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn pos4_depth1() {
+        use crate::api::game::game_from_fen;
+        let fen = "r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1RK1 w kq - 0 1";
+        let mut g = game_from_fen(fen);
+        assert_eq!(perft(&mut g, 1, fen_color(fen)), 6);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn pos4_depth2() {
+        use crate::api::game::game_from_fen;
+        let fen = "r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1RK1 w kq - 0 1";
+        let mut g = game_from_fen(fen);
+        assert_eq!(perft(&mut g, 2, fen_color(fen)), 264);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn pos4_depth3() {
+        use crate::api::game::game_from_fen;
+        let fen = "r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1RK1 w kq - 0 1";
+        let mut g = game_from_fen(fen);
+        assert_eq!(perft(&mut g, 3, fen_color(fen)), 9_467);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn pos4_mirrored_depth3() {
+        use crate::api::game::game_from_fen;
+        let fen = "r2q1rk1/pP1p2pp/Q4n2/bbp1p3/Np6/1B3NBn/pPPP1PPP/R3K2R b KQ - 0 1";
+        let mut g = game_from_fen(fen);
+        assert_eq!(perft(&mut g, 3, fen_color(fen)), 9_467);
+    }
+
     // ── Position 5: promotion-heavy ───────────────────────────────────────────
 
     #[cfg(feature = "std")]
@@ -289,4 +328,33 @@ mod tests {
         let mut g = game_from_fen(fen);
         assert_eq!(perft(&mut g, 3, fen_color(fen)), 62_379);
     }
+
+    // ── Position 6: a quiet middlegame ──────────────────────────────────────
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn pos6_depth1() {
+        use crate::api::game::game_from_fen;
+        let fen = "r4rk1/1pp1qppp/p1np1n2/2b1p1B1/2B1P1b1/P1NP1N2/1PP1QPPP/R4RK1 w - - 0 10";
+        let mut g = game_from_fen(fen);
+        assert_eq!(perft(&mut g, 1, fen_color(fen)), 46);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn pos6_depth2() {
+        use crate::api::game::game_from_fen;
+        let fen = "r4rk1/1pp1qppp/p1np1n2/2b1p1B1/2B1P1b1/P1NP1N2/1PP1QPPP/R4RK1 w - - 0 10";
+        let mut g = game_from_fen(fen);
+        assert_eq!(perft(&mut g, 2, fen_color(fen)), 2_079);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn pos6_depth3() {
+        use crate::api::game::game_from_fen;
+        let fen = "r4rk1/1pp1qppp/p1np1n2/2b1p1B1/2B1P1b1/P1NP1N2/1PP1QPPP/R4RK1 w - - 0 10";
+        let mut g = game_from_fen(fen);
+        assert_eq!(perft(&mut g, 3, fen_color(fen)), 89_890);
+    }
 }
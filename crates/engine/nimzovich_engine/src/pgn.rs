@@ -122,7 +122,8 @@ pub fn move_to_san(game: &mut Game, src: i8, dst: i8, promo: i8) -> String {
     #[cfg(feature = "search")]
     let hash_before = game.current_hash;
 
-    crate::api::moves::do_move(game, src, dst, true);
+    // With the promoted piece itself: a knight may check where a queen wouldn't.
+    crate::api::moves::do_move_with_promo(game, src, dst, true, promo);
     let opponent = -color;
     let in_check = is_in_check(game, opponent);
     let has_legal = has_any_legal_move(game, opponent);
@@ -465,6 +466,26 @@ pub fn parse_pgn(text: &str) -> Result<ParsedPgnGame, PgnParseError> {
             continue;
         }
 
+        // Numeric annotation glyphs ("$1", "$14") say nothing about the move.
+        if token.starts_with('$') {
+            continue;
+        }
+
+        // Move numbers written against the move ("12.e4", "12...e5") and
+        // annotation glyphs after it ("e4!", "Nf3?!").
+        let token = match token.rfind('.') {
+            Some(dot)
+                if token[..dot]
+                    .trim_end_matches('.')
+                    .bytes()
+                    .all(|b| b.is_ascii_digit()) =>
+            {
+                &token[dot + 1..]
+            }
+            _ => token,
+        };
+        let token = token.trim_end_matches(['!', '?']);
+
         // SAN move
         if token.len() >= 2 {
             game.moves.push(token.to_string());
@@ -992,4 +1013,18 @@ mod san_tests {
         let san = move_to_san(&mut game, sq(4, 0), sq(4, 6), 0); // Re1-e7+
         assert_eq!(san, "Re7+");
     }
+
+    /// Comments, variations, NAGs, glued move numbers and "!?" glyphs are
+    /// dropped, leaving only the moves of the main line.
+    #[test]
+    fn parse_pgn_ignores_annotations_around_the_moves() {
+        let pgn = parse_pgn(
+            "[Event \"Casual\"]\n\n1.e4 {best by test} e5!? 2. Nf3 $1 (2. f4 exf4) Nc6 \
+             3...a6?! $6 1-0",
+        )
+        .unwrap();
+        assert_eq!(pgn.moves, ["e4", "e5", "Nf3", "Nc6", "a6"]);
+        assert_eq!(pgn.result, "1-0");
+        assert_eq!(pgn.tags.get("Event").map(String::as_str), Some("Casual"));
+    }
 }
@@ -28,13 +28,24 @@ use core::sync::atomic::Ordering;
 static SP: SearchParams = SearchParams::sarah_tuned();
 
 /// Master switch for the pruning that only became reachable after the
-/// is_capture fix (LMR, LMP, per-move futility). Match-tested 2026-06-12 with
-/// conservative LMR coefficients: REGRESSED −90 vs Rustic A2 (−168 vs −80
-/// baseline, 40 games each). Stays off until per-feature SPRT tuning
-/// (enable one of the three at a time; suspect LMP's limit is too tight and
-/// futility margins too low for the PeSTO eval scale).
+/// is_capture fix (LMP, per-move futility). Match-tested 2026-06-12 together
+/// with LMR: REGRESSED −90 vs Rustic A2 (−168 vs −80 baseline, 40 games
+/// each). LMR has since moved to its own switch
+/// ([`Game::late_move_reductions`]) with a full-depth re-search on PV nodes;
+/// these two stay off until per-feature SPRT tuning (suspect LMP's limit is
+/// too tight and futility margins too low for the PeSTO eval scale).
 const SPECULATIVE_PRUNING: bool = false;
 
+/// Plies from the root searched without reductions, so the root moves and
+/// the replies to them are always compared at full depth.
+const LMR_MIN_PLY: i32 = 2;
+
+/// Most check extensions granted along one line. Enough to see through a
+/// forcing sequence of checks to the mate behind it; past that, perpetual-
+/// check lines fall back to nominal depth instead of searching until
+/// `MAX_PLY`.
+const MAX_CHECK_EXTENSIONS: i32 = 16;
+
 /// Search node type
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 enum NodeType {
@@ -60,9 +71,24 @@ pub(crate) fn alphabeta(
         false,
         0,
         false,
+        0,
     )
 }
 
+/// True (and the search flagged as aborted) once `search_node_limit` nodes
+/// have been visited. Checked on every node, unlike the 1024-node time poll,
+/// so node-bounded searches stop exactly at their budget.
+#[inline]
+pub(crate) fn node_budget_exhausted(game: &mut Game) -> bool {
+    match game.search_node_limit {
+        Some(limit) if game.calls >= limit => {
+            game.abort_search.store(true, Ordering::Relaxed);
+            true
+        }
+        _ => false,
+    }
+}
+
 /// Recursive negamax search with PVS and all pruning guards.
 fn search(
     game: &mut Game,
@@ -74,7 +100,12 @@ fn search(
     cut_node: bool,
     ply: i32,
     skip_null: bool,
+    extensions: i32,
 ) -> ChessEngineResult<i16> {
+    if node_budget_exhausted(game) {
+        return Ok(0);
+    }
+
     // Periodic poll: external abort and the hard wall-clock deadline. The
     // deadline must be checked inside the node loop — time checked only
     // between iterations loses games on the clock when an iteration runs long.
@@ -125,6 +156,17 @@ fn search(
         }
     }
 
+    // Check extension, applied before the leaf test: a side in check at the
+    // horizon gets one more ply so a mate (or the escape from one) is seen by
+    // the full search rather than scored by quiescence stand-pat, which has
+    // no notion of "no legal moves". Capped per line to bound forcing lines.
+    let in_check = is_in_check(game, color);
+    let extend = CHECK_EXTEND && in_check && extensions < MAX_CHECK_EXTENSIONS;
+    let extensions = extensions + extend as i32;
+    if extend {
+        depth += 1;
+    }
+
     // Quiescence at leaf
     if depth <= 0 {
         return quiescence_search(game, alpha, beta, color);
@@ -160,12 +202,6 @@ fn search(
         }
     }
 
-    // Check detection and extension
-    let in_check = is_in_check(game, color);
-    if in_check {
-        depth += 1;
-    }
-
     // Static evaluation, recorded per ply for the `improving` heuristic.
     let eval = evaluate_position(game) * (if color > 0 { 1 } else { -1 });
     let ply_idx = ply as usize; // ply < MAX_PLY = 128 guaranteed above
@@ -219,6 +255,7 @@ fn search(
             !cut_node,
             ply + 1,
             true,
+            extensions,
         )?;
         crate::hash::toggle_turn(game);
 
@@ -310,12 +347,13 @@ fn search(
         let mut new_depth = depth;
 
         // Check extension is applied once, at the checked node's entry (the
-        // `in_check` extension above). Extending here as well would grant +2
+        // `extend` step above). Extending here as well would grant +2
         // per check and let forcing lines grow the search without bound.
 
         // Late Move Reduction (LMR)
         let mut reduction = 0;
-        if SPECULATIVE_PRUNING
+        if game.late_move_reductions
+            && ply >= LMR_MIN_PLY
             && depth >= SP.lmr_depth
             && legal_moves > SP.lmr_move_start + if pv_node { 0 } else { 1 }
             && !is_capture
@@ -356,7 +394,28 @@ fn search(
                 false,
                 ply + 1,
                 false,
+                extensions,
             )?;
+
+            // A reduced PV move that beats alpha is searched again unreduced
+            if reduction > 0 && score > alpha {
+                score = -search(
+                    game,
+                    if pv_node {
+                        NodeType::Pv
+                    } else {
+                        NodeType::NonPv
+                    },
+                    depth - 1,
+                    -beta,
+                    -alpha,
+                    -color,
+                    false,
+                    ply + 1,
+                    false,
+                    extensions,
+                )?;
+            }
         } else {
             // Null-window scout
             score = -search(
@@ -369,6 +428,7 @@ fn search(
                 !cut_node,
                 ply + 1,
                 false,
+                extensions,
             )?;
 
             // Research if scout exceeded alpha
@@ -383,6 +443,7 @@ fn search(
                     false,
                     ply + 1,
                     false,
+                    extensions,
                 )?;
             }
         }
@@ -554,3 +615,69 @@ fn update_histories(game: &mut Game, depth: i32, best_move: KK, searched_quiets:
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::game::game_from_fen;
+
+    #[test]
+    fn check_extension_finds_mate_beyond_nominal_depth() {
+        //! Re8+ Rxe8 Rxe8# is three plies; a depth-2 search only sees the
+        //! mate because the checked king's nodes are extended past the horizon
+
+        let mut game = game_from_fen("3r2k1/5ppp/8/8/8/8/4R3/4R1K1 w - - 0 1");
+        let score = alphabeta(&mut game, 2, -AB_INF, AB_INF, COLOR_WHITE).unwrap();
+        assert!(
+            score > MATE_BOUND,
+            "expected a mate score at depth 2, got {}",
+            score
+        );
+    }
+
+    /// Best move and node count of a fixed-depth search of `fen`.
+    fn search_to_depth(fen: &str, depth: i64, lmr: bool) -> (i64, i64, i64) {
+        let mut game = game_from_fen(fen);
+        game.abs_max_depth = depth;
+        game.late_move_reductions = lmr;
+        let color = if fen.contains(" w ") {
+            COLOR_WHITE
+        } else {
+            COLOR_BLACK
+        };
+        let mv =
+            super::super::iterative::iterative_deepening_bounded(&mut game, 600.0, None, color);
+        (mv.src, mv.dst, game.calls)
+    }
+
+    #[test]
+    fn late_move_reductions_keep_the_best_move_with_fewer_nodes() {
+        //! On tactical positions LMR finds the same move as the full-width
+        //! search while visiting substantially fewer nodes
+
+        const POSITIONS: [&str; 4] = [
+            // Scholar's mate: Qxf7#
+            "r1bqkb1r/pppp1ppp/2n2n2/4p2Q/2B1P3/8/PPPP1PPP/RNB1K1NR w KQkq - 4 4",
+            // WAC.001: Qg6
+            "2rr3k/pp3pp1/1nnqbN1p/3pN3/2pP4/2P3Q1/PPB4P/R4RK1 w - - 0 1",
+            // WAC.002: Rxb2
+            "8/7p/5k2/5p2/p1p2P2/Pr1pPK2/1P1R3P/8 b - - 0 1",
+            // WAC.003: Rg3
+            "5rk1/1ppb3p/p1pb4/6q1/3P1p1r/2P1R2P/PP1BQ1P1/5RKN w - - 0 1",
+        ];
+
+        let mut nodes_full = 0;
+        let mut nodes_lmr = 0;
+        for fen in POSITIONS {
+            let (src, dst, full) = search_to_depth(fen, 6, false);
+            let (lmr_src, lmr_dst, reduced) = search_to_depth(fen, 6, true);
+            assert_eq!((lmr_src, lmr_dst), (src, dst), "best move changed in {fen}");
+            nodes_full += full;
+            nodes_lmr += reduced;
+        }
+        assert!(
+            nodes_lmr * 10 < nodes_full * 7,
+            "LMR searched {nodes_lmr} nodes against {nodes_full} without"
+        );
+    }
+}
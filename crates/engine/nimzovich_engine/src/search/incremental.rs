@@ -0,0 +1,207 @@
+//! Resumable, node-budgeted search
+//!
+//! Drives iterative deepening one slice at a time so a caller without threads
+//! (the WASM build, where the search would otherwise block the page) can
+//! interleave it with rendering. Each [`reply_incremental`] call searches at
+//! most `node_budget` nodes and returns [`SearchProgress::Thinking`] until the
+//! search settles on a move.
+//!
+//! An iteration that runs out of budget mid-search is discarded and retried on
+//! the next call; the transposition-table entries from earlier iterations make
+//! each retry cheaper. A depth that still cannot be finished after
+//! [`MAX_STALLED_CALLS`] attempts ends the search with the last completed
+//! iteration's move. No wall-clock time is read, so this path is safe on
+//! targets where `std::time::Instant` is unavailable.
+
+use super::alphabeta::alphabeta;
+use super::iterative::{fill_first_legal_move, update_best_move_from_tt};
+use crate::constants::*;
+use crate::types::*;
+use core::sync::atomic::Ordering;
+
+/// Consecutive over-budget attempts at one depth before settling for the
+/// previous iteration's move.
+pub const MAX_STALLED_CALLS: u32 = 8;
+
+/// Result of one [`reply_incremental`] slice.
+#[derive(Debug, Clone, Copy)]
+pub enum SearchProgress {
+    /// Budget used up; call again to keep searching.
+    Thinking,
+    /// The search has finished with this move.
+    Done(Move),
+}
+
+/// State carried between [`reply_incremental`] calls for one search.
+#[derive(Debug, Clone)]
+pub struct IncrementalSearch {
+    color: Color,
+    next_depth: usize,
+    best_move: Move,
+    best_score: i16,
+    completed_depth: usize,
+    stalled_calls: u32,
+    total_nodes: i64,
+    max_total_nodes: i64,
+    result: Option<Move>,
+}
+
+impl IncrementalSearch {
+    /// Start a search for `color` that gives up after `max_total_nodes`
+    /// nodes across all calls.
+    pub fn new(color: Color, max_total_nodes: i64) -> Self {
+        Self {
+            color,
+            next_depth: 1,
+            best_move: Move::default(),
+            best_score: LOWEST_SCORE as i16,
+            completed_depth: 0,
+            stalled_calls: 0,
+            total_nodes: 0,
+            max_total_nodes: max_total_nodes.max(1),
+            result: None,
+        }
+    }
+
+    /// Nodes searched so far across all calls.
+    pub fn total_nodes(&self) -> i64 {
+        self.total_nodes
+    }
+
+    /// Deepest fully completed iteration.
+    pub fn completed_depth(&self) -> usize {
+        self.completed_depth
+    }
+
+    fn finish(&mut self, game: &mut Game) -> Move {
+        if self.best_move.src == 0 && self.best_move.dst == 0 {
+            fill_first_legal_move(game, self.color, &mut self.best_move, self.best_score);
+        }
+        self.result = Some(self.best_move);
+        self.best_move
+    }
+}
+
+/// Advance `search` by at most `node_budget` nodes.
+///
+/// Returns [`SearchProgress::Done`] once a move is chosen; further calls keep
+/// returning the same move without searching. `game.calls` holds the nodes
+/// used by the latest slice and never exceeds `node_budget`.
+pub fn reply_incremental(
+    game: &mut Game,
+    search: &mut IncrementalSearch,
+    node_budget: i64,
+) -> SearchProgress {
+    if let Some(mv) = search.result {
+        return SearchProgress::Done(mv);
+    }
+
+    let depth_limit = if game.abs_max_depth > 0 {
+        (game.abs_max_depth as usize).min(MAX_DEPTH)
+    } else {
+        MAX_DEPTH
+    };
+
+    game.calls = 0;
+    game.abort_search.store(false, Ordering::Relaxed);
+    game.search_deadline = None;
+    game.search_node_limit = Some(node_budget.max(1));
+
+    let depth = search.next_depth;
+    let result = alphabeta(game, depth as i32, -AB_INF, AB_INF, search.color);
+    let aborted = game.abort_search.load(Ordering::Relaxed);
+
+    game.search_node_limit = None;
+    game.abort_search.store(false, Ordering::Relaxed);
+    search.total_nodes += game.calls;
+
+    match result {
+        Ok(score) if !aborted => {
+            search.best_score = score;
+            update_best_move_from_tt(game, &mut search.best_move, score);
+            search.completed_depth = depth;
+            game.max_depth_so_far = depth as i64;
+            search.next_depth += 1;
+            search.stalled_calls = 0;
+
+            if score.abs() > KING_VALUE_DIV_2 {
+                search.best_move.state = STATE_CHECKMATE;
+                search.best_move.checkmate_in = ((KING_VALUE - score.abs()) / 2) as i64;
+                return SearchProgress::Done(search.finish(game));
+            }
+            if depth >= depth_limit {
+                return SearchProgress::Done(search.finish(game));
+            }
+        }
+        _ => search.stalled_calls += 1,
+    }
+
+    if search.total_nodes >= search.max_total_nodes || search.stalled_calls >= MAX_STALLED_CALLS {
+        return SearchProgress::Done(search.finish(game));
+    }
+    SearchProgress::Thinking
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::game::game_from_fen;
+    use crate::{is_legal_move, COLOR_WHITE};
+
+    const START: &str = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1";
+
+    #[test]
+    fn reply_incremental_finishes_within_per_call_budget() {
+        //! Repeated slices eventually yield a legal move and no slice overruns
+
+        const BUDGET: i64 = 2_000;
+        let mut game = game_from_fen(START);
+        let mut search = IncrementalSearch::new(COLOR_WHITE, 60_000);
+
+        let mut calls = 0;
+        let mv = loop {
+            calls += 1;
+            assert!(calls < 1_000, "search never finished");
+            let progress = reply_incremental(&mut game, &mut search, BUDGET);
+            assert!(
+                game.calls <= BUDGET,
+                "slice searched {} nodes, budget {}",
+                game.calls,
+                BUDGET
+            );
+            if let SearchProgress::Done(mv) = progress {
+                break mv;
+            }
+        };
+
+        assert!(calls > 1, "budget should force more than one slice");
+        assert!(search.completed_depth() >= 1);
+        assert!(is_legal_move(
+            &mut game,
+            mv.src as i8,
+            mv.dst as i8,
+            COLOR_WHITE
+        ));
+    }
+
+    #[test]
+    fn reply_incremental_with_tiny_budget_still_moves() {
+        //! A budget too small for depth 1 falls back to a legal move
+
+        let mut game = game_from_fen(START);
+        let mut search = IncrementalSearch::new(COLOR_WHITE, i64::MAX);
+
+        let mv = loop {
+            if let SearchProgress::Done(mv) = reply_incremental(&mut game, &mut search, 1) {
+                break mv;
+            }
+            assert!(game.calls <= 1);
+        };
+        assert!(is_legal_move(
+            &mut game,
+            mv.src as i8,
+            mv.dst as i8,
+            COLOR_WHITE
+        ));
+    }
+}
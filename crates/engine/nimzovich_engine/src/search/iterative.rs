@@ -5,7 +5,9 @@
 
 use super::alphabeta::alphabeta;
 use super::make_unmake::{make_move, unmake_move};
+use super::parallel::search_threaded;
 use super::params::SearchParams;
+use super::time::TimeManager;
 use crate::constants::*;
 use crate::hash::*;
 use crate::move_gen::is_in_check;
@@ -16,8 +18,15 @@ use std::time::Instant;
 
 static SP: SearchParams = SearchParams::sarah_tuned();
 
-/// Iterative deepening search (Synchronous)
-pub fn iterative_deepening(game: &mut Game, max_time_secs: f32, color: Color) -> Move {
+/// Iterative deepening with an optional hard node limit on top of the time
+/// budget. Whichever runs out first ends the search; the deepest completed
+/// iteration's move is returned.
+pub fn iterative_deepening_bounded(
+    game: &mut Game,
+    max_time_secs: f32,
+    max_nodes: Option<i64>,
+    color: Color,
+) -> Move {
     let start_time = Instant::now();
     let mut best_move = Move::default();
     let mut best_score = LOWEST_SCORE as i16;
@@ -31,10 +40,12 @@ pub fn iterative_deepening(game: &mut Game, max_time_secs: f32, color: Color) ->
     // Time budget: a hard wall-clock deadline polled inside the search (so a
     // long iteration aborts instead of flagging), plus a soft limit below
     // that stops starting new iterations (the next iteration typically costs
-    // 2-4x the previous one).
+    // 2-4x the previous one). The soft limit grows in sharp positions and
+    // while the search is unsettled; see `TimeManager`.
     let budget = max_time_secs.max(0.01);
-    let soft_limit = budget * 0.7;
-    game.search_deadline = Some(start_time + std::time::Duration::from_secs_f32(budget * 0.95));
+    let mut time = TimeManager::new(game, color, budget);
+    game.search_deadline = Some(start_time + std::time::Duration::from_secs_f32(time.hard_limit()));
+    game.search_node_limit = max_nodes.map(|n| n.max(1));
 
     let mut prev_score = 0i16;
 
@@ -82,6 +93,7 @@ pub fn iterative_deepening(game: &mut Game, max_time_secs: f32, color: Color) ->
             };
         }
 
+        let swing = score.saturating_sub(prev_score);
         prev_score = score;
 
         // If the iteration was aborted (deadline hit inside the search or an
@@ -93,7 +105,11 @@ pub fn iterative_deepening(game: &mut Game, max_time_secs: f32, color: Color) ->
 
         game.max_depth_so_far = depth as i64;
         best_score = score;
+        let previous_best = (best_move.src, best_move.dst);
         update_best_move_from_tt(game, &mut best_move, score);
+        if depth > 1 {
+            time.record_iteration(swing, (best_move.src, best_move.dst) != previous_best);
+        }
         #[cfg(feature = "salewskiChessDebug")]
         eprintln!(
             "[id] depth {} score {} tt_best {}->{}",
@@ -108,35 +124,56 @@ pub fn iterative_deepening(game: &mut Game, max_time_secs: f32, color: Color) ->
         }
 
         // Soft limit: don't start an iteration we can't plausibly finish.
-        if start_time.elapsed().as_secs_f32() > soft_limit {
+        if start_time.elapsed().as_secs_f32() >= time.soft_limit() {
             break;
         }
     }
 
     game.search_deadline = None;
+    game.search_node_limit = None;
 
     // If no move found, find any legal move
     if best_move.src == 0 && best_move.dst == 0 {
-        let moves = generate_pseudo_legal_moves(game, color);
-        for mv in moves {
-            let undo = make_move(game, mv);
-            let legal = !is_in_check(game, color);
-            unmake_move(game, mv, undo);
-
-            if legal {
-                best_move.src = mv.src as i64;
-                best_move.dst = mv.dst as i64;
-                best_move.score = best_score as i64;
-                best_move.promo = (mv.nxt_dir_idx >> 4) as i8;
-                break;
-            }
-        }
+        fill_first_legal_move(game, color, &mut best_move, best_score);
     }
 
     best_move
 }
 
-fn update_best_move_from_tt(game: &Game, best_move: &mut Move, score: i16) {
+/// Fallback when no iteration completed: play the first legal move found.
+///
+/// With no legal move at all the move stays `src == dst == 0` and `state`
+/// says why: [`STATE_CHECKMATE`] if `color` is in check, otherwise
+/// [`STATE_STALEMATE`].
+pub(super) fn fill_first_legal_move(
+    game: &mut Game,
+    color: Color,
+    best_move: &mut Move,
+    score: i16,
+) {
+    let moves = generate_pseudo_legal_moves(game, color);
+    for mv in moves {
+        let undo = make_move(game, mv);
+        let legal = !is_in_check(game, color);
+        unmake_move(game, mv, undo);
+
+        if legal {
+            best_move.src = mv.src as i64;
+            best_move.dst = mv.dst as i64;
+            best_move.score = score as i64;
+            best_move.promo = (mv.nxt_dir_idx >> 4) as i8;
+            return;
+        }
+    }
+    best_move.state = if is_in_check(game, color) {
+        STATE_CHECKMATE
+    } else {
+        STATE_STALEMATE
+    };
+    best_move.checkmate_in = 0;
+}
+
+pub(super) fn update_best_move_from_tt(game: &Game, best_move: &mut Move, score: i16) {
     let hash = position_hash(game);
     if let Some(cached) = tt_probe(game, &hash) {
         if !cached.h.is_empty() {
@@ -149,7 +186,17 @@ fn update_best_move_from_tt(game: &Game, best_move: &mut Move, score: i16) {
     }
 }
 
-/// Find best move for current position
+/// Find best move for current position, on `game.search_threads` threads
 pub fn find_best_move(game: &mut Game, think_time: f32, color: Color) -> Move {
-    iterative_deepening(game, think_time, color)
+    search_threaded(game, think_time, None, color)
+}
+
+/// Find best move within both a time budget and a hard node limit.
+pub fn find_best_move_bounded(
+    game: &mut Game,
+    think_time: f32,
+    max_nodes: i64,
+    color: Color,
+) -> Move {
+    search_threaded(game, think_time, Some(max_nodes), color)
 }
@@ -5,7 +5,7 @@
 
 use crate::board::update_bitboards;
 use crate::constants::*;
-use crate::hash::{toggle_turn, update_hash};
+use crate::hash::{rights_hash, toggle_turn, update_hash};
 use crate::types::*;
 
 /// Information needed to fully undo a move during search
@@ -41,6 +41,9 @@ pub(crate) fn make_move(game: &mut Game, mv: KK) -> UndoInfo {
             game.black_rook_63_has_moved,
         ],
     };
+    // Castling and en passant are about to change; their part of the hash
+    // goes back in once they have.
+    game.current_hash ^= rights_hash(game);
 
     // 1. Handle En Passant Capture
     if piece_type == PAWN_ID && game.en_passant_target == Some(dst as i8) {
@@ -137,6 +140,8 @@ pub(crate) fn make_move(game: &mut Game, mv: KK) -> UndoInfo {
         game.halfmove_clock += 1;
     }
 
+    game.current_hash ^= rights_hash(game);
+
     // Record the new position for repetition detection.
     game.hash_history.push(game.current_hash);
 
@@ -152,6 +157,7 @@ pub(crate) fn unmake_move(game: &mut Game, mv: KK, undo: UndoInfo) {
 
     game.hash_history.pop();
     game.halfmove_clock = undo.old_halfmove_clock;
+    game.current_hash ^= rights_hash(game);
 
     toggle_turn(game);
 
@@ -213,4 +219,42 @@ pub(crate) fn unmake_move(game: &mut Game, mv: KK, undo: UndoInfo) {
     game.black_rook_56_has_moved = undo.flags[4];
     game.black_rook_63_has_moved = undo.flags[5];
     game.move_counter -= 1;
+    game.current_hash ^= rights_hash(game);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::game::game_from_fen_no_tt;
+    use crate::hash::compute_full_hash;
+    use crate::move_gen::generate_pseudo_legal_moves;
+
+    #[test]
+    fn make_and_unmake_keep_the_hash_in_step() {
+        //! After every move, including castling, en passant and promotion, the incremental hash matches a fresh one, and unmaking restores it
+
+        for (fen, color) in [
+            ("r3k2r/8/8/8/8/8/8/R3K2R w KQkq - 0 1", COLOR_WHITE),
+            ("r3k2r/8/8/8/8/8/8/R3K2R b KQkq - 0 1", COLOR_BLACK),
+            ("4k3/8/8/3pP3/8/8/8/4K3 w - d6 0 1", COLOR_WHITE),
+            ("1n2k3/P7/8/8/8/8/6p1/4K2R b K - 0 1", COLOR_BLACK),
+        ] {
+            let mut game = game_from_fen_no_tt(fen);
+            let start = game.current_hash;
+            assert_eq!(start, compute_full_hash(&game));
+            for mv in generate_pseudo_legal_moves(&game, color) {
+                let undo = make_move(&mut game, mv);
+                assert_eq!(
+                    game.current_hash,
+                    compute_full_hash(&game),
+                    "{} -> {} in {}",
+                    mv.src,
+                    mv.dst,
+                    fen
+                );
+                unmake_move(&mut game, mv, undo);
+                assert_eq!(game.current_hash, start);
+            }
+        }
+    }
 }
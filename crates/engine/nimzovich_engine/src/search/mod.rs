@@ -17,16 +17,23 @@
 //! - `ordering` - Move ordering heuristics
 //! - `make_unmake` - Move making/unmaking utilities
 //! - `iterative` - Iterative deepening wrapper
+//! - `time` - How much of the move's budget iterative deepening uses
+//! - `incremental` - Resumable, node-budgeted search for single-threaded hosts
+//! - `parallel` - Lazy SMP: helper threads sharing the transposition table
 
 mod alphabeta;
+mod incremental;
 mod iterative;
 pub(crate) mod make_unmake;
 mod move_picker;
 mod ordering;
+mod parallel;
 pub mod params;
 mod quiescence;
+mod time;
 
-pub use iterative::find_best_move;
+pub use incremental::{reply_incremental, IncrementalSearch, SearchProgress};
+pub use iterative::{find_best_move, find_best_move_bounded};
 
 /// Ad-hoc, fixed-time-budget nodes-searched measurement — not a criterion
 /// harness (none exists in this crate), just enough to compare an engine
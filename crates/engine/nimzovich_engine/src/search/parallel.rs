@@ -0,0 +1,145 @@
+//! Lazy SMP: several threads searching the same root through one table
+//!
+//! The calling thread runs the usual iterative deepening, and its move is the
+//! one returned. Each helper thread runs its own iterative deepening on a
+//! copy of the game, every other one starting a ply deeper, with a full
+//! window and its own killer and history tables so the threads drift apart.
+//! Helpers never report a move: what they contribute is the entries they
+//! leave in the shared transposition table, which cut the main thread's tree
+//! and sharpen its move ordering. When the main thread finishes, the helpers
+//! are told to stop and joined, and their nodes are added to `game.calls`.
+
+use super::alphabeta::alphabeta;
+use super::iterative::iterative_deepening_bounded;
+use crate::constants::*;
+use crate::types::*;
+use core::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Search with `game.search_threads` threads; a plain iterative deepening
+/// when that is 1.
+pub(crate) fn search_threaded(
+    game: &mut Game,
+    max_time_secs: f32,
+    max_nodes: Option<i64>,
+    color: Color,
+) -> Move {
+    let helpers = game.search_threads.saturating_sub(1);
+    if helpers == 0 || game.shared_tt.is_none() {
+        return iterative_deepening_bounded(game, max_time_secs, max_nodes, color);
+    }
+
+    // Helpers share one stop flag, separate from the main thread's, so the
+    // main thread can end them without touching its own search.
+    let stop = Arc::new(AtomicBool::new(false));
+    let deadline = Instant::now() + Duration::from_secs_f32(max_time_secs.max(0.01));
+    let mut workers: Vec<Game> = (0..helpers)
+        .map(|_| {
+            let mut helper = game.clone();
+            helper.abort_search = stop.clone();
+            helper.search_deadline = Some(deadline);
+            helper.search_node_limit = None;
+            helper
+        })
+        .collect();
+
+    std::thread::scope(|scope| {
+        let handles: Vec<_> = workers
+            .iter_mut()
+            .enumerate()
+            .map(|(i, helper)| scope.spawn(move || helper_search(helper, color, 1 + (i + 1) % 2)))
+            .collect();
+
+        let best = iterative_deepening_bounded(game, max_time_secs, max_nodes, color);
+        stop.store(true, Ordering::Relaxed);
+        for handle in handles {
+            game.calls += handle.join().unwrap_or(0);
+        }
+        best
+    })
+}
+
+/// One helper's iterative deepening from `first_depth`, until it is stopped
+/// or reaches the depth limit. Returns the nodes it searched.
+fn helper_search(game: &mut Game, color: Color, first_depth: usize) -> i64 {
+    game.calls = 0;
+    let depth_limit = if game.abs_max_depth > 0 {
+        (game.abs_max_depth as usize).min(MAX_DEPTH)
+    } else {
+        MAX_DEPTH
+    };
+    for depth in first_depth..=depth_limit {
+        if alphabeta(game, depth as i32, -AB_INF, AB_INF, color).is_err()
+            || game.abort_search.load(Ordering::Relaxed)
+        {
+            break;
+        }
+    }
+    game.calls
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::game::game_from_fen;
+    use crate::api::moves::is_legal_move_unchecked;
+    use crate::hash::{position_hash, tt_probe, SharedTT};
+
+    #[test]
+    fn threaded_search_plays_legal_moves_through_a_shared_table() {
+        //! Four threads hammering the same small table, round after round,
+        //! still return legal moves, and the root entries they leave behind
+        //! name legal moves too
+
+        const POSITIONS: [(&str, Color); 3] = [
+            (
+                "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+                COLOR_WHITE,
+            ),
+            (
+                "r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1RK1 w kq - 0 1",
+                COLOR_WHITE,
+            ),
+            (
+                "8/7p/5k2/5p2/p1p2P2/Pr1pPK2/1P1R3P/8 b - - 0 1",
+                COLOR_BLACK,
+            ),
+        ];
+
+        let mut sound_roots = 0;
+        for _ in 0..4 {
+            for (fen, color) in POSITIONS {
+                let mut game = game_from_fen(fen);
+                // Set up directly rather than through `set_search_threads`,
+                // which would clamp to the cores of the machine running this.
+                game.tt = Vec::new();
+                game.shared_tt = Some(Arc::new(SharedTT::new(game.tt_capacity)));
+                game.search_threads = 4;
+
+                let mv = search_threaded(&mut game, 0.15, None, color);
+                assert!(
+                    is_legal_move_unchecked(&mut game, mv.src as i8, mv.dst as i8, color),
+                    "illegal {}->{} in {}",
+                    mv.src,
+                    mv.dst,
+                    fen
+                );
+
+                // Another thread may have replaced the root entry by now;
+                // when it is still there, it must be sound.
+                if let Some(root) = tt_probe(&game, &position_hash(&game)) {
+                    let guide = root.h[0];
+                    assert!(is_legal_move_unchecked(
+                        &mut game,
+                        guide.best_move_src,
+                        guide.best_move_dst,
+                        color
+                    ));
+                    sound_roots += 1;
+                }
+            }
+        }
+        assert!(sound_roots > 0, "no round left a root entry");
+    }
+}
@@ -5,6 +5,7 @@
 //! - SEE filtering: skip bad captures statically
 //! - Check evasion: generate all moves when in check
 
+use super::alphabeta::node_budget_exhausted;
 use super::make_unmake::{make_move, unmake_move};
 use super::ordering::order_moves;
 use super::params::SearchParams;
@@ -35,6 +36,9 @@ fn quiescence_recursive(
     color: Color,
     qs_depth: i32,
 ) -> ChessEngineResult<i16> {
+    if node_budget_exhausted(game) {
+        return Ok(alpha);
+    }
     game.calls += 1;
 
     // Stand-pat evaluation
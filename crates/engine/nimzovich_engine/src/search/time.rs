@@ -0,0 +1,143 @@
+//! Time allocation for iterative deepening
+//!
+//! The budget the caller passes is the most one move may take: the search is
+//! aborted at [`HARD_SHARE`] of it whatever happens. How much of that is
+//! actually used depends on the position. Before each new iteration the
+//! elapsed time is compared with a soft limit, which starts at
+//! [`BASE_SOFT_SHARE`] of the budget and is scaled by
+//!
+//! - how sharp the root is: being in check, and how many captures and checks
+//!   are available, stretch it;
+//! - how settled the search is: a best move that changed or a score that
+//!   swung in the last iteration stretch it, a stable one doesn't.
+//!
+//! A position with a single legal move gets no soft time at all, so the
+//! search stops after the first iteration.
+
+use super::make_unmake::{make_move, unmake_move};
+use crate::move_gen::{generate_pseudo_legal_moves, is_in_check};
+use crate::types::*;
+
+/// Share of the budget after which a running iteration is aborted.
+const HARD_SHARE: f32 = 0.95;
+/// Share of the budget a quiet, stable position may start iterations in.
+const BASE_SOFT_SHARE: f32 = 0.45;
+/// Extra soft time per capture or check available at the root.
+const TACTIC_WEIGHT: f32 = 0.08;
+/// Captures and checks counted towards sharpness, at most.
+const MAX_TACTICS: usize = 10;
+/// Score swing (centipawns) between iterations that starts to count.
+const SWING_THRESHOLD: i16 = 30;
+
+/// Decides when iterative deepening should stop starting new iterations.
+#[derive(Debug, Clone)]
+pub(crate) struct TimeManager {
+    budget: f32,
+    /// Root sharpness, 0 for a forced move and 1 or more otherwise
+    sharpness: f32,
+    /// Search instability from the last iteration, 1 when settled
+    instability: f32,
+}
+
+impl TimeManager {
+    /// Allocation for `color` to move in `game` with `budget` seconds.
+    pub(crate) fn new(game: &mut Game, color: Color, budget: f32) -> Self {
+        Self {
+            budget,
+            sharpness: root_sharpness(game, color),
+            instability: 1.0,
+        }
+    }
+
+    /// Seconds after which the search is aborted mid-iteration.
+    pub(crate) fn hard_limit(&self) -> f32 {
+        self.budget * HARD_SHARE
+    }
+
+    /// Seconds after which no new iteration is started.
+    pub(crate) fn soft_limit(&self) -> f32 {
+        (self.budget * BASE_SOFT_SHARE * self.sharpness * self.instability).min(self.hard_limit())
+    }
+
+    /// Take a finished iteration into account: `swing` is how far its score
+    /// moved from the previous one.
+    pub(crate) fn record_iteration(&mut self, swing: i16, best_move_changed: bool) {
+        let mut instability = 1.0;
+        if best_move_changed {
+            instability += 0.4;
+        }
+        if swing.saturating_abs() > SWING_THRESHOLD {
+            instability += (swing.saturating_abs() as f32 / 100.0).min(0.8);
+        }
+        self.instability = instability;
+    }
+}
+
+/// 0 with one legal move; otherwise 1, plus a little for being in check and
+/// for each capture or check on offer.
+fn root_sharpness(game: &mut Game, color: Color) -> f32 {
+    let mut legal = 0;
+    let mut tactics = 0;
+    for mv in generate_pseudo_legal_moves(game, color) {
+        let capture = game.board[mv.dst as usize] != 0;
+        let undo = make_move(game, mv);
+        if !is_in_check(game, color) {
+            legal += 1;
+            if capture || is_in_check(game, -color) {
+                tactics += 1;
+            }
+        }
+        unmake_move(game, mv, undo);
+    }
+
+    if legal <= 1 {
+        return 0.0;
+    }
+    let in_check = if is_in_check(game, color) { 0.3 } else { 0.0 };
+    1.0 + in_check + TACTIC_WEIGHT * tactics.min(MAX_TACTICS) as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::game::game_from_fen_no_tt;
+    use crate::constants::COLOR_WHITE;
+
+    fn manager(fen: &str) -> TimeManager {
+        TimeManager::new(&mut game_from_fen_no_tt(fen), COLOR_WHITE, 10.0)
+    }
+
+    #[test]
+    fn tactical_positions_get_more_time_than_quiet_ones() {
+        //! Under the same budget a capture-heavy middlegame may search longer
+        //! than the opening position, and an unstable search longer still,
+        //! but never past the hard cap
+
+        let quiet = manager("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1");
+        let mut tactical =
+            manager("r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1");
+        assert!(
+            tactical.soft_limit() > quiet.soft_limit(),
+            "tactical {} vs quiet {}",
+            tactical.soft_limit(),
+            quiet.soft_limit()
+        );
+
+        let settled = tactical.soft_limit();
+        tactical.record_iteration(150, true);
+        assert!(tactical.soft_limit() > settled);
+        tactical.record_iteration(i16::MAX, true);
+        assert!(tactical.soft_limit() <= tactical.hard_limit());
+        tactical.record_iteration(0, false);
+        assert_eq!(tactical.soft_limit(), settled);
+    }
+
+    #[test]
+    fn a_forced_move_gets_no_soft_time() {
+        //! With only one legal reply there is nothing to think about
+
+        // Black's rook checks along the first rank; only Rf1 blocks it.
+        let forced = manager("6k1/8/8/8/8/5R2/6PP/r6K w - - 0 1");
+        assert_eq!(forced.soft_limit(), 0.0);
+    }
+}
@@ -165,12 +165,19 @@ pub struct Game {
     pub black_pawn: [KKS; 64],
 
     /// Transposition table. Owned exclusively by whichever thread runs the search —
-    /// no mutex needed because the search is always single-threaded per Game instance.
+    /// no mutex needed. Empty while `shared_tt` holds the table instead.
     #[cfg(feature = "search")]
     pub tt: Vec<TTE>,
     /// Number of TT buckets (always a power of two for fast modulo).
     #[cfg(feature = "search")]
     pub tt_capacity: usize,
+    /// The transposition table when searching with more than one thread;
+    /// every thread's `Game` points at the same one. See `set_search_threads`.
+    #[cfg(feature = "search")]
+    pub shared_tt: Option<Arc<crate::hash::SharedTT>>,
+    /// Threads a search uses, 1 for a plain single-threaded search.
+    #[cfg(feature = "search")]
+    pub search_threads: usize,
 
     /// Zobrist random bitstrings for O(1) incremental hashing.
     #[cfg(feature = "search")]
@@ -178,6 +185,13 @@ pub struct Game {
     /// Zobrist bitstring to XOR when it's black's turn.
     #[cfg(feature = "search")]
     pub zobrist_black_turn: u64,
+    /// Zobrist bitstrings for the castling rights: white short and long,
+    /// then black short and long.
+    #[cfg(feature = "search")]
+    pub zobrist_castling: [u64; 4],
+    /// Zobrist bitstrings for a capturable en-passant square, by file.
+    #[cfg(feature = "search")]
+    pub zobrist_en_passant: [u64; 8],
     /// The current position's hash, updated incrementally during search.
     #[cfg(feature = "search")]
     pub current_hash: BitBuffer192,
@@ -237,6 +251,15 @@ pub struct Game {
     /// node loop so a long iteration cannot blow the clock.
     #[cfg(feature = "search")]
     pub search_deadline: Option<std::time::Instant>,
+    /// Hard cap on `calls` for the running search — checked at every node so
+    /// a bounded search never visits more than this many nodes.
+    #[cfg(feature = "search")]
+    pub search_node_limit: Option<i64>,
+    /// Search late, quiet moves to reduced depth (LMR), re-searching at full
+    /// depth any that beat alpha. Off by default until it has been
+    /// match-tested on its own (see `SPECULATIVE_PRUNING`).
+    #[cfg(feature = "search")]
+    pub late_move_reductions: bool,
 }
 
 #[derive(Debug, Clone, Copy)]
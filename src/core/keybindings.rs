@@ -0,0 +1,330 @@
+//! Remappable keyboard shortcuts
+//!
+//! Every in-game hotkey is a [`GameAction`], including the keys held to pan
+//! and turn the camera. [`KeyBindings`], kept in
+//! [`GameSettings::key_bindings`](crate::core::GameSettings::key_bindings),
+//! records only the actions the player has moved; the rest use their default
+//! key, so a settings file written before an action existed still gets it.
+//! Keys are saved by name ("KeyB", "F5") from [`NAMED_KEYS`], which is also
+//! the set of keys an action can be bound to.
+//!
+//! Rebinding screens set [`KeyRebind`] to the action the player clicked and
+//! run [`capture_rebind_key`], which binds the next key pressed.
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Something a hotkey does during a game
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, Reflect,
+)]
+pub enum GameAction {
+    /// Ask to leave the game
+    LeaveGame,
+    /// Show or hide the in-game HUD
+    ToggleHud,
+    /// Put the 3D camera back at its starting height and angle
+    ResetCamera,
+    /// Switch between the 3D and 2D boards
+    ToggleView,
+    /// Cycle the camera presets (top-down, fixed, free, cinematic)
+    CycleCameraMode,
+    /// Save to the quick-save slot
+    QuickSave,
+    /// Load the quick-save slot
+    QuickLoad,
+    /// Open the engine board inspector
+    BoardInspector,
+    /// Take back the last move (offline games)
+    Undo,
+    /// Replay a move that was taken back
+    Redo,
+    /// Turn the board round to see it from the other side
+    FlipBoard,
+    /// Save a PNG of the 3D board
+    Screenshot,
+    /// Pan the camera forward while held
+    CameraForward,
+    /// Pan the camera back while held
+    CameraBack,
+    /// Pan the camera left while held
+    CameraLeft,
+    /// Pan the camera right while held
+    CameraRight,
+    /// Turn the camera left while held
+    CameraTurnLeft,
+    /// Turn the camera right while held
+    CameraTurnRight,
+}
+
+impl GameAction {
+    /// Every action, in the order the settings screen lists them.
+    pub const ALL: [GameAction; 18] = [
+        GameAction::LeaveGame,
+        GameAction::ToggleHud,
+        GameAction::ResetCamera,
+        GameAction::ToggleView,
+        GameAction::CycleCameraMode,
+        GameAction::QuickSave,
+        GameAction::QuickLoad,
+        GameAction::BoardInspector,
+        GameAction::Undo,
+        GameAction::Redo,
+        GameAction::FlipBoard,
+        GameAction::Screenshot,
+        GameAction::CameraForward,
+        GameAction::CameraBack,
+        GameAction::CameraLeft,
+        GameAction::CameraRight,
+        GameAction::CameraTurnLeft,
+        GameAction::CameraTurnRight,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            GameAction::LeaveGame => "Leave game",
+            GameAction::ToggleHud => "Show/hide HUD",
+            GameAction::ResetCamera => "Reset camera",
+            GameAction::ToggleView => "Switch 2D/3D",
+            GameAction::CycleCameraMode => "Cycle camera presets",
+            GameAction::QuickSave => "Quick-save",
+            GameAction::QuickLoad => "Quick-load",
+            GameAction::BoardInspector => "Board inspector",
+            GameAction::Undo => "Take back",
+            GameAction::Redo => "Redo",
+            GameAction::FlipBoard => "Flip board",
+            GameAction::Screenshot => "Screenshot",
+            GameAction::CameraForward => "Camera forward",
+            GameAction::CameraBack => "Camera back",
+            GameAction::CameraLeft => "Camera left",
+            GameAction::CameraRight => "Camera right",
+            GameAction::CameraTurnLeft => "Turn camera left",
+            GameAction::CameraTurnRight => "Turn camera right",
+        }
+    }
+
+    /// The built-in key.
+    pub fn default_key(self) -> KeyCode {
+        match self {
+            GameAction::LeaveGame => KeyCode::Escape,
+            GameAction::ToggleHud => KeyCode::KeyB,
+            GameAction::ResetCamera => KeyCode::KeyN,
+            GameAction::ToggleView => KeyCode::KeyV,
+            GameAction::CycleCameraMode => KeyCode::KeyR,
+            GameAction::QuickSave => KeyCode::F5,
+            GameAction::QuickLoad => KeyCode::F8,
+            GameAction::BoardInspector => KeyCode::F1,
+            GameAction::Undo => KeyCode::KeyU,
+            GameAction::Redo => KeyCode::KeyY,
+            GameAction::FlipBoard => KeyCode::KeyF,
+            GameAction::Screenshot => KeyCode::F2,
+            GameAction::CameraForward => KeyCode::KeyW,
+            GameAction::CameraBack => KeyCode::KeyS,
+            GameAction::CameraLeft => KeyCode::KeyA,
+            GameAction::CameraRight => KeyCode::KeyD,
+            GameAction::CameraTurnLeft => KeyCode::KeyQ,
+            GameAction::CameraTurnRight => KeyCode::KeyE,
+        }
+    }
+}
+
+macro_rules! named_keys {
+    ($($key:ident),* $(,)?) => {
+        /// Keys an action can be bound to, with the names they are saved under.
+        pub const NAMED_KEYS: &[(KeyCode, &str)] = &[$((KeyCode::$key, stringify!($key))),*];
+    };
+}
+
+named_keys! {
+    KeyA, KeyB, KeyC, KeyD, KeyE, KeyF, KeyG, KeyH, KeyI, KeyJ, KeyK, KeyL, KeyM, KeyN, KeyO,
+    KeyP, KeyQ, KeyR, KeyS, KeyT, KeyU, KeyV, KeyW, KeyX, KeyY, KeyZ, Digit0, Digit1, Digit2,
+    Digit3, Digit4, Digit5, Digit6, Digit7, Digit8, Digit9, F1, F2, F3, F4, F5, F6, F7, F8, F9,
+    F10, F12, Escape, Space, Tab, Enter, Backspace, Delete, Insert, Home, End, PageUp, PageDown,
+    ArrowUp, ArrowDown, ArrowLeft, ArrowRight, Minus, Equal, BracketLeft, BracketRight,
+    Semicolon, Quote, Comma, Period, Slash, Backslash, Backquote,
+}
+
+/// The name `key` is saved under, if it can be bound.
+pub fn key_name(key: KeyCode) -> Option<&'static str> {
+    NAMED_KEYS
+        .iter()
+        .find(|(named, _)| *named == key)
+        .map(|(_, name)| *name)
+}
+
+/// The key saved as `name`.
+pub fn key_from_name(name: &str) -> Option<KeyCode> {
+    NAMED_KEYS
+        .iter()
+        .find(|(_, named)| *named == name)
+        .map(|(key, _)| *key)
+}
+
+/// How a key is written on screen: "B" for `KeyB`, "1" for `Digit1`.
+pub fn key_label(key: KeyCode) -> String {
+    let name = key_name(key).unwrap_or("?");
+    name.strip_prefix("Key")
+        .or_else(|| name.strip_prefix("Digit"))
+        .unwrap_or(name)
+        .to_string()
+}
+
+/// The player's changes to the default hotkeys
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, Reflect)]
+#[serde(transparent)]
+pub struct KeyBindings {
+    /// Key name per remapped action
+    overrides: BTreeMap<GameAction, String>,
+}
+
+impl KeyBindings {
+    /// The key `action` is bound to. A saved name this build doesn't know
+    /// falls back to the default.
+    pub fn key(&self, action: GameAction) -> KeyCode {
+        self.overrides
+            .get(&action)
+            .and_then(|name| key_from_name(name))
+            .unwrap_or_else(|| action.default_key())
+    }
+
+    /// Bind `action` to `key`. Returns false, changing nothing, for a key
+    /// that can't be bound.
+    pub fn set(&mut self, action: GameAction, key: KeyCode) -> bool {
+        let Some(name) = key_name(key) else {
+            return false;
+        };
+        if key == action.default_key() {
+            self.overrides.remove(&action);
+        } else {
+            self.overrides.insert(action, name.to_string());
+        }
+        true
+    }
+
+    /// Whether `action`'s key went down this frame.
+    pub fn just_pressed(&self, keyboard: &ButtonInput<KeyCode>, action: GameAction) -> bool {
+        keyboard.just_pressed(self.key(action))
+    }
+
+    /// Whether `action`'s key is held down.
+    pub fn pressed(&self, keyboard: &ButtonInput<KeyCode>, action: GameAction) -> bool {
+        keyboard.pressed(self.key(action))
+    }
+
+    /// Pairs of actions bound to the same key, with that key.
+    pub fn conflicts(&self) -> Vec<(GameAction, GameAction, KeyCode)> {
+        let mut conflicts = Vec::new();
+        for (i, &first) in GameAction::ALL.iter().enumerate() {
+            for &second in &GameAction::ALL[i + 1..] {
+                let key = self.key(first);
+                if key == self.key(second) {
+                    conflicts.push((first, second, key));
+                }
+            }
+        }
+        conflicts
+    }
+}
+
+/// The action waiting for its new key on a rebinding screen, if any
+#[derive(Resource, Default)]
+pub struct KeyRebind {
+    pub action: Option<GameAction>,
+}
+
+/// Bind the first key pressed to the action waiting for one. Escape
+/// cancels; keys that can't be bound are ignored.
+pub fn capture_rebind_key(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut rebind: ResMut<KeyRebind>,
+    mut settings: ResMut<crate::core::GameSettings>,
+) {
+    let Some(action) = rebind.action else {
+        return;
+    };
+    for &key in keyboard.get_just_pressed() {
+        if key == KeyCode::Escape || settings.key_bindings.set(action, key) {
+            rebind.action = None;
+            return;
+        }
+    }
+}
+
+/// Stop waiting for a key, e.g. when the rebinding screen closes.
+pub fn cancel_key_rebind(mut rebind: ResMut<KeyRebind>) {
+    rebind.action = None;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::GameSettings;
+    use crate::ui::game::game_ui::{toggle_in_game_hud, InGameHudVisibility};
+    use bevy::ecs::system::RunSystemOnce;
+
+    /// Press `key` for one run of the HUD toggle; returns whether the HUD
+    /// visibility changed.
+    fn hud_toggled_by(world: &mut World, key: KeyCode) -> bool {
+        let before = world.resource::<InGameHudVisibility>().visible;
+        let mut keyboard = world.resource_mut::<ButtonInput<KeyCode>>();
+        keyboard.clear();
+        keyboard.release_all();
+        keyboard.press(key);
+        world.run_system_once(toggle_in_game_hud).unwrap();
+        world.resource::<InGameHudVisibility>().visible != before
+    }
+
+    #[test]
+    fn test_remapped_key_replaces_the_default() {
+        //! After moving the HUD toggle to H, H toggles the HUD and B no longer does
+
+        let mut settings = GameSettings::default();
+        assert!(settings
+            .key_bindings
+            .set(GameAction::ToggleHud, KeyCode::KeyH));
+
+        let mut world = World::new();
+        world.insert_resource(settings);
+        world.init_resource::<ButtonInput<KeyCode>>();
+        world.init_resource::<InGameHudVisibility>();
+
+        assert!(hud_toggled_by(&mut world, KeyCode::KeyH));
+        assert!(!hud_toggled_by(&mut world, KeyCode::KeyB));
+    }
+
+    #[test]
+    fn test_conflicts_are_reported_and_defaults_not_stored() {
+        //! Two actions on one key are listed as a conflict; moving an action back to its default clears the override
+
+        let mut bindings = KeyBindings::default();
+        assert!(bindings.conflicts().is_empty());
+
+        bindings.set(GameAction::QuickSave, KeyCode::KeyB);
+        assert_eq!(
+            bindings.conflicts(),
+            [(GameAction::ToggleHud, GameAction::QuickSave, KeyCode::KeyB)]
+        );
+
+        bindings.set(GameAction::QuickSave, KeyCode::F5);
+        assert_eq!(bindings, KeyBindings::default());
+        assert!(!bindings.set(GameAction::QuickSave, KeyCode::F11));
+        assert_eq!(key_label(KeyCode::Digit7), "7");
+    }
+
+    #[test]
+    fn test_camera_keys_are_held_on_their_binding() {
+        //! Panning forward follows the arrow key it is moved to, and W stops panning
+
+        let mut bindings = KeyBindings::default();
+        assert!(bindings.set(GameAction::CameraForward, KeyCode::ArrowUp));
+
+        let mut keyboard = ButtonInput::<KeyCode>::default();
+        keyboard.press(KeyCode::KeyW);
+        assert!(!bindings.pressed(&keyboard, GameAction::CameraForward));
+        keyboard.press(KeyCode::ArrowUp);
+        keyboard.clear();
+        assert!(bindings.pressed(&keyboard, GameAction::CameraForward));
+        assert!(!bindings.just_pressed(&keyboard, GameAction::CameraForward));
+    }
+}
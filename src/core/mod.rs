@@ -2,14 +2,18 @@
 
 pub mod crash;
 pub mod error_handling;
+pub mod keybindings;
 pub mod persistent_camera;
 pub mod plugin;
 pub mod resources;
 pub mod settings_persistence;
+pub mod settings_transfer;
 pub mod state_lifecycle;
 pub mod states;
+pub mod stats_persistence;
 pub mod window_config;
 
+pub use keybindings::{GameAction, KeyBindings};
 pub use plugin::CorePlugin;
 pub use resources::*;
 pub use states::*;
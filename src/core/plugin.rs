@@ -33,9 +33,10 @@ use std::path::Path;
 use std::sync::{Mutex, OnceLock};
 
 use super::{
-    settings_persistence::load_settings_system,
+    settings_persistence::{load_settings_system, save_settings_system},
     state_lifecycle::*,
     states::{log_game_state_system, validate_and_log_state_transitions},
+    stats_persistence::{load_stats_system, save_stats_system},
     GameSettings, GameState, GameStatistics, InGameplay, InMenus, MenuState, PreviousState,
     StateLoggerTimer, WindowConfig,
 };
@@ -106,7 +107,22 @@ impl Plugin for CorePlugin {
 
         // Add settings persistence system (runs in Startup schedule)
         // Initialize settings
-        app.add_systems(Startup, load_settings_system);
+        app.add_systems(Startup, (load_settings_system, load_stats_system));
+        // Write settings back whenever they change (e.g. the auto-save interval)
+        app.add_systems(
+            Update,
+            (
+                save_settings_system.run_if(resource_exists::<GameSettings>),
+                save_stats_system,
+            ),
+        );
+
+        // Apply the saved window mode and resolution, and later changes to them
+        app.add_systems(
+            Update,
+            super::window_config::apply_window_settings_system
+                .run_if(resource_exists::<GameSettings>),
+        );
 
         // Add state logging and validation systems
         app.add_systems(
@@ -3,6 +3,7 @@
 //! These resources are used across multiple states and provide global
 //! configuration and tracking capabilities.
 
+use crate::core::keybindings::KeyBindings;
 use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
 
@@ -17,10 +18,16 @@ pub enum GraphicsQuality {
 }
 
 impl GraphicsQuality {
+    /// Whether the preset switches bloom on when it is selected.
     pub fn bloom_enabled(self) -> bool {
         matches!(self, Self::High | Self::Ultra)
     }
 
+    /// Whether the preset switches distance fog on when it is selected.
+    pub fn fog_enabled(self) -> bool {
+        matches!(self, Self::High | Self::Ultra)
+    }
+
     pub fn ambient_occlusion_enabled(self) -> bool {
         self == Self::Ultra
     }
@@ -29,6 +36,15 @@ impl GraphicsQuality {
         !matches!(self, Self::Low)
     }
 
+    /// Anti-aliasing the preset switches to when it is selected.
+    pub fn msaa(self) -> MsaaLevel {
+        match self {
+            Self::Low => MsaaLevel::Off,
+            Self::Medium => MsaaLevel::X2,
+            Self::High | Self::Ultra => MsaaLevel::X4,
+        }
+    }
+
     /// Shadow atlas resolution in texels (used for `ShadowmapSettings`).
     pub fn shadow_map_size(self) -> u32 {
         match self {
@@ -41,16 +57,436 @@ impl GraphicsQuality {
 
     pub fn description(self) -> &'static str {
         match self {
-            Self::Low => "Low – shadows off, no bloom, fastest",
-            Self::Medium => "Medium – shadows on, no bloom",
-            Self::High => "High – shadows + bloom",
-            Self::Ultra => "Ultra – shadows + bloom + SSAO",
+            Self::Low => "Low – shadows off, no bloom or fog, fastest",
+            Self::Medium => "Medium – shadows on, no bloom or fog",
+            Self::High => "High – shadows + bloom + fog, 4x MSAA",
+            Self::Ultra => "Ultra – shadows + bloom + fog, 4x MSAA; SSAO once MSAA is turned off",
+        }
+    }
+}
+
+/// Multisample anti-aliasing of the 3D view
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, Reflect)]
+pub enum MsaaLevel {
+    Off,
+    /// The Medium preset's level
+    #[default]
+    X2,
+    X4,
+}
+
+impl MsaaLevel {
+    /// Samples per pixel
+    pub fn samples(self) -> u32 {
+        match self {
+            Self::Off => 1,
+            Self::X2 => 2,
+            Self::X4 => 4,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Off => "Off",
+            Self::X2 => "2x",
+            Self::X4 => "4x",
+        }
+    }
+}
+
+/// How the game window fills the screen
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, Reflect)]
+pub enum DisplayMode {
+    /// A normal window at the chosen resolution
+    #[default]
+    Windowed,
+    /// A window without decorations covering the whole monitor
+    Borderless,
+    /// Exclusive fullscreen in the monitor's current video mode
+    Fullscreen,
+}
+
+impl DisplayMode {
+    pub const ALL: [DisplayMode; 3] = [Self::Windowed, Self::Borderless, Self::Fullscreen];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Windowed => "Windowed",
+            Self::Borderless => "Borderless",
+            Self::Fullscreen => "Fullscreen",
+        }
+    }
+}
+
+/// How the in-game clocks are written
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, Reflect)]
+pub enum ClockFormat {
+    /// Whole seconds, e.g. "4:59"
+    MinutesSeconds,
+    /// Whole seconds, switching to tenths below ten seconds, e.g. "0:09.3"
+    #[default]
+    TenthsWhenLow,
+}
+
+impl ClockFormat {
+    /// Remaining time below which [`Self::TenthsWhenLow`] shows tenths.
+    pub const TENTHS_THRESHOLD_SECS: f32 = 10.0;
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::MinutesSeconds => "m:ss",
+            Self::TenthsWhenLow => "m:ss, tenths under 10s",
+        }
+    }
+
+    /// Format a remaining time for display.
+    pub fn format(self, seconds: f32) -> String {
+        let seconds = seconds.max(0.0);
+        if self == Self::TenthsWhenLow && seconds < Self::TENTHS_THRESHOLD_SECS {
+            // Truncate like the whole-second format so the clock never
+            // shows more time than is left.
+            let tenths = (seconds * 10.0) as u32;
+            return format!("0:{:02}.{}", tenths / 10, tenths % 10);
+        }
+        let total_seconds = seconds as u32;
+        format!("{}:{:02}", total_seconds / 60, total_seconds % 60)
+    }
+}
+
+/// How a clock's increment is granted after each move
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, Reflect)]
+pub enum IncrementMode {
+    /// The full increment is added after every move, so the clock can grow
+    #[default]
+    Fischer,
+    /// The time actually used is given back, up to the increment; the clock
+    /// never ends a move above where it started
+    Bronstein,
+    /// The clock waits for the increment before it starts running down;
+    /// unused delay is lost
+    SimpleDelay,
+}
+
+impl IncrementMode {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Fischer => "Fischer",
+            Self::Bronstein => "Bronstein delay",
+            Self::SimpleDelay => "Simple delay",
+        }
+    }
+}
+
+/// Piece a pawn becomes without the promotion dialog in bullet and blitz
+/// games. Holding Alt as the pawn lands brings the dialog back for an
+/// under-promotion.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, Reflect)]
+pub enum AutoPromotion {
+    /// Always ask
+    Off,
+    #[default]
+    Queen,
+    Rook,
+    Bishop,
+    Knight,
+}
+
+impl AutoPromotion {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Off => "Off",
+            Self::Queen => "Queen",
+            Self::Rook => "Rook",
+            Self::Bishop => "Bishop",
+            Self::Knight => "Knight",
+        }
+    }
+
+    /// The piece to promote to, or `None` to ask.
+    pub fn piece(self) -> Option<crate::rendering::pieces::PieceType> {
+        use crate::rendering::pieces::PieceType;
+        match self {
+            Self::Off => None,
+            Self::Queen => Some(PieceType::Queen),
+            Self::Rook => Some(PieceType::Rook),
+            Self::Bishop => Some(PieceType::Bishop),
+            Self::Knight => Some(PieceType::Knight),
+        }
+    }
+
+    /// The next option, for cycling through them with one button.
+    pub fn next(self) -> Self {
+        match self {
+            Self::Off => Self::Queen,
+            Self::Queen => Self::Rook,
+            Self::Rook => Self::Bishop,
+            Self::Bishop => Self::Knight,
+            Self::Knight => Self::Off,
+        }
+    }
+}
+
+/// Set of move, capture, check and illegal-move sounds
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, Reflect)]
+pub enum SoundTheme {
+    #[default]
+    Classic,
+    /// Chiptune blips to go with the TempleOS view
+    Retro,
+}
+
+impl SoundTheme {
+    /// Themes offered in settings. Retro joins once `assets/game_sounds/retro`
+    /// has its sounds; until then it would only play the Classic ones.
+    pub const PICKABLE: &'static [Self] = &[Self::Classic];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Classic => "Classic",
+            Self::Retro => "Retro",
+        }
+    }
+
+    /// The next pickable theme, for cycling through them with one button.
+    pub fn next(self) -> Self {
+        let at = Self::PICKABLE.iter().position(|&t| t == self);
+        Self::PICKABLE[at.map_or(0, |i| (i + 1) % Self::PICKABLE.len())]
+    }
+}
+
+/// How square highlights (selection, last move, drag target) are drawn
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, Reflect)]
+pub enum HighlightStyle {
+    /// The whole square is tinted
+    #[default]
+    Solid,
+    /// A thin frame around the square
+    Outline,
+    /// A dot in each corner, leaving the piece clear
+    CornerDots,
+}
+
+impl HighlightStyle {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Solid => "Solid",
+            Self::Outline => "Outline",
+            Self::CornerDots => "Corner Dots",
+        }
+    }
+
+    /// The next style, for cycling through them with one button.
+    pub fn next(self) -> Self {
+        match self {
+            Self::Solid => Self::Outline,
+            Self::Outline => Self::CornerDots,
+            Self::CornerDots => Self::Solid,
+        }
+    }
+}
+
+/// What happens to a captured piece on the 3D board
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, Reflect)]
+pub enum CaptureAnimation {
+    /// Knocked back and sunk into the board
+    #[default]
+    Fade,
+    /// Lifted off the board and carried, fading, to the capture tray
+    Slide,
+    /// Placed in the capture tray at once
+    Instant,
+}
+
+impl CaptureAnimation {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Fade => "Fade",
+            Self::Slide => "Slide to Tray",
+            Self::Instant => "Instant",
+        }
+    }
+
+    /// The next option, for cycling through them with one button.
+    pub fn next(self) -> Self {
+        match self {
+            Self::Fade => Self::Slide,
+            Self::Slide => Self::Instant,
+            Self::Instant => Self::Fade,
+        }
+    }
+}
+
+/// Look of the 3D board's squares
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, Reflect)]
+pub enum BoardTheme {
+    /// Light and dark stained wood
+    Wood,
+    /// Polished white and grey stone
+    Marble,
+    /// Cream and green, the original look
+    #[default]
+    GreenCream,
+    /// Pale and slate blue
+    Blue,
+    /// White and VGA blue, flat and unlit-looking
+    TempleOS,
+}
+
+impl BoardTheme {
+    /// Every theme, in the order the settings screen lists them.
+    pub const ALL: [BoardTheme; 5] = [
+        Self::Wood,
+        Self::Marble,
+        Self::GreenCream,
+        Self::Blue,
+        Self::TempleOS,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Wood => "Wood",
+            Self::Marble => "Marble",
+            Self::GreenCream => "Green & Cream",
+            Self::Blue => "Blue",
+            Self::TempleOS => "TempleOS",
+        }
+    }
+
+    /// Light and dark square colours.
+    pub fn colors(self) -> (Color, Color) {
+        match self {
+            Self::Wood => (Color::srgb(0.87, 0.72, 0.53), Color::srgb(0.55, 0.35, 0.2)),
+            Self::Marble => (Color::srgb(0.92, 0.91, 0.89), Color::srgb(0.52, 0.54, 0.57)),
+            Self::GreenCream => (Color::srgb(0.93, 0.93, 0.82), Color::srgb(0.46, 0.59, 0.34)),
+            Self::Blue => (Color::srgb(0.87, 0.89, 0.9), Color::srgb(0.55, 0.64, 0.68)),
+            Self::TempleOS => (Color::srgb(1.0, 1.0, 1.0), Color::srgb(0.0, 0.0, 0.67)),
+        }
+    }
+
+    /// Perceptual roughness, metallic and reflectance of the squares.
+    pub fn finish(self) -> (f32, f32, f32) {
+        match self {
+            Self::Wood => (0.7, 0.0, 0.3),
+            Self::Marble => (0.15, 0.0, 0.6),
+            Self::GreenCream | Self::Blue => (0.5, 0.0, 0.5),
+            Self::TempleOS => (1.0, 0.0, 0.0),
+        }
+    }
+}
+
+/// Surface of the 3D pieces
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, Reflect)]
+pub enum PieceFinish {
+    /// Glossy ivory and charcoal, the original look
+    #[default]
+    Polished,
+    /// Rough and unreflective, like painted wood
+    Matte,
+    /// Silver and gunmetal
+    Metallic,
+    /// Clear and smoked see-through pieces
+    Glass,
+    /// Glowing yellow and cyan, after the TempleOS palette
+    TempleOsNeon,
+}
+
+impl PieceFinish {
+    /// Every finish, in the order the settings screen lists them.
+    pub const ALL: [PieceFinish; 5] = [
+        Self::Polished,
+        Self::Matte,
+        Self::Metallic,
+        Self::Glass,
+        Self::TempleOsNeon,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Polished => "Polished",
+            Self::Matte => "Matte",
+            Self::Metallic => "Metallic",
+            Self::Glass => "Glass",
+            Self::TempleOsNeon => "TempleOS Neon",
+        }
+    }
+}
+
+/// Where the board camera looks from when a game starts
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, Reflect)]
+pub enum StartingView {
+    /// The angled view from the human player's side
+    #[default]
+    Angled,
+    /// The angled view from White's side, whichever colour the human plays
+    WhiteSide,
+    /// The angled view from Black's side, whichever colour the human plays
+    BlackSide,
+    /// Nearly overhead, from the human player's side
+    TopDown,
+}
+
+impl StartingView {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Angled => "Angled",
+            Self::WhiteSide => "White Side",
+            Self::BlackSide => "Black Side",
+            Self::TopDown => "Top-Down",
+        }
+    }
+
+    /// The next view, for cycling through them with one button.
+    pub fn next(self) -> Self {
+        match self {
+            Self::Angled => Self::WhiteSide,
+            Self::WhiteSide => Self::BlackSide,
+            Self::BlackSide => Self::TopDown,
+            Self::TopDown => Self::Angled,
+        }
+    }
+}
+
+/// How close and how far the 3D board camera may zoom, and where it starts.
+/// Heights are in board units above the board.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Reflect)]
+#[serde(default)]
+pub struct CameraZoomSettings {
+    /// Closest the camera may get
+    pub min: f32,
+    /// Farthest the camera may get
+    pub max: f32,
+    /// Height at the start of a game and after a camera reset
+    pub initial: f32,
+}
+
+impl CameraZoomSettings {
+    /// Lowest and highest heights offered in the settings screen.
+    pub const RANGE: std::ops::RangeInclusive<f32> = 2.0..=60.0;
+
+    /// `zoom` pulled into the configured range. A `max` below `min` counts
+    /// as equal to it.
+    pub fn clamp(&self, zoom: f32) -> f32 {
+        zoom.clamp(self.min, self.max.max(self.min))
+    }
+
+    /// Starting height, kept inside the range.
+    pub fn initial_height(&self) -> f32 {
+        self.clamp(self.initial)
+    }
+}
+
+impl Default for CameraZoomSettings {
+    fn default() -> Self {
+        Self {
+            min: 3.0,
+            max: 30.0,
+            initial: 16.0,
         }
     }
 }
 
 /// Dynamic orbital lighting configuration
-#[derive(Debug, Clone, Serialize, Deserialize, Reflect)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Reflect)]
 pub struct DynamicLightingSettings {
     pub enabled: bool,
     pub light_count: u32,
@@ -107,12 +543,25 @@ impl Default for DynamicLightingSettings {
 }
 
 /// Resource tracking settings that can be changed from the settings menu
-#[derive(Resource, Debug, Clone, Serialize, Deserialize, Reflect)]
+#[derive(Resource, Debug, Clone, PartialEq, Serialize, Deserialize, Reflect)]
 #[reflect(Resource)]
 pub struct GameSettings {
     /// Master volume (0.0 to 1.0)
     pub master_volume: f32,
 
+    /// Volume of sound effects (moves, captures, clicks), 0.0 to 1.0, under
+    /// the master volume
+    #[serde(default = "default_channel_volume")]
+    pub sfx_volume: f32,
+
+    /// Volume of music, 0.0 to 1.0, under the master volume
+    #[serde(default = "default_channel_volume")]
+    pub music_volume: f32,
+
+    /// Play sound effects at all; off silences them whatever their volume
+    #[serde(default = "default_true")]
+    pub sound_effects: bool,
+
     /// Whether audio is muted
     #[serde(default)]
     pub muted: bool,
@@ -131,6 +580,11 @@ pub struct GameSettings {
     #[serde(default)]
     pub graphics_quality: GraphicsQuality,
 
+    /// Board/piece shadows on or off, independent of the quality preset
+    /// (the preset can still force them off, e.g. `Low`)
+    #[serde(default = "default_true")]
+    pub shadows: bool,
+
     /// Dynamic orbital lighting configuration
     #[serde(default)]
     pub dynamic_lighting: DynamicLightingSettings,
@@ -139,6 +593,14 @@ pub struct GameSettings {
     #[serde(default)]
     pub board_theme: u8,
 
+    /// Look of the 3D board's squares
+    #[serde(default)]
+    pub board_theme_3d: BoardTheme,
+
+    /// Surface of the 3D pieces
+    #[serde(default)]
+    pub piece_finish: PieceFinish,
+
     /// Blindfold mode — hides piece symbols on the 2D board
     #[serde(default)]
     pub blindfold: bool,
@@ -150,22 +612,273 @@ pub struct GameSettings {
     /// Show vertical centipawn eval bar (offline/AI games only)
     #[serde(default)]
     pub show_eval_bar: bool,
+
+    /// Seconds between auto-saves of an offline game (0 = off)
+    #[serde(default = "default_autosave_interval")]
+    pub autosave_interval_secs: u32,
+
+    /// Upper bound on the computer's think time per move, in seconds.
+    /// The strength level's own budget applies below this.
+    #[serde(default = "default_max_ai_think_secs")]
+    pub max_ai_think_secs: f32,
+
+    /// Briefly show mode, difficulty, clock and colour when a game starts
+    #[serde(default = "default_true")]
+    pub show_game_start_summary: bool,
+
+    /// Let the computer resign a hopeless position (off so beginners can
+    /// practise converting a won game)
+    #[serde(default)]
+    pub ai_resigns: bool,
+
+    /// Eval, in centipawns against the computer, at which a move counts
+    /// towards resignation
+    #[serde(default = "default_ai_resign_threshold_cp")]
+    pub ai_resign_threshold_cp: u32,
+
+    /// Consecutive moves at or past the threshold before the computer resigns
+    #[serde(default = "default_ai_resign_streak")]
+    pub ai_resign_streak: u32,
+
+    /// Threads the built-in engine searches with at the difficulty levels;
+    /// a target ELO always uses one so its strength stays calibrated
+    #[serde(default = "default_search_threads")]
+    pub search_threads: u32,
+
+    /// How the in-game clocks are written
+    #[serde(default)]
+    pub clock_format: ClockFormat,
+
+    /// Vary the computer's openings from game to game via the opening book;
+    /// off plays the search's best move every time
+    #[serde(default = "default_true")]
+    pub ai_opening_variety: bool,
+
+    /// Show a piece's legal moves while hovering it, before it is selected
+    #[serde(default)]
+    pub preview_moves_on_hover: bool,
+
+    /// How far above the board a dragged piece is held, in board units
+    #[serde(default = "default_drag_lift_height")]
+    pub drag_lift_height: f32,
+
+    /// Multiplier on egui's pixels-per-point, for large or high-DPI screens
+    #[serde(default = "default_ui_scale")]
+    pub ui_scale: f32,
+
+    /// How the increment of offline time controls is applied
+    #[serde(default)]
+    pub increment_mode: IncrementMode,
+
+    /// Ask before playing a move a quick engine check calls a blunder
+    /// (offline games only)
+    #[serde(default)]
+    pub confirm_blunders: bool,
+
+    /// Ask before a capture that loses the exchange on its square
+    /// (offline games only)
+    #[serde(default)]
+    pub confirm_losing_captures: bool,
+
+    /// Touch-move: a selected piece that can move must be the one moved
+    #[serde(default)]
+    pub touch_move: bool,
+
+    /// Lay files out h→a from White's side instead of a→h; ranks are
+    /// unaffected. Takes effect from the next game.
+    #[serde(default)]
+    pub mirror_files: bool,
+
+    /// Show file letters and rank numbers around the board
+    #[serde(default = "default_true")]
+    pub show_coordinates: bool,
+
+    /// Pan move and capture sounds towards their square; mono when off
+    #[serde(default = "default_true")]
+    pub spatial_move_sounds: bool,
+
+    /// Promote without the dialog in bullet and blitz games
+    #[serde(default)]
+    pub auto_promotion: AutoPromotion,
+
+    /// Which set of game sounds to play
+    #[serde(default)]
+    pub sound_theme: SoundTheme,
+
+    /// Zoom range and starting height of the 3D board camera
+    #[serde(default)]
+    pub camera_zoom: CameraZoomSettings,
+
+    /// Viewpoint of the board camera at the start of a game
+    #[serde(default)]
+    pub starting_view: StartingView,
+
+    /// Hotkeys the player has moved off their defaults
+    #[serde(default)]
+    pub key_bindings: KeyBindings,
+
+    /// Multiplier on the opacity of every board overlay (hints, selection,
+    /// last move, check), from `HIGHLIGHT_OPACITY_MIN` to 1
+    #[serde(default = "default_highlight_opacity")]
+    pub highlight_opacity: f32,
+
+    /// How square highlights are drawn
+    #[serde(default)]
+    pub highlight_style: HighlightStyle,
+
+    /// What happens to captured pieces on the 3D board
+    #[serde(default)]
+    pub capture_animation: CaptureAnimation,
+
+    /// Seconds a capture or promotion animation takes on the 3D board, from
+    /// `EFFECT_ANIMATION_SECS_MIN` to `EFFECT_ANIMATION_SECS_MAX`
+    #[serde(default = "default_effect_animation_secs")]
+    pub effect_animation_secs: f32,
+
+    /// Multiplier on the length of every piece animation, from 0 (instant)
+    /// to `ANIMATION_SCALE_MAX` (slow)
+    #[serde(default = "default_animation_scale")]
+    pub animation_scale: f32,
+
+    /// Anti-aliasing of the 3D view; reset by choosing a quality preset
+    #[serde(default)]
+    pub msaa: MsaaLevel,
+
+    /// Resolution the 3D view is drawn at, as a fraction of the window's,
+    /// from `RENDER_SCALE_MIN` to 1. The UI is unaffected.
+    #[serde(default = "default_render_scale")]
+    pub render_scale: f32,
+
+    /// Glow around bright and emissive surfaces; reset by choosing a quality
+    /// preset
+    #[serde(default)]
+    pub bloom: bool,
+
+    /// Strength of the bloom, from 0 to `BLOOM_INTENSITY_MAX`
+    #[serde(default = "default_bloom_intensity")]
+    pub bloom_intensity: f32,
+
+    /// Distance fog over the 3D board; reset by choosing a quality preset
+    #[serde(default)]
+    pub fog: bool,
+
+    /// Density of the distance fog, from `FOG_DENSITY_MIN` to `FOG_DENSITY_MAX`
+    #[serde(default = "default_fog_density")]
+    pub fog_density: f32,
+
+    /// Windowed, borderless or fullscreen
+    #[serde(default)]
+    pub display_mode: DisplayMode,
+
+    /// Size of the window in logical pixels when [`DisplayMode::Windowed`]
+    #[serde(default = "default_window_resolution")]
+    pub window_resolution: [u32; 2],
+
+    /// Realistic promotion: a pawn may only become a piece its side has lost,
+    /// as with a physical set
+    #[serde(default)]
+    pub realistic_promotion: bool,
+}
+
+impl GameSettings {
+    /// Whether the key scene lights should cast shadows: the user's toggle
+    /// and the quality preset must both allow it.
+    pub fn shadows_enabled(&self) -> bool {
+        self.shadows && self.graphics_quality.shadow_enabled()
+    }
+
+    /// Switch to a quality preset, taking its anti-aliasing level, bloom and
+    /// fog with it.
+    pub fn select_graphics_quality(&mut self, quality: GraphicsQuality) {
+        self.graphics_quality = quality;
+        self.msaa = quality.msaa();
+        self.bloom = quality.bloom_enabled();
+        self.fog = quality.fog_enabled();
+    }
+
+    /// Volume everything plays at: the master volume, or 0 when muted.
+    pub fn output_volume(&self) -> f32 {
+        if self.muted {
+            0.0
+        } else {
+            self.master_volume
+        }
+    }
+
+    /// Volume sound effects play at, after the master volume and mute, or
+    /// 0 when sound effects are off.
+    pub fn sfx_output_volume(&self) -> f32 {
+        if self.sound_effects {
+            self.output_volume() * self.sfx_volume
+        } else {
+            0.0
+        }
+    }
+
+    /// Volume music plays at, after the master volume and mute.
+    pub fn music_output_volume(&self) -> f32 {
+        self.output_volume() * self.music_volume
+    }
 }
 
 impl Default for GameSettings {
     fn default() -> Self {
         Self {
             master_volume: 0.7,
+            sfx_volume: default_channel_volume(),
+            music_volume: default_channel_volume(),
+            sound_effects: true,
             muted: false,
             show_hints: true,
             highlight_last_move: true,
             use_vps_relay: true,
             graphics_quality: GraphicsQuality::Medium,
+            shadows: true,
             dynamic_lighting: DynamicLightingSettings::default(),
             board_theme: 0,
+            board_theme_3d: BoardTheme::default(),
+            piece_finish: PieceFinish::default(),
             blindfold: false,
             piece_set: 0,
             show_eval_bar: false,
+            autosave_interval_secs: default_autosave_interval(),
+            max_ai_think_secs: default_max_ai_think_secs(),
+            show_game_start_summary: true,
+            ai_resigns: false,
+            ai_resign_threshold_cp: default_ai_resign_threshold_cp(),
+            ai_resign_streak: default_ai_resign_streak(),
+            search_threads: default_search_threads(),
+            clock_format: ClockFormat::default(),
+            ai_opening_variety: true,
+            preview_moves_on_hover: false,
+            drag_lift_height: default_drag_lift_height(),
+            ui_scale: default_ui_scale(),
+            increment_mode: IncrementMode::default(),
+            confirm_blunders: false,
+            confirm_losing_captures: false,
+            touch_move: false,
+            mirror_files: false,
+            show_coordinates: true,
+            spatial_move_sounds: true,
+            auto_promotion: AutoPromotion::default(),
+            sound_theme: SoundTheme::default(),
+            camera_zoom: CameraZoomSettings::default(),
+            starting_view: StartingView::default(),
+            key_bindings: KeyBindings::default(),
+            highlight_opacity: default_highlight_opacity(),
+            highlight_style: HighlightStyle::default(),
+            capture_animation: CaptureAnimation::default(),
+            effect_animation_secs: default_effect_animation_secs(),
+            animation_scale: default_animation_scale(),
+            msaa: MsaaLevel::default(),
+            render_scale: default_render_scale(),
+            bloom: GraphicsQuality::Medium.bloom_enabled(),
+            bloom_intensity: default_bloom_intensity(),
+            fog: GraphicsQuality::Medium.fog_enabled(),
+            fog_density: default_fog_density(),
+            display_mode: DisplayMode::default(),
+            window_resolution: default_window_resolution(),
+            realistic_promotion: false,
         }
     }
 }
@@ -174,9 +887,120 @@ fn default_true() -> bool {
     true
 }
 
+fn default_autosave_interval() -> u32 {
+    120
+}
+
+fn default_max_ai_think_secs() -> f32 {
+    3.0
+}
+
+fn default_ai_resign_threshold_cp() -> u32 {
+    1000
+}
+
+fn default_ai_resign_streak() -> u32 {
+    3
+}
+
+fn default_search_threads() -> u32 {
+    1
+}
+
+/// Most search threads worth offering on this machine: its cores, up to the
+/// engine's own cap. Always 1 on the web.
+pub fn max_search_threads() -> u32 {
+    std::thread::available_parallelism()
+        .map_or(1, |n| n.get())
+        .min(nimzovich_engine::MAX_SEARCH_THREADS) as u32
+}
+
+fn default_drag_lift_height() -> f32 {
+    0.5
+}
+
+fn default_ui_scale() -> f32 {
+    1.0
+}
+
+fn default_channel_volume() -> f32 {
+    1.0
+}
+
+fn default_highlight_opacity() -> f32 {
+    1.0
+}
+
+fn default_render_scale() -> f32 {
+    1.0
+}
+
+fn default_effect_animation_secs() -> f32 {
+    0.75
+}
+
+fn default_animation_scale() -> f32 {
+    1.0
+}
+
+fn default_bloom_intensity() -> f32 {
+    0.15
+}
+
+fn default_fog_density() -> f32 {
+    0.03
+}
+
+fn default_window_resolution() -> [u32; 2] {
+    crate::core::window_config::SAFE_RESOLUTION
+}
+
+/// Number of AI difficulty levels tracked in [`GameStatistics::vs_ai`].
+pub const AI_LEVELS: usize = 8;
+
+/// Wins, losses and draws against one opponent, from the player's side
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, Reflect)]
+pub struct WinLossRecord {
+    pub wins: u32,
+    pub losses: u32,
+    pub draws: u32,
+}
+
+impl WinLossRecord {
+    pub fn games(&self) -> u32 {
+        self.wins + self.losses + self.draws
+    }
+
+    /// Share of games won, as a percentage; 0 before any game.
+    pub fn win_rate(&self) -> f32 {
+        match self.games() {
+            0 => 0.0,
+            games => self.wins as f32 / games as f32 * 100.0,
+        }
+    }
+}
+
+impl std::ops::AddAssign for WinLossRecord {
+    fn add_assign(&mut self, other: Self) {
+        self.wins += other.wins;
+        self.losses += other.losses;
+        self.draws += other.draws;
+    }
+}
+
+impl std::fmt::Display for WinLossRecord {
+    /// "W-L-D", e.g. "2-5-1"
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-{}-{}", self.wins, self.losses, self.draws)
+    }
+}
+
 /// Resource for tracking game statistics
-#[derive(Resource, Debug, Clone, Default, Reflect)]
+///
+/// Saved to disk after every change (see `stats_persistence`).
+#[derive(Resource, Debug, Clone, Default, PartialEq, Serialize, Deserialize, Reflect)]
 #[reflect(Resource)]
+#[serde(default)]
 pub struct GameStatistics {
     /// Total games played
     pub games_played: u32,
@@ -198,6 +1022,10 @@ pub struct GameStatistics {
 
     /// Shortest game (in moves)
     pub shortest_game: u32,
+
+    /// Record against the computer, by difficulty level (1-8, index 0-7)
+    /// and the colour the player had (White, Black)
+    pub vs_ai: [[WinLossRecord; 2]; AI_LEVELS],
 }
 
 impl GameStatistics {
@@ -226,6 +1054,57 @@ impl GameStatistics {
         }
     }
 
+    /// Count a finished game against the computer at `difficulty` (1-8) in
+    /// which the player had `player_color`. Out-of-range levels are ignored.
+    pub fn record_vs_ai(
+        &mut self,
+        difficulty: u8,
+        player_color: crate::rendering::pieces::PieceColor,
+        winner: Option<crate::rendering::pieces::PieceColor>,
+    ) {
+        let Some(record) = self.vs_ai_record_mut(difficulty, player_color) else {
+            return;
+        };
+        match winner {
+            Some(color) if color == player_color => record.wins += 1,
+            Some(_) => record.losses += 1,
+            None => record.draws += 1,
+        }
+    }
+
+    /// Record against the computer at `difficulty` (1-8) as `player_color`.
+    pub fn vs_ai_record(
+        &self,
+        difficulty: u8,
+        player_color: crate::rendering::pieces::PieceColor,
+    ) -> WinLossRecord {
+        let level = (difficulty as usize).wrapping_sub(1);
+        self.vs_ai
+            .get(level)
+            .map(|by_color| by_color[player_color as usize])
+            .unwrap_or_default()
+    }
+
+    /// Record against the computer over every level and both colours.
+    pub fn vs_ai_total(&self) -> WinLossRecord {
+        let mut total = WinLossRecord::default();
+        for record in self.vs_ai.iter().flatten() {
+            total += *record;
+        }
+        total
+    }
+
+    fn vs_ai_record_mut(
+        &mut self,
+        difficulty: u8,
+        player_color: crate::rendering::pieces::PieceColor,
+    ) -> Option<&mut WinLossRecord> {
+        let level = (difficulty as usize).wrapping_sub(1);
+        self.vs_ai
+            .get_mut(level)
+            .map(|by_color| &mut by_color[player_color as usize])
+    }
+
     pub fn average_moves(&self) -> f32 {
         if self.games_played > 0 {
             self.total_moves as f32 / self.games_played as f32
@@ -250,3 +1129,58 @@ impl GameStatistics {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clock_format_tenths_below_threshold() {
+        //! 9.3 s reads "0:09.3" with tenths on and "0:09" without
+
+        assert_eq!(ClockFormat::TenthsWhenLow.format(9.3), "0:09.3");
+        assert_eq!(ClockFormat::MinutesSeconds.format(9.3), "0:09");
+        assert_eq!(ClockFormat::TenthsWhenLow.format(299.9), "4:59");
+        assert_eq!(ClockFormat::TenthsWhenLow.format(-1.0), "0:00.0");
+    }
+
+    #[test]
+    fn test_loss_vs_ai_counts_in_one_bucket() {
+        //! Losing to level 4 (the default, "medium") as Black touches only that bucket
+        use crate::rendering::pieces::PieceColor;
+
+        let mut stats = GameStatistics::default();
+        stats.record_vs_ai(4, PieceColor::Black, Some(PieceColor::White));
+
+        let mut expected = GameStatistics::default();
+        expected.vs_ai[3][PieceColor::Black as usize].losses = 1;
+        assert_eq!(stats, expected);
+        assert_eq!(
+            stats.vs_ai_record(4, PieceColor::Black).to_string(),
+            "0-1-0"
+        );
+        assert_eq!(stats.vs_ai_record(4, PieceColor::White).games(), 0);
+
+        stats.record_vs_ai(0, PieceColor::White, None);
+        stats.record_vs_ai(9, PieceColor::White, None);
+        assert_eq!(stats, expected);
+    }
+
+    #[test]
+    fn test_vs_ai_total_sums_every_level_and_colour() {
+        //! Wins, losses and draws add up across levels, and the win rate is over all of them
+        use crate::rendering::pieces::PieceColor;
+
+        let mut stats = GameStatistics::default();
+        assert_eq!(stats.vs_ai_total().win_rate(), 0.0);
+
+        stats.record_vs_ai(1, PieceColor::White, Some(PieceColor::White));
+        stats.record_vs_ai(8, PieceColor::Black, Some(PieceColor::Black));
+        stats.record_vs_ai(4, PieceColor::Black, Some(PieceColor::White));
+        stats.record_vs_ai(4, PieceColor::White, None);
+
+        let total = stats.vs_ai_total();
+        assert_eq!(total.to_string(), "2-1-1");
+        assert_eq!(total.win_rate(), 50.0);
+    }
+}
@@ -14,6 +14,11 @@
 //! - Load failures fall back to default settings
 //! - Save failures are logged but don't interrupt gameplay
 //!
+//! # Older Files
+//!
+//! A field a file predates takes its serde default, unless
+//! [`migrate_settings`] can work out what the older build did instead.
+//!
 //! # Usage
 //!
 //! Settings are automatically loaded on startup via [`load_settings_system`]
@@ -21,6 +26,7 @@
 
 use crate::core::GameSettings;
 use bevy::prelude::*;
+use serde_json::{Map, Value};
 
 #[cfg(not(target_arch = "wasm32"))]
 use directories::ProjectDirs;
@@ -52,6 +58,25 @@ fn get_settings_path() -> PathBuf {
     }
 }
 
+/// Fill in fields `saved` predates from the ones it has.
+///
+/// Bloom used to come with the High and Ultra presets; a file from before
+/// it had its own switch keeps it on for them.
+pub fn migrate_settings(settings: &mut GameSettings, saved: &Map<String, Value>) {
+    if !saved.contains_key("bloom") {
+        settings.bloom = settings.graphics_quality.bloom_enabled();
+    }
+}
+
+/// Deserialize a saved settings object and migrate it.
+fn settings_from_json(value: Value) -> Result<GameSettings, serde_json::Error> {
+    let mut settings: GameSettings = serde_json::from_value(value.clone())?;
+    if let Value::Object(saved) = &value {
+        migrate_settings(&mut settings, saved);
+    }
+    Ok(settings)
+}
+
 /// Load settings from file on startup
 ///
 /// Attempts to load settings from the system config directory. If the file doesn't exist or
@@ -60,8 +85,11 @@ fn get_settings_path() -> PathBuf {
 pub fn load_settings_system(mut commands: Commands) {
     #[cfg(target_arch = "wasm32")]
     {
-        match LocalStorage::get("xfchess_settings") {
-            Ok(mut settings) => {
+        match LocalStorage::get::<Value>("xfchess_settings")
+            .map_err(|e| e.to_string())
+            .and_then(|value| settings_from_json(value).map_err(|e| e.to_string()))
+        {
+            Ok(settings) => {
                 // Sync colors from serialized format (if needed, dependent on how serde handles it)
                 // Assuming serde handles the GameSettings struct cleanly, but if we need manual sync:
                 let temp_settings: GameSettings = settings;
@@ -84,7 +112,7 @@ pub fn load_settings_system(mut commands: Commands) {
         if settings_path.exists() {
             match fs::read_to_string(&settings_path) {
                 Ok(contents) => {
-                    match serde_json::from_str::<GameSettings>(&contents) {
+                    match serde_json::from_str::<Value>(&contents).and_then(settings_from_json) {
                         Ok(settings) => {
                             // Sync colors from serialized format
 
@@ -122,11 +150,16 @@ pub fn load_settings_system(mut commands: Commands) {
 /// Save settings to file when they change
 ///
 /// Watches for changes to [`GameSettings`] and automatically saves to `settings.json`
-/// in the user's configuration directory.
-pub fn save_settings_system(settings: ResMut<GameSettings>) {
-    if !settings.is_changed() {
+/// in the user's configuration directory. A change that leaves every value as
+/// last saved (a UI holding `&mut` without editing) writes nothing.
+pub fn save_settings_system(
+    settings: Res<GameSettings>,
+    mut last_saved: Local<Option<GameSettings>>,
+) {
+    if !settings.is_changed() || last_saved.as_ref() == Some(&*settings) {
         return;
     }
+    *last_saved = Some(settings.clone());
 
     // Sync colors for serialization
 
@@ -176,3 +209,32 @@ pub fn save_settings_system(settings: ResMut<GameSettings>) {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::GraphicsQuality;
+
+    #[test]
+    fn test_files_from_before_the_bloom_switch_keep_the_presets_bloom() {
+        //! A High settings file without a bloom field loads with bloom on; a saved choice is kept
+
+        let high = GameSettings {
+            graphics_quality: GraphicsQuality::High,
+            bloom: false,
+            ..GameSettings::default()
+        };
+        let Ok(Value::Object(mut saved)) = serde_json::to_value(&high) else {
+            panic!("settings serialize to an object");
+        };
+        saved.remove("bloom");
+        let loaded = settings_from_json(Value::Object(saved.clone())).unwrap();
+        assert!(loaded.bloom);
+
+        saved.insert("graphics_quality".to_string(), Value::from("Medium"));
+        assert!(!settings_from_json(Value::Object(saved)).unwrap().bloom);
+
+        let kept = settings_from_json(serde_json::to_value(&high).unwrap()).unwrap();
+        assert!(!kept.bloom);
+    }
+}
@@ -0,0 +1,409 @@
+//! Settings export and import
+//!
+//! Writes the current [`GameSettings`] together with the local profile
+//! records ([`GameStatistics`]) to a single JSON file the player can copy to
+//! another machine, and reads such a file back. Unlike `settings.json`, which
+//! is only ever read by the build that wrote it, an export carries a
+//! `version` field so a file from an older or newer build is handled on
+//! purpose:
+//!
+//! - A newer version than [`SETTINGS_EXPORT_VERSION`] is rejected, since its
+//!   fields may mean something this build doesn't know about.
+//! - A file without a version is taken to be a bare `settings.json` copied by
+//!   hand, and imported as settings only.
+//! - Within a known version, each setting is checked on its own: a field that
+//!   doesn't parse keeps its default, and values outside what the settings
+//!   screen allows are pulled back into range. The fields touched are listed
+//!   in [`ImportedSettings::repaired`].
+
+use crate::core::settings_persistence::migrate_settings;
+use crate::core::window_config::RESOLUTIONS;
+use crate::core::{CameraZoomSettings, GameSettings, GameStatistics};
+use crate::game::components::{EFFECT_ANIMATION_SECS_MAX, EFFECT_ANIMATION_SECS_MIN};
+use crate::game::resources::ANIMATION_SCALE_MAX;
+use crate::rendering::effects::HIGHLIGHT_OPACITY_MIN;
+use crate::rendering::graphics_quality::{
+    BLOOM_INTENSITY_MAX, FOG_DENSITY_MAX, FOG_DENSITY_MIN, RENDER_SCALE_MIN,
+};
+use crate::ui::ui_scale::{UI_SCALE_MAX, UI_SCALE_MIN};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::path::Path;
+
+/// Current export format. Bump when [`SettingsExport`] changes incompatibly.
+pub const SETTINGS_EXPORT_VERSION: u32 = 1;
+
+/// File name suggested when exporting.
+pub const EXPORT_FILE_NAME: &str = "xfchess-settings.json";
+
+/// Errors raised while exporting or importing settings.
+#[derive(Debug, thiserror::Error)]
+pub enum SettingsTransferError {
+    /// The file could not be read or written.
+    #[error("settings file I/O failed: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// The file is not a settings export at all.
+    #[error("not a settings export: {0}")]
+    Invalid(String),
+
+    /// The file was written by a newer version of the game.
+    #[error("settings export v{found} is newer than this version supports (v{supported})")]
+    Version { found: u32, supported: u32 },
+}
+
+/// On-disk layout of an export.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SettingsExport {
+    pub version: u32,
+    /// Unix timestamp of the export.
+    pub exported_at: i64,
+    pub settings: GameSettings,
+    /// Records shown on the profile panel.
+    pub statistics: GameStatistics,
+}
+
+/// What an import produced, ready to replace the live resources.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImportedSettings {
+    pub settings: GameSettings,
+    /// `None` when the file carried settings only.
+    pub statistics: Option<GameStatistics>,
+    /// Settings that were reset or clamped, by field name.
+    pub repaired: Vec<String>,
+}
+
+/// Serialize `settings` and `statistics` as a versioned export.
+pub fn export_to_string(
+    settings: &GameSettings,
+    statistics: &GameStatistics,
+) -> Result<String, SettingsTransferError> {
+    let export = SettingsExport {
+        version: SETTINGS_EXPORT_VERSION,
+        exported_at: chrono::Utc::now().timestamp(),
+        settings: settings.clone(),
+        statistics: statistics.clone(),
+    };
+    serde_json::to_string_pretty(&export).map_err(|e| SettingsTransferError::Invalid(e.to_string()))
+}
+
+/// Read an export (or a bare `settings.json`), repairing what it can.
+pub fn import_from_str(json: &str) -> Result<ImportedSettings, SettingsTransferError> {
+    let value: Value =
+        serde_json::from_str(json).map_err(|e| SettingsTransferError::Invalid(e.to_string()))?;
+    let Value::Object(mut root) = value else {
+        return Err(SettingsTransferError::Invalid(
+            "expected a JSON object".to_string(),
+        ));
+    };
+
+    let Some(version) = root.get("version") else {
+        let (settings, repaired) = merge_settings(&root);
+        return Ok(ImportedSettings {
+            settings,
+            statistics: None,
+            repaired,
+        });
+    };
+    let version = version
+        .as_u64()
+        .and_then(|v| u32::try_from(v).ok())
+        .ok_or_else(|| SettingsTransferError::Invalid(format!("bad version {}", version)))?;
+    if version > SETTINGS_EXPORT_VERSION {
+        return Err(SettingsTransferError::Version {
+            found: version,
+            supported: SETTINGS_EXPORT_VERSION,
+        });
+    }
+
+    let Some(Value::Object(settings)) = root.remove("settings") else {
+        return Err(SettingsTransferError::Invalid(
+            "missing settings".to_string(),
+        ));
+    };
+    let (settings, mut repaired) = merge_settings(&settings);
+    let statistics = match root.remove("statistics") {
+        None => None,
+        Some(stats) => match serde_json::from_value::<GameStatistics>(stats) {
+            Ok(stats) => Some(stats),
+            Err(_) => {
+                repaired.push("statistics".to_string());
+                None
+            }
+        },
+    };
+    Ok(ImportedSettings {
+        settings,
+        statistics,
+        repaired,
+    })
+}
+
+/// Write an export of `settings` and `statistics` to `path`.
+pub fn export_to_file(
+    path: &Path,
+    settings: &GameSettings,
+    statistics: &GameStatistics,
+) -> Result<(), SettingsTransferError> {
+    std::fs::write(path, export_to_string(settings, statistics)?)?;
+    Ok(())
+}
+
+/// Read an export from `path`.
+pub fn import_from_file(path: &Path) -> Result<ImportedSettings, SettingsTransferError> {
+    import_from_str(&std::fs::read_to_string(path)?)
+}
+
+/// Lay each incoming field over the defaults, keeping only the ones that
+/// parse, then pull numbers back into their allowed ranges. Returns the
+/// settings and the names of fields that were dropped or clamped.
+fn merge_settings(incoming: &Map<String, Value>) -> (GameSettings, Vec<String>) {
+    let defaults = GameSettings::default();
+    let Ok(Value::Object(mut merged)) = serde_json::to_value(&defaults) else {
+        return (defaults, Vec::new());
+    };
+    let mut repaired = Vec::new();
+
+    for (key, value) in incoming {
+        // Fields from other builds that this one doesn't have are ignored.
+        if !merged.contains_key(key) {
+            continue;
+        }
+        let previous = merged.insert(key.clone(), value.clone());
+        if serde_json::from_value::<GameSettings>(Value::Object(merged.clone())).is_err() {
+            if let Some(previous) = previous {
+                merged.insert(key.clone(), previous);
+            }
+            repaired.push(key.clone());
+        }
+    }
+
+    let mut settings = serde_json::from_value(Value::Object(merged)).unwrap_or(defaults);
+    migrate_settings(&mut settings, incoming);
+    repaired.extend(
+        clamp_settings(&mut settings)
+            .into_iter()
+            .map(str::to_string),
+    );
+    (settings, repaired)
+}
+
+/// Pull out-of-range values back to what the settings screen allows,
+/// returning the fields changed.
+fn clamp_settings(settings: &mut GameSettings) -> Vec<&'static str> {
+    fn clamp_f32(value: &mut f32, min: f32, max: f32, default: f32) -> bool {
+        let fixed = if value.is_finite() {
+            value.clamp(min, max)
+        } else {
+            default
+        };
+        let changed = fixed != *value;
+        *value = fixed;
+        changed
+    }
+
+    let defaults = GameSettings::default();
+    let mut changed = Vec::new();
+    if clamp_f32(
+        &mut settings.master_volume,
+        0.0,
+        1.0,
+        defaults.master_volume,
+    ) {
+        changed.push("master_volume");
+    }
+    if clamp_f32(&mut settings.sfx_volume, 0.0, 1.0, defaults.sfx_volume) {
+        changed.push("sfx_volume");
+    }
+    if clamp_f32(&mut settings.music_volume, 0.0, 1.0, defaults.music_volume) {
+        changed.push("music_volume");
+    }
+    if clamp_f32(
+        &mut settings.ui_scale,
+        UI_SCALE_MIN,
+        UI_SCALE_MAX,
+        defaults.ui_scale,
+    ) {
+        changed.push("ui_scale");
+    }
+    if clamp_f32(
+        &mut settings.drag_lift_height,
+        0.0,
+        1.5,
+        defaults.drag_lift_height,
+    ) {
+        changed.push("drag_lift_height");
+    }
+    if clamp_f32(
+        &mut settings.highlight_opacity,
+        HIGHLIGHT_OPACITY_MIN,
+        1.0,
+        defaults.highlight_opacity,
+    ) {
+        changed.push("highlight_opacity");
+    }
+    if clamp_f32(
+        &mut settings.render_scale,
+        RENDER_SCALE_MIN,
+        1.0,
+        defaults.render_scale,
+    ) {
+        changed.push("render_scale");
+    }
+    if clamp_f32(
+        &mut settings.bloom_intensity,
+        0.0,
+        BLOOM_INTENSITY_MAX,
+        defaults.bloom_intensity,
+    ) {
+        changed.push("bloom_intensity");
+    }
+    if clamp_f32(
+        &mut settings.fog_density,
+        FOG_DENSITY_MIN,
+        FOG_DENSITY_MAX,
+        defaults.fog_density,
+    ) {
+        changed.push("fog_density");
+    }
+    if clamp_f32(
+        &mut settings.effect_animation_secs,
+        EFFECT_ANIMATION_SECS_MIN,
+        EFFECT_ANIMATION_SECS_MAX,
+        defaults.effect_animation_secs,
+    ) {
+        changed.push("effect_animation_secs");
+    }
+    if clamp_f32(
+        &mut settings.animation_scale,
+        0.0,
+        ANIMATION_SCALE_MAX,
+        defaults.animation_scale,
+    ) {
+        changed.push("animation_scale");
+    }
+    let (lo, hi) = (
+        *CameraZoomSettings::RANGE.start(),
+        *CameraZoomSettings::RANGE.end(),
+    );
+    let zoom = &mut settings.camera_zoom;
+    let zoom_default = defaults.camera_zoom;
+    if clamp_f32(&mut zoom.min, lo, hi, zoom_default.min)
+        | clamp_f32(&mut zoom.max, lo, hi, zoom_default.max)
+        | clamp_f32(&mut zoom.initial, lo, hi, zoom_default.initial)
+    {
+        changed.push("camera_zoom");
+    }
+    if settings.board_theme > 4 {
+        settings.board_theme = defaults.board_theme;
+        changed.push("board_theme");
+    }
+    if settings.piece_set > 2 {
+        settings.piece_set = defaults.piece_set;
+        changed.push("piece_set");
+    }
+    if !RESOLUTIONS.contains(&settings.window_resolution) {
+        settings.window_resolution = defaults.window_resolution;
+        changed.push("window_resolution");
+    }
+    if !(1..=nimzovich_engine::MAX_SEARCH_THREADS as u32).contains(&settings.search_threads) {
+        settings.search_threads = defaults.search_threads;
+        changed.push("search_threads");
+    }
+    if settings.ai_resign_streak == 0 {
+        settings.ai_resign_streak = defaults.ai_resign_streak;
+        changed.push("ai_resign_streak");
+    }
+    changed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{
+        AutoPromotion, BoardTheme, CaptureAnimation, DisplayMode, GameAction, GraphicsQuality,
+        HighlightStyle, PieceFinish, SoundTheme, StartingView,
+    };
+    use crate::rendering::pieces::PieceColor;
+    use bevy::prelude::KeyCode;
+
+    #[test]
+    fn export_round_trips_every_field() {
+        //! Non-default settings and records come back from an export unchanged
+
+        let mut settings = GameSettings::default();
+        settings.master_volume = 0.4;
+        settings.sfx_volume = 0.6;
+        settings.music_volume = 0.25;
+        settings.sound_effects = false;
+        settings.muted = true;
+        settings.board_theme = 3;
+        settings.board_theme_3d = BoardTheme::Marble;
+        settings.piece_finish = PieceFinish::Glass;
+        settings.ui_scale = 1.5;
+        settings.touch_move = true;
+        settings.show_coordinates = false;
+        settings.auto_promotion = AutoPromotion::Knight;
+        settings.realistic_promotion = true;
+        settings.sound_theme = SoundTheme::Retro;
+        settings.highlight_opacity = 0.6;
+        settings.highlight_style = HighlightStyle::CornerDots;
+        settings.capture_animation = CaptureAnimation::Slide;
+        settings.effect_animation_secs = 1.25;
+        settings.animation_scale = 0.0;
+        settings.select_graphics_quality(GraphicsQuality::Ultra);
+        settings.render_scale = 0.75;
+        settings.bloom_intensity = 0.3;
+        settings.fog_density = 0.05;
+        settings.display_mode = DisplayMode::Borderless;
+        settings.window_resolution = [1920, 1080];
+        settings.search_threads = 4;
+        settings.camera_zoom.max = 45.0;
+        settings.starting_view = StartingView::TopDown;
+        settings
+            .key_bindings
+            .set(GameAction::QuickSave, KeyCode::KeyK);
+        settings.dynamic_lighting.colors = vec![[0.5, 0.25, 1.0]];
+        let mut statistics = GameStatistics::default();
+        statistics.record_game(Some(PieceColor::White), 42);
+        statistics.record_vs_ai(3, PieceColor::Black, None);
+
+        let json = export_to_string(&settings, &statistics).unwrap();
+        let imported = import_from_str(&json).unwrap();
+
+        assert_eq!(imported.settings, settings);
+        assert_eq!(imported.statistics, Some(statistics));
+        assert!(imported.repaired.is_empty());
+    }
+
+    #[test]
+    fn newer_versions_are_rejected_and_bad_fields_repaired() {
+        //! A future version is refused; a bare settings file keeps its good fields and fixes the rest
+
+        let future = format!(
+            r#"{{"version": {}, "settings": {{}}}}"#,
+            SETTINGS_EXPORT_VERSION + 1
+        );
+        assert!(matches!(
+            import_from_str(&future),
+            Err(SettingsTransferError::Version { .. })
+        ));
+        assert!(matches!(
+            import_from_str("[1, 2]"),
+            Err(SettingsTransferError::Invalid(_))
+        ));
+
+        let bare = r#"{
+            "master_volume": 3.0,
+            "blindfold": true,
+            "sound_theme": "Kazoo",
+            "from_a_newer_build": 1
+        }"#;
+        let imported = import_from_str(bare).unwrap();
+        assert_eq!(imported.settings.master_volume, 1.0);
+        assert!(imported.settings.blindfold);
+        assert_eq!(imported.settings.sound_theme, SoundTheme::Classic);
+        assert_eq!(imported.statistics, None);
+        assert_eq!(imported.repaired, ["sound_theme", "master_volume"]);
+    }
+}
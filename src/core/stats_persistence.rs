@@ -0,0 +1,111 @@
+//! Statistics persistence system
+//!
+//! Saves and loads [`GameStatistics`] to/from a JSON file next to
+//! `settings.json`, so records (including the per-difficulty records against
+//! the computer) carry over between sessions. Errors are handled the same way
+//! as for settings: a failed load starts from empty statistics and a failed
+//! save is only logged.
+
+use crate::core::GameStatistics;
+use bevy::prelude::*;
+
+#[cfg(not(target_arch = "wasm32"))]
+use directories::ProjectDirs;
+#[cfg(not(target_arch = "wasm32"))]
+use std::fs;
+#[cfg(not(target_arch = "wasm32"))]
+use std::path::PathBuf;
+
+#[cfg(target_arch = "wasm32")]
+use gloo_storage::{LocalStorage, Storage};
+
+/// Statistics filename
+#[cfg(not(target_arch = "wasm32"))]
+const STATS_FILENAME: &str = "statistics.json";
+
+/// LocalStorage key on the web build
+#[cfg(target_arch = "wasm32")]
+const STATS_STORAGE_KEY: &str = "xfchess_statistics";
+
+/// Path to `statistics.json` in the user's configuration directory, falling
+/// back to the current directory.
+#[cfg(not(target_arch = "wasm32"))]
+fn get_stats_path() -> PathBuf {
+    if let Some(proj_dirs) = ProjectDirs::from("com", "trilltino", "XFChess") {
+        proj_dirs.config_dir().join(STATS_FILENAME)
+    } else {
+        PathBuf::from(STATS_FILENAME)
+    }
+}
+
+/// Load statistics on startup, keeping the empty defaults if there are none.
+pub fn load_stats_system(mut commands: Commands) {
+    #[cfg(target_arch = "wasm32")]
+    {
+        if let Ok(stats) = LocalStorage::get::<GameStatistics>(STATS_STORAGE_KEY) {
+            info!("[STATS] Loaded statistics from LocalStorage");
+            commands.insert_resource(stats);
+        }
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let stats_path = get_stats_path();
+        if !stats_path.exists() {
+            return;
+        }
+        match fs::read_to_string(&stats_path)
+            .map_err(|e| e.to_string())
+            .and_then(|contents| {
+                serde_json::from_str::<GameStatistics>(&contents).map_err(|e| e.to_string())
+            }) {
+            Ok(stats) => {
+                info!("[STATS] Loaded statistics from {:?}", stats_path);
+                commands.insert_resource(stats);
+            }
+            Err(e) => warn!(
+                "[STATS] Failed to load statistics from {:?}: {}. Starting fresh.",
+                stats_path, e
+            ),
+        }
+    }
+}
+
+/// Save statistics whenever they change (normally once per finished game).
+pub fn save_stats_system(stats: Res<GameStatistics>) {
+    if !stats.is_changed() || stats.is_added() {
+        return;
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    {
+        if let Err(e) = LocalStorage::set(STATS_STORAGE_KEY, stats.as_ref()) {
+            error!("[STATS] Failed to save statistics to LocalStorage: {:?}", e);
+        }
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let stats_path = get_stats_path();
+        if let Some(parent) = stats_path.parent() {
+            if let Err(e) = fs::create_dir_all(parent) {
+                error!(
+                    "[STATS] Failed to create statistics directory at {:?}: {}",
+                    parent, e
+                );
+                return;
+            }
+        }
+
+        match serde_json::to_string_pretty(stats.as_ref()) {
+            Ok(json) => match fs::write(&stats_path, json) {
+                Ok(_) => info!("[STATS] Saved statistics to {:?}", stats_path),
+                Err(e) => error!(
+                    "[STATS] Failed to write statistics file at {:?}: {}",
+                    stats_path, e
+                ),
+            },
+            Err(e) => error!("[STATS] Failed to serialize statistics: {}", e),
+        }
+    }
+}
@@ -2,9 +2,34 @@
 //!
 //! Centralizes window settings for the application, allowing easy configuration
 //! and modification of window properties.
+//!
+//! The player's choice of [`DisplayMode`] and windowed resolution lives in
+//! [`GameSettings`], so it is saved with the other settings.
+//! [`apply_window_settings_system`] applies it to the primary window at
+//! startup and whenever it changes. A saved resolution larger than the
+//! monitor, e.g. after moving to a smaller screen, falls back to
+//! [`SAFE_RESOLUTION`].
 
+use crate::core::{DisplayMode, GameSettings};
 use bevy::prelude::*;
-use bevy::window::{MonitorSelection, PresentMode, VideoModeSelection, Window};
+use bevy::window::{
+    Monitor, MonitorSelection, PresentMode, PrimaryMonitor, PrimaryWindow, VideoModeSelection,
+    Window, WindowMode,
+};
+
+/// Windowed resolutions offered in the settings, in logical pixels.
+pub const RESOLUTIONS: [[u32; 2]; 6] = [
+    [1280, 720],
+    [1366, 768],
+    [1600, 900],
+    [1920, 1080],
+    [2560, 1440],
+    [3840, 2160],
+];
+
+/// Windowed resolution used by default, and when the saved one doesn't fit
+/// the monitor.
+pub const SAFE_RESOLUTION: [u32; 2] = [1280, 720];
 
 /// Configuration for the primary application window
 ///
@@ -61,3 +86,100 @@ impl WindowConfig {
         }
     }
 }
+
+/// The Bevy window mode for `mode`, on the window's current monitor.
+pub fn window_mode(mode: DisplayMode) -> WindowMode {
+    match mode {
+        DisplayMode::Windowed => WindowMode::Windowed,
+        DisplayMode::Borderless => WindowMode::BorderlessFullscreen(MonitorSelection::Current),
+        DisplayMode::Fullscreen => {
+            WindowMode::Fullscreen(MonitorSelection::Current, VideoModeSelection::Current)
+        }
+    }
+}
+
+/// `resolution` if it fits on a monitor `monitor` logical pixels in size,
+/// otherwise [`SAFE_RESOLUTION`]. Without a known monitor it is kept.
+pub fn fit_resolution(resolution: [u32; 2], monitor: Option<Vec2>) -> [u32; 2] {
+    match monitor {
+        Some(size) if resolution[0] as f32 > size.x || resolution[1] as f32 > size.y => {
+            SAFE_RESOLUTION
+        }
+        _ => resolution,
+    }
+}
+
+/// Give the primary window the display mode and resolution in
+/// [`GameSettings`] whenever they change, correcting a resolution too large
+/// for the monitor.
+pub fn apply_window_settings_system(
+    mut settings: ResMut<GameSettings>,
+    monitors: Query<&Monitor, With<PrimaryMonitor>>,
+    mut windows: Query<&mut Window, With<PrimaryWindow>>,
+    mut applied: Local<Option<(DisplayMode, [u32; 2])>>,
+) {
+    let wanted = (settings.display_mode, settings.window_resolution);
+    if *applied == Some(wanted) {
+        return;
+    }
+    let Ok(mut window) = windows.single_mut() else {
+        return;
+    };
+
+    let monitor = monitors.single().ok().map(|monitor| {
+        Vec2::new(
+            monitor.physical_width as f32,
+            monitor.physical_height as f32,
+        ) / monitor.scale_factor as f32
+    });
+    let resolution = fit_resolution(settings.window_resolution, monitor);
+    if resolution != settings.window_resolution {
+        warn!(
+            "[WINDOW] {}x{} is larger than the monitor; using {}x{}",
+            settings.window_resolution[0],
+            settings.window_resolution[1],
+            resolution[0],
+            resolution[1]
+        );
+        settings.window_resolution = resolution;
+    }
+
+    let mode = window_mode(settings.display_mode);
+    if window.mode != mode {
+        window.mode = mode;
+    }
+    // In the browser the canvas is sized to its parent instead.
+    if settings.display_mode == DisplayMode::Windowed && cfg!(not(target_arch = "wasm32")) {
+        window
+            .resolution
+            .set(resolution[0] as f32, resolution[1] as f32);
+    }
+    *applied = Some((settings.display_mode, resolution));
+    info!(
+        "[WINDOW] {} at {}x{}",
+        settings.display_mode.label(),
+        resolution[0],
+        resolution[1]
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolution_larger_than_the_monitor_falls_back() {
+        //! 2560x1440 is kept on a 4K monitor but replaced on a 1080p one, and an unknown monitor keeps it
+
+        let wanted = [2560, 1440];
+        assert_eq!(
+            fit_resolution(wanted, Some(Vec2::new(3840.0, 2160.0))),
+            wanted
+        );
+        assert_eq!(
+            fit_resolution(wanted, Some(Vec2::new(1920.0, 1080.0))),
+            SAFE_RESOLUTION
+        );
+        assert_eq!(fit_resolution(wanted, None), wanted);
+    }
+}
@@ -10,8 +10,9 @@ use crate::game::components::HasMoved;
 use crate::rendering::pieces::{Piece, PieceColor, PieceType};
 use bevy::prelude::*;
 use nimzovich_engine::{
-    game_from_fen_no_tt, generate_pseudo_legal_moves, is_legal_move, is_legal_move_unchecked,
-    set_game_from_fen, Game, BISHOP_ID, KING_ID, KNIGHT_ID, PAWN_ID, QUEEN_ID, ROOK_ID,
+    game_from_fen_no_tt, generate_pseudo_legal_moves, get_game_status, is_legal_move,
+    is_legal_move_unchecked, see_value, set_game_from_fen, Game, GameStatus, BISHOP_ID, KING_ID,
+    KK, KNIGHT_ID, PAWN_ID, QUEEN_ID, ROOK_ID,
 };
 use std::collections::HashMap;
 
@@ -47,6 +48,14 @@ pub struct ChessEngine {
     pub move_cache_valid: bool,
 }
 
+/// The engine's position together with its repetition history, taken
+/// before a move so the move can be taken back.
+#[derive(Debug, Clone)]
+pub struct EngineSnapshot {
+    fen: String,
+    hash_history: Vec<nimzovich_engine::types::BitBuffer192>,
+}
+
 /// A wrapper for a chess move to maintain some compatibility with the previous shakmaty-based API.
 pub struct MoveWrapper {
     pub from: (u8, u8),
@@ -137,6 +146,15 @@ impl ChessEngine {
         }
     }
 
+    /// Signed engine code for a piece: the type id, negated for Black.
+    pub fn piece_code(color: PieceColor, piece_type: PieceType) -> i8 {
+        let id = Self::piece_type_to_id(piece_type);
+        match color {
+            PieceColor::White => id,
+            PieceColor::Black => -id,
+        }
+    }
+
     // ─── Board sync ─────────────────────────────────────────────────────────
 
     pub fn sync_ecs_to_engine_mut(
@@ -180,18 +198,13 @@ impl ChessEngine {
                 continue;
             }
             let sq = Self::square_to_index(piece.x, piece.y) as usize;
-            let id = Self::piece_type_to_id(piece.piece_type);
-            board[sq] = if piece.color == PieceColor::White {
-                id
-            } else {
-                -id
-            };
+            board[sq] = Self::piece_code(piece.color, piece.piece_type);
 
             if piece.piece_type == PieceType::King {
-                if piece.color == PieceColor::White {
-                    castling.white_king_moved = has_moved.moved;
-                } else {
-                    castling.black_king_moved = has_moved.moved;
+                match (piece.color, sq) {
+                    (PieceColor::White, 4) => castling.white_king_moved = has_moved.moved,
+                    (PieceColor::Black, 60) => castling.black_king_moved = has_moved.moved,
+                    _ => {}
                 }
             } else if piece.piece_type == PieceType::Rook {
                 match (piece.color, sq) {
@@ -223,7 +236,22 @@ impl ChessEngine {
         );
 
         self.castling_rights = castling_str;
+
+        // Loading the FEN starts a fresh repetition history. Carry the
+        // positions since the last capture or pawn move across, so the
+        // engine can see a threefold repetition spanning several moves.
+        let mut history = std::mem::take(&mut self.game.hash_history);
         self.refresh_position();
+        let current = self.game.current_hash;
+        if self.halfmove_clock > 0 && !history.is_empty() {
+            // A re-sync of the same position must not count it twice.
+            if history.last() != Some(&current) {
+                history.push(current);
+            }
+            let keep = (self.halfmove_clock as usize + 1).min(history.len());
+            history.drain(..history.len() - keep);
+            self.game.hash_history = history;
+        }
     }
 
     pub fn refresh_position(&mut self) {
@@ -238,13 +266,16 @@ impl ChessEngine {
     pub fn rebuild_legal_move_cache(&mut self) {
         self.move_cache.clear();
         let side = if self.fen.contains(" w ") { 1 } else { -1 };
+        let color = if side == 1 {
+            PieceColor::White
+        } else {
+            PieceColor::Black
+        };
         let moves = generate_pseudo_legal_moves(&self.game, side);
-        for mv in moves {
-            if is_legal_move_unchecked(&mut self.game, mv.src, mv.dst, side) {
-                let from = Self::index_to_coords(mv.src);
-                let to = Self::index_to_coords(mv.dst);
-                self.move_cache.entry(from).or_default().push(to);
-            }
+        for mv in filter_legal(moves, &mut self.game, color) {
+            let from = Self::index_to_coords(mv.src);
+            let to = Self::index_to_coords(mv.dst);
+            self.move_cache.entry(from).or_default().push(to);
         }
         self.move_cache_valid = true;
     }
@@ -300,6 +331,13 @@ impl ChessEngine {
         !self.move_cache.is_empty()
     }
 
+    /// Engine verdict for the side to move: mate, stalemate, fifty-move
+    /// rule, threefold repetition or insufficient material.
+    pub fn game_status(&mut self) -> GameStatus {
+        let side = if self.fen.contains(" w ") { 1 } else { -1 };
+        get_game_status(&mut self.game, side)
+    }
+
     /// SAN (Standard Algebraic Notation) for a move about to be applied,
     /// computed from the engine's *current* (pre-move) position — including
     /// correct disambiguation (e.g. `Nbd2` vs `Nfd2`) and promotion suffix.
@@ -318,6 +356,25 @@ impl ChessEngine {
         nimzovich_engine::move_to_san(&mut self.game, src, dst, promo)
     }
 
+    /// Static exchange evaluation of `from` → `to` in the current position:
+    /// centipawns the mover comes out ahead (negative: behind) once both
+    /// sides have recaptured with their cheapest pieces.
+    pub fn exchange_value(&self, from: (u8, u8), to: (u8, u8)) -> i32 {
+        let mv = KK {
+            src: Self::square_to_index(from.0, from.1),
+            dst: Self::square_to_index(to.0, to.1),
+            ..Default::default()
+        };
+        see_value(&self.game, mv)
+    }
+
+    /// The engine's own board, indexed `rank * 8 + file`, holding
+    /// [`Self::piece_code`] values (0 for empty). For debugging desyncs
+    /// against the ECS pieces.
+    pub fn board(&self) -> &[i8; 64] {
+        &self.game.board
+    }
+
     pub fn legal_moves(&self) -> Vec<MoveWrapper> {
         self.move_cache
             .iter()
@@ -352,6 +409,26 @@ impl ChessEngine {
         *self = Self::default();
     }
 
+    /// The current position, for [`Self::restore`].
+    pub fn snapshot(&self) -> EngineSnapshot {
+        EngineSnapshot {
+            fen: self.fen.clone(),
+            hash_history: self.game.hash_history.clone(),
+        }
+    }
+
+    /// Go back to a position from [`Self::snapshot`], repetition history
+    /// included. The legal-move cache is rebuilt by the next
+    /// `update_game_phase`, as after a move.
+    pub fn restore(&mut self, snapshot: &EngineSnapshot) {
+        // `set_from_fen` can't fail; it only reports a FEN it half-read.
+        let _ = self.set_from_fen(&snapshot.fen);
+        self.game.hash_history = snapshot.hash_history.clone();
+        self.move_cache.clear();
+        self.move_cache_valid = false;
+        self.synced_this_move = true;
+    }
+
     pub fn set_from_fen(&mut self, fen_str: &str) -> Result<(), String> {
         set_game_from_fen(&mut self.game, fen_str);
         self.fen = fen_str.to_string();
@@ -377,7 +454,20 @@ impl ChessEngine {
     }
 }
 
-#[derive(Default)]
+/// Keep the pseudo-legal `moves` for `color` that don't leave its own king
+/// attacked, trying each one on `board` and taking it back. This is what
+/// stops a pinned piece from leaving its pin line.
+pub fn filter_legal(moves: Vec<KK>, board: &mut Game, color: PieceColor) -> Vec<KK> {
+    let side = match color {
+        PieceColor::White => 1,
+        PieceColor::Black => -1,
+    };
+    moves
+        .into_iter()
+        .filter(|mv| is_legal_move_unchecked(board, mv.src, mv.dst, side))
+        .collect()
+}
+
 struct CastlingRights {
     white_king_moved: bool,
     black_king_moved: bool,
@@ -387,6 +477,22 @@ struct CastlingRights {
     bh_rook_moved: bool,
 }
 
+/// Every right starts lost: it only survives when its king and rook are
+/// found unmoved on their home squares, so a rook captured at home takes
+/// its side's right with it.
+impl Default for CastlingRights {
+    fn default() -> Self {
+        Self {
+            white_king_moved: true,
+            black_king_moved: true,
+            wa_rook_moved: true,
+            wh_rook_moved: true,
+            ba_rook_moved: true,
+            bh_rook_moved: true,
+        }
+    }
+}
+
 fn castling_to_string(rights: &CastlingRights) -> String {
     let mut castling_str = String::new();
     if !rights.white_king_moved && !rights.wh_rook_moved {
@@ -460,4 +566,93 @@ mod tests {
         assert_eq!(ChessEngine::coords_to_uci(4, 3), "e4");
         assert_eq!(ChessEngine::uci_to_coords("e4"), Some((4, 3)));
     }
+
+    #[test]
+    fn queen_taking_defended_pawn_loses_the_exchange() {
+        //! Qxe5 with e5 covered by the d6 pawn has negative SEE; undefended it wins the pawn
+        let mut engine = ChessEngine::default();
+
+        engine
+            .set_from_fen("4k3/8/3p4/4p3/8/8/8/4QK2 w - - 0 1")
+            .unwrap();
+        assert!(engine.exchange_value((4, 0), (4, 4)) < 0);
+
+        engine
+            .set_from_fen("4k3/8/8/4p3/8/8/8/4QK2 w - - 0 1")
+            .unwrap();
+        assert_eq!(engine.exchange_value((4, 0), (4, 4)), 100);
+    }
+
+    #[test]
+    fn board_reflects_loaded_position() {
+        //! The engine board accessor shows a FEN's pieces on their squares with signed codes
+        let mut engine = ChessEngine::default();
+        engine
+            .set_from_fen("4k3/8/8/8/8/8/8/R3K3 w Q - 0 1")
+            .unwrap();
+
+        let board = engine.board();
+        assert_eq!(
+            board[0],
+            ChessEngine::piece_code(PieceColor::White, PieceType::Rook)
+        );
+        assert_eq!(board[4], KING_ID);
+        assert_eq!(board[60], -KING_ID);
+        assert_eq!(board.iter().filter(|&&code| code != 0).count(), 3);
+    }
+
+    #[test]
+    fn absolutely_pinned_rook_has_no_moves() {
+        //! A rook pinned along the diagonal to its king keeps none of its rook moves; unpinned it has them all
+        let mut engine = ChessEngine::default();
+        engine
+            .set_from_fen("4k3/8/8/8/1b6/8/3R4/4K3 w - - 0 1")
+            .unwrap();
+        engine.rebuild_legal_move_cache();
+        assert!(engine
+            .get_legal_moves_for_square((3, 1), PieceColor::White)
+            .is_empty());
+        // The king itself may still step off the diagonal.
+        assert!(!engine
+            .get_legal_moves_for_square((4, 0), PieceColor::White)
+            .is_empty());
+
+        let rook = ChessEngine::square_to_index(3, 1);
+        let pseudo_legal: Vec<KK> = generate_pseudo_legal_moves(&engine.game, 1)
+            .into_iter()
+            .filter(|mv| mv.src == rook)
+            .collect();
+        assert_eq!(pseudo_legal.len(), 14);
+        assert!(filter_legal(pseudo_legal, &mut engine.game, PieceColor::White).is_empty());
+
+        engine
+            .set_from_fen("4k3/8/8/8/8/8/3R4/4K3 w - - 0 1")
+            .unwrap();
+        engine.rebuild_legal_move_cache();
+        assert_eq!(
+            engine
+                .get_legal_moves_for_square((3, 1), PieceColor::White)
+                .len(),
+            14
+        );
+    }
+
+    #[test]
+    fn castling_right_needs_its_rook_at_home() {
+        //! With the h1 rook gone the synced FEN drops White's kingside right, and keeps the queenside one
+        let mut engine = ChessEngine::default();
+        let unmoved = HasMoved::default();
+        let pieces = [
+            Piece::new(PieceColor::White, PieceType::King, 4, 0),
+            Piece::new(PieceColor::White, PieceType::Rook, 0, 0),
+            Piece::new(PieceColor::Black, PieceType::King, 4, 7),
+        ];
+        engine.sync_ecs_to_engine_impl(
+            pieces
+                .iter()
+                .map(|piece| (Entity::PLACEHOLDER, piece, &unmoved)),
+        );
+
+        assert_eq!(engine.current_fen().split_whitespace().nth(2), Some("Q"));
+    }
 }
@@ -0,0 +1,158 @@
+//! Engine hint for the human's move
+//!
+//! The "Hint" button in the game panel sends a [`HintRequest`]. While a human
+//! is to move, a short search for their colour runs on the async task pool,
+//! held in [`PendingHint`] the way [`PendingAIMove`] holds the computer's
+//! search, and its best move lands in [`EngineHint`] for the board to
+//! highlight. The move is only shown, never played. Cancelling, or any move
+//! on the board, clears the hint and drops a search still running.
+//!
+//! [`PendingAIMove`]: super::PendingAIMove
+
+use super::side_search::{side_search_game, side_to_move};
+use crate::engine::board_state::ChessEngine;
+use crate::game::resources::{CurrentTurn, GameOverState, MoveHistory, Players};
+use bevy::prelude::*;
+use bevy::tasks::{AsyncComputeTaskPool, Task};
+use nimzovich_engine::{get_game_status, reply_limited, GameStatus};
+
+/// Node budget for a hint, which keeps it under a second on slow machines.
+const HINT_NODES: u64 = 300_000;
+
+/// Time limit for a hint.
+const HINT_SECS: f32 = 0.5;
+
+/// What the hint button asks for.
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HintRequest {
+    /// Search the position and show the best move for the side to move.
+    Show,
+    /// Drop the hint, or the search for one.
+    Cancel,
+}
+
+/// A suggested move, as `(file, rank)` squares.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HintMove {
+    pub from: (u8, u8),
+    pub to: (u8, u8),
+}
+
+/// Resource holding the async hint search.
+#[derive(Resource)]
+pub struct PendingHint(pub Task<Option<HintMove>>);
+
+/// The hint on show, if any.
+#[derive(Resource, Default, Debug)]
+pub struct EngineHint {
+    pub shown: Option<HintMove>,
+}
+
+/// Best move for the side to move in `fen` from a short search. `None` when
+/// the game there is already over.
+pub fn best_move_for(fen: &str) -> Option<HintMove> {
+    let color = side_to_move(fen);
+    let mut game = side_search_game(fen);
+    if get_game_status(&mut game, color) != GameStatus::Playing {
+        return None;
+    }
+    let mv = reply_limited(&mut game, color, HINT_SECS, Some(HINT_NODES), None);
+    Some(HintMove {
+        from: ((mv.src % 8) as u8, (mv.src / 8) as u8),
+        to: ((mv.dst % 8) as u8, (mv.dst / 8) as u8),
+    })
+}
+
+/// Start or cancel a hint search on request. Only a human to move gets one.
+pub fn handle_hint_requests(
+    mut commands: Commands,
+    mut requests: MessageReader<HintRequest>,
+    mut hint: ResMut<EngineHint>,
+    engine: Res<ChessEngine>,
+    current_turn: Res<CurrentTurn>,
+    players: Res<Players>,
+    game_over: Res<GameOverState>,
+) {
+    for request in requests.read() {
+        hint.shown = None;
+        commands.remove_resource::<PendingHint>();
+        if *request == HintRequest::Cancel
+            || !players.current(current_turn.color).is_human
+            || game_over.is_game_over()
+        {
+            continue;
+        }
+        let fen = engine.current_fen().to_string();
+        let task = AsyncComputeTaskPool::get().spawn(async move { best_move_for(&fen) });
+        commands.insert_resource(PendingHint(task));
+    }
+}
+
+/// Show the hint once its search finishes.
+pub fn poll_hint_search(
+    mut commands: Commands,
+    pending: Option<ResMut<PendingHint>>,
+    mut hint: ResMut<EngineHint>,
+) {
+    let Some(mut pending) = pending else {
+        return;
+    };
+    let Some(best) =
+        futures_lite::future::block_on(futures_lite::future::poll_once(&mut pending.0))
+    else {
+        return;
+    };
+    commands.remove_resource::<PendingHint>();
+    hint.shown = best;
+}
+
+/// A hint is for one position only: clear it when a move is played.
+pub fn clear_hint_on_move(
+    mut commands: Commands,
+    history: Res<MoveHistory>,
+    pending: Option<Res<PendingHint>>,
+    mut hint: ResMut<EngineHint>,
+) {
+    if !history.is_changed() {
+        return;
+    }
+    if pending.is_some() {
+        commands.remove_resource::<PendingHint>();
+    }
+    if hint.shown.is_some() {
+        hint.shown = None;
+    }
+}
+
+/// Forget any hint from the previous game.
+pub fn reset_hint(mut commands: Commands, mut hint: ResMut<EngineHint>) {
+    hint.shown = None;
+    commands.remove_resource::<PendingHint>();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hint_finds_the_mating_move() {
+        //! The hint for a back-rank mate in one is the rook lift, for whichever side has it
+
+        assert_eq!(
+            best_move_for("6k1/5ppp/8/8/8/8/5PPP/R5K1 w - - 0 1"),
+            Some(HintMove {
+                from: (0, 0),
+                to: (0, 7),
+            })
+        );
+        assert_eq!(
+            best_move_for("r5k1/5ppp/8/8/8/8/5PPP/6K1 b - - 0 1"),
+            Some(HintMove {
+                from: (0, 7),
+                to: (0, 0),
+            })
+        );
+        // Mated already: nothing to suggest.
+        assert_eq!(best_move_for("R5k1/5ppp/8/8/8/8/5PPP/6K1 b - - 1 1"), None);
+    }
+}
@@ -9,6 +9,10 @@
 //! - `PendingAIMove`: Task handle for async AI computation
 //! - Systems spawn AI tasks when it's the AI's turn
 //! - Systems poll completed tasks and execute the AI's chosen move
+//! - `PendingHint`: the same kind of search run for the human, whose best
+//!   move is highlighted rather than played (see [`hint`])
+//! - [`side_search`]: the small throwaway games the hint, blunder check and
+//!   evaluation bar search in
 //!
 //! # Integration Strategy
 //!
@@ -20,7 +24,9 @@
 //!
 //! This ensures AI moves follow the same validation rules as human moves.
 
+pub mod hint;
 pub mod resource;
+pub mod side_search;
 pub mod systems;
 
 // Re-export for convenience
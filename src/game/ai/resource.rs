@@ -20,6 +20,21 @@
 //!
 //! Depth increases with search time thanks to iterative deepening in the engine.
 //!
+//! The built-in engine is held to a node budget per level
+//! ([`AIDifficulty::node_budget`]) rather than the times above, so a level
+//! plays the same strength on a fast machine as on a slow one; the time is
+//! then only a ceiling. Stockfish still gets the per-level move time.
+//!
+//! # Target ELO
+//!
+//! Instead of a level, a game can ask for an approximate rating between
+//! [`ELO_MIN`] and [`ELO_MAX`] ([`ChessAIResource::target_elo`]). The rating
+//! picks a row of a calibration table ([`EloStrength::for_elo`]) that caps the
+//! search depth and think time and sets how often the engine throws in a
+//! random move or settles for a slightly worse one. The ratings are rough
+//! guides, not measured against rated play, and apply to the built-in engine
+//! only.
+//!
 //! # Integration
 //!
 //! The UI menu sets these resources based on player selection:
@@ -92,6 +107,31 @@ pub struct ChessAIResource {
 
     /// AI engine selection
     pub engine: AIEngine,
+
+    /// Approximate rating to play at instead of `difficulty`
+    ///
+    /// `None` uses the difficulty level. Only the built-in engine honours it;
+    /// see [`EloStrength`].
+    pub target_elo: Option<u16>,
+
+    /// Play book moves while the game is still in known opening theory
+    ///
+    /// Starts from [`AIDifficulty::plays_book`] for the chosen level; the
+    /// player-wide "Vary AI openings" setting has to be on as well.
+    pub opening_book: bool,
+}
+
+impl ChessAIResource {
+    /// Strength the AI plays `color` at: the side's own level in an
+    /// AI-vs-AI game, [`Self::difficulty`] otherwise.
+    pub fn difficulty_for(&self, color: PieceColor) -> AIDifficulty {
+        self.mode.difficulty_for(color).unwrap_or(self.difficulty)
+    }
+
+    /// Engine parameters for [`Self::target_elo`], if one is set.
+    pub fn elo_strength(&self) -> Option<EloStrength> {
+        self.target_elo.map(EloStrength::for_elo)
+    }
 }
 
 impl Default for ChessAIResource {
@@ -105,6 +145,8 @@ impl Default for ChessAIResource {
             },
             difficulty: AIDifficulty::Level4,
             engine: AIEngine::XFChessEngine,
+            target_elo: None,
+            opening_book: AIDifficulty::Level4.plays_book(),
         }
     }
 }
@@ -177,16 +219,50 @@ pub enum GameMode {
         /// a move computation task.
         ai_color: PieceColor,
     },
+
+    /// The engine plays itself
+    ///
+    /// Both colors are moved by the AI, each at its own strength; the board
+    /// takes no input, so the game is only watched.
+    AIvsAI {
+        /// Strength of the side playing White
+        white: AIDifficulty,
+        /// Strength of the side playing Black
+        black: AIDifficulty,
+    },
 }
 
 impl GameMode {
     /// Get the AI player's color
     ///
-    /// Returns the color that the AI is playing.
+    /// Returns the color that the AI is playing. With an AI on both sides
+    /// ([`GameMode::AIvsAI`]) there is no single answer; use [`Self::plays`].
     pub fn ai_color(self) -> PieceColor {
         match self {
             GameMode::VsAI { ai_color } => ai_color,
-            GameMode::Multiplayer | GameMode::MultiplayerCompetitive => PieceColor::Black,
+            GameMode::Multiplayer | GameMode::MultiplayerCompetitive | GameMode::AIvsAI { .. } => {
+                PieceColor::Black
+            }
+        }
+    }
+
+    /// Whether the AI moves for `color`
+    pub fn plays(self, color: PieceColor) -> bool {
+        match self {
+            GameMode::VsAI { ai_color } => ai_color == color,
+            GameMode::AIvsAI { .. } => true,
+            GameMode::Multiplayer | GameMode::MultiplayerCompetitive => false,
+        }
+    }
+
+    /// The strength set for `color` when each side has its own
+    pub fn difficulty_for(self, color: PieceColor) -> Option<AIDifficulty> {
+        match self {
+            GameMode::AIvsAI { white, black } => Some(match color {
+                PieceColor::White => white,
+                PieceColor::Black => black,
+            }),
+            _ => None,
         }
     }
 }
@@ -279,6 +355,30 @@ impl AIDifficulty {
         self.stockfish_movetime_ms().unwrap_or(0) as f32 / 1000.0
     }
 
+    /// Nodes the built-in engine may search per move at this level. On one
+    /// search thread this fixes the move it plays in a given position,
+    /// whatever the hardware; roughly what [`Self::seconds_per_move`] buys on
+    /// a desktop CPU.
+    pub fn node_budget(self) -> u64 {
+        match self {
+            Self::Level1 => 2_000,
+            Self::Level2 => 10_000,
+            Self::Level3 => 50_000,
+            Self::Level4 => 200_000,
+            Self::Level5 => 500_000,
+            Self::Level6 => 1_000_000,
+            Self::Level7 => 2_000_000,
+            Self::Level8 => 4_000_000,
+        }
+    }
+
+    /// Whether this level opens from the book by default. The two lowest
+    /// levels find their own opening moves, mistakes included, so a beginner
+    /// isn't met with a line of theory the engine can't follow up.
+    pub fn plays_book(self) -> bool {
+        !matches!(self, Self::Level1 | Self::Level2)
+    }
+
     /// Approximate ELO rating for this difficulty, for display next to the
     /// "Computer" name in the in-game HUD (see [`Self::description`] for the
     /// combined name+ELO string used elsewhere).
@@ -343,6 +443,65 @@ impl AIDifficulty {
     }
 }
 
+/// Lowest target ELO offered.
+pub const ELO_MIN: u16 = 800;
+
+/// Highest target ELO offered; above this the difficulty levels take over.
+pub const ELO_MAX: u16 = 2000;
+
+/// Gap between calibration buckets.
+pub const ELO_STEP: u16 = 200;
+
+/// How the built-in engine is held back to play at a target ELO
+///
+/// All values are approximate: they were tuned by feel against the
+/// difficulty levels, not against rated opponents.
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+pub struct EloStrength {
+    /// Deepest search allowed, in plies.
+    pub max_depth: u8,
+    /// Think time per move, in seconds.
+    pub think_secs: f32,
+    /// Chance (0–1) of playing a random legal move instead of searching.
+    pub blunder_rate: f32,
+    /// A random alternative is played when it scores within this many
+    /// centipawns of the best move; 0 always plays the best move.
+    pub eval_noise_cp: i64,
+}
+
+/// Calibration table, one row per [`ELO_STEP`] bucket from [`ELO_MIN`] up.
+const ELO_CALIBRATION: [(u16, EloStrength); 7] = [
+    (800, EloStrength::new(1, 0.10, 0.20, 150)),
+    (1000, EloStrength::new(2, 0.15, 0.14, 120)),
+    (1200, EloStrength::new(3, 0.25, 0.09, 90)),
+    (1400, EloStrength::new(4, 0.40, 0.05, 60)),
+    (1600, EloStrength::new(5, 0.60, 0.03, 40)),
+    (1800, EloStrength::new(6, 0.80, 0.01, 20)),
+    (2000, EloStrength::new(8, 1.00, 0.0, 0)),
+];
+
+impl EloStrength {
+    const fn new(max_depth: u8, think_secs: f32, blunder_rate: f32, eval_noise_cp: i64) -> Self {
+        Self {
+            max_depth,
+            think_secs,
+            blunder_rate,
+            eval_noise_cp,
+        }
+    }
+
+    /// Parameters for the bucket `elo` falls in, clamped to
+    /// [`ELO_MIN`]..=[`ELO_MAX`].
+    pub fn for_elo(elo: u16) -> Self {
+        let elo = elo.clamp(ELO_MIN, ELO_MAX);
+        ELO_CALIBRATION
+            .iter()
+            .rev()
+            .find(|(floor, _)| elo >= *floor)
+            .map_or(ELO_CALIBRATION[0].1, |(_, strength)| *strength)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -358,6 +517,7 @@ mod tests {
             }
         ));
         assert_eq!(ai_config.difficulty, AIDifficulty::Level4);
+        assert!(ai_config.opening_book);
     }
 
     #[test]
@@ -405,6 +565,37 @@ mod tests {
         assert_eq!(original, cloned);
     }
 
+    #[test]
+    fn test_ai_vs_ai_moves_both_sides_at_their_own_levels() {
+        //! Both colors belong to the AI, each at the level chosen for it
+        let ai_config = ChessAIResource {
+            mode: GameMode::AIvsAI {
+                white: AIDifficulty::Level2,
+                black: AIDifficulty::Level7,
+            },
+            ..Default::default()
+        };
+        assert!(ai_config.mode.plays(PieceColor::White));
+        assert!(ai_config.mode.plays(PieceColor::Black));
+        assert_eq!(
+            ai_config.difficulty_for(PieceColor::White),
+            AIDifficulty::Level2
+        );
+        assert_eq!(
+            ai_config.difficulty_for(PieceColor::Black),
+            AIDifficulty::Level7
+        );
+
+        let vs_ai = GameMode::VsAI {
+            ai_color: PieceColor::Black,
+        };
+        assert!(!vs_ai.plays(PieceColor::White));
+        assert_eq!(
+            ChessAIResource::default().difficulty_for(PieceColor::Black),
+            AIDifficulty::Level4
+        );
+    }
+
     #[test]
     fn test_game_mode_clone() {
         //! Verifies GameMode can be cloned
@@ -435,6 +626,28 @@ mod tests {
         assert_eq!(ai_config.difficulty, AIDifficulty::Level8);
     }
 
+    #[test]
+    fn test_higher_elo_searches_deeper_and_blunders_less() {
+        //! Each ELO bucket searches at least as deep and blunders no more than the one below
+        let mut previous = EloStrength::for_elo(ELO_MIN);
+        for elo in (ELO_MIN + ELO_STEP..=ELO_MAX).step_by(ELO_STEP as usize) {
+            let strength = EloStrength::for_elo(elo);
+            assert!(strength.max_depth > previous.max_depth, "{elo}");
+            assert!(strength.think_secs > previous.think_secs, "{elo}");
+            assert!(strength.blunder_rate < previous.blunder_rate, "{elo}");
+            assert!(strength.eval_noise_cp < previous.eval_noise_cp, "{elo}");
+            previous = strength;
+        }
+
+        assert_eq!(EloStrength::for_elo(0), EloStrength::for_elo(ELO_MIN));
+        assert_eq!(EloStrength::for_elo(1399), EloStrength::for_elo(1200));
+        assert_eq!(
+            EloStrength::for_elo(u16::MAX),
+            EloStrength::for_elo(ELO_MAX)
+        );
+        assert_eq!(EloStrength::for_elo(ELO_MAX).blunder_rate, 0.0);
+    }
+
     #[test]
     fn test_time_increases_with_difficulty() {
         //! Verifies harder difficulties get more thinking time
@@ -445,4 +658,15 @@ mod tests {
         assert!(low_time < med_time);
         assert!(med_time < high_time);
     }
+
+    #[test]
+    fn test_node_budget_increases_with_difficulty() {
+        //! Each level searches more nodes than the one below it
+        for level in 1..8 {
+            assert!(
+                AIDifficulty::from_u8(level).node_budget()
+                    < AIDifficulty::from_u8(level + 1).node_budget()
+            );
+        }
+    }
 }
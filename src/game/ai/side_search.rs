@@ -0,0 +1,27 @@
+//! Throwaway engine games for short searches beside the computer's own
+//!
+//! The hint, the blunder check and the evaluation bar each search a position
+//! away from the AI's game. They set one up here: a game for the FEN with a
+//! small transposition table of its own, so none of them pays for the
+//! full-size table the AI allocates.
+
+use nimzovich_engine::{game_from_fen_no_tt, set_tt_size_mb, Game, COLOR_BLACK, COLOR_WHITE};
+
+/// Transposition table for a side search, in megabytes.
+pub const SIDE_SEARCH_TT_MB: usize = 4;
+
+/// Engine colour of the side to move in `fen`.
+pub fn side_to_move(fen: &str) -> i64 {
+    if fen.split_whitespace().nth(1) == Some("b") {
+        COLOR_BLACK
+    } else {
+        COLOR_WHITE
+    }
+}
+
+/// A game for `fen` with a [`SIDE_SEARCH_TT_MB`] table, ready to search.
+pub fn side_search_game(fen: &str) -> Game {
+    let mut game = game_from_fen_no_tt(fen);
+    set_tt_size_mb(&mut game, SIDE_SEARCH_TT_MB);
+    game
+}
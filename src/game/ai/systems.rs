@@ -1,11 +1,17 @@
-use super::resource::ChessAIResource;
+use super::resource::{ChessAIResource, EloStrength};
 use crate::engine::board_state::ChessEngine;
 use crate::game::components::GamePhase;
 use crate::game::components::HasMoved;
 use crate::game::components::Piece;
-use crate::game::resources::{CapturedPieces, CurrentGamePhase, CurrentTurn, MoveHistory};
+use crate::game::resources::{
+    is_promotion_move, CapturedPieces, CurrentGamePhase, CurrentTurn, GameOverState, MoveHistory,
+    PromotionSelected,
+};
 use crate::game::system_sets::GameSystems;
-use crate::game::systems::shared::{execute_move, CapturedTarget, MoveContext};
+use crate::game::systems::game_logic::board_result;
+use crate::game::systems::shared::{execute_move, find_move_entities, move_sounds, MoveContext};
+use crate::rendering::pieces::{PieceColor, PieceType};
+use crate::ui::menus::popup::{GamePopup, GamePopupQueue};
 use bevy::ecs::system::{ParamSet, SystemParam};
 use bevy::prelude::*;
 use bevy::tasks::{AsyncComputeTaskPool, Task};
@@ -138,6 +144,33 @@ pub struct AIStatistics {
     pub last_depth: i64,
     pub last_nodes: i64,
     pub thinking_time: f32,
+    /// Consecutive AI moves evaluated at or below the resignation threshold
+    pub losing_streak: u32,
+}
+
+impl AIStatistics {
+    /// Record the AI's own evaluation (centipawns, from its side) of the move
+    /// it just found. Returns true when "AI resigns" is on and the eval has
+    /// stayed hopeless for the configured number of moves in a row.
+    ///
+    /// Book, random and fallback moves come without a search (`depth` 0);
+    /// their placeholder score says nothing, so they leave the streak alone.
+    pub fn record_eval(
+        &mut self,
+        score: i32,
+        depth: u8,
+        settings: &crate::core::GameSettings,
+    ) -> bool {
+        if depth == 0 {
+            return false;
+        }
+        if score <= -(settings.ai_resign_threshold_cp as i32) {
+            self.losing_streak += 1;
+        } else {
+            self.losing_streak = 0;
+        }
+        settings.ai_resigns && self.losing_streak >= settings.ai_resign_streak.max(1)
+    }
 }
 
 /// Plugin for AI systems
@@ -147,6 +180,9 @@ impl Plugin for AIPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<ChessAIResource>()
             .init_resource::<AIStatistics>()
+            .init_resource::<PolyglotOpeningBook>()
+            .init_resource::<super::hint::EngineHint>()
+            .add_message::<super::hint::HintRequest>()
             .register_type::<ChessAIResource>()
             .register_type::<AIStatistics>()
             .add_systems(
@@ -155,15 +191,119 @@ impl Plugin for AIPlugin {
                     .chain()
                     .in_set(GameSystems::Execution),
             )
+            .add_systems(
+                Update,
+                (
+                    super::hint::clear_hint_on_move,
+                    super::hint::poll_hint_search,
+                    super::hint::handle_hint_requests,
+                )
+                    .chain()
+                    .run_if(in_state(crate::core::GameState::InGame)),
+            )
+            .add_systems(Startup, load_polyglot_book)
             .add_systems(
                 OnEnter(crate::core::GameState::InGame),
-                warmup_xf_engine_pool,
+                (
+                    warmup_xf_engine_pool,
+                    reset_ai_statistics,
+                    reseed_openings,
+                    super::hint::reset_hint,
+                ),
             );
 
         let _pool = AsyncComputeTaskPool::get_or_init(Default::default);
     }
 }
 
+/// Seed for the AI's opening-book choices, fixed for one game so its book
+/// moves stay consistent within the game but differ between games.
+#[derive(Resource, Debug, Clone, Copy, Default)]
+pub struct OpeningSeed(pub u64);
+
+fn reseed_openings(mut commands: Commands) {
+    commands.insert_resource(OpeningSeed(rand::random()));
+}
+
+/// A Polyglot `.bin` book dropped at [`polyglot_book_path`], consulted before
+/// the built-in book lines. `None` when there is no book there.
+#[derive(Resource, Default)]
+pub struct PolyglotOpeningBook(pub Option<nimzovich_engine::opening_book::PolyglotBook>);
+
+/// Where a Polyglot book is looked for: `book.bin` beside the save folder.
+pub fn polyglot_book_path() -> PathBuf {
+    crate::game::save::saves_dir().with_file_name("book.bin")
+}
+
+fn load_polyglot_book(mut book: ResMut<PolyglotOpeningBook>) {
+    let path = polyglot_book_path();
+    if !path.exists() {
+        return;
+    }
+    match nimzovich_engine::opening_book::PolyglotBook::open(&path) {
+        Ok(loaded) => {
+            info!(
+                "[AI] Loaded opening book {:?} ({} entries)",
+                path,
+                loaded.len()
+            );
+            book.0 = Some(loaded);
+        }
+        Err(e) => warn!("[AI] Ignoring opening book {:?}: {}", path, e),
+    }
+}
+
+/// Book reply from the Polyglot `book` for the position in `fen`, as UCI. The
+/// pick is weighted by the book and driven by the game's `seed` and `ply`.
+fn polyglot_book_move(
+    book: &nimzovich_engine::opening_book::PolyglotBook,
+    fen: &str,
+    seed: u64,
+    ply: usize,
+) -> Option<String> {
+    let mut roll = seed ^ (ply as u64).wrapping_mul(0x9e37_79b9_7f4a_7c15);
+    roll = (roll ^ (roll >> 31)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    let mv = book.probe(fen, roll ^ (roll >> 29))?;
+    let square = |sq: i8| ChessEngine::coords_to_uci((sq % 8) as u8, (sq / 8) as u8);
+    let promo = match mv.promo {
+        nimzovich_engine::KNIGHT_ID => "n",
+        nimzovich_engine::BISHOP_ID => "b",
+        nimzovich_engine::ROOK_ID => "r",
+        nimzovich_engine::QUEEN_ID => "q",
+        _ => "",
+    };
+    Some(format!("{}{}{}", square(mv.src), square(mv.dst), promo))
+}
+
+/// Placement, side and castling of the standard start position — book lines
+/// only apply to games that began there.
+const START_POSITION_PREFIX: &str = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq";
+
+/// Book reply for the game so far, or `None` once out of book or when the
+/// game did not start from the standard position.
+fn opening_book_move(
+    history: &crate::game::resources::MoveHistory,
+    fen: &str,
+    seed: u64,
+) -> Option<&'static str> {
+    if history.moves.is_empty() && !fen.starts_with(START_POSITION_PREFIX) {
+        return None;
+    }
+    let played: Vec<String> = history
+        .moves
+        .iter()
+        .map(|m| {
+            format!(
+                "{}{}",
+                ChessEngine::coords_to_uci(m.from.0, m.from.1),
+                ChessEngine::coords_to_uci(m.to.0, m.to.1)
+            )
+        })
+        .collect();
+    let played: Vec<&str> = played.iter().map(String::as_str).collect();
+    nimzovich_engine::book::book_move_seeded(&played, seed)
+}
+
 /// Pre-allocate the XFChess engine game on game entry to avoid the 2.2 GB TT
 /// zero-write during the first AI move. Runs immediately after transitioning to
 /// InGame while the board and assets are loading, so it finishes before the player
@@ -196,6 +336,11 @@ fn warmup_xf_engine_pool(mut commands: Commands, ai_config: Res<ChessAIResource>
     info!("[AI] XFChess engine warm-up started");
 }
 
+/// A new game starts with a clean resignation streak and no evaluation.
+fn reset_ai_statistics(mut ai_stats: ResMut<AIStatistics>) {
+    *ai_stats = AIStatistics::default();
+}
+
 /// System params for spawning AI task
 #[derive(SystemParam)]
 pub struct AiSpawnParams<'w, 's> {
@@ -211,17 +356,33 @@ pub struct AiSpawnParams<'w, 's> {
     pub move_history: Res<'w, crate::game::resources::MoveHistory>,
     pub active_tc: Option<Res<'w, crate::game::resources::active_time_control::ActiveTimeControl>>,
     pub game_pool: Option<Res<'w, XFChessGamePool>>,
+    pub save_restore: Option<Res<'w, crate::game::save::PendingSaveRestore>>,
+    pub redo: Option<Res<'w, crate::game::systems::takeback::PendingRedo>>,
+    pub settings: Res<'w, crate::core::GameSettings>,
+    pub opening_seed: Option<Res<'w, OpeningSeed>>,
+    pub polyglot_book: Res<'w, PolyglotOpeningBook>,
 }
 
+/// Floor for the user's max-think setting so a zero/negative value can't
+/// starve the search entirely.
+const MIN_THINK_SECS: f32 = 0.05;
+
 /// Compute think_time and an optional depth cap from time control context.
 ///
+/// - Caps think_time to the player's "max AI think time" setting
+///   (`GameSettings::max_ai_think_secs`). This is the only fixed ceiling —
+///   `base_think` is used as-is below it. For the difficulty levels that is
+///   the setting itself, the level's node budget being what limits the
+///   search; a target ELO passes its own think time.
 /// - Caps think_time to `base_seconds / 40` so the AI can't flag in short games.
 /// - Sets `max_depth = Some(6)` for fast games with no increment (< 60 s + 0).
 fn compute_think_params(
     base_think: f32,
+    max_think: f32,
     half_moves_played: usize,
     active_tc: Option<&crate::game::resources::active_time_control::ActiveTimeControl>,
 ) -> (f32, Option<u8>) {
+    let base_think = base_think.min(max_think.max(MIN_THINK_SECS));
     let Some(tc) = active_tc.map(|a| a.control) else {
         return (base_think, None);
     };
@@ -262,12 +423,17 @@ pub struct AiPollParams<'w, 's> {
     pub move_history: ResMut<'w, MoveHistory>,
     pub captured_pieces: ResMut<'w, CapturedPieces>,
     pub ai_stats: ResMut<'w, AIStatistics>,
+    pub settings: Res<'w, crate::core::GameSettings>,
+    pub animation: Res<'w, crate::game::resources::AnimationSettings>,
+    pub game_over: ResMut<'w, crate::game::resources::GameOverState>,
     pub pending_turn: ResMut<'w, crate::game::resources::PendingTurnAdvance>,
     pub engine: ResMut<'w, ChessEngine>,
     pub sounds: Option<Res<'w, crate::game::resources::GameSounds>>,
+    pub popups: Option<ResMut<'w, GamePopupQueue>>,
+    pub promotion_messages: MessageWriter<'w, PromotionSelected>,
 }
 
-fn spawn_ai_task_system(mut commands: Commands, params: AiSpawnParams) {
+fn spawn_ai_task_system(mut commands: Commands, mut params: AiSpawnParams) {
     #[cfg(not(target_arch = "wasm32"))]
     let _start_time = std::time::Instant::now();
 
@@ -282,17 +448,65 @@ fn spawn_ai_task_system(mut commands: Commands, params: AiSpawnParams) {
         return;
     }
 
+    // A save slot is still being restored — the engine isn't on its position yet.
+    if params.save_restore.is_some() {
+        return;
+    }
+
+    // Redo is replaying the computer's own earlier reply.
+    if params.redo.is_some() {
+        return;
+    }
+
     // FEN is already current — game_logic.rs synced the engine and built the move cache.
     let fen = params.engine.current_fen().to_string();
-    let depth = params.ai_config.difficulty.stockfish_depth();
-    let movetime_ms = params.ai_config.difficulty.stockfish_movetime_ms();
-    let ai_color = params.ai_config.mode.ai_color();
+    // The side to move is the AI's (checked above); in an AI-vs-AI game each
+    // side has its own level.
+    let ai_color = params.current_turn.color;
+    let difficulty = params.ai_config.difficulty_for(ai_color);
+    let depth = difficulty.stockfish_depth();
+    let movetime_ms = difficulty.stockfish_movetime_ms();
+
+    // Opening variety: play a book move (picked by this game's seed) while
+    // the game is still in known theory, whichever engine is selected. A
+    // Polyglot book, when there is one, goes first.
+    if params.settings.ai_opening_variety && params.ai_config.opening_book {
+        let seed = params.opening_seed.as_ref().map_or(0, |s| s.0);
+        let book_move = params
+            .polyglot_book
+            .0
+            .as_ref()
+            .and_then(|book| polyglot_book_move(book, &fen, seed, params.move_history.len()))
+            .or_else(|| opening_book_move(&params.move_history, &fen, seed).map(str::to_string));
+        if let Some(uci) = book_move {
+            let from = ChessEngine::uci_to_coords(&uci[0..2]);
+            let to = ChessEngine::uci_to_coords(&uci[2..4]);
+            if let (Some(from), Some(to), true) =
+                (from, to, params.engine.is_move_legal_by_uci(&uci))
+            {
+                info!("[AI] Playing book move {}", uci);
+                let task = AsyncComputeTaskPool::get().spawn(async move {
+                    Ok(AIMove {
+                        from,
+                        to,
+                        uci,
+                        score: 0,
+                        depth: 0,
+                        thinking_time: 0.0,
+                    })
+                });
+                commands.insert_resource(PendingAIMove(task));
+                return;
+            }
+        }
+    }
 
     match params.ai_config.engine {
         crate::game::ai::resource::AIEngine::Stockfish => {
             info!("[AI] Spawning Stockfish task (persistent process)");
             let depth = depth.unwrap_or(12);
-            let movetime = movetime_ms.unwrap_or(1500);
+            let max_ms = (params.settings.max_ai_think_secs.max(MIN_THINK_SECS) * 1000.0) as u64;
+            let movetime = movetime_ms.unwrap_or(1500).min(max_ms);
 
             // Get or create the persistent process Arc, then clone it for the task.
             let sf_arc = if let Some(sf) = params.sf_process.as_ref() {
@@ -315,35 +529,147 @@ fn spawn_ai_task_system(mut commands: Commands, params: AiSpawnParams) {
             commands.insert_resource(PendingAIMove(task));
         }
         crate::game::ai::resource::AIEngine::XFChessEngine => {
-            let base_think = params.ai_config.difficulty.seconds_per_move();
-            let (think_time, max_depth) = compute_think_params(
+            let elo_strength = params.ai_config.elo_strength();
+            let legal: Vec<LegalMove> = if elo_strength.is_some() {
+                params
+                    .engine
+                    .legal_moves()
+                    .iter()
+                    .map(|m| (m.from, m.to))
+                    .collect()
+            } else {
+                Vec::new()
+            };
+
+            // Target ELO: now and then skip the search and play any legal move.
+            if let Some(strength) = elo_strength {
+                if let Some((from, to)) = random_blunder(&legal, strength) {
+                    info!(
+                        "[AI] Target ELO {:?}: playing a random move",
+                        params.ai_config.target_elo
+                    );
+                    let task = AsyncComputeTaskPool::get().spawn(async move {
+                        Ok(AIMove {
+                            from,
+                            to,
+                            uci: format!(
+                                "{}{}",
+                                ChessEngine::coords_to_uci(from.0, from.1),
+                                ChessEngine::coords_to_uci(to.0, to.1)
+                            ),
+                            score: 0,
+                            depth: 0,
+                            thinking_time: 0.0,
+                        })
+                    });
+                    commands.insert_resource(PendingAIMove(task));
+                    return;
+                }
+            }
+
+            // Levels are defined by nodes searched, not time, so they play
+            // alike on any machine; the time budget only guards the clock.
+            let (base_think, max_nodes) = match elo_strength {
+                Some(strength) => (strength.think_secs, None),
+                None => (
+                    params.settings.max_ai_think_secs,
+                    Some(difficulty.node_budget()),
+                ),
+            };
+            let (think_time, mut max_depth) = compute_think_params(
                 base_think,
+                params.settings.max_ai_think_secs,
                 params.move_history.len(),
                 params.active_tc.as_deref(),
             );
+            if let Some(strength) = elo_strength {
+                max_depth =
+                    Some(max_depth.map_or(strength.max_depth, |d| d.min(strength.max_depth)));
+            }
             info!(
-                "[AI] Spawning XFChessEngine task — think_time={:.2}s max_depth={:?}",
-                think_time, max_depth
+                "[AI] Spawning XFChessEngine task — think_time={:.2}s max_nodes={:?} max_depth={:?}",
+                think_time, max_nodes, max_depth
             );
             // Try to take the pre-warmed game from the pool to avoid re-allocating the
             // 2.2 GB transposition table on every move. Pass the pool Arc into the
             // task so it can put the game back when the search finishes.
             let pool_arc = params.game_pool.as_ref().map(|p| p.0.clone());
             let preloaded = pool_arc.as_ref().and_then(|arc| arc.lock().ok()?.take());
-            let task =
-                spawn_xf_engine_task(fen, think_time, max_depth, ai_color, preloaded, pool_arc);
+            let roughen = elo_strength
+                .filter(|s| s.eval_noise_cp > 0)
+                .map(|s| (s, legal));
+            let threads = if elo_strength.is_some() {
+                1
+            } else {
+                params.settings.search_threads as usize
+            };
+            let task = spawn_xf_engine_task(
+                fen, think_time, max_nodes, max_depth, threads, ai_color, preloaded, pool_arc,
+                roughen,
+            );
             commands.insert_resource(PendingAIMove(task));
         }
     }
 }
 
+/// Nodes searched per frame-slice on WASM — small enough to stay well under a
+/// frame's budget on the main thread.
+#[cfg(target_arch = "wasm32")]
+const WASM_SLICE_NODES: i64 = 4_000;
+
+/// Rough single-threaded WASM search speed, used to turn the difficulty's
+/// think time into a total node budget.
+#[cfg(target_arch = "wasm32")]
+const WASM_NODES_PER_SEC: f32 = 150_000.0;
+
+/// A legal move as `(from, to)` board coordinates.
+type LegalMove = ((u8, u8), (u8, u8));
+
+/// Roll for a target-ELO blunder: a uniformly random legal move, played
+/// [`EloStrength::blunder_rate`] of the time.
+fn random_blunder(legal: &[LegalMove], strength: EloStrength) -> Option<LegalMove> {
+    if legal.is_empty() || rand::random::<f32>() >= strength.blunder_rate {
+        return None;
+    }
+    Some(legal[(rand::random::<u64>() % legal.len() as u64) as usize])
+}
+
+/// Rough up the engine's `best` move for a target ELO: a random alternative
+/// is played instead when a shallow check scores it within
+/// [`EloStrength::eval_noise_cp`] of the best.
+fn roughen_move(
+    fen: &str,
+    color: crate::rendering::pieces::PieceColor,
+    best: LegalMove,
+    legal: &[LegalMove],
+    strength: EloStrength,
+) -> LegalMove {
+    if legal.len() < 2 {
+        return best;
+    }
+    let alternative = legal[(rand::random::<u64>() % legal.len() as u64) as usize];
+    if alternative == best {
+        return best;
+    }
+    let drop = crate::game::blunder_check::eval_drop(fen, color, alternative.0, alternative.1);
+    if drop <= strength.eval_noise_cp {
+        alternative
+    } else {
+        best
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
 fn spawn_xf_engine_task(
     fen: String,
     think_time: f32,
+    max_nodes: Option<u64>,
     max_depth: Option<u8>,
+    threads: usize,
     color: crate::rendering::pieces::PieceColor,
     preloaded_game: Option<nimzovich_engine::Game>,
     pool: Option<std::sync::Arc<std::sync::Mutex<Option<nimzovich_engine::Game>>>>,
+    roughen: Option<(EloStrength, Vec<LegalMove>)>,
 ) -> Task<Result<AIMove, String>> {
     AsyncComputeTaskPool::get().spawn(async move {
         let start_time = Instant::now();
@@ -359,6 +685,8 @@ fn spawn_xf_engine_task(
         };
 
         game.secs_per_move = think_time;
+        // A no-op unless the count changed since this game last searched.
+        nimzovich_engine::set_search_threads(&mut game, threads);
         if let Some(d) = max_depth {
             game.abs_max_depth = d as i64;
         }
@@ -368,7 +696,28 @@ fn spawn_xf_engine_task(
             crate::rendering::pieces::PieceColor::Black => -1,
         };
 
-        let mv = nimzovich_engine::reply(&mut game, engine_color).await;
+        // On WASM the task pool runs on the page's only thread, so a blocking
+        // search would freeze rendering for the whole think time. Search in
+        // small node slices instead, yielding back to the frame loop between
+        // them; the total node cap stands in for the wall-clock budget.
+        #[cfg(target_arch = "wasm32")]
+        let mv = {
+            let max_nodes = ((think_time * WASM_NODES_PER_SEC) as i64)
+                .min(max_nodes.map_or(i64::MAX, |n| n as i64));
+            let mut search = nimzovich_engine::IncrementalSearch::new(engine_color, max_nodes);
+            loop {
+                match nimzovich_engine::reply_incremental(&mut game, &mut search, WASM_SLICE_NODES)
+                {
+                    nimzovich_engine::SearchProgress::Done(mv) => break mv,
+                    nimzovich_engine::SearchProgress::Thinking => {
+                        futures_lite::future::yield_now().await
+                    }
+                }
+            }
+        };
+        #[cfg(not(target_arch = "wasm32"))]
+        let mv =
+            nimzovich_engine::reply_limited(&mut game, engine_color, think_time, max_nodes, None);
 
         let depth_reached = game.max_depth_so_far as u8;
 
@@ -379,15 +728,34 @@ fn spawn_xf_engine_task(
             }
         }
 
-        let from_file = mv.src as u8 % 8;
-        let from_rank = mv.src as u8 / 8;
-        let to_file = mv.dst as u8 % 8;
-        let to_rank = mv.dst as u8 / 8;
+        // No legal move: the engine says whether it is mate or stalemate.
+        if mv.src == mv.dst {
+            return Err(match mv.state {
+                nimzovich_engine::STATE_CHECKMATE => "no legal move (checkmated)".to_string(),
+                nimzovich_engine::STATE_STALEMATE => "no legal move (stalemated)".to_string(),
+                _ => "engine returned no move".to_string(),
+            });
+        }
+
+        let best = (
+            (mv.src as u8 % 8, mv.src as u8 / 8),
+            (mv.dst as u8 % 8, mv.dst as u8 / 8),
+        );
+        let ((from_file, from_rank), (to_file, to_rank)) = match &roughen {
+            Some((strength, legal)) => roughen_move(&fen, color, best, legal, *strength),
+            None => best,
+        };
 
         let from_uci = ChessEngine::coords_to_uci(from_file, from_rank);
         let to_uci = ChessEngine::coords_to_uci(to_file, to_rank);
 
-        let promo_char = match mv.promo {
+        // A roughened move is not the one the engine's promotion belongs to.
+        let promo = if ((from_file, from_rank), (to_file, to_rank)) == best {
+            mv.promo
+        } else {
+            0
+        };
+        let promo_char = match promo {
             5 => "q",
             4 => "r",
             3 => "b",
@@ -498,6 +866,12 @@ fn spawn_stockfish_task_persistent(
                             score = s;
                         }
                     }
+                    // "score mate N": kept as a mate score so it shows as "M3".
+                    if part == "mate" && i + 1 < parts.len() {
+                        if let Ok(m) = parts[i + 1].parse::<i64>() {
+                            score = crate::ui::eval_format::mate_score(m) as i32;
+                        }
+                    }
                 }
             }
             if parts.len() >= 2 && parts[0] == "bestmove" {
@@ -580,12 +954,11 @@ fn should_skip_ai_spawn(
         return true;
     }
 
-    let ai_color = ai_config.mode.ai_color();
-    if current_turn.color != ai_color {
+    if !ai_config.mode.plays(current_turn.color) {
         trace!(
-            "[AI] Skipping spawn: turn color ({:?}) != AI color ({:?})",
+            "[AI] Skipping spawn: the AI doesn't play {:?} ({:?})",
             current_turn.color,
-            ai_color
+            ai_config.mode
         );
         return true;
     }
@@ -621,9 +994,10 @@ fn poll_ai_task_system(mut commands: Commands, mut params: AiPollParams) {
                         ai_move.uci
                     );
                     let uci_move = format!(
-                        "{}{}",
+                        "{}{}{}",
                         ChessEngine::coords_to_uci(ai_move.from.0, ai_move.from.1),
-                        ChessEngine::coords_to_uci(ai_move.to.0, ai_move.to.1)
+                        ChessEngine::coords_to_uci(ai_move.to.0, ai_move.to.1),
+                        ai_move.uci.get(4..5).unwrap_or("")
                     );
                     move_found = Some(uci_move);
                     move_from_direct_stockfish = true;
@@ -632,9 +1006,40 @@ fn poll_ai_task_system(mut commands: Commands, mut params: AiPollParams) {
                     params.ai_stats.last_score = ai_move.score as i64;
                     params.ai_stats.last_depth = ai_move.depth as i64;
                     params.ai_stats.thinking_time = ai_move.thinking_time;
+
+                    if params
+                        .ai_stats
+                        .record_eval(ai_move.score, ai_move.depth, &params.settings)
+                    {
+                        info!(
+                            "[AI] Resigning: eval {} after {} losing moves",
+                            ai_move.score, params.ai_stats.losing_streak
+                        );
+                        if let Some(popups) = params.popups.as_mut() {
+                            popups.push(GamePopup::notice(
+                                "Computer resigned",
+                                "The position is lost for the computer.",
+                            ));
+                        }
+                        *params.game_over = match params.current_turn.color {
+                            crate::rendering::pieces::PieceColor::White => {
+                                crate::game::resources::GameOverState::BlackWonByResignation
+                            }
+                            crate::rendering::pieces::PieceColor::Black => {
+                                crate::game::resources::GameOverState::WhiteWonByResignation
+                            }
+                        };
+                        return;
+                    }
                 }
                 Err(e) => {
-                    error!("[AI] Stockfish task failed: {}", e);
+                    if !end_game_if_ai_cannot_move(
+                        &mut params.engine,
+                        params.current_turn.color,
+                        &mut params.game_over,
+                    ) {
+                        error!("[AI] Stockfish task failed: {}", e);
+                    }
                 }
             }
         }
@@ -655,8 +1060,7 @@ fn poll_ai_task_system(mut commands: Commands, mut params: AiPollParams) {
                 None => return,
             };
 
-            let promotion_type =
-                promotion_char.and_then(crate::rendering::pieces::PieceType::from_char);
+            let promotion_type = promotion_char.and_then(PieceType::from_char);
 
             if move_from_direct_stockfish {
                 info!(
@@ -670,12 +1074,6 @@ fn poll_ai_task_system(mut commands: Commands, mut params: AiPollParams) {
                 );
             }
 
-            let (move_sound, capture_sound) = if let Some(s) = &params.sounds {
-                (Some(s.move_piece.clone()), Some(s.capture_piece.clone()))
-            } else {
-                (None, None)
-            };
-
             let mut p0 = params.pieces_queries.p0();
 
             // Validate with the cached legal-move table — O(1) lookup, no extra generation.
@@ -692,6 +1090,12 @@ fn poll_ai_task_system(mut commands: Commands, mut params: AiPollParams) {
                 find_move_entities(&p0, from_coords, to_coords)
             {
                 debug!("[AI] Found move entities: entity={:?}, piece={:?}, is_first_move={:?}, capture_target={:?}", entity, piece, is_first_move, capture_target);
+                // A pawn reaching the last rank without a named piece queens.
+                let promotion_type = promotion_type.or_else(|| {
+                    is_promotion_move(piece.piece_type, piece.color, to_coords.1)
+                        .then_some(PieceType::Queen)
+                });
+                let sounds = move_sounds(params.sounds.as_deref());
                 let ctx = MoveContext {
                     origin: "ai",
                     entity,
@@ -701,12 +1105,13 @@ fn poll_ai_task_system(mut commands: Commands, mut params: AiPollParams) {
                     promotion: promotion_type,
                     was_first_move: is_first_move,
                     remote: false,
-                    move_sound,
-                    capture_sound,
+                    sounds,
                     game_id: None,
+                    capture_animation: params.settings.capture_animation,
+                    animation: *params.animation,
                 };
 
-                execute_move(
+                let moved = execute_move(
                     &ctx,
                     &mut commands,
                     &mut params.pending_turn,
@@ -718,6 +1123,14 @@ fn poll_ai_task_system(mut commands: Commands, mut params: AiPollParams) {
                     None, // BoardStateSync not available in AI context
                     &params.current_turn,
                 );
+                // execute_move already changed the piece type; this swaps the mesh.
+                if let (true, Some(promoted_to)) = (moved, promotion_type) {
+                    params.promotion_messages.write(PromotionSelected {
+                        entity,
+                        position: to_coords,
+                        promoted_to,
+                    });
+                }
             } else {
                 warn!("[AI] Could not find valid piece at {:?}", from_coords);
             }
@@ -725,27 +1138,202 @@ fn poll_ai_task_system(mut commands: Commands, mut params: AiPollParams) {
     }
 }
 
-/// Find entity, piece data, and potential capture target for a move
-fn find_move_entities(
-    pieces_query: &Query<(Entity, &mut Piece, &mut HasMoved)>,
-    from: (u8, u8),
-    to: (u8, u8),
-) -> Option<(Entity, Piece, bool, Option<CapturedTarget>)> {
-    let mut move_data = None;
-    let mut capture_target = None;
-
-    for (entity, piece, has_moved) in pieces_query.iter() {
-        if piece.x == from.0 && piece.y == from.1 {
-            move_data = Some((entity, *piece, !has_moved.moved));
+/// End the game if the AI, on move as `ai_color`, has no legal move:
+/// checkmate is a win for the other side and stalemate a draw, mapped the
+/// same way as every other board ending ([`board_result`]). Returns false,
+/// changing nothing, while a move exists.
+fn end_game_if_ai_cannot_move(
+    engine: &mut ChessEngine,
+    ai_color: PieceColor,
+    game_over: &mut GameOverState,
+) -> bool {
+    let status = engine.game_status();
+    if !matches!(
+        status,
+        nimzovich_engine::GameStatus::Checkmate | nimzovich_engine::GameStatus::Stalemate
+    ) {
+        return false;
+    }
+    if let Some(result) = board_result(status, ai_color) {
+        info!(
+            "[AI] {:?} has no legal move: {}",
+            ai_color,
+            result.message()
+        );
+        *game_over = result;
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::ai::resource::AIDifficulty;
+    use crate::game::resources::active_time_control::ActiveTimeControl;
+    use crate::game::time_control::TimeControl;
+
+    #[test]
+    fn test_difficulty_budget_reaches_engine_uncapped() {
+        //! Level 8's 3 s budget is passed through when the max setting allows it
+
+        let base = AIDifficulty::Level8.seconds_per_move();
+        let (think, depth) = compute_think_params(base, 10.0, 0, None);
+        assert_eq!(think, 3.0);
+        assert_eq!(depth, None);
+
+        let (easy, _) =
+            compute_think_params(AIDifficulty::Level2.seconds_per_move(), 10.0, 0, None);
+        assert!(
+            easy < think,
+            "harder levels must get more time than easier ones"
+        );
+    }
+
+    #[test]
+    fn test_sustained_losing_eval_triggers_resignation() {
+        //! The AI resigns only once the eval stays below the threshold for the streak
+
+        let settings = crate::core::GameSettings {
+            ai_resigns: true,
+            ai_resign_threshold_cp: 800,
+            ai_resign_streak: 3,
+            ..Default::default()
+        };
+        let mut stats = AIStatistics::default();
+
+        assert!(!stats.record_eval(-900, 12, &settings));
+        assert!(!stats.record_eval(-1200, 12, &settings));
+        // A recovery resets the streak.
+        assert!(!stats.record_eval(-100, 12, &settings));
+        assert!(!stats.record_eval(-900, 12, &settings));
+        // A book or fallback move (no search) neither extends nor breaks it.
+        assert!(!stats.record_eval(0, 0, &settings));
+        assert_eq!(stats.losing_streak, 1);
+        assert!(!stats.record_eval(-900, 12, &settings));
+        assert!(stats.record_eval(-30_000, 12, &settings));
+
+        let off = crate::core::GameSettings {
+            ai_resigns: false,
+            ..settings
+        };
+        let mut stats = AIStatistics::default();
+        for _ in 0..10 {
+            assert!(!stats.record_eval(-5_000, 12, &off));
         }
-        if piece.x == to.0 && piece.y == to.1 {
-            capture_target = Some(CapturedTarget {
-                entity,
-                piece_type: piece.piece_type,
-                color: piece.color,
-            });
+    }
+
+    #[test]
+    fn test_opening_variety_diverges_only_across_seeds() {
+        //! Different game seeds can open differently; one seed always repeats
+
+        let history = crate::game::resources::MoveHistory::default();
+        let start = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1";
+
+        let first = opening_book_move(&history, start, 9);
+        assert!(first.is_some());
+        assert_eq!(opening_book_move(&history, start, 9), first);
+
+        let distinct: std::collections::HashSet<_> = (0..64)
+            .filter_map(|seed| opening_book_move(&history, start, seed))
+            .collect();
+        assert!(distinct.len() > 1);
+
+        let custom = "4k3/8/8/8/8/8/8/4K2R w K - 0 1";
+        assert_eq!(opening_book_move(&history, custom, 9), None);
+    }
+
+    #[test]
+    fn test_polyglot_book_moves_come_back_as_uci() {
+        //! A Polyglot book's entry for the start position is played as UCI, castling as the king's move
+
+        use nimzovich_engine::opening_book::{polyglot_key, PolyglotBook};
+
+        let castled = "r3k2r/8/8/8/8/8/8/R3K2R w KQkq - 0 1";
+        let mut bytes = Vec::new();
+        for (fen, raw_move) in [
+            (
+                "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+                (12u16 << 6) | 28,
+            ),
+            (castled, (4 << 6) | 7),
+        ] {
+            bytes.extend_from_slice(&polyglot_key(fen).unwrap().to_be_bytes());
+            bytes.extend_from_slice(&raw_move.to_be_bytes());
+            bytes.extend_from_slice(&1u16.to_be_bytes());
+            bytes.extend_from_slice(&0u32.to_be_bytes());
+        }
+        let book = PolyglotBook::from_bytes(&bytes).unwrap();
+
+        let start = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1";
+        for seed in 0..8 {
+            assert_eq!(
+                polyglot_book_move(&book, start, seed, 0).as_deref(),
+                Some("e2e4")
+            );
         }
+        assert_eq!(
+            polyglot_book_move(&book, castled, 3, 20).as_deref(),
+            Some("e1g1")
+        );
+        assert_eq!(
+            polyglot_book_move(&book, "4k3/8/8/8/8/8/8/4K3 w - - 0 1", 3, 0),
+            None
+        );
+    }
+
+    #[test]
+    fn test_max_think_setting_caps_budget() {
+        //! The configured max think time is what reaches the engine when lower
+
+        let base = AIDifficulty::Level8.seconds_per_move();
+        let (think, _) = compute_think_params(base, 1.25, 0, None);
+        assert_eq!(think, 1.25);
+
+        let (floored, _) = compute_think_params(base, 0.0, 0, None);
+        assert_eq!(floored, MIN_THINK_SECS);
+    }
+
+    #[test]
+    fn test_time_control_still_caps_below_setting() {
+        //! A short clock keeps the per-move budget under base / moves-left
+
+        let tc = ActiveTimeControl {
+            control: TimeControl::Bullet,
+            ai_game: true,
+        };
+        let base = AIDifficulty::Level8.seconds_per_move();
+        let (think, _) = compute_think_params(base, 10.0, 0, Some(&tc));
+        assert!(think <= TimeControl::Bullet.base_seconds() as f32 / 40.0 + f32::EPSILON);
+    }
+
+    fn ai_without_moves(fen: &str) -> (bool, GameOverState) {
+        let mut engine = ChessEngine::default();
+        engine.set_from_fen(fen).unwrap();
+        let mut game_over = GameOverState::Playing;
+        let ended = end_game_if_ai_cannot_move(&mut engine, PieceColor::Black, &mut game_over);
+        (ended, game_over)
     }
 
-    move_data.map(|(e, p, first)| (e, p, first, capture_target))
+    #[test]
+    fn test_stalemated_ai_draws() {
+        //! An AI left without a move but not in check ends the game as a stalemate draw
+
+        let (ended, result) = ai_without_moves("7k/5Q2/6K1/8/8/8/8/8 b - - 0 1");
+        assert!(ended);
+        assert_eq!(result, GameOverState::Stalemate);
+        assert!(result.is_draw());
+    }
+
+    #[test]
+    fn test_mated_ai_loses_to_human() {
+        //! A mated AI hands the win to the human; with a move available nothing changes
+
+        let (ended, result) = ai_without_moves("7k/6Q1/6K1/8/8/8/8/8 b - - 0 1");
+        assert!(ended);
+        assert_eq!(result, GameOverState::WhiteWon);
+
+        let (ended, result) = ai_without_moves("7k/8/6K1/8/8/8/8/5Q2 b - - 0 1");
+        assert!(!ended);
+        assert_eq!(result, GameOverState::Playing);
+    }
 }
@@ -0,0 +1,196 @@
+//! Arrows and circled squares drawn on the 3D board
+//!
+//! Dragging with the right button from one square to another draws an arrow
+//! between them; pressing and releasing on the same square circles it.
+//! Drawing the same mark again takes it off. Holding Shift, Alt or Ctrl
+//! picks red, blue or yellow instead of green. The marks are kept in
+//! [`Annotations`] as the [`crate::game::study`] types, so a study can take
+//! them as they are.
+//!
+//! A left click on the board or any move on it clears them. While a right
+//! drag that started on the board is live the camera does not orbit.
+
+use crate::game::resources::MoveHistory;
+use crate::game::study::{MarkColor, StudyArrow, StudySquare};
+use crate::rendering::pieces::Piece;
+use crate::rendering::utils::Square;
+use bevy::picking::events::{Pointer, Press, Release};
+use bevy::picking::pointer::PointerButton;
+use bevy::prelude::*;
+
+/// Marks drawn on the board.
+#[derive(Resource, Default, Debug)]
+pub struct Annotations {
+    pub arrows: Vec<StudyArrow>,
+    pub squares: Vec<StudySquare>,
+    /// Square the right button went down on, while it is held.
+    pub drag_from: Option<(u8, u8)>,
+}
+
+impl Annotations {
+    /// True while a right drag started on the board is in progress.
+    pub fn is_drawing(&self) -> bool {
+        self.drag_from.is_some()
+    }
+
+    /// Draw an arrow, or take it off when the same one is already there. An
+    /// arrow in another colour between the same squares is replaced.
+    pub fn toggle_arrow(&mut self, from: (u8, u8), to: (u8, u8), color: MarkColor) {
+        if let Some(i) = self
+            .arrows
+            .iter()
+            .position(|a| a.from == from && a.to == to)
+        {
+            if self.arrows.remove(i).color == color {
+                return;
+            }
+        }
+        self.arrows.push(StudyArrow { from, to, color });
+    }
+
+    /// Circle a square, or take the circle off, as [`Self::toggle_arrow`].
+    pub fn toggle_square(&mut self, square: (u8, u8), color: MarkColor) {
+        if let Some(i) = self.squares.iter().position(|s| s.square == square) {
+            if self.squares.remove(i).color == color {
+                return;
+            }
+        }
+        self.squares.push(StudySquare { square, color });
+    }
+
+    /// Take every mark off the board.
+    pub fn clear(&mut self) {
+        self.arrows.clear();
+        self.squares.clear();
+    }
+}
+
+/// Mark colour picked by the modifier keys held.
+pub fn mark_color(keyboard: &ButtonInput<KeyCode>) -> MarkColor {
+    if keyboard.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]) {
+        MarkColor::Red
+    } else if keyboard.any_pressed([KeyCode::AltLeft, KeyCode::AltRight]) {
+        MarkColor::Blue
+    } else if keyboard.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight]) {
+        MarkColor::Yellow
+    } else {
+        MarkColor::Green
+    }
+}
+
+/// The `(file, rank)` of a square or of the piece standing on it.
+fn board_square(
+    entity: Entity,
+    squares: &Query<&Square>,
+    pieces: &Query<&Piece>,
+) -> Option<(u8, u8)> {
+    if let Ok(square) = squares.get(entity) {
+        return Some((square.x, square.y));
+    }
+    pieces
+        .get(entity)
+        .ok()
+        .filter(|piece| piece.is_on_board())
+        .map(|piece| (piece.x, piece.y))
+}
+
+/// Observer on squares and pieces: the right button starts a mark, the left
+/// clears them all.
+pub fn on_annotation_press(
+    press: On<Pointer<Press>>,
+    mut annotations: ResMut<Annotations>,
+    squares: Query<&Square>,
+    pieces: Query<&Piece>,
+) {
+    match press.event.button {
+        PointerButton::Secondary => {
+            annotations.drag_from = board_square(press.entity, &squares, &pieces);
+        }
+        PointerButton::Primary => {
+            if !annotations.arrows.is_empty() || !annotations.squares.is_empty() {
+                annotations.clear();
+            }
+        }
+        PointerButton::Middle => {}
+    }
+}
+
+/// Observer on squares and pieces: releasing the right button finishes the
+/// mark begun by [`on_annotation_press`].
+pub fn on_annotation_release(
+    release: On<Pointer<Release>>,
+    mut annotations: ResMut<Annotations>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    squares: Query<&Square>,
+    pieces: Query<&Piece>,
+) {
+    if release.event.button != PointerButton::Secondary {
+        return;
+    }
+    let Some(from) = annotations.drag_from.take() else {
+        return;
+    };
+    let Some(to) = board_square(release.entity, &squares, &pieces) else {
+        return;
+    };
+    let color = mark_color(&keyboard);
+    if from == to {
+        annotations.toggle_square(to, color);
+    } else {
+        annotations.toggle_arrow(from, to, color);
+    }
+}
+
+/// Drop a mark whose button came up off the board.
+pub fn end_annotation_drag(
+    mouse: Res<ButtonInput<MouseButton>>,
+    mut annotations: ResMut<Annotations>,
+) {
+    if annotations.is_drawing() && !mouse.pressed(MouseButton::Right) {
+        annotations.drag_from = None;
+    }
+}
+
+/// Marks are for one position only: clear them when a move is played or
+/// taken back.
+pub fn clear_annotations_on_move(history: Res<MoveHistory>, mut annotations: ResMut<Annotations>) {
+    if history.is_changed() && (!annotations.arrows.is_empty() || !annotations.squares.is_empty()) {
+        annotations.clear();
+    }
+}
+
+/// Forget the previous game's marks.
+pub fn reset_annotations(mut annotations: ResMut<Annotations>) {
+    *annotations = Annotations::default();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_drawing_a_mark_twice_takes_it_off() {
+        //! The same arrow or circle drawn again goes away; in another colour it is recoloured
+
+        let mut annotations = Annotations::default();
+        annotations.toggle_arrow((4, 1), (4, 3), MarkColor::Green);
+        annotations.toggle_arrow((4, 3), (4, 1), MarkColor::Green);
+        assert_eq!(annotations.arrows.len(), 2);
+
+        annotations.toggle_arrow((4, 1), (4, 3), MarkColor::Red);
+        assert_eq!(annotations.arrows.len(), 2);
+        assert_eq!(annotations.arrows[1].color, MarkColor::Red);
+        annotations.toggle_arrow((4, 1), (4, 3), MarkColor::Red);
+        assert_eq!(annotations.arrows.len(), 1);
+
+        annotations.toggle_square((3, 3), MarkColor::Blue);
+        annotations.toggle_square((3, 3), MarkColor::Yellow);
+        assert_eq!(annotations.squares.len(), 1);
+        assert_eq!(annotations.squares[0].color, MarkColor::Yellow);
+        annotations.toggle_square((3, 3), MarkColor::Yellow);
+        assert!(annotations.squares.is_empty());
+
+        annotations.clear();
+        assert!(annotations.arrows.is_empty());
+    }
+}
@@ -0,0 +1,277 @@
+//! "Are you sure?" check for moves that throw away material
+//!
+//! A training aid for beginners, off by default
+//! ([`GameSettings::confirm_blunders`]). When the human commits a move in an
+//! offline game, [`try_move_sequence`] hands it to [`BlunderCheck`] instead of
+//! playing it. A shallow engine search then runs on the async task pool,
+//! comparing the best score available before the move with the score after
+//! the opponent's best reply. A clean move is played as soon as the task
+//! finishes, normally within a frame or two. A drop of [`BLUNDER_DROP_CP`]
+//! or more holds the move until the player confirms or takes it back.
+//!
+//! The same hold also serves the losing-capture check
+//! ([`GameSettings::confirm_losing_captures`]): a capture whose static
+//! exchange evaluation comes out negative is held straight away, with no
+//! search, since SEE is cheap enough to run at the click.
+//!
+//! [`GameSettings::confirm_blunders`]: crate::core::GameSettings::confirm_blunders
+//! [`GameSettings::confirm_losing_captures`]: crate::core::GameSettings::confirm_losing_captures
+//! [`try_move_sequence`]: crate::game::systems::input::try_move_sequence
+
+use crate::engine::board_state::ChessEngine;
+use crate::game::ai::side_search::side_search_game;
+use crate::game::systems::input::{clear_selection_state, try_move_sequence, InputSystemParams};
+use crate::game::systems::shared::CapturedTarget;
+use crate::rendering::pieces::PieceColor;
+use bevy::prelude::*;
+use bevy::tasks::{AsyncComputeTaskPool, Task};
+use nimzovich_engine::{
+    do_move_with_promo, reply_incremental, Game, IncrementalSearch, SearchProgress, COLOR_BLACK,
+    COLOR_WHITE,
+};
+
+/// Score loss, in centipawns, at which a move needs confirming — a minor
+/// piece with some margin for positional noise.
+pub const BLUNDER_DROP_CP: i64 = 250;
+
+/// Node budget for each of the two searches behind one check.
+const CHECK_NODES: i64 = 50_000;
+
+/// Plies searched; enough to see a piece left en prise and the recapture.
+const CHECK_DEPTH: i64 = 3;
+
+/// Best score `color` can reach in `game`, from `color`'s point of view.
+fn shallow_score(game: &mut Game, color: i64) -> i64 {
+    let mut search = IncrementalSearch::new(color, CHECK_NODES);
+    loop {
+        if let SearchProgress::Done(mv) = reply_incremental(game, &mut search, CHECK_NODES) {
+            return mv.score;
+        }
+    }
+}
+
+/// How many centipawns `color` loses by playing `from` → `to` in `fen`
+/// instead of its best move. Pawns reaching the last rank are scored as
+/// queening.
+pub fn eval_drop(fen: &str, color: PieceColor, from: (u8, u8), to: (u8, u8)) -> i64 {
+    let (mover, opponent) = match color {
+        PieceColor::White => (COLOR_WHITE, COLOR_BLACK),
+        PieceColor::Black => (COLOR_BLACK, COLOR_WHITE),
+    };
+    let src = ChessEngine::square_to_index(from.0, from.1);
+    let dst = ChessEngine::square_to_index(to.0, to.1);
+
+    let mut game = side_search_game(fen);
+    game.abs_max_depth = CHECK_DEPTH;
+    let before = shallow_score(&mut game, mover);
+
+    let mut game = side_search_game(fen);
+    game.abs_max_depth = CHECK_DEPTH;
+    let promo = if game.board[src as usize].abs() == 1 && (to.1 == 0 || to.1 == 7) {
+        5
+    } else {
+        0
+    };
+    do_move_with_promo(&mut game, src, dst, true, promo);
+    let after = -shallow_score(&mut game, opponent);
+
+    before - after
+}
+
+/// Whether playing `from` → `to` in `fen` loses at least [`BLUNDER_DROP_CP`].
+pub fn is_blunder(fen: &str, color: PieceColor, from: (u8, u8), to: (u8, u8)) -> bool {
+    eval_drop(fen, color, from, to) >= BLUNDER_DROP_CP
+}
+
+/// Why a move is waiting on the player.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HoldReason {
+    /// The engine check says it throws away material.
+    Blunder,
+    /// A capture that loses the exchange on the square (negative SEE).
+    LosingCapture,
+}
+
+/// Where a held move stands.
+pub enum BlunderStage {
+    /// The engine is still looking at it.
+    Checking(Task<bool>),
+    /// Flagged; waiting on the player.
+    AwaitingConfirmation,
+    /// Play it anyway.
+    Confirmed,
+    /// Take it back.
+    Cancelled,
+}
+
+/// A move held back until the blunder check clears it.
+pub struct HeldMove {
+    pub entity: Entity,
+    pub target: (u8, u8),
+    pub capture: Option<CapturedTarget>,
+    pub origin: String,
+    pub reason: HoldReason,
+    pub stage: BlunderStage,
+}
+
+/// The move currently being checked, if any, and the one move allowed
+/// through without a check.
+#[derive(Resource, Default)]
+pub struct BlunderCheck {
+    held: Option<HeldMove>,
+    approved: Option<(Entity, (u8, u8))>,
+}
+
+impl BlunderCheck {
+    /// A move is being checked or awaiting the player; board input waits.
+    pub fn is_busy(&self) -> bool {
+        self.held.is_some()
+    }
+
+    /// The flagged move's destination, and why it was flagged, while the
+    /// confirmation is showing.
+    pub fn awaiting_confirmation(&self) -> Option<((u8, u8), HoldReason)> {
+        match &self.held {
+            Some(held) if matches!(held.stage, BlunderStage::AwaitingConfirmation) => {
+                Some((held.target, held.reason))
+            }
+            _ => None,
+        }
+    }
+
+    /// Hold `entity` → `target` and start checking it in the background.
+    #[allow(clippy::too_many_arguments)]
+    pub fn start(
+        &mut self,
+        fen: String,
+        color: PieceColor,
+        from: (u8, u8),
+        entity: Entity,
+        target: (u8, u8),
+        capture: Option<CapturedTarget>,
+        origin: &str,
+    ) {
+        let task =
+            AsyncComputeTaskPool::get().spawn(async move { is_blunder(&fen, color, from, target) });
+        self.held = Some(HeldMove {
+            entity,
+            target,
+            capture,
+            origin: origin.to_string(),
+            reason: HoldReason::Blunder,
+            stage: BlunderStage::Checking(task),
+        });
+    }
+
+    /// Hold `entity` → `target` and ask the player straight away.
+    pub fn hold(
+        &mut self,
+        entity: Entity,
+        target: (u8, u8),
+        capture: Option<CapturedTarget>,
+        origin: &str,
+        reason: HoldReason,
+    ) {
+        self.held = Some(HeldMove {
+            entity,
+            target,
+            capture,
+            origin: origin.to_string(),
+            reason,
+            stage: BlunderStage::AwaitingConfirmation,
+        });
+    }
+
+    /// Mark the flagged move as played-anyway.
+    pub fn confirm(&mut self) {
+        if let Some(held) = &mut self.held {
+            held.stage = BlunderStage::Confirmed;
+        }
+    }
+
+    /// Mark the flagged move as taken back.
+    pub fn cancel(&mut self) {
+        if let Some(held) = &mut self.held {
+            held.stage = BlunderStage::Cancelled;
+        }
+    }
+
+    /// Consume the pass for `entity` → `target`, if the check cleared it.
+    pub fn take_approval(&mut self, entity: Entity, target: (u8, u8)) -> bool {
+        if self.approved == Some((entity, target)) {
+            self.approved = None;
+            return true;
+        }
+        false
+    }
+
+    /// Drop any held move, e.g. when a new game starts.
+    pub fn clear(&mut self) {
+        self.held = None;
+        self.approved = None;
+    }
+}
+
+/// Play, hold or drop the checked move once its verdict or the player's
+/// answer is in.
+pub fn resolve_blunder_check(mut params: InputSystemParams) {
+    let Some(held) = params.blunder_check.held.as_mut() else {
+        return;
+    };
+
+    let play = match &mut held.stage {
+        BlunderStage::Checking(task) => {
+            let Some(blunder) =
+                futures_lite::future::block_on(futures_lite::future::poll_once(task))
+            else {
+                return;
+            };
+            if blunder {
+                info!("[BLUNDER] Move to {:?} flagged, asking first", held.target);
+                held.stage = BlunderStage::AwaitingConfirmation;
+                return;
+            }
+            true
+        }
+        BlunderStage::AwaitingConfirmation => return,
+        BlunderStage::Confirmed => true,
+        BlunderStage::Cancelled => false,
+    };
+
+    let Some(held) = params.blunder_check.held.take() else {
+        return;
+    };
+    if play {
+        params.blunder_check.approved = Some((held.entity, held.target));
+        try_move_sequence(&mut params, held.target, held.capture, &held.origin);
+    } else {
+        clear_selection_state(
+            &mut params.commands,
+            &mut params.selection,
+            &params.selected_pieces,
+        );
+    }
+}
+
+/// Forget any move still held from the previous game.
+pub fn reset_blunder_check(mut check: ResMut<BlunderCheck>) {
+    check.clear();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hanging_queen_is_flagged() {
+        //! Putting the queen where a knight takes it for free trips the check; developing doesn't
+
+        // 1. e4 e5 2. Nf3, Black to move
+        let fen = "rnbqkbnr/pppp1ppp/8/4p3/4P3/5N2/PPPP1PPP/RNBQKB1R b KQkq - 1 2";
+
+        // ...Qg5 walks into Nxg5
+        assert!(is_blunder(fen, PieceColor::Black, (3, 7), (6, 4)));
+        // ...Nc6 defends e5
+        assert!(!is_blunder(fen, PieceColor::Black, (1, 7), (2, 5)));
+    }
+}
@@ -9,7 +9,13 @@
 //!
 //! # World-space mapping
 //!
-//! Bevy world coordinates: X = file, Z = rank (Y is up).
+//! Bevy world coordinates: X = file, Z = rank (Y is up). Files are mirrored
+//! (`x = 7 - file`) so a-file sits on White's left; with
+//! [`GameSettings::mirror_files`] on they are not, and a-file sits on the
+//! right. Every file ↔ world-X conversion goes through [`file_world_x`] and
+//! [`world_x_file`] so squares, pieces, highlights and labels agree.
+//!
+//! [`GameSettings::mirror_files`]: crate::core::GameSettings::mirror_files
 //!
 //! # Reference
 //!
@@ -17,6 +23,54 @@
 //! - <https://stackoverflow.com/questions/16523> (SQL-style indexing pitfalls)
 
 use bevy::prelude::*;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Whether the current game lays files out a→h right-to-left from White's
+/// side. Latched from settings when a game starts (see [`apply_file_mirroring`]).
+static FILES_MIRRORED: AtomicBool = AtomicBool::new(false);
+
+/// Set the file orientation used by [`file_world_x`] and [`world_x_file`].
+pub fn set_files_mirrored(mirrored: bool) {
+    FILES_MIRRORED.store(mirrored, Ordering::Relaxed);
+}
+
+/// Whether files are currently mirrored.
+pub fn files_mirrored() -> bool {
+    FILES_MIRRORED.load(Ordering::Relaxed)
+}
+
+/// World-space X of `file` for the given orientation.
+#[inline]
+pub fn file_world_x_for(file: f32, mirrored: bool) -> f32 {
+    if mirrored {
+        file
+    } else {
+        7.0 - file
+    }
+}
+
+/// World-space X of `file` (0 = a) in the current orientation.
+#[inline]
+pub fn file_world_x(file: u8) -> f32 {
+    file_world_x_for(file as f32, files_mirrored())
+}
+
+/// File (0 = a, possibly fractional) under world-space `x`; the inverse of
+/// [`file_world_x`].
+#[inline]
+pub fn world_x_file(x: f32) -> f32 {
+    // The mapping is its own inverse in both orientations.
+    file_world_x_for(x, files_mirrored())
+}
+
+/// Latch [`GameSettings::mirror_files`] for the game about to start. Runs on
+/// entering `InGame` before the board is built, so one game never mixes
+/// orientations.
+///
+/// [`GameSettings::mirror_files`]: crate::core::GameSettings::mirror_files
+pub fn apply_file_mirroring(settings: Res<crate::core::GameSettings>) {
+    set_files_mirrored(settings.mirror_files);
+}
 
 /// A typed chess board position.
 ///
@@ -102,11 +156,12 @@ impl BoardPos {
     }
 
     /// World-space X coordinate (file maps to X).
-    /// Files are mirrored (7 - file) so that from the White-side camera (at -Z looking +Z,
-    /// whose right vector is -X), a-file appears on screen-left and h-file on screen-right.
+    /// By default files are mirrored (7 - file) so that from the White-side camera (at -Z
+    /// looking +Z, whose right vector is -X), a-file appears on screen-left and h-file on
+    /// screen-right. See [`file_world_x`].
     #[inline]
     pub fn world_x(self) -> f32 {
-        7.0 - self.file as f32
+        file_world_x(self.file)
     }
 
     /// World-space Z coordinate (rank maps to Z).
@@ -186,6 +241,27 @@ mod tests {
         assert_eq!(format!("{}", BoardPos::new(7, 7)), "h8");
     }
 
+    #[test]
+    fn mirroring_swaps_files_only() {
+        //! Mirrored, file a moves to the other edge of the board; ranks never move
+        let a1 = BoardPos::new(0, 0);
+        let h8 = BoardPos::new(7, 7);
+
+        assert_eq!(file_world_x_for(a1.file as f32, false), 7.0);
+        assert_eq!(file_world_x_for(a1.file as f32, true), 0.0);
+        assert_eq!(file_world_x_for(h8.file as f32, false), 0.0);
+        assert_eq!(file_world_x_for(h8.file as f32, true), 7.0);
+        assert_eq!(a1.world_z(), 0.0);
+        assert_eq!(h8.world_z(), 7.0);
+
+        for mirrored in [false, true] {
+            for file in 0..8u8 {
+                let x = file_world_x_for(file as f32, mirrored);
+                assert_eq!(file_world_x_for(x, mirrored), file as f32);
+            }
+        }
+    }
+
     #[test]
     fn from_uci_edge_cases() {
         assert_eq!(BoardPos::from_uci(""), None);
@@ -0,0 +1,213 @@
+//! PNG screenshots of the 3D board
+//!
+//! The [`GameAction::Screenshot`] key (F2 by default) saves what the board
+//! camera sees to `screenshots/` under the user's data directory, named by
+//! the time it was taken (e.g. `xfchess_2024-05-01_18-20-07.png`).
+//!
+//! The image is clean: for the frame it is taken in the persistent UI camera
+//! is switched off, so no egui panel or popup is drawn over the board, and
+//! the capture is cropped to the board camera's viewport between the side
+//! panels. The position's FEN goes into the file as a PNG `tEXt` chunk under
+//! the keyword `FEN`, so a shared image carries the exact position.
+//!
+//! The 2D board is drawn by egui itself, so the key does nothing there but
+//! say so.
+
+use crate::core::{GameAction, GameSettings};
+use crate::engine::board_state::ChessEngine;
+use crate::game::systems::camera::BoardCamera;
+use crate::game::view_mode::ViewMode;
+use crate::ui::menus::popup::{GamePopup, GamePopupQueue};
+use bevy::prelude::*;
+use bevy::render::view::screenshot::{Screenshot, ScreenshotCaptured};
+use directories::ProjectDirs;
+use std::path::{Path, PathBuf};
+
+/// Keyword the FEN is stored under in the PNG.
+pub const FEN_KEYWORD: &str = "FEN";
+
+/// Directory screenshots are written to.
+///
+/// Falls back to a local `screenshots/` directory if the system data dir
+/// cannot be resolved.
+pub fn screenshots_dir() -> PathBuf {
+    ProjectDirs::from("com", "trilltino", "XFChess")
+        .map(|dirs| dirs.data_dir().join("screenshots"))
+        .unwrap_or_else(|| PathBuf::from("screenshots"))
+}
+
+/// File name for a screenshot taken at `time`.
+pub fn screenshot_file_name(time: chrono::DateTime<chrono::Local>) -> String {
+    format!("xfchess_{}.png", time.format("%Y-%m-%d_%H-%M-%S"))
+}
+
+/// CRC-32 as PNG chunks use it (ISO 3309, reflected, init and xor `!0`).
+fn png_crc(bytes: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &byte in bytes {
+        crc ^= u32::from(byte);
+        for _ in 0..8 {
+            crc = if crc & 1 != 0 {
+                (crc >> 1) ^ 0xEDB8_8320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
+
+/// Insert a `tEXt` chunk holding `keyword` and `text` into an encoded PNG,
+/// straight after its header chunk. `None` if `png` doesn't start with a
+/// PNG signature and header.
+pub fn add_png_text(png: &[u8], keyword: &str, text: &str) -> Option<Vec<u8>> {
+    const SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1A, b'\n'];
+    // Signature, then IHDR: length, type, 13 bytes of data, CRC.
+    const HEADER_END: usize = 8 + 4 + 4 + 13 + 4;
+    if png.len() < HEADER_END || png[..8] != SIGNATURE || &png[12..16] != b"IHDR" {
+        return None;
+    }
+
+    let mut chunk = b"tEXt".to_vec();
+    chunk.extend_from_slice(keyword.as_bytes());
+    chunk.push(0);
+    chunk.extend_from_slice(text.as_bytes());
+
+    let mut out = Vec::with_capacity(png.len() + chunk.len() + 8);
+    out.extend_from_slice(&png[..HEADER_END]);
+    out.extend_from_slice(&((chunk.len() - 4) as u32).to_be_bytes());
+    out.extend_from_slice(&chunk);
+    out.extend_from_slice(&png_crc(&chunk).to_be_bytes());
+    out.extend_from_slice(&png[HEADER_END..]);
+    Some(out)
+}
+
+/// Crop the captured window to `crop`, encode it as PNG with the FEN and
+/// write it to `path`.
+fn write_board_png(
+    captured: Image,
+    crop: Option<URect>,
+    fen: &str,
+    path: &Path,
+) -> Result<(), String> {
+    let mut image = captured
+        .try_into_dynamic()
+        .map_err(|e| format!("unreadable capture: {:?}", e))?;
+    if let Some(crop) = crop {
+        image = image.crop_imm(crop.min.x, crop.min.y, crop.width(), crop.height());
+    }
+    let mut png = Vec::new();
+    image
+        .to_rgb8()
+        .write_to(&mut std::io::Cursor::new(&mut png), image::ImageFormat::Png)
+        .map_err(|e| e.to_string())?;
+    let png = add_png_text(&png, FEN_KEYWORD, fen).ok_or("encoder wrote no PNG header")?;
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir).map_err(|e| e.to_string())?;
+    }
+    std::fs::write(path, png).map_err(|e| e.to_string())
+}
+
+/// Take a screenshot on the [`GameAction::Screenshot`] key, hiding the UI
+/// camera for that frame and bringing it back on the next.
+#[allow(clippy::too_many_arguments)]
+pub fn board_screenshot_system(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    settings: Res<GameSettings>,
+    view_mode: Res<ViewMode>,
+    engine: Res<ChessEngine>,
+    persistent_camera: Res<crate::PersistentEguiCamera>,
+    board_camera: Query<&Camera, With<BoardCamera>>,
+    mut cameras: Query<&mut Camera, Without<BoardCamera>>,
+    mut popups: Option<ResMut<GamePopupQueue>>,
+    mut hidden_ui: Local<Option<Entity>>,
+) {
+    if let Some(entity) = hidden_ui.take() {
+        if let Ok(mut camera) = cameras.get_mut(entity) {
+            camera.is_active = true;
+        }
+    }
+    if !settings
+        .key_bindings
+        .just_pressed(&keyboard, GameAction::Screenshot)
+    {
+        return;
+    }
+    if *view_mode == ViewMode::Standard2D || view_mode.is_templeos() {
+        if let Some(popups) = popups.as_mut() {
+            popups.push(GamePopup::notice(
+                "Screenshot",
+                "Screenshots are of the 3D board. Switch views to take one.",
+            ));
+        }
+        return;
+    }
+    let Ok(board_camera) = board_camera.single() else {
+        return;
+    };
+
+    if let Some(entity) = persistent_camera.entity {
+        if let Ok(mut camera) = cameras.get_mut(entity) {
+            camera.is_active = false;
+            *hidden_ui = Some(entity);
+        }
+    }
+
+    let crop = board_camera.viewport.as_ref().map(|viewport| {
+        URect::from_corners(
+            viewport.physical_position,
+            viewport.physical_position + viewport.physical_size,
+        )
+    });
+    let fen = engine.current_fen().to_string();
+    let path = screenshots_dir().join(screenshot_file_name(chrono::Local::now()));
+    commands.spawn(Screenshot::primary_window()).observe(
+        move |captured: On<ScreenshotCaptured>| match write_board_png(
+            captured.image.clone(),
+            crop,
+            &fen,
+            &path,
+        ) {
+            Ok(()) => info!("[SCREENSHOT] Saved {}", path.display()),
+            Err(e) => warn!("[SCREENSHOT] Could not save {}: {}", path.display(), e),
+        },
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fen_goes_into_a_valid_png() {
+        //! The FEN chunk sits after the header with a correct CRC, and the image still decodes
+
+        let mut png = Vec::new();
+        image::RgbImage::from_pixel(2, 2, image::Rgb([40, 90, 200]))
+            .write_to(&mut std::io::Cursor::new(&mut png), image::ImageFormat::Png)
+            .unwrap();
+        let fen = "rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq e3 0 1";
+        let tagged = add_png_text(&png, FEN_KEYWORD, fen).unwrap();
+
+        let chunk = [b"tEXtFEN\0".as_slice(), fen.as_bytes()].concat();
+        assert_eq!(&tagged[33..37], &(chunk.len() as u32 - 4).to_be_bytes());
+        assert_eq!(&tagged[37..37 + chunk.len()], chunk.as_slice());
+        assert_eq!(png_crc(b"IEND"), 0xAE42_6082);
+
+        let decoded = image::load_from_memory(&tagged).unwrap().to_rgb8();
+        assert_eq!(decoded.get_pixel(1, 1), &image::Rgb([40, 90, 200]));
+        assert!(add_png_text(b"not a png", FEN_KEYWORD, fen).is_none());
+
+        let taken = chrono::NaiveDate::from_ymd_opt(2024, 5, 1)
+            .unwrap()
+            .and_hms_opt(18, 20, 7)
+            .unwrap()
+            .and_local_timezone(chrono::Local)
+            .unwrap();
+        assert_eq!(
+            screenshot_file_name(taken),
+            "xfchess_2024-05-01_18-20-07.png"
+        );
+    }
+}
@@ -85,7 +85,8 @@ pub enum GamePhase {
 
     /// Game over: Current player has no legal moves but is not in check
     ///
-    /// Result is a draw. Common in endgames with insufficient material.
+    /// Result is a draw. Also used for the other drawn endings (fifty-move
+    /// rule, repetition, insufficient material); `GameOverState` has the reason.
     Stalemate,
 }
 
@@ -147,6 +148,11 @@ pub struct MoveRecord {
     /// - `Some(PieceType)`: Capture move (piece removed from board)
     pub captured: Option<PieceType>,
 
+    /// Piece a pawn was promoted to, if this move promoted
+    ///
+    /// `piece_type` stays `Pawn` for these moves.
+    pub promotion: Option<PieceType>,
+
     /// Whether this move was a castling maneuver
     ///
     /// Castling involves moving both king and rook:
@@ -257,6 +263,7 @@ mod tests {
             from: (4, 1),
             to: (4, 3),
             captured: None,
+            promotion: None,
             is_castling: false,
             is_en_passant: false,
             is_check: false,
@@ -279,6 +286,7 @@ mod tests {
             from: (3, 7),
             to: (7, 3),
             captured: Some(PieceType::Rook),
+            promotion: None,
             is_castling: false,
             is_en_passant: false,
             is_check: true,
@@ -300,6 +308,7 @@ mod tests {
             from: (4, 0),
             to: (6, 0),
             captured: None,
+            promotion: None,
             is_castling: true,
             is_en_passant: false,
             is_check: false,
@@ -320,6 +329,7 @@ mod tests {
             from: (4, 3),
             to: (3, 2),
             captured: Some(PieceType::Pawn),
+            promotion: None,
             is_castling: false,
             is_en_passant: true,
             is_check: false,
@@ -340,6 +350,7 @@ mod tests {
             from: (3, 4),
             to: (5, 6),
             captured: Some(PieceType::Pawn),
+            promotion: None,
             is_castling: false,
             is_en_passant: false,
             is_check: true,
@@ -360,6 +371,7 @@ mod tests {
             from: (1, 0),
             to: (2, 2),
             captured: None,
+            promotion: None,
             is_castling: false,
             is_en_passant: false,
             is_check: false,
@@ -381,6 +393,7 @@ mod tests {
             from: (2, 0),
             to: (5, 3),
             captured: None,
+            promotion: None,
             is_castling: false,
             is_en_passant: false,
             is_check: false,
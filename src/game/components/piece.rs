@@ -82,6 +82,15 @@ pub struct SelectedPiece {
     pub position: (u8, u8),
 }
 
+/// Component marking a piece the player is dragging
+///
+/// While present, the piece follows the pointer instead of being snapped back
+/// to its logical square by `animate_piece_movement`. Added on drag start and
+/// removed on drop, whether or not the drop made a move.
+#[derive(Component, Clone, Copy, Debug, Default, Reflect)]
+#[reflect(Component)]
+pub struct DraggedPiece;
+
 /// Component tracking whether a piece has moved from its starting position
 ///
 /// Critical for enforcing chess rules that depend on movement history:
@@ -130,7 +139,7 @@ pub struct HasMoved {
 #[reflect(Component)]
 pub struct Captured;
 
-/// Component for fading out a captured piece before despawning.
+/// Component for fading out a captured piece before hiding it.
 ///
 /// Drives a 3-phase capture animation:
 /// 1. **Arc** — piece flies upward along a parabolic arc
@@ -181,6 +190,102 @@ impl PieceMoveAnimation {
     }
 }
 
+/// Shortest capture or promotion animation the settings allow, in seconds.
+pub const EFFECT_ANIMATION_SECS_MIN: f32 = 0.2;
+/// Longest capture or promotion animation the settings allow, in seconds.
+pub const EFFECT_ANIMATION_SECS_MAX: f32 = 2.0;
+
+/// Height a captured piece is lifted to half way along its [`CaptureFlight`].
+pub const CAPTURE_FLIGHT_LIFT: f32 = 1.5;
+/// Opacity a captured piece fades down to half way along its flight.
+pub const CAPTURE_FLIGHT_MIN_ALPHA: f32 = 0.25;
+
+/// Component flying a captured piece to its slot in the capture tray
+///
+/// Mirrors [`PieceMoveAnimation`], but the piece is lifted well clear of the
+/// board and fades out on the way, coming back to full opacity as it lands.
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component)]
+pub struct CaptureFlight {
+    pub start: Vec3,
+    pub end: Vec3,
+    pub elapsed: f32,
+    pub duration: f32,
+}
+
+impl CaptureFlight {
+    pub fn new(start: Vec3, end: Vec3, duration: f32) -> Self {
+        Self {
+            start,
+            end,
+            elapsed: 0.0,
+            duration: duration.max(f32::EPSILON),
+        }
+    }
+
+    /// Linear progress through the flight, 0 to 1.
+    fn fraction(&self) -> f32 {
+        (self.elapsed / self.duration).clamp(0.0, 1.0)
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.elapsed >= self.duration
+    }
+
+    /// Where the piece is now: eased along the line to the tray, and lifted
+    /// by up to [`CAPTURE_FLIGHT_LIFT`] at the midpoint.
+    pub fn position(&self) -> Vec3 {
+        let t = self.fraction();
+        let eased = t * t * (3.0 - 2.0 * t);
+        self.start.lerp(self.end, eased) + Vec3::Y * CAPTURE_FLIGHT_LIFT * 4.0 * t * (1.0 - t)
+    }
+
+    /// Multiplier on the piece's opacity now: 1 at both ends and
+    /// [`CAPTURE_FLIGHT_MIN_ALPHA`] at the midpoint.
+    pub fn alpha(&self) -> f32 {
+        let t = self.fraction();
+        1.0 - (1.0 - CAPTURE_FLIGHT_MIN_ALPHA) * 4.0 * t * (1.0 - t)
+    }
+}
+
+/// Component for the flourish on a piece that has just been promoted
+///
+/// The new piece swells and glows, then settles back to its normal size and
+/// finish over `duration`.
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component)]
+pub struct PromotionAnimation {
+    pub elapsed: f32,
+    pub duration: f32,
+}
+
+impl PromotionAnimation {
+    /// How far past full size the piece swells at the midpoint.
+    const SWELL: f32 = 0.3;
+
+    pub fn new(duration: f32) -> Self {
+        Self {
+            elapsed: 0.0,
+            duration: duration.max(f32::EPSILON),
+        }
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.elapsed >= self.duration
+    }
+
+    /// Strength of the swell and glow now: 0 at both ends, 1 at the midpoint.
+    pub fn pulse(&self) -> f32 {
+        let t = (self.elapsed / self.duration).clamp(0.0, 1.0);
+        (t * std::f32::consts::PI).sin()
+    }
+
+    /// Uniform scale of the piece now.
+    pub fn scale(&self) -> f32 {
+        1.0 + Self::SWELL * self.pulse()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -358,4 +463,24 @@ mod tests {
         assert!(anim.duration > 0.0);
         assert_eq!(anim.progress(), 0.0);
     }
+
+    #[test]
+    fn test_capture_flight_lifts_and_fades_mid_way() {
+        //! A capture flight rises and dims towards its midpoint and lands at full opacity on the tray
+
+        let start = Vec3::new(4.0, 0.0, 4.0);
+        let end = Vec3::new(-1.0, 0.0, 0.0);
+        let mut flight = CaptureFlight::new(start, end, 1.0);
+        assert_eq!(flight.position(), start);
+        assert_eq!(flight.alpha(), 1.0);
+
+        flight.elapsed = 0.5;
+        assert!((flight.position().y - CAPTURE_FLIGHT_LIFT).abs() < 1e-5);
+        assert!((flight.alpha() - CAPTURE_FLIGHT_MIN_ALPHA).abs() < 1e-5);
+
+        flight.elapsed = 1.2;
+        assert!(flight.is_finished());
+        assert_eq!(flight.position(), end);
+        assert_eq!(flight.alpha(), 1.0);
+    }
 }
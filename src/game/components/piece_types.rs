@@ -66,6 +66,18 @@ impl PieceType {
             _ => None,
         }
     }
+
+    /// The piece's uppercase SAN letter ('Q', 'N'), with 'P' for a pawn.
+    pub fn san_char(self) -> char {
+        match self {
+            PieceType::King => 'K',
+            PieceType::Queen => 'Q',
+            PieceType::Bishop => 'B',
+            PieceType::Knight => 'N',
+            PieceType::Rook => 'R',
+            PieceType::Pawn => 'P',
+        }
+    }
 }
 
 /// Represents a chess piece on the board.
@@ -98,4 +110,10 @@ impl Piece {
             y: rank,
         }
     }
+
+    /// False once the piece is captured: its coordinates are then set past
+    /// the board.
+    pub fn is_on_board(&self) -> bool {
+        self.x < 8 && self.y < 8
+    }
 }
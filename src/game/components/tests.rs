@@ -85,6 +85,7 @@ fn test_move_record_simple_move() {
         from: (1, 4),
         to: (3, 4),
         captured: None,
+        promotion: None,
         is_castling: false,
         is_en_passant: false,
         is_check: false,
@@ -116,6 +117,7 @@ fn test_move_record_with_capture() {
         from: (2, 2),
         to: (4, 3),
         captured: Some(PieceType::Pawn),
+        promotion: None,
         is_castling: false,
         is_en_passant: false,
         is_check: true, // Knight capture could give check
@@ -140,6 +142,7 @@ fn test_move_record_castling() {
         from: (0, 4),
         to: (0, 6),
         captured: None,
+        promotion: None,
         is_castling: true,
         is_en_passant: false,
         is_check: false,
@@ -166,6 +169,7 @@ fn test_move_record_en_passant() {
         from: (4, 3),
         to: (5, 4),
         captured: Some(PieceType::Pawn),
+        promotion: None,
         is_castling: false,
         is_en_passant: true,
         is_check: false,
@@ -190,6 +194,7 @@ fn test_move_record_checkmate() {
         from: (5, 3),
         to: (7, 5),
         captured: None,
+        promotion: None,
         is_castling: false,
         is_en_passant: false,
         is_check: true,
@@ -215,6 +220,7 @@ fn test_move_record_clone() {
         from: (7, 0),
         to: (7, 7),
         captured: Some(PieceType::Bishop),
+        promotion: None,
         is_castling: false,
         is_en_passant: false,
         is_check: false,
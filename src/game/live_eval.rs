@@ -0,0 +1,148 @@
+//! Live score for the evaluation bar
+//!
+//! After every move the bar gets a fresh White-relative score in
+//! [`EvalBarState`]. A move the computer just made already comes with the
+//! score of its own search ([`AIStatistics::last_score`]), so that is used as
+//! it is. Any other move (the human's, or either side's in a local
+//! two-player game) starts a shallow search of the new position on the async
+//! task pool, as [`crate::game::blunder_check`] does, and the bar moves when
+//! it finishes. Competitive online games get no evaluation.
+
+use crate::core::GameMode as CoreGameMode;
+use crate::engine::board_state::ChessEngine;
+use crate::game::ai::resource::ChessAIResource;
+use crate::game::ai::side_search::{side_search_game, side_to_move};
+use crate::game::ai::AIStatistics;
+use crate::game::resources::MoveHistory;
+use crate::rendering::pieces::PieceColor;
+use crate::ui::game::game_2d::EvalBarState;
+use bevy::prelude::*;
+use bevy::tasks::{AsyncComputeTaskPool, Task};
+use nimzovich_engine::{get_game_status, reply_limited, GameStatus, COLOR_WHITE};
+
+/// Plies searched; enough to see a piece hanging or a short mate.
+const EVAL_DEPTH: i64 = 4;
+
+/// Node budget for one search, which keeps it to a few frames.
+const EVAL_NODES: u64 = 100_000;
+
+/// Time limit for one search, in case the node budget is slow to run out.
+const EVAL_SECS: f32 = 1.0;
+
+/// The search behind the bar, if one is running.
+#[derive(Resource, Default)]
+pub struct LiveEval {
+    task: Option<Task<Option<i64>>>,
+    /// Length of the move history the bar's score belongs to.
+    plies: usize,
+}
+
+/// White-relative score of `fen` from a shallow search. `None` when the side
+/// to move is mated, where there is nothing left to search; any draw scores
+/// zero.
+pub fn shallow_eval(fen: &str) -> Option<i64> {
+    let color = side_to_move(fen);
+    let mut game = side_search_game(fen);
+    match get_game_status(&mut game, color) {
+        GameStatus::Playing => {}
+        GameStatus::Checkmate => return None,
+        _ => return Some(0),
+    }
+    let score = reply_limited(
+        &mut game,
+        color,
+        EVAL_SECS,
+        Some(EVAL_NODES),
+        Some(EVAL_DEPTH),
+    )
+    .score;
+    Some(if color == COLOR_WHITE { score } else { -score })
+}
+
+/// Score the position after each new move: from the AI's own search when the
+/// computer made it, otherwise by starting a shallow search.
+pub fn start_live_eval(
+    history: Res<MoveHistory>,
+    engine: Res<ChessEngine>,
+    ai_config: Res<ChessAIResource>,
+    ai_stats: Res<AIStatistics>,
+    game_mode: Res<CoreGameMode>,
+    mut eval: ResMut<EvalBarState>,
+    mut live: ResMut<LiveEval>,
+) {
+    if !history.is_changed() || live.plies == history.len() {
+        return;
+    }
+    // After a takeback the AI's last score belongs to a move that is gone.
+    let grew = history.len() > live.plies;
+    live.plies = history.len();
+    // Dropping the task cancels a search of a position that is gone.
+    live.task = None;
+
+    if *game_mode == CoreGameMode::MultiplayerCompetitive {
+        eval.score = 0;
+        return;
+    }
+
+    if let Some(last) = history.last_move() {
+        if grew
+            && *game_mode == CoreGameMode::SinglePlayer
+            && ai_config.mode.plays(last.piece_color)
+        {
+            // The AI scores from its own side.
+            let score = match last.piece_color {
+                PieceColor::White => ai_stats.last_score,
+                PieceColor::Black => -ai_stats.last_score,
+            };
+            eval.score = score.clamp(i16::MIN as i64, i16::MAX as i64) as i16;
+            return;
+        }
+    }
+
+    let fen = engine.current_fen().to_string();
+    live.task = Some(AsyncComputeTaskPool::get().spawn(async move { shallow_eval(&fen) }));
+}
+
+/// Move the bar once the running search finishes.
+pub fn poll_live_eval(mut live: ResMut<LiveEval>, mut eval: ResMut<EvalBarState>) {
+    let Some(task) = live.task.as_mut() else {
+        return;
+    };
+    let Some(score) = futures_lite::future::block_on(futures_lite::future::poll_once(task)) else {
+        return;
+    };
+    live.task = None;
+    if let Some(score) = score {
+        eval.score = score.clamp(i16::MIN as i64, i16::MAX as i64) as i16;
+    }
+}
+
+/// Start the next game's bar from an even score.
+pub fn reset_live_eval(mut live: ResMut<LiveEval>, mut eval: ResMut<EvalBarState>) {
+    *live = LiveEval::default();
+    eval.score = 0;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::eval_format::{format_eval, mate_in};
+
+    #[test]
+    fn test_shallow_eval_reads_from_whites_side() {
+        //! An extra White queen scores positive whoever is to move, and a mate in one reads as M1
+
+        let up_a_queen = shallow_eval("4k3/8/8/8/8/8/8/3QK3 w - - 0 1").unwrap();
+        assert!(up_a_queen > 500, "{up_a_queen}");
+        let black_to_move = shallow_eval("4k3/8/8/8/8/8/8/3QK3 b - - 0 1").unwrap();
+        assert!(black_to_move > 500, "{black_to_move}");
+
+        let back_rank = shallow_eval("6k1/5ppp/8/8/8/8/5PPP/R5K1 w - - 0 1").unwrap();
+        assert_eq!(mate_in(back_rank), Some(1));
+        assert_eq!(format_eval(back_rank), "M1");
+
+        // Already mated: nothing to search. Stalemate is level.
+        assert_eq!(shallow_eval("R5k1/5ppp/8/8/8/8/5PPP/6K1 b - - 1 1"), None);
+        assert_eq!(shallow_eval("7k/5Q2/6K1/8/8/8/8/8 b - - 0 1"), Some(0));
+    }
+}
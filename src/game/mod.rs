@@ -1,17 +1,27 @@
 pub mod ai;
+pub mod annotations;
+pub mod blunder_check;
+pub mod board_screenshot;
 pub mod board_coords;
 pub mod camera_modes;
 pub mod components;
 pub mod events;
+pub mod live_eval;
+pub mod pgn;
 pub mod plugin;
+pub mod premove;
 pub mod replay;
 pub mod replay_braid;
 pub mod replay_shorts;
 pub mod resources;
+pub mod save;
 pub mod shorts_state;
+pub mod study;
 pub mod sync;
 pub mod system_sets;
 pub mod systems;
+#[cfg(any(test, feature = "test-support"))]
+pub mod test_support;
 pub mod time_control;
 pub mod types;
 pub mod view_mode;
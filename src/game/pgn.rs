@@ -0,0 +1,275 @@
+//! PGN import and export
+//!
+//! Turns a PGN document into the [`MoveRecord`]s the rest of the game works
+//! with, by replaying its SAN from the initial position (or the one in its
+//! `FEN` tag): the board at each
+//! ply settles which piece an ambiguous move means, whether it captures
+//! (en passant included), castles or promotes, and whether it gives check or
+//! mate. Tag pairs, comments, variations and annotation glyphs are dropped by
+//! [`nimzovich_engine::parse_pgn`] before the moves are read.
+//!
+//! The PGN player in the main menu checks a game here before replaying it,
+//! so a game with an illegal move is refused with the ply that broke rather
+//! than stopping part way through the replay.
+//!
+//! [`pgn_to_string`] writes a game back out; the game's own PGN comes from
+//! [`crate::game::resources::MoveHistory::to_pgn`].
+
+use crate::game::components::{MoveRecord, PieceColor, PieceType};
+use nimzovich_engine::{
+    do_move_with_promo, game_from_fen_no_tt, move_to_san, new_game_no_tt, san_to_move, Game,
+    ParsedPgnGame, PgnParseError,
+};
+use std::path::Path;
+
+/// Errors raised while reading a PGN game.
+#[derive(Debug, thiserror::Error)]
+pub enum PgnError {
+    /// The file could not be read.
+    #[error("PGN file I/O failed: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// The text is not a PGN game.
+    #[error("{0}")]
+    Parse(PgnParseError),
+
+    /// A move can't be played in the position it was written for.
+    #[error("move {number} ({san}) is not legal here")]
+    IllegalMove { number: String, san: String },
+}
+
+/// A parsed game together with its moves as records.
+#[derive(Debug, Clone)]
+pub struct ImportedPgn {
+    pub game: ParsedPgnGame,
+    pub records: Vec<MoveRecord>,
+}
+
+/// Parse `text` and replay its moves. Only the first game of a text holding
+/// several is read.
+pub fn import_pgn(text: &str) -> Result<ImportedPgn, PgnError> {
+    let game = nimzovich_engine::parse_pgn(first_game(text)).map_err(PgnError::Parse)?;
+    let records = records_from_pgn(&game)?;
+    Ok(ImportedPgn { game, records })
+}
+
+/// Read and replay the first game in a `.pgn` file.
+pub fn import_pgn_file(path: &Path) -> Result<ImportedPgn, PgnError> {
+    import_pgn(&std::fs::read_to_string(path)?)
+}
+
+/// The position `pgn` starts from: its `FEN` tag, or the initial position.
+pub fn start_position(pgn: &ParsedPgnGame) -> Game {
+    // No search ever runs on this game, so skip the transposition table.
+    match pgn.tags.get("FEN") {
+        Some(fen) => game_from_fen_no_tt(fen),
+        None => new_game_no_tt(),
+    }
+}
+
+/// Move number of the first move of `pgn`, and whether Black plays it.
+fn first_move(pgn: &ParsedPgnGame) -> (usize, bool) {
+    let Some(fen) = pgn.tags.get("FEN") else {
+        return (1, false);
+    };
+    let fields: Vec<&str> = fen.split_whitespace().collect();
+    let number = fields.get(5).and_then(|n| n.parse().ok()).unwrap_or(1);
+    (number, fields.get(1) == Some(&"b"))
+}
+
+/// Replay the SAN moves of `pgn` from its starting position.
+pub fn records_from_pgn(pgn: &ParsedPgnGame) -> Result<Vec<MoveRecord>, PgnError> {
+    let mut game = start_position(pgn);
+    let mut records = Vec::with_capacity(pgn.moves.len());
+    let (first_number, black_first) = first_move(pgn);
+
+    for (ply, san) in pgn.moves.iter().enumerate() {
+        // Plies counted from White's move of the first move number.
+        let ply_from_white = ply + black_first as usize;
+        let illegal = || PgnError::IllegalMove {
+            number: format!(
+                "{}{}",
+                first_number + ply_from_white / 2,
+                if ply_from_white % 2 == 0 { "." } else { "..." }
+            ),
+            san: san.clone(),
+        };
+        let (src, dst, promo) = san_to_move(&mut game, san).map_err(|_| illegal())?;
+        let piece_color = if ply_from_white % 2 == 0 {
+            PieceColor::White
+        } else {
+            PieceColor::Black
+        };
+        let moving = game.board[src as usize];
+        // `san_to_move` leaves legality to the caller for unambiguous moves.
+        if moving == 0
+            || (moving > 0) != (piece_color == PieceColor::White)
+            || !nimzovich_engine::is_legal_move(&mut game, src, dst, moving.signum() as i64)
+        {
+            return Err(illegal());
+        }
+
+        let piece_type = piece_type_from_id(moving.abs());
+        let is_en_passant =
+            piece_type == PieceType::Pawn && src % 8 != dst % 8 && game.board[dst as usize] == 0;
+        let captured = match game.board[dst as usize] {
+            0 if is_en_passant => Some(PieceType::Pawn),
+            0 => None,
+            target => Some(piece_type_from_id(target.abs())),
+        };
+        let is_promotion = piece_type == PieceType::Pawn && (dst / 8 == 7 || dst / 8 == 0);
+        let promo = match promo {
+            0 if is_promotion => nimzovich_engine::QUEEN_ID,
+            promo => promo,
+        };
+        // The board's own SAN, so the check and mate marks don't depend on
+        // the PGN having them.
+        let san = move_to_san(&mut game, src, dst, promo);
+
+        records.push(MoveRecord {
+            piece_type,
+            piece_color,
+            from: ((src % 8) as u8, (src / 8) as u8),
+            to: ((dst % 8) as u8, (dst / 8) as u8),
+            captured,
+            promotion: is_promotion.then(|| piece_type_from_id(promo)),
+            is_castling: piece_type == PieceType::King && (src - dst).abs() == 2,
+            is_en_passant,
+            is_check: san.ends_with('+') || san.ends_with('#'),
+            is_checkmate: san.ends_with('#'),
+        });
+        do_move_with_promo(&mut game, src, dst, true, promo);
+    }
+    Ok(records)
+}
+
+/// Render a PGN string from a `ParsedPgnGame`. Move numbers follow the
+/// `FEN` tag when the game was set up from one.
+pub fn pgn_to_string(pgn: &ParsedPgnGame) -> String {
+    let mut out = String::new();
+    for (k, v) in &pgn.tags {
+        out.push_str(&format!("[{} \"{}\"]\n", k, v));
+    }
+    out.push('\n');
+
+    let (first_number, black_first) = first_move(pgn);
+    let mut line = String::new();
+    let mut ply = black_first as usize;
+    let mut flush = |line: &mut String, out: &mut String| {
+        if !line.is_empty() {
+            out.push_str(line.trim_end());
+            out.push('\n');
+            line.clear();
+        }
+    };
+    for mv in &pgn.moves {
+        let number = first_number + ply / 2;
+        let token = if ply % 2 == 0 {
+            format!("{}. {}", number, mv)
+        } else if black_first && ply == 1 {
+            format!("{}... {}", number, mv)
+        } else {
+            mv.clone()
+        };
+        if !line.is_empty() && line.len() + 1 + token.len() > 80 {
+            flush(&mut line, &mut out);
+        }
+        if !line.is_empty() {
+            line.push(' ');
+        }
+        line.push_str(&token);
+        ply += 1;
+    }
+    if !line.is_empty() {
+        if line.len() + 1 + pgn.result.len() > 80 {
+            out.push_str(line.trim_end());
+            out.push('\n');
+            out.push_str(&pgn.result);
+        } else {
+            out.push_str(line.trim_end());
+            out.push(' ');
+            out.push_str(&pgn.result);
+        }
+    } else {
+        out.push_str(&pgn.result);
+    }
+    out.push('\n');
+    out
+}
+
+/// The text of the first game in a file that may hold several, each
+/// starting with its own tag section.
+fn first_game(text: &str) -> &str {
+    let mut seen_moves = false;
+    let mut offset = 0;
+    for line in text.split_inclusive('\n') {
+        let trimmed = line.trim();
+        if trimmed.starts_with('[') && seen_moves {
+            return &text[..offset];
+        }
+        if !trimmed.is_empty() && !trimmed.starts_with('[') {
+            seen_moves = true;
+        }
+        offset += line.len();
+    }
+    text
+}
+
+/// Convert engine piece ID to PieceType.
+fn piece_type_from_id(id: i8) -> PieceType {
+    use nimzovich_engine::{BISHOP_ID, KING_ID, KNIGHT_ID, PAWN_ID, QUEEN_ID, ROOK_ID};
+    match id {
+        PAWN_ID => PieceType::Pawn,
+        KNIGHT_ID => PieceType::Knight,
+        BISHOP_ID => PieceType::Bishop,
+        ROOK_ID => PieceType::Rook,
+        QUEEN_ID => PieceType::Queen,
+        KING_ID => PieceType::King,
+        _ => PieceType::Pawn,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pgn_replays_into_move_records() {
+        //! An annotated game becomes records with the right pieces, captures, castling, promotions and mate
+
+        let text = "[Event \"Casual\"]\n[Result \"1-0\"]\n\n\
+            1. e4 d5 2. exd5 {the pawn goes} Nf6 3. Nf3 $1 Nxd5 4. Be2 Nb4?! 5. O-O N8c6 \
+            6. d4 Bf5 7. c4 e5 8. d5 e4 9. dxc6 exf3 10. cxb7 fxe2 11. Qxd8+ Kxd8 \
+            12. bxa8=Q+ Ke7 13. Nc3 exf1=N 14. Kxf1 1-0\n\n\
+            [Event \"Second game\"]\n\n1. d4 *\n";
+        let path = std::env::temp_dir().join(format!("xfchess_pgn_{}.pgn", std::process::id()));
+        std::fs::write(&path, text).unwrap();
+        let imported = import_pgn_file(&path).expect("import");
+        let _ = std::fs::remove_file(&path);
+
+        let records = imported.records;
+        assert_eq!(records.len(), 27);
+        assert_eq!(imported.game.result, "1-0");
+
+        let take = &records[2];
+        assert_eq!(take.captured, Some(PieceType::Pawn));
+        assert_eq!((take.from, take.to), ((4, 3), (3, 4)));
+        assert!(records[8].is_castling);
+        // N8c6: the b8 knight, not the one on b4.
+        assert_eq!(records[9].from, (1, 7));
+        assert!(records[20].is_check && !records[20].is_checkmate);
+        assert_eq!(records[22].promotion, Some(PieceType::Queen));
+        assert_eq!(records[22].captured, Some(PieceType::Rook));
+        assert_eq!(records[25].promotion, Some(PieceType::Knight));
+        assert_eq!(records[25].piece_color, PieceColor::Black);
+
+        let mate = import_pgn("1. f3 e5 2. g4 Qh4#").unwrap().records;
+        assert!(mate[3].is_checkmate);
+
+        assert!(matches!(
+            import_pgn("1. e4 e5 2. Ke3"),
+            Err(PgnError::IllegalMove { ref number, .. }) if number == "2."
+        ));
+        assert!(matches!(import_pgn(""), Err(PgnError::Parse(_))));
+    }
+}
@@ -30,13 +30,15 @@ use super::systems::*;
 use crate::core::{debug_current_gamestate, GameMode, GameState};
 use crate::engine::board_state::ChessEngine;
 use crate::game::components::{
-    FadingCapture, GamePhase, HasMoved, MoveRecord, PieceMoveAnimation, SelectedPiece,
+    CaptureFlight, FadingCapture, GamePhase, HasMoved, MoveRecord, PieceMoveAnimation,
+    PromotionAnimation, SelectedPiece,
 };
 
 use crate::rendering::pieces::{Piece, PieceColor, PieceType};
 use crate::ui::game_2d::Board2DTheme;
 use crate::ui::game_ui::{
-    reset_in_game_hud_visibility, toggle_in_game_hud, InGameHudVisibility, IncrementFlash,
+    close_in_game_help, reset_in_game_hud_visibility, toggle_in_game_hud, InGameHelp,
+    InGameHudVisibility, IncrementFlash,
 };
 use bevy::input::common_conditions::{input_just_pressed, input_toggle_active};
 use bevy::picking::mesh_picking::MeshPickingPlugin;
@@ -54,6 +56,8 @@ impl Plugin for GamePlugin {
         app.init_resource::<CurrentTurn>()
             .init_resource::<CurrentGamePhase>()
             .init_resource::<Selection>()
+            .init_resource::<HoverPreview>()
+            .init_resource::<DragTarget>()
             .init_resource::<MoveHistory>()
             .init_resource::<GameTimer>()
             .init_resource::<crate::game::resources::active_time_control::ActiveTimeControl>()
@@ -65,6 +69,7 @@ impl Plugin for GamePlugin {
             .init_resource::<ChessEngine>()
             .init_resource::<Players>()
             .init_resource::<super::systems::camera::CameraRotationState>()
+            .init_resource::<BoardOrientation>()
             .init_resource::<super::view_mode::ViewMode>()
             .init_resource::<PendingPromotion>()
             .init_resource::<GameSounds>()
@@ -73,7 +78,9 @@ impl Plugin for GamePlugin {
             .init_resource::<super::camera_modes::CinematicSequence>()
             .init_resource::<super::camera_modes::CinematicFadeOverlay>()
             .init_resource::<InGameHudVisibility>()
+            .init_resource::<InGameHelp>()
             .init_resource::<IncrementFlash>()
+            .init_resource::<crate::ui::game::game_ui::GameStartSummary>()
             .init_resource::<Board2DTheme>()
             .init_resource::<super::systems::input::InGameExitConfirmation>()
             .init_resource::<super::systems::network_move::PendingDrawOffer>()
@@ -101,6 +108,8 @@ impl Plugin for GamePlugin {
             .register_type::<HasMoved>()
             .register_type::<PieceMoveAnimation>()
             .register_type::<FadingCapture>()
+            .register_type::<CaptureFlight>()
+            .register_type::<PromotionAnimation>()
             .register_type::<SelectedPiece>()
             .register_type::<CameraController>()
             .register_type::<Player>()
@@ -127,6 +136,44 @@ impl Plugin for GamePlugin {
         // Add spectator sync plugin
         app.add_plugins(SpectateSyncPlugin);
 
+        // Flip Board button and key
+        app.add_message::<FlipBoardRequest>();
+        app.add_systems(
+            Update,
+            (flip_board_hotkey, apply_board_flip)
+                .chain()
+                .in_set(GameSystems::Input)
+                .before(camera_rotate_on_turn_system),
+        );
+
+        // PNG screenshots of the 3D board
+        app.add_systems(
+            Update,
+            super::board_screenshot::board_screenshot_system.run_if(in_state(GameState::InGame)),
+        );
+
+        // Save slots, auto-save and quick-save hotkeys
+        app.add_plugins(super::save::SavePlugin);
+        app.add_plugins(super::study::StudyPlugin);
+
+        // Takebacks and redo from their buttons, keys and the move list
+        // (offline games only)
+        app.add_message::<super::systems::takeback::TakebackRequest>()
+            .add_message::<super::systems::takeback::RedoRequest>()
+            .add_message::<super::systems::takeback::JumpToMove>();
+        app.add_systems(
+            Update,
+            (
+                super::systems::takeback::takeback_hotkey,
+                super::systems::takeback::apply_takeback,
+                super::systems::takeback::redo_hotkey,
+                super::systems::takeback::handle_redo_requests,
+                super::systems::takeback::apply_redo,
+            )
+                .chain()
+                .in_set(GameSystems::Input),
+        );
+
         // 30-second first-move grace period (online games only)
         super::systems::first_move_timer::register(app);
 
@@ -151,9 +198,12 @@ impl Plugin for GamePlugin {
             OnEnter(GameState::InGame),
             (
                 purge_stale_board_visuals,
+                super::board_coords::apply_file_mirroring,
                 reset_game_resources,
                 initialize_players,
+                reset_board_orientation,
                 reset_in_game_hud_visibility,
+                close_in_game_help,
                 reset_in_game_exit_confirmation,
                 setup_game_camera,
                 setup_game_scene,
@@ -168,7 +218,9 @@ impl Plugin for GamePlugin {
             OnEnter(GameState::InGame),
             (
                 purge_stale_board_visuals,
+                super::board_coords::apply_file_mirroring,
                 super::replay::setup_replay,
+                reset_board_orientation,
                 setup_game_camera,
                 setup_game_scene,
             )
@@ -261,7 +313,10 @@ impl Plugin for GamePlugin {
                 detect_pawn_promotion
                     .in_set(GameSystems::Execution)
                     .run_if(|view_mode: Res<super::view_mode::ViewMode>| !view_mode.is_templeos()),
-                apply_pawn_promotion
+                // A promotion picked in the dialog may give check the move
+                // itself didn't, so look again once it is applied.
+                (apply_pawn_promotion, recheck_after_promotion)
+                    .chain()
                     .in_set(GameSystems::Execution)
                     .run_if(|view_mode: Res<super::view_mode::ViewMode>| !view_mode.is_templeos()),
                 // Network Move Verification/Execution
@@ -280,13 +335,29 @@ impl Plugin for GamePlugin {
                 // Nested to stay under Bevy's tuple-arity limit for `.chain()`
                 // (the flat list above this point already has 19 systems) —
                 // this sub-tuple is itself chained, so overall ordering is
-                // unchanged from a flat 21-element chain.
+                // unchanged from a flat 22-element chain.
                 (
                     animate_piece_movement.in_set(GameSystems::Visual),
-                    // animate_capture_fade is skipped when nothing is mid-fade.
+                    tint_selected_piece.in_set(GameSystems::Visual).run_if(
+                        |view_mode: Res<super::view_mode::ViewMode>| !view_mode.is_templeos(),
+                    ),
+                    // The capture and promotion animations are skipped when
+                    // nothing is playing one.
                     animate_capture_fade
                         .in_set(GameSystems::Visual)
                         .run_if(any_with_component::<FadingCapture>),
+                    animate_capture_flight
+                        .in_set(GameSystems::Visual)
+                        .run_if(any_with_component::<CaptureFlight>),
+                    animate_promotion
+                        .in_set(GameSystems::Visual)
+                        .run_if(any_with_component::<PromotionAnimation>),
+                    (mark_king_in_check, pulse_check_highlight)
+                        .chain()
+                        .in_set(GameSystems::Visual)
+                        .run_if(|view_mode: Res<super::view_mode::ViewMode>| {
+                            !view_mode.is_templeos()
+                        }),
                 )
                     .chain(),
             ),
@@ -308,10 +379,9 @@ impl Plugin for GamePlugin {
                 .run_if(in_state(GameState::InGame))
                 .run_if(not(in_mode(GameMode::PgnReplay))),
         );
-        // 2D board arrow overlays, drag-to-move, premove, piece animation
+        // 2D board arrow overlays, drag-to-move, piece animation
         app.init_resource::<crate::ui::game::game_2d::BoardArrows>();
         app.init_resource::<crate::ui::game::game_2d::DragState2D>();
-        app.init_resource::<crate::ui::game::game_2d::PremoveState>();
         app.init_resource::<crate::ui::game::game_2d::PieceAnim2D>();
         app.add_systems(
             Update,
@@ -337,6 +407,20 @@ impl Plugin for GamePlugin {
             Update,
             crate::ui::game::game_2d::update_eval_bar.run_if(in_state(GameState::InGame)),
         );
+        app.init_resource::<super::live_eval::LiveEval>();
+        app.add_systems(
+            OnEnter(GameState::InGame),
+            super::live_eval::reset_live_eval,
+        );
+        app.add_systems(
+            Update,
+            (
+                super::live_eval::start_live_eval,
+                super::live_eval::poll_live_eval,
+            )
+                .chain()
+                .run_if(in_state(GameState::InGame)),
+        );
 
         // Sync Board2DTheme and eval bar visibility from GameSettings on settings change
         app.add_systems(
@@ -344,6 +428,8 @@ impl Plugin for GamePlugin {
             crate::ui::game::game_2d::sync_board_theme_from_settings,
         );
         app.add_systems(Update, crate::ui::game::game_2d::sync_eval_bar_visibility);
+        app.init_resource::<AnimationSettings>();
+        app.add_systems(Update, sync_animation_settings);
         app.add_systems(
             Update,
             crate::rendering::pieces::pieces::reload_piece_sprites,
@@ -382,12 +468,6 @@ impl Plugin for GamePlugin {
             crate::ui::game::game_ui::avatar_fetch_system.run_if(in_state(GameState::InGame)),
         );
 
-        // Check sound cue
-        app.add_systems(
-            Update,
-            crate::ui::game::game_ui::play_check_sound_system.run_if(in_state(GameState::InGame)),
-        );
-
         // Blindfold toggle — Ctrl+B
         app.add_systems(
             Update,
@@ -407,6 +487,117 @@ impl Plugin for GamePlugin {
             crate::ui::game::game_ui::increment_flash_system.run_if(in_state(GameState::InGame)),
         );
 
+        // Debug builds: no two pieces may share a square after a move
+        #[cfg(debug_assertions)]
+        app.init_resource::<super::systems::board_invariants::BoardInvariantCheck>()
+            .add_systems(
+                Update,
+                super::systems::board_invariants::check_piece_square_invariant
+                    .after(GameSystems::Execution)
+                    .before(GameSystems::Visual)
+                    .run_if(in_state(GameState::InGame)),
+            );
+
+        // Right-drag arrows and circles on the 3D board
+        app.init_resource::<super::annotations::Annotations>();
+        app.add_systems(
+            OnEnter(GameState::InGame),
+            super::annotations::reset_annotations,
+        );
+        app.add_systems(
+            Update,
+            (
+                super::annotations::end_annotation_drag,
+                // After a restored game's move list is put back, so the marks
+                // of an opened study aren't cleared for it a frame later.
+                super::annotations::clear_annotations_on_move.after(GameSystems::Validation),
+            )
+                .run_if(in_state(GameState::InGame)),
+        );
+
+        // Premoves queued while the computer thinks, shared by both boards
+        app.init_resource::<super::premove::Premove>();
+        app.add_systems(OnEnter(GameState::InGame), super::premove::reset_premove);
+        app.add_systems(
+            Update,
+            super::premove::play_premove
+                .in_set(GameSystems::Execution)
+                .after(update_game_phase)
+                .run_if(in_state(GameState::InGame)),
+        );
+
+        // Beginner blunder check — holds a flagged move until the player answers
+        app.init_resource::<super::blunder_check::BlunderCheck>();
+        app.add_systems(
+            OnEnter(GameState::InGame),
+            super::blunder_check::reset_blunder_check,
+        );
+        app.add_systems(
+            Update,
+            super::blunder_check::resolve_blunder_check
+                .in_set(GameSystems::Input)
+                .run_if(|check: Res<super::blunder_check::BlunderCheck>| check.is_busy()),
+        );
+        app.add_systems(
+            bevy_egui::EguiPrimaryContextPass,
+            crate::ui::game::blunder_ui::blunder_confirm_ui_system
+                .run_if(in_state(GameState::InGame)),
+        );
+
+        // Zoom range from settings; the input system clamps to it
+        app.add_systems(
+            Update,
+            super::systems::camera::sync_camera_zoom_limits
+                .in_set(GameSystems::Input)
+                .before(camera_zoom_input_system)
+                .run_if(resource_changed::<crate::core::GameSettings>),
+        );
+
+        // Sound themes: reload on change, Classic for anything a theme lacks
+        app.add_systems(
+            Update,
+            (
+                crate::game::resources::sounds::apply_sound_theme
+                    .run_if(resource_changed::<crate::core::GameSettings>),
+                crate::game::resources::sounds::fall_back_missing_sounds,
+            )
+                .chain(),
+        );
+
+        // F1: engine board next to the ECS board, for spotting desyncs
+        app.init_resource::<crate::ui::game::board_inspector::BoardInspector>();
+        app.add_systems(
+            Update,
+            crate::ui::game::board_inspector::toggle_board_inspector
+                .run_if(in_state(GameState::InGame)),
+        );
+        app.add_systems(
+            bevy_egui::EguiPrimaryContextPass,
+            crate::ui::game::board_inspector::board_inspector_ui
+                .run_if(in_state(GameState::InGame))
+                .run_if(
+                    |inspector: Res<crate::ui::game::board_inspector::BoardInspector>| {
+                        inspector.open
+                    },
+                ),
+        );
+
+        // "Game started" summary toast — filled on entry, fades over a few seconds
+        app.add_systems(
+            OnEnter(GameState::InGame),
+            crate::ui::game::game_ui::show_game_start_summary,
+        );
+        app.add_systems(
+            Update,
+            crate::ui::game::game_ui::game_start_summary_tick_system
+                .run_if(in_state(GameState::InGame)),
+        );
+        app.add_systems(
+            bevy_egui::EguiPrimaryContextPass,
+            crate::ui::game::game_ui::game_start_summary_overlay
+                .run_if(in_state(GameState::InGame)),
+        );
+
         // Chat now renders inline inside game_status_ui's left panel (see
         // crate::ui::game::left_panel) — no standalone system needed here.
 
@@ -0,0 +1,200 @@
+//! Premoves: a move queued while the computer is thinking
+//!
+//! When the computer is to move and the other side is human, clicking one of
+//! the human's pieces and then a square queues that move in [`Premove`]
+//! instead of selecting anything. Both boards share the queue: the 3D
+//! observers and the 2D board feed clicks to [`Premove::click`], and both
+//! show the queued squares in their premove colour.
+//!
+//! As soon as the turn comes back, [`play_premove`] plays the move through
+//! the same helpers a click uses, so the beginner checks and promotion
+//! dialog still apply. A premove that is not legal by then is dropped.
+
+use crate::core::states::GameMode;
+use crate::game::systems::input::{
+    can_move_color, clear_selection_state, try_move_sequence, try_select_piece, InputSystemParams,
+};
+use crate::game::systems::shared::{find_piece_on_square, CapturedTarget};
+use crate::rendering::pieces::PieceColor;
+use bevy::prelude::*;
+
+/// The queued move, filled in one click at a time.
+#[derive(Resource, Default, Debug, PartialEq, Eq)]
+pub struct Premove {
+    pub from: Option<(u8, u8)>,
+    pub to: Option<(u8, u8)>,
+}
+
+impl Premove {
+    pub fn is_set(&self) -> bool {
+        self.from.is_some() && self.to.is_some()
+    }
+
+    pub fn clear(&mut self) {
+        self.from = None;
+        self.to = None;
+    }
+
+    /// Feed in a clicked square. The first click must be on one of the
+    /// premoving side's pieces (`own_piece`), the second picks the target;
+    /// clicking the first square again, or a third click, cancels.
+    pub fn click(&mut self, square: (u8, u8), own_piece: bool) {
+        match (self.from, self.to) {
+            (None, _) => {
+                if own_piece {
+                    self.from = Some(square);
+                }
+            }
+            (Some(from), None) if from != square => self.to = Some(square),
+            _ => self.clear(),
+        }
+    }
+}
+
+/// The side that may queue a premove now: the human waiting for the
+/// computer's move. `None` while a human is to move, and for spectators.
+pub fn premove_color(params: &InputSystemParams) -> Option<PieceColor> {
+    if *params.game_mode == GameMode::Spectator
+        || params.players.current(params.current_turn.color).is_human
+    {
+        return None;
+    }
+    let waiting = match params.current_turn.color {
+        PieceColor::White => PieceColor::Black,
+        PieceColor::Black => PieceColor::White,
+    };
+    params.players.current(waiting).is_human.then_some(waiting)
+}
+
+/// Feed a click on `square` into the premove queue, if a premove can be
+/// made now. Returns whether the click was taken.
+pub fn click_premove(params: &mut InputSystemParams, square: (u8, u8)) -> bool {
+    let Some(color) = premove_color(params) else {
+        return false;
+    };
+    let own_piece = find_piece_on_square(&params.pieces.p1(), square)
+        .is_some_and(|(_, piece)| piece.color == color && piece.is_on_board());
+    params.premove.click(square, own_piece);
+    true
+}
+
+/// Play the queued move once the human is to move again, or drop it when it
+/// is no longer legal.
+pub fn play_premove(mut params: InputSystemParams) {
+    if !params.premove.is_set()
+        || params.game_over.is_game_over()
+        || params.pending_promotion.is_active()
+        || params.pending_turn.is_pending()
+        || !params.players.current(params.current_turn.color).is_human
+    {
+        return;
+    }
+    let (Some(from), Some(to)) = (params.premove.from, params.premove.to) else {
+        return;
+    };
+    params.premove.clear();
+
+    let Some((entity, piece)) = find_piece_on_square(&params.pieces.p1(), from) else {
+        return;
+    };
+    if !piece.is_on_board()
+        || piece.color != params.current_turn.color
+        || !can_move_color(&params, piece.color)
+    {
+        return;
+    }
+    try_select_piece(&mut params, entity, piece, true);
+    if !params.selection.possible_moves.contains(&to) {
+        info!(
+            "[PREMOVE] {:?} from {:?} to {:?} is no longer legal - dropped",
+            piece.piece_type, from, to
+        );
+        clear_selection_state(
+            &mut params.commands,
+            &mut params.selection,
+            &params.selected_pieces,
+        );
+        return;
+    }
+    let capture =
+        find_piece_on_square(&params.pieces.p1(), to).map(|(entity, target)| CapturedTarget {
+            entity,
+            piece_type: target.piece_type,
+            color: target.color,
+        });
+    try_move_sequence(&mut params, to, capture, "premove");
+}
+
+/// Forget a premove from the previous game.
+pub fn reset_premove(mut premove: ResMut<Premove>) {
+    premove.clear();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::resources::{MoveHistory, Players, Selection};
+    use crate::game::test_support::GameHarness;
+
+    fn set_black_human(game: &mut GameHarness, is_human: bool) {
+        game.app
+            .world_mut()
+            .resource_mut::<Players>()
+            .player_2
+            .is_human = is_human;
+    }
+
+    #[test]
+    fn test_premove_plays_when_the_turn_comes_back() {
+        //! A move queued during the computer's turn is played after its reply, and one made illegal by the reply is dropped
+
+        let mut game = GameHarness::builder().build();
+        assert!(game.play("e2e4"));
+        set_black_human(&mut game, false);
+
+        // Only the waiting side's pieces start a premove.
+        game.premove_click("e7");
+        assert_eq!(*game.resource::<Premove>(), Premove::default());
+        game.premove_click("g1");
+        game.premove_click("f3");
+        assert!(game.resource::<Premove>().is_set());
+
+        // The reply hands the turn back, and the premove goes at once.
+        set_black_human(&mut game, true);
+        assert!(game.play("e7e5"));
+        set_black_human(&mut game, false);
+        assert_eq!(game.resource::<MoveHistory>().len(), 3);
+        assert!(game.piece_at("f3").is_some());
+        assert_eq!(*game.resource::<Premove>(), Premove::default());
+
+        // exd5 needs a pawn on d5, and the reply doesn't put one there.
+        game.premove_click("e4");
+        game.premove_click("d5");
+        assert!(game.resource::<Premove>().is_set());
+        set_black_human(&mut game, true);
+        assert!(game.play("b8c6"));
+        assert_eq!(game.resource::<MoveHistory>().len(), 4);
+        assert!(!game.resource::<Premove>().is_set());
+        assert!(game.resource::<Selection>().selected_entity.is_none());
+        assert!(game.piece_at("e4").is_some());
+    }
+
+    #[test]
+    fn test_undo_forgets_the_premove() {
+        //! A premove queued during the computer's turn is dropped by Undo instead of playing in the position taken back
+
+        let mut game = GameHarness::builder().build();
+        assert!(game.play("e2e4"));
+        set_black_human(&mut game, false);
+        game.premove_click("g1");
+        game.premove_click("f3");
+        assert!(game.resource::<Premove>().is_set());
+
+        game.take_back();
+        game.update();
+        assert!(game.resource::<MoveHistory>().is_empty());
+        assert_eq!(*game.resource::<Premove>(), Premove::default());
+        assert!(game.piece_at("g1").is_some());
+        assert!(game.piece_at("e2").is_some());
+    }
+}
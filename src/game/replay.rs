@@ -11,8 +11,10 @@
 
 use crate::core::{DespawnOnExit, GameMode, GameState};
 use crate::engine::board_state::ChessEngine;
+use crate::game::board_coords::file_world_x;
 use crate::game::components::{HasMoved, PieceMoveAnimation};
 use crate::game::replay_shorts::{PuzzleOverlay, ReplayAnnotations, ScreenshotRequested};
+use crate::game::resources::AnimationSettings;
 use crate::game::shorts_state::{ContentTier, HookStyle, HookText, ShortsState};
 use crate::game::view_mode::ViewMode;
 use crate::multiplayer::traits::MessageWriter;
@@ -138,14 +140,13 @@ pub fn setup_replay(
 
     // Reset replay state
     *replay = PgnReplayState::default();
-    replay.engine = new_game_no_tt();
+    replay.engine = crate::game::pgn::start_position(&pgn.inner);
 
-    // Pre-generate all FEN snapshots by applying moves sequentially
-    let mut temp_engine = new_game_no_tt();
+    // Pre-generate all FEN snapshots by applying moves sequentially, from
+    // the FEN tag of a game set up from a position.
+    let mut temp_engine = crate::game::pgn::start_position(&pgn.inner);
     replay.fen_snapshots.clear();
-    replay
-        .fen_snapshots
-        .push("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1".to_string());
+    replay.fen_snapshots.push(engine_to_fen(&temp_engine));
 
     for (i, san) in pgn.inner.moves.iter().enumerate() {
         match san_to_move(&mut temp_engine, san) {
@@ -277,6 +278,7 @@ pub fn replay_spawn_pieces_system(
     mut pieces_spawned: ResMut<PiecesSpawned>,
     existing_pieces: Query<Entity, With<Piece>>,
     sprite_handles: Option<Res<PieceSpriteHandles>>,
+    animation: Res<AnimationSettings>,
 ) {
     if replay.board_ready {
         return;
@@ -360,12 +362,20 @@ pub fn replay_spawn_pieces_system(
             }
         }
         if let (Some(src), Some(dst)) = (src_sq, dst_sq) {
-            // World X is mirrored (7 - file), matching spawn_piece_at_replay /
+            // World X via file_world_x, matching spawn_piece_at_replay /
             // execute_move's PieceMoveAnimation targets — see pieces.rs:484.
-            let src_world = Vec3::new(7.0 - (src % 8) as f32, PIECE_ON_BOARD_Y, (src / 8) as f32);
-            let dst_world = Vec3::new(7.0 - (dst % 8) as f32, PIECE_ON_BOARD_Y, (dst / 8) as f32);
+            let src_world = Vec3::new(
+                file_world_x((src % 8) as u8),
+                PIECE_ON_BOARD_Y,
+                (src / 8) as f32,
+            );
+            let dst_world = Vec3::new(
+                file_world_x((dst % 8) as u8),
+                PIECE_ON_BOARD_Y,
+                (dst / 8) as f32,
+            );
             if let Some(&ent) = entity_at_sq.get(&dst) {
-                let duration = 0.3 / slow_factor.max(0.05);
+                let duration = animation.duration(0.3) / slow_factor.max(0.05);
                 commands
                     .entity(ent)
                     .insert(PieceMoveAnimation::new(src_world, dst_world, duration));
@@ -1325,7 +1335,7 @@ fn spawn_piece_at_replay(
     sprite_handles: &Option<Res<PieceSpriteHandles>>,
 ) -> Entity {
     let (file, rank) = position;
-    let world_pos = Vec3::new(7.0 - file as f32, PIECE_ON_BOARD_Y, rank as f32);
+    let world_pos = Vec3::new(file_world_x(file), PIECE_ON_BOARD_Y, rank as f32);
 
     let mesh = meshes.get(piece_type, color);
     let rotation = replay_piece_rotation(piece_type, color);
@@ -0,0 +1,104 @@
+//! Animation settings resource — how long piece animations take, kept in
+//! step with [`GameSettings`] so animation code doesn't read settings fields
+//! itself.
+
+use crate::core::GameSettings;
+use bevy::prelude::*;
+
+/// Longest multiplier the animation speed setting allows ("slow").
+pub const ANIMATION_SCALE_MAX: f32 = 3.0;
+
+/// Seconds a piece takes to move at normal speed.
+pub const MOVE_ANIMATION_SECS: f32 = 0.4;
+/// Seconds the rook takes to follow its king when castling, at normal speed.
+pub const CASTLING_ROOK_ANIMATION_SECS: f32 = 0.25;
+
+/// Lengths of the board's animations, from [`GameSettings::animation_scale`]
+/// and [`GameSettings::effect_animation_secs`].
+///
+/// Every animation length is a base length times `scale`, so a scale of 0
+/// plays moves, captures and promotions instantly.
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct AnimationSettings {
+    /// Multiplier on every animation's length, from 0 to [`ANIMATION_SCALE_MAX`].
+    pub scale: f32,
+    /// Length of capture and promotion animations at normal speed, in seconds.
+    pub effect_base_secs: f32,
+}
+
+impl Default for AnimationSettings {
+    fn default() -> Self {
+        Self::from_settings(&GameSettings::default())
+    }
+}
+
+impl AnimationSettings {
+    pub fn from_settings(settings: &GameSettings) -> Self {
+        Self {
+            scale: settings.animation_scale.clamp(0.0, ANIMATION_SCALE_MAX),
+            effect_base_secs: settings.effect_animation_secs,
+        }
+    }
+
+    /// True when animations are switched off.
+    pub fn is_instant(&self) -> bool {
+        self.scale <= 0.0
+    }
+
+    /// `base_secs` at the chosen speed.
+    pub fn duration(&self, base_secs: f32) -> f32 {
+        base_secs * self.scale
+    }
+
+    /// Length of a piece's move.
+    pub fn move_secs(&self) -> f32 {
+        self.duration(MOVE_ANIMATION_SECS)
+    }
+
+    /// Length of a capture or promotion animation.
+    pub fn effect_secs(&self) -> f32 {
+        self.duration(self.effect_base_secs)
+    }
+}
+
+/// Keep [`AnimationSettings`] in step with [`GameSettings`].
+pub fn sync_animation_settings(
+    settings: Res<GameSettings>,
+    mut animation: ResMut<AnimationSettings>,
+) {
+    if !settings.is_changed() {
+        return;
+    }
+    let synced = AnimationSettings::from_settings(&settings);
+    if *animation != synced {
+        *animation = synced;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_instant_speed_zeroes_every_animation() {
+        //! The speed setting scales moves and effects alike, and 0 makes them instant
+
+        let settings = GameSettings {
+            animation_scale: 2.0,
+            effect_animation_secs: 0.5,
+            ..Default::default()
+        };
+        let slow = AnimationSettings::from_settings(&settings);
+        assert_eq!(slow.move_secs(), MOVE_ANIMATION_SECS * 2.0);
+        assert_eq!(slow.effect_secs(), 1.0);
+        assert!(!slow.is_instant());
+
+        let instant = AnimationSettings::from_settings(&GameSettings {
+            animation_scale: 0.0,
+            ..settings
+        });
+        assert!(instant.is_instant());
+        assert_eq!(instant.move_secs(), 0.0);
+        assert_eq!(instant.effect_secs(), 0.0);
+    }
+}
@@ -123,6 +123,16 @@ pub enum GameOverState {
     /// Automatically declared as a draw when detected.
     InsufficientMaterial,
 
+    /// Draw by the fifty-move rule
+    ///
+    /// Fifty moves by each side without a capture or a pawn move.
+    FiftyMoveRule,
+
+    /// Draw by threefold repetition
+    ///
+    /// The same position arose for the third time with the same side to move.
+    ThreefoldRepetition,
+
     /// White won on time
     ///
     /// Black's time expired before completing their move. Only possible in
@@ -213,6 +223,8 @@ impl GameOverState {
             GameOverState::BlackWon => "Black wins by checkmate!",
             GameOverState::Stalemate => "Draw by stalemate",
             GameOverState::InsufficientMaterial => "Draw by insufficient material",
+            GameOverState::FiftyMoveRule => "Draw by the fifty-move rule",
+            GameOverState::ThreefoldRepetition => "Draw by threefold repetition",
             GameOverState::WhiteWonByTime => "White wins on time!",
             GameOverState::BlackWonByTime => "Black wins on time!",
             GameOverState::WhiteWonByResignation => "White wins by resignation!",
@@ -282,7 +294,10 @@ impl GameOverState {
     pub fn is_draw(&self) -> bool {
         matches!(
             self,
-            GameOverState::Stalemate | GameOverState::InsufficientMaterial
+            GameOverState::Stalemate
+                | GameOverState::InsufficientMaterial
+                | GameOverState::FiftyMoveRule
+                | GameOverState::ThreefoldRepetition
         )
     }
 
@@ -296,6 +311,8 @@ impl GameOverState {
             GameOverState::WhiteWonByTime | GameOverState::BlackWonByTime => "on timeout",
             GameOverState::Stalemate => "by stalemate",
             GameOverState::InsufficientMaterial => "insufficient material",
+            GameOverState::FiftyMoveRule => "fifty-move rule",
+            GameOverState::ThreefoldRepetition => "threefold repetition",
             GameOverState::Aborted => "White didn't move in time",
             GameOverState::WhiteWonByAbandonment | GameOverState::BlackWonByAbandonment => {
                 "opponent disconnected"
@@ -530,6 +547,27 @@ mod tests {
         assert_eq!(state.message(), "Game aborted");
     }
 
+    #[test]
+    fn test_engine_draw_rules_are_draws() {
+        //! Fifty-move and repetition endings are draws with their own messages
+        for state in [
+            GameOverState::FiftyMoveRule,
+            GameOverState::ThreefoldRepetition,
+        ] {
+            assert!(state.is_game_over());
+            assert!(state.is_draw());
+            assert_eq!(state.winner(), None);
+        }
+        assert_eq!(
+            GameOverState::FiftyMoveRule.message(),
+            "Draw by the fifty-move rule"
+        );
+        assert_eq!(
+            GameOverState::ThreefoldRepetition.message(),
+            "Draw by threefold repetition"
+        );
+    }
+
     #[test]
     fn test_game_over_state_clone() {
         //! Tests that GameOverState can be cloned
@@ -555,6 +593,8 @@ mod tests {
             GameOverState::BlackWon,
             GameOverState::Stalemate,
             GameOverState::InsufficientMaterial,
+            GameOverState::FiftyMoveRule,
+            GameOverState::ThreefoldRepetition,
             GameOverState::WhiteWonByTime,
             GameOverState::BlackWonByTime,
         ];
@@ -26,7 +26,9 @@
 //! - `reference/chess_engine/src/types.rs` - Move representation patterns
 //! - PGN specification: https://en.wikipedia.org/wiki/Portable_Game_Notation
 
+use super::Takeback;
 use crate::game::components::MoveRecord;
+use crate::rendering::pieces::{PieceColor, PieceType};
 use bevy::prelude::*;
 
 /// Resource storing the complete move history for the current game
@@ -54,6 +56,24 @@ pub struct MoveHistory {
     /// by plain [`Self::add_move`] calls (e.g. in tests), in which case the
     /// UI falls back to its own simplified notation for that entry.
     pub sans: Vec<String>,
+
+    /// State before each move played on this board, for taking moves back.
+    /// Moves that came from elsewhere (a loaded save, a replayed PGN) have
+    /// none, so the game can only be taken back to where they end.
+    #[reflect(ignore)]
+    takebacks: Vec<Takeback>,
+
+    /// Moves taken back, the most recent last, for replaying with redo. A
+    /// new move that isn't the next of them clears the lot.
+    redo: Vec<MoveRecord>,
+
+    /// SAN of each redo move, parallel to `redo`, for the move list.
+    redo_sans: Vec<Option<String>>,
+
+    /// FEN the game started from, when it didn't start from the initial
+    /// position (a pasted FEN, or a save of such a game). Notation and PGN
+    /// replay the moves from here.
+    pub start_fen: Option<String>,
 }
 
 impl MoveHistory {
@@ -68,6 +88,7 @@ impl MoveHistory {
     ///
     /// For usage examples, see `tests/resources/history_tests.rs`
     pub fn add_move(&mut self, record: MoveRecord) {
+        self.follow_redo(&record);
         self.moves.push(record);
     }
 
@@ -76,10 +97,39 @@ impl MoveHistory {
     /// call site so the move list can render correct algebraic notation
     /// (with disambiguation and promotion) without re-deriving it in the UI.
     pub fn add_move_with_san(&mut self, record: MoveRecord, san: String) {
+        self.follow_redo(&record);
         self.moves.push(record);
         self.sans.push(san);
     }
 
+    /// Drop the next redo move if `record` replays it, or every redo move if
+    /// `record` goes another way.
+    fn follow_redo(&mut self, record: &MoveRecord) {
+        let replays = self.redo.last().is_some_and(|next| {
+            next.piece_color == record.piece_color
+                && next.from == record.from
+                && next.to == record.to
+        });
+        if replays {
+            self.redo.pop();
+            self.redo_sans.pop();
+        } else {
+            self.redo.clear();
+            self.redo_sans.clear();
+        }
+    }
+
+    /// Moves that redo would replay, the next one last.
+    pub fn redo_moves(&self) -> &[MoveRecord] {
+        &self.redo
+    }
+
+    /// SAN notation for the redo move at `index` of [`Self::redo_moves`], if
+    /// it was played with one.
+    pub fn redo_san_at(&self, index: usize) -> Option<&str> {
+        self.redo_sans.get(index)?.as_deref()
+    }
+
     /// SAN notation for the move at `index`, if it was recorded with one.
     pub fn san_at(&self, index: usize) -> Option<&str> {
         self.sans.get(index).map(String::as_str)
@@ -100,6 +150,12 @@ impl MoveHistory {
         self.moves.last()
     }
 
+    /// Mutable access to the most recent move, for details only known once
+    /// the move is on the board (such as whether it gives check).
+    pub fn last_move_mut(&mut self) -> Option<&mut MoveRecord> {
+        self.moves.last_mut()
+    }
+
     /// Get the total number of half-moves (ply) made
     ///
     /// In chess, a "ply" or "half-move" is one player's move. Two ply = one full move.
@@ -136,6 +192,51 @@ impl MoveHistory {
     pub fn clear(&mut self) {
         self.moves.clear();
         self.sans.clear();
+        self.takebacks.clear();
+        self.redo.clear();
+        self.redo_sans.clear();
+        self.start_fen = None;
+    }
+
+    /// Keep what the move about to be added changes, for [`Self::pop_move`].
+    /// `takeback.ply` should be the current [`Self::len`].
+    pub fn record_takeback(&mut self, takeback: Takeback) {
+        // One left by a move that was refused after all would shadow it.
+        while self.takebacks.last().is_some_and(|t| t.ply >= takeback.ply) {
+            self.takebacks.pop();
+        }
+        self.takebacks.push(takeback);
+    }
+
+    /// How many of the last moves can be taken back, one after another.
+    pub fn takeback_depth(&self) -> usize {
+        self.takebacks
+            .iter()
+            .rev()
+            .zip((0..self.moves.len()).rev())
+            .take_while(|(takeback, ply)| takeback.ply == *ply)
+            .count()
+    }
+
+    /// Whether the last move can be taken back.
+    pub fn can_take_back(&self) -> bool {
+        self.takeback_depth() > 0
+    }
+
+    /// Remove the last move, returning it with what it changed, and keep it
+    /// for redo. `None`, and nothing removed, when [`Self::can_take_back`] is
+    /// false.
+    pub fn pop_move(&mut self) -> Option<(MoveRecord, Takeback)> {
+        if !self.can_take_back() {
+            return None;
+        }
+        let takeback = self.takebacks.pop()?;
+        let record = self.moves.pop()?;
+        self.redo_sans
+            .push(self.sans.get(self.moves.len()).cloned());
+        self.sans.truncate(self.moves.len());
+        self.redo.push(record);
+        Some((record, takeback))
     }
 
     /// Get a specific move by index (ply number)
@@ -169,12 +270,134 @@ impl MoveHistory {
     pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, MoveRecord> {
         self.moves.iter_mut()
     }
+
+    /// Record the piece chosen for a promotion made after the move itself,
+    /// in the promotion dialog.
+    ///
+    /// Applies only when the last move is a pawn arriving on `square` with no
+    /// promotion yet; its SAN gets the "=Q" suffix (replacing any guessed one).
+    pub fn record_promotion(&mut self, square: (u8, u8), piece: PieceType) {
+        let index = self.moves.len().saturating_sub(1);
+        let Some(last) = self.moves.last_mut() else {
+            return;
+        };
+        if last.piece_type != PieceType::Pawn || last.to != square || last.promotion.is_some() {
+            return;
+        }
+        last.promotion = Some(piece);
+
+        if let Some(san) = self.sans.get_mut(index) {
+            let checks = san.trim_end_matches(['+', '#']).len();
+            let (mv, suffix) = san.split_at(checks);
+            let mv = mv.split('=').next().unwrap_or(mv);
+            *san = format!("{}={}{}", mv, piece.san_char(), suffix);
+        }
+    }
+
+    /// Set whether the last move gives check or mate, and the "+" or "#" on
+    /// its SAN to match. Used when a promotion picked after the move changes
+    /// what the move gives.
+    pub fn mark_check(&mut self, check: bool, mate: bool) {
+        let index = self.moves.len().saturating_sub(1);
+        let Some(last) = self.moves.last_mut() else {
+            return;
+        };
+        last.is_check = check;
+        last.is_checkmate = mate;
+
+        if let Some(san) = self.sans.get_mut(index) {
+            let suffix = match (check, mate) {
+                (_, true) => "#",
+                (true, false) => "+",
+                _ => "",
+            };
+            *san = format!("{}{}", san.trim_end_matches(['+', '#']), suffix);
+        }
+    }
+
+    /// The engine position the game started from.
+    fn start_position(&self) -> nimzovich_engine::Game {
+        use nimzovich_engine::{game_from_fen_no_tt, new_game_no_tt};
+
+        // No search ever runs on this game, so skip the transposition table
+        // `new_game` would allocate.
+        match &self.start_fen {
+            Some(fen) => game_from_fen_no_tt(fen),
+            None => new_game_no_tt(),
+        }
+    }
+
+    /// Play every move on a copy of the starting position, calling `each`
+    /// with the board before the move, and return the final board.
+    ///
+    /// A pawn on the last rank with no promotion recorded yet (the dialog is
+    /// still open) counts as a queen.
+    fn replay(
+        &self,
+        mut each: impl FnMut(&mut nimzovich_engine::Game, i8, i8, i8),
+    ) -> nimzovich_engine::Game {
+        let mut game = self.start_position();
+        for rec in &self.moves {
+            let src = rec.from.1 as i8 * 8 + rec.from.0 as i8;
+            let dst = rec.to.1 as i8 * 8 + rec.to.0 as i8;
+            let is_promo = rec.piece_type == PieceType::Pawn && (rec.to.1 == 7 || rec.to.1 == 0);
+            let promo: i8 = match rec.promotion {
+                Some(piece) => crate::engine::board_state::ChessEngine::piece_type_to_id(piece),
+                None if is_promo => 5,
+                None => 0,
+            };
+            each(&mut game, src, dst, promo);
+            nimzovich_engine::do_move_with_promo(&mut game, src, dst, true, promo);
+        }
+        game
+    }
+
+    /// SAN for every move, worked out by replaying the game from its starting
+    /// position so that disambiguation, checks and mates come from the board
+    /// at each ply rather than from the stored flags.
+    pub fn replay_sans(&self) -> Vec<String> {
+        let mut sans = Vec::with_capacity(self.moves.len());
+        self.replay(|game, src, dst, promo| {
+            sans.push(nimzovich_engine::move_to_san(game, src, dst, promo));
+        });
+        sans
+    }
+
+    /// FEN of the position after the last move.
+    pub fn final_fen(&self) -> String {
+        nimzovich_engine::game_to_fen(&self.replay(|_, _, _, _| {}))
+    }
+
+    /// The game as PGN with `result`, moves from [`Self::replay_sans`]. A
+    /// game set up from a FEN carries it in `SetUp` and `FEN` tags.
+    pub fn to_pgn(&self, result: &str) -> nimzovich_engine::ParsedPgnGame {
+        use std::collections::BTreeMap;
+
+        let mut tags = BTreeMap::new();
+        tags.insert("Event".to_string(), "XFChess Game".to_string());
+        tags.insert("Site".to_string(), "xfchess.app".to_string());
+        tags.insert(
+            "Date".to_string(),
+            chrono::Utc::now().format("%Y.%m.%d").to_string(),
+        );
+        tags.insert("Result".to_string(), result.to_string());
+        if let Some(fen) = &self.start_fen {
+            tags.insert("SetUp".to_string(), "1".to_string());
+            tags.insert("FEN".to_string(), fen.clone());
+        }
+
+        nimzovich_engine::ParsedPgnGame {
+            tags,
+            moves: self.replay_sans(),
+            result: result.to_string(),
+            per_ply_annotations: Vec::new(),
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::rendering::pieces::{PieceColor, PieceType};
 
     #[test]
     fn test_move_history_default() {
@@ -197,6 +420,7 @@ mod tests {
             from: (4, 1),
             to: (4, 3),
             captured: None,
+            promotion: None,
             is_castling: false,
             is_en_passant: false,
             is_check: false,
@@ -221,6 +445,7 @@ mod tests {
             from: (4, 1),
             to: (4, 3),
             captured: None,
+            promotion: None,
             is_castling: false,
             is_en_passant: false,
             is_check: false,
@@ -233,6 +458,7 @@ mod tests {
             from: (1, 7),
             to: (2, 5),
             captured: None,
+            promotion: None,
             is_castling: false,
             is_en_passant: false,
             is_check: false,
@@ -265,6 +491,7 @@ mod tests {
                 from: (i as u8 % 8, 1),
                 to: (i as u8 % 8, 3),
                 captured: None,
+                promotion: None,
                 is_castling: false,
                 is_en_passant: false,
                 is_check: false,
@@ -288,6 +515,7 @@ mod tests {
                 from: (0, 1),
                 to: (0, 3),
                 captured: None,
+                promotion: None,
                 is_castling: false,
                 is_en_passant: false,
                 is_check: false,
@@ -315,6 +543,7 @@ mod tests {
             from: (4, 1),
             to: (4, 3),
             captured: None,
+            promotion: None,
             is_castling: false,
             is_en_passant: false,
             is_check: false,
@@ -327,6 +556,7 @@ mod tests {
             from: (1, 7),
             to: (2, 5),
             captured: None,
+            promotion: None,
             is_castling: false,
             is_en_passant: false,
             is_check: false,
@@ -367,6 +597,7 @@ mod tests {
                 from: (i, 1),
                 to: (i, 3),
                 captured: None,
+                promotion: None,
                 is_castling: false,
                 is_en_passant: false,
                 is_check: false,
@@ -394,6 +625,7 @@ mod tests {
             from: (3, 4),
             to: (7, 4),
             captured: Some(PieceType::Rook),
+            promotion: None,
             is_castling: false,
             is_en_passant: false,
             is_check: true,
@@ -419,6 +651,7 @@ mod tests {
             from: (0, 4),
             to: (0, 6),
             captured: None,
+            promotion: None,
             is_castling: true,
             is_en_passant: false,
             is_check: false,
@@ -432,6 +665,7 @@ mod tests {
             from: (4, 3),
             to: (3, 2),
             captured: Some(PieceType::Pawn),
+            promotion: None,
             is_castling: false,
             is_en_passant: true,
             is_check: false,
@@ -445,6 +679,7 @@ mod tests {
             from: (3, 4),
             to: (5, 6),
             captured: Some(PieceType::Pawn),
+            promotion: None,
             is_castling: false,
             is_en_passant: false,
             is_check: true,
@@ -469,6 +704,7 @@ mod tests {
             from: (1, 4),
             to: (3, 4),
             captured: None,
+            promotion: None,
             is_castling: false,
             is_en_passant: false,
             is_check: false,
@@ -482,6 +718,7 @@ mod tests {
             from: (6, 4),
             to: (4, 4),
             captured: None,
+            promotion: None,
             is_castling: false,
             is_en_passant: false,
             is_check: false,
@@ -495,6 +732,7 @@ mod tests {
             from: (0, 6),
             to: (2, 5),
             captured: None,
+            promotion: None,
             is_castling: false,
             is_en_passant: false,
             is_check: false,
@@ -509,4 +747,135 @@ mod tests {
         assert_eq!(first_move.from, (1, 4));
         assert_eq!(first_move.to, (3, 4));
     }
+
+    #[test]
+    fn test_record_promotion_marks_the_pawn_move_and_its_san() {
+        //! A promotion picked in the dialog lands on the last move's record and SAN, once
+
+        let mut history = MoveHistory::default();
+        history.add_move_with_san(
+            MoveRecord {
+                piece_type: PieceType::Pawn,
+                piece_color: PieceColor::White,
+                from: (4, 6),
+                to: (4, 7),
+                captured: None,
+                promotion: None,
+                is_castling: false,
+                is_en_passant: false,
+                is_check: true,
+                is_checkmate: false,
+            },
+            "e8=Q+".to_string(),
+        );
+
+        history.record_promotion((3, 7), PieceType::Rook);
+        assert_eq!(history.last_move().unwrap().promotion, None);
+
+        history.record_promotion((4, 7), PieceType::Knight);
+        history.record_promotion((4, 7), PieceType::Queen);
+        assert_eq!(
+            history.last_move().unwrap().promotion,
+            Some(PieceType::Knight)
+        );
+        assert_eq!(history.san_at(0), Some("e8=N+"));
+    }
+
+    /// A history of `plies`, each a piece and its move in UCI notation.
+    fn history_of(plies: &[(PieceType, &str)]) -> MoveHistory {
+        let square = |s: &str| {
+            let b = s.as_bytes();
+            (b[0] - b'a', b[1] - b'1')
+        };
+        let mut history = MoveHistory::default();
+        for (i, (piece_type, uci)) in plies.iter().enumerate() {
+            history.add_move(MoveRecord {
+                piece_type: *piece_type,
+                piece_color: if i % 2 == 0 {
+                    PieceColor::White
+                } else {
+                    PieceColor::Black
+                },
+                from: square(&uci[0..2]),
+                to: square(&uci[2..4]),
+                captured: None,
+                promotion: None,
+                is_castling: false,
+                is_en_passant: false,
+                is_check: false,
+                is_checkmate: false,
+            });
+        }
+        history
+    }
+
+    #[test]
+    fn test_to_pgn_replays_the_board_for_notation() {
+        //! Captures, checks, disambiguation and castling come from replaying the moves, not from the stored flags
+
+        use PieceType::*;
+        let history = history_of(&[
+            (Pawn, "e2e4"),
+            (Pawn, "e7e5"),
+            (Knight, "g1f3"),
+            (Knight, "b8c6"),
+            (Bishop, "f1c4"),
+            (Knight, "g8f6"),
+            (Knight, "f3g5"),
+            (Pawn, "d7d5"),
+            (Pawn, "e4d5"),
+            (Knight, "f6d5"),
+            (Knight, "g5f7"),
+            (King, "e8f7"),
+            (Queen, "d1f3"),
+            (King, "f7e6"),
+            (Knight, "b1c3"),
+            (Knight, "c6b4"),
+            (King, "e1g1"),
+            (Pawn, "c7c6"),
+        ]);
+        let pgn = crate::game::pgn::pgn_to_string(&history.to_pgn("*"));
+        assert!(pgn.contains("[Result \"*\"]"));
+        assert!(!pgn.contains("[FEN"));
+        assert!(pgn.contains(
+            "1. e4 e5 2. Nf3 Nc6 3. Bc4 Nf6 4. Ng5 d5 5. exd5 Nxd5 6. Nxf7 Kxf7 7. Qf3+ Ke6"
+        ));
+        assert!(pgn.trim_end().ends_with("8. Nc3 Ncb4 9. O-O c6 *"));
+
+        let fools_mate = history_of(&[
+            (Pawn, "f2f3"),
+            (Pawn, "e7e5"),
+            (Pawn, "g2g4"),
+            (Queen, "d8h4"),
+        ]);
+        assert_eq!(fools_mate.replay_sans().last().unwrap(), "Qh4#");
+    }
+
+    #[test]
+    fn test_game_from_a_fen_replays_and_exports_from_it() {
+        //! A game set up from a FEN gets its SAN and final position from that FEN, and its PGN carries it
+
+        use PieceType::*;
+        let start = "4k3/8/8/8/8/8/4P3/4K3 b - - 0 12";
+        let mut history = history_of(&[(King, "e8d7"), (Pawn, "e2e4")]);
+        history.moves[0].piece_color = PieceColor::Black;
+        history.moves[1].piece_color = PieceColor::White;
+        history.start_fen = Some(start.to_string());
+
+        assert_eq!(history.replay_sans(), ["Kd7", "e4"]);
+        assert!(history.final_fen().starts_with("8/3k4/8/8/4P3/8/8/4K3 b "));
+
+        let pgn = history.to_pgn("*");
+        assert_eq!(pgn.tags.get("SetUp").map(String::as_str), Some("1"));
+        assert_eq!(pgn.tags.get("FEN").map(String::as_str), Some(start));
+        let text = crate::game::pgn::pgn_to_string(&pgn);
+        assert!(text.trim_end().ends_with("12... Kd7 13. e4 *"));
+
+        let records = crate::game::pgn::records_from_pgn(&pgn).expect("replays");
+        assert_eq!(records[0].piece_color, PieceColor::Black);
+        assert_eq!(records[1].to, (4, 3));
+
+        history.clear();
+        assert_eq!(history.start_fen, None);
+    }
 }
@@ -5,8 +5,10 @@
 pub mod captured;
 pub mod game_over;
 pub mod history;
+pub mod takeback;
 
 // Re-export all public items
 pub use captured::*;
 pub use game_over::*;
 pub use history::*;
+pub use takeback::*;
@@ -0,0 +1,39 @@
+//! What a move changed, kept so it can be taken back
+//!
+//! [`execute_move`] records a [`Takeback`] in [`MoveHistory`] before it
+//! touches anything: the engine's position, and the pieces the move is
+//! about to change (the one moving, the castling rook, the one captured) as
+//! they stood. Taking the move back puts each of those back exactly.
+//!
+//! [`execute_move`]: crate::game::systems::shared::execute_move
+//! [`MoveHistory`]: super::MoveHistory
+
+use crate::engine::board_state::EngineSnapshot;
+use crate::game::components::HasMoved;
+use crate::game::resources::CurrentTurn;
+use crate::rendering::pieces::Piece;
+use bevy::prelude::*;
+
+/// A piece entity's state before a move.
+#[derive(Debug, Clone, Copy)]
+pub struct PieceSnapshot {
+    pub entity: Entity,
+    pub piece: Piece,
+    pub has_moved: HasMoved,
+}
+
+/// Everything needed to undo one ply.
+#[derive(Debug, Clone)]
+pub struct Takeback {
+    /// Index of the move in [`super::MoveHistory::moves`].
+    pub ply: usize,
+    /// Side to move and move number before the move.
+    pub turn: CurrentTurn,
+    pub engine: EngineSnapshot,
+    /// The piece that moved.
+    pub moved: PieceSnapshot,
+    /// The rook, when the move castles.
+    pub rook: Option<PieceSnapshot>,
+    /// The piece taken, en passant included.
+    pub captured: Option<PieceSnapshot>,
+}
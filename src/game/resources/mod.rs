@@ -53,6 +53,7 @@
 
 // Submodules
 pub mod active_time_control;
+pub mod animation;
 pub mod history;
 pub mod menu_sounds;
 pub mod player;
@@ -69,6 +70,7 @@ mod tests;
 
 // Re-export all resources for convenience
 pub use active_time_control::*;
+pub use animation::*;
 pub use debug::*;
 pub use first_move_deadline::*;
 pub use history::*;
@@ -22,6 +22,8 @@ pub struct PendingPromotion {
     pub color: Option<PieceColor>,
     /// Whether a promotion is currently pending
     pub is_pending: bool,
+    /// Pieces the dialog greys out (realistic promotion); empty allows all
+    pub unavailable: Vec<PieceType>,
 }
 
 impl PendingPromotion {
@@ -39,6 +41,12 @@ impl PendingPromotion {
         self.position = None;
         self.color = None;
         self.is_pending = false;
+        self.unavailable.clear();
+    }
+
+    /// Whether the pawn may become `piece_type`
+    pub fn allows(&self, piece_type: PieceType) -> bool {
+        !self.unavailable.contains(&piece_type)
     }
 
     /// Check if a promotion is pending
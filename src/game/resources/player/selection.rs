@@ -56,6 +56,55 @@ impl Selection {
     }
 }
 
+/// Legal destinations of the piece under the cursor, shown without selecting it
+///
+/// Filled by the piece hover observer when
+/// [`GameSettings::preview_moves_on_hover`](crate::core::GameSettings) is on
+/// and cleared when the pointer leaves that piece. Clicking still goes through
+/// [`Selection`]; a selection always takes precedence over the preview.
+#[derive(Resource, Debug, Default, Reflect)]
+#[reflect(Resource)]
+pub struct HoverPreview {
+    pub entity: Option<Entity>,
+    pub square: Option<(u8, u8)>,
+    pub moves: Vec<(u8, u8)>,
+}
+
+impl HoverPreview {
+    pub fn show(&mut self, entity: Entity, square: (u8, u8), moves: Vec<(u8, u8)>) {
+        self.entity = Some(entity);
+        self.square = Some(square);
+        self.moves = moves;
+    }
+
+    /// Clear the preview if it belongs to `entity`, so an `Out` from one piece
+    /// arriving after the `Over` of the next doesn't wipe the new preview.
+    pub fn clear_for(&mut self, entity: Entity) {
+        if self.entity == Some(entity) {
+            self.clear();
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.entity = None;
+        self.square = None;
+        self.moves.clear();
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.entity.is_some()
+    }
+}
+
+/// Square under the piece being dragged, highlighted as the drop target
+///
+/// `None` when nothing is being dragged or the piece is off the board.
+#[derive(Resource, Debug, Default, Reflect)]
+#[reflect(Resource)]
+pub struct DragTarget {
+    pub square: Option<(u8, u8)>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -201,4 +250,22 @@ mod tests {
         assert!(!selection.is_dragging);
         assert!(selection.drag_start.is_none());
     }
+
+    #[test]
+    fn test_hover_preview_clears_only_for_its_piece() {
+        //! A stale unhover from another piece leaves the current preview intact
+        let first = Entity::from_bits(10);
+        let second = Entity::from_bits(42);
+        let mut preview = HoverPreview::default();
+
+        preview.show(second, (4, 1), vec![(4, 2), (4, 3)]);
+        preview.clear_for(first);
+        assert!(preview.is_active());
+        assert_eq!(preview.moves, vec![(4, 2), (4, 3)]);
+
+        preview.clear_for(second);
+        assert!(!preview.is_active());
+        assert!(preview.square.is_none());
+        assert!(preview.moves.is_empty());
+    }
 }
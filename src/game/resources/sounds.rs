@@ -1,10 +1,49 @@
 //! Game sounds resource for chess move and capture audio feedback
 //!
 //! Loads and stores handles to game sound effects that are played during gameplay.
+//!
+//! Each [`SoundTheme`] is a folder under `assets/game_sounds/` holding the
+//! same file names as the Classic set at its root. A theme may leave files
+//! out: any sound that fails to load is swapped for the Classic one by
+//! [`fall_back_missing_sounds`].
 
+use crate::core::{GameSettings, SoundTheme};
+use bevy::asset::LoadState;
 use bevy::audio::AudioSource;
 use bevy::prelude::*;
 
+/// One of the game's sound effects.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GameSound {
+    Move,
+    Capture,
+    Check,
+    Illegal,
+}
+
+impl GameSound {
+    pub const ALL: [Self; 4] = [Self::Move, Self::Capture, Self::Check, Self::Illegal];
+
+    /// File name shared by every theme's folder.
+    pub fn file_name(self) -> &'static str {
+        match self {
+            Self::Move => "move_piece.mp3",
+            Self::Capture => "capture_piece.mp3",
+            Self::Check => "check.mp3",
+            Self::Illegal => "illegal.mp3",
+        }
+    }
+}
+
+/// Asset path of `sound` in `theme`'s folder.
+pub fn sound_path(theme: SoundTheme, sound: GameSound) -> String {
+    let folder = match theme {
+        SoundTheme::Classic => "game_sounds",
+        SoundTheme::Retro => "game_sounds/retro",
+    };
+    format!("{folder}/{}", sound.file_name())
+}
+
 /// Resource storing handles to all game sound effects
 ///
 /// Sounds are loaded when entering InGame state and can be played
@@ -19,17 +58,141 @@ pub struct GameSounds {
     pub check: Handle<AudioSource>,
     /// Sound played when an illegal move is attempted
     pub illegal: Handle<AudioSource>,
+    /// Theme the handles were loaded from
+    pub theme: SoundTheme,
 }
 
-impl FromWorld for GameSounds {
-    fn from_world(world: &mut World) -> Self {
-        let asset_server = world.resource::<AssetServer>();
+impl GameSounds {
+    /// Start loading every sound from `theme`'s folder.
+    pub fn load(asset_server: &AssetServer, theme: SoundTheme) -> Self {
         Self {
-            move_piece: asset_server.load("game_sounds/move_piece.mp3"),
-            capture_piece: asset_server.load("game_sounds/capture_piece.mp3"),
+            move_piece: asset_server.load(sound_path(theme, GameSound::Move)),
+            capture_piece: asset_server.load(sound_path(theme, GameSound::Capture)),
             // These fall back to silent if files are missing
-            check: asset_server.load("game_sounds/check.mp3"),
-            illegal: asset_server.load("game_sounds/illegal.mp3"),
+            check: asset_server.load(sound_path(theme, GameSound::Check)),
+            illegal: asset_server.load(sound_path(theme, GameSound::Illegal)),
+            theme,
+        }
+    }
+
+    pub fn handle(&self, sound: GameSound) -> &Handle<AudioSource> {
+        match sound {
+            GameSound::Move => &self.move_piece,
+            GameSound::Capture => &self.capture_piece,
+            GameSound::Check => &self.check,
+            GameSound::Illegal => &self.illegal,
+        }
+    }
+
+    fn handle_mut(&mut self, sound: GameSound) -> &mut Handle<AudioSource> {
+        match sound {
+            GameSound::Move => &mut self.move_piece,
+            GameSound::Capture => &mut self.capture_piece,
+            GameSound::Check => &mut self.check,
+            GameSound::Illegal => &mut self.illegal,
         }
     }
 }
+
+impl FromWorld for GameSounds {
+    fn from_world(world: &mut World) -> Self {
+        let theme = world
+            .get_resource::<GameSettings>()
+            .map(|settings| settings.sound_theme)
+            .unwrap_or_default();
+        Self::load(world.resource::<AssetServer>(), theme)
+    }
+}
+
+/// Reload the sounds when the theme picked in settings changes.
+pub fn apply_sound_theme(
+    settings: Res<GameSettings>,
+    asset_server: Res<AssetServer>,
+    mut sounds: ResMut<GameSounds>,
+) {
+    if sounds.theme != settings.sound_theme {
+        info!(
+            "[SOUNDS] Switching to the {} theme",
+            settings.sound_theme.label()
+        );
+        *sounds = GameSounds::load(&asset_server, settings.sound_theme);
+    }
+}
+
+/// Swap any sound the current theme doesn't provide for the Classic one.
+pub fn fall_back_missing_sounds(asset_server: Res<AssetServer>, mut sounds: ResMut<GameSounds>) {
+    if sounds.theme == SoundTheme::Classic {
+        return;
+    }
+    for sound in GameSound::ALL {
+        if matches!(
+            asset_server.load_state(sounds.handle(sound)),
+            LoadState::Failed(_)
+        ) {
+            warn!(
+                "[SOUNDS] {} theme has no {}; using the Classic one",
+                sounds.theme.label(),
+                sound.file_name()
+            );
+            *sounds.handle_mut(sound) = asset_server.load(sound_path(SoundTheme::Classic, sound));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_theme_loads_its_files_and_falls_back_for_missing_ones() {
+        //! Retro keeps the sound its folder has and takes Classic's for the rest
+
+        let root = std::env::temp_dir().join(format!("xfchess_sounds_{}", std::process::id()));
+        let retro = root.join("game_sounds").join("retro");
+        std::fs::create_dir_all(&retro).unwrap();
+        std::fs::write(retro.join(GameSound::Move.file_name()), b"not really audio").unwrap();
+
+        let mut app = App::new();
+        app.add_plugins((
+            MinimalPlugins,
+            AssetPlugin {
+                file_path: root.to_string_lossy().into_owned(),
+                ..default()
+            },
+        ))
+        .init_asset::<AudioSource>()
+        .init_asset_loader::<bevy::audio::AudioLoader>();
+        let sounds = GameSounds::load(app.world().resource::<AssetServer>(), SoundTheme::Retro);
+        app.insert_resource(sounds)
+            .add_systems(Update, fall_back_missing_sounds);
+
+        let path = |sounds: &GameSounds, sound| {
+            sounds
+                .handle(sound)
+                .path()
+                .map(|p| p.to_string())
+                .unwrap_or_default()
+        };
+        for _ in 0..500 {
+            app.update();
+            let sounds = app.world().resource::<GameSounds>();
+            if [GameSound::Capture, GameSound::Check, GameSound::Illegal]
+                .into_iter()
+                .all(|sound| !path(sounds, sound).contains("retro"))
+            {
+                break;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(5));
+        }
+
+        let sounds = app.world().resource::<GameSounds>();
+        assert_eq!(
+            path(sounds, GameSound::Move),
+            "game_sounds/retro/move_piece.mp3"
+        );
+        assert_eq!(path(sounds, GameSound::Illegal), "game_sounds/illegal.mp3");
+        assert_eq!(path(sounds, GameSound::Check), "game_sounds/check.mp3");
+
+        std::fs::remove_dir_all(&root).ok();
+    }
+}
@@ -185,6 +185,7 @@ fn test_move_history_add_move() {
         piece_type: crate::rendering::pieces::PieceType::Pawn,
         piece_color: PieceColor::White,
         captured: None,
+        promotion: None,
         is_castling: false,
         is_en_passant: false,
         is_check: false,
@@ -216,6 +217,7 @@ fn test_move_history_multiple_moves() {
         piece_type: crate::rendering::pieces::PieceType::Pawn,
         piece_color: PieceColor::White,
         captured: None,
+        promotion: None,
         is_castling: false,
         is_en_passant: false,
         is_check: false,
@@ -228,6 +230,7 @@ fn test_move_history_multiple_moves() {
         piece_type: crate::rendering::pieces::PieceType::Pawn,
         piece_color: PieceColor::Black,
         captured: None,
+        promotion: None,
         is_castling: false,
         is_en_passant: false,
         is_check: false,
@@ -240,6 +243,7 @@ fn test_move_history_multiple_moves() {
         piece_type: crate::rendering::pieces::PieceType::Knight,
         piece_color: PieceColor::White,
         captured: None,
+        promotion: None,
         is_castling: false,
         is_en_passant: false,
         is_check: false,
@@ -273,6 +277,7 @@ fn test_move_history_with_capture() {
         piece_type: crate::rendering::pieces::PieceType::Pawn,
         piece_color: PieceColor::White,
         captured: Some(crate::rendering::pieces::PieceType::Pawn),
+        promotion: None,
         is_castling: false,
         is_en_passant: false,
         is_check: false,
@@ -329,6 +334,7 @@ fn test_fischer_increment_white() {
         black_time_left: 100.0,
         increment: 5.0,
         is_running: true,
+        ..Default::default()
     };
 
     timer.apply_increment(PieceColor::White);
@@ -353,6 +359,7 @@ fn test_fischer_increment_black() {
         black_time_left: 100.0,
         increment: 5.0,
         is_running: true,
+        ..Default::default()
     };
 
     timer.apply_increment(PieceColor::Black);
@@ -377,6 +384,7 @@ fn test_fischer_increment_zero() {
         black_time_left: 100.0,
         increment: 0.0,
         is_running: true,
+        ..Default::default()
     };
 
     timer.apply_increment(PieceColor::White);
@@ -404,6 +412,7 @@ fn test_multiple_increments() {
         black_time_left: 100.0,
         increment: 3.0,
         is_running: true,
+        ..Default::default()
     };
 
     // White moves
@@ -436,6 +445,7 @@ fn test_custom_time_control() {
         black_time_left: 180.0,
         increment: 2.0,
         is_running: false,
+        ..Default::default()
     };
 
     assert_eq!(timer.white_time_left, 180.0);
@@ -1,7 +1,7 @@
-//! Game timer resource with Fischer increment support
+//! Game timer resource with Fischer increment and delay support
 //!
-//! Manages time control for chess games using the Fischer (incremental) time system.
-//! Each player starts with a base time and receives an increment after each move.
+//! Manages time control for chess games. Each player starts with a base time;
+//! how the per-move increment is granted depends on the [`IncrementMode`].
 //!
 //! # Fischer Time Control
 //!
@@ -10,11 +10,20 @@
 //!
 //! Example: 10+5 means 10 minutes base time with 5 second increment per move.
 //!
+//! # Delay Modes
+//!
+//! Delay modes never let a clock gain time over a move:
+//!
+//! - **Bronstein**: after the move, the time used is given back up to the
+//!   increment, so the clock ends no higher than it started that move.
+//! - **Simple delay**: the clock holds for the first `increment` seconds of
+//!   each move before running down; unused delay is lost.
+//!
 //! # Time Management
 //!
 //! - Timer only runs during `GamePhase::Playing`
-//! - Decrements the current player's time each frame
-//! - Applies increment after move completion
+//! - Decrements the current player's time each frame via [`GameTimer::consume`]
+//! - Applies increment after move completion via [`GameTimer::apply_increment`]
 //! - Sets `GameOverState` when time expires
 //!
 //! # Reference
@@ -22,16 +31,20 @@
 //! Fischer increment time control is standard in online chess (Chess.com, Lichess).
 //! See: https://en.wikipedia.org/wiki/Time_control#Increment_and_delay_methods
 
+use crate::core::IncrementMode;
+use crate::rendering::pieces::PieceColor;
 use bevy::prelude::*;
 
-/// Resource for game timer with Fischer increment support
+/// Resource for game timer with Fischer increment and delay support
 ///
 /// # Fields
 ///
 /// - `white_time_left`: White's remaining time in seconds
 /// - `black_time_left`: Black's remaining time in seconds
-/// - `increment`: Time added after each move in seconds (0.0 = no increment)
+/// - `increment`: Increment or delay per move in seconds (0.0 = none)
 /// - `is_running`: Whether timer is actively counting down
+/// - `mode`: How `increment` is applied
+/// - `move_elapsed`: Time spent on the move in progress
 ///
 /// # Default Configuration
 ///
@@ -43,10 +56,14 @@ pub struct GameTimer {
     pub white_time_left: f32,
     /// Black player's remaining time in seconds
     pub black_time_left: f32,
-    /// Fischer increment added after each move (in seconds)
+    /// Increment or delay per move (in seconds)
     pub increment: f32,
     /// Whether the timer is currently running
     pub is_running: bool,
+    /// How `increment` is applied
+    pub mode: IncrementMode,
+    /// Seconds spent on the move in progress, reset when a move completes
+    pub move_elapsed: f32,
 }
 
 impl Default for GameTimer {
@@ -56,15 +73,43 @@ impl Default for GameTimer {
             black_time_left: 600.0,
             increment: 0.0,
             is_running: false,
+            mode: IncrementMode::Fischer,
+            move_elapsed: 0.0,
         }
     }
 }
 
 impl GameTimer {
-    /// Apply Fischer increment to the player who just moved
+    /// Remaining time for `color`
+    pub fn time_left_mut(&mut self, color: PieceColor) -> &mut f32 {
+        match color {
+            PieceColor::White => &mut self.white_time_left,
+            PieceColor::Black => &mut self.black_time_left,
+        }
+    }
+
+    /// Run `color`'s clock down by `delta` seconds of thinking time
     ///
-    /// Adds the configured increment time to the player's remaining time.
-    /// This is called after a player completes their move.
+    /// Under [`IncrementMode::SimpleDelay`] the first `increment` seconds of
+    /// each move are free; every other mode charges the full `delta`.
+    pub fn consume(&mut self, color: PieceColor, delta: f32) {
+        let charged = match self.mode {
+            IncrementMode::SimpleDelay => {
+                let delay_left = (self.increment - self.move_elapsed).max(0.0);
+                (delta - delay_left).max(0.0)
+            }
+            IncrementMode::Fischer | IncrementMode::Bronstein => delta,
+        };
+        self.move_elapsed += delta;
+        *self.time_left_mut(color) -= charged;
+    }
+
+    /// Apply the increment to the player who just moved
+    ///
+    /// Fischer adds the full increment; Bronstein gives back the time used
+    /// on this move, capped at the increment; simple delay was already
+    /// granted while the clock ran. Called after a player completes their
+    /// move, and starts timing the next one.
     ///
     /// # Arguments
     ///
@@ -78,27 +123,28 @@ impl GameTimer {
     ///     black_time_left: 300.0,
     ///     increment: 5.0,  // 5 second increment
     ///     is_running: true,
+    ///     ..Default::default()
     /// };
     ///
     /// timer.apply_increment(PieceColor::White);
     /// assert_eq!(timer.white_time_left, 305.0); // 300 + 5
     /// ```
-    pub fn apply_increment(&mut self, color: crate::rendering::pieces::PieceColor) {
-        use crate::rendering::pieces::PieceColor;
-
-        if self.increment > 0.0 {
-            match color {
-                PieceColor::White => self.white_time_left += self.increment,
-                PieceColor::Black => self.black_time_left += self.increment,
-            }
+    pub fn apply_increment(&mut self, color: PieceColor) {
+        let bonus = match self.mode {
+            IncrementMode::Fischer => self.increment,
+            IncrementMode::Bronstein => self.increment.min(self.move_elapsed),
+            IncrementMode::SimpleDelay => 0.0,
+        };
+        if bonus > 0.0 {
+            *self.time_left_mut(color) += bonus;
         }
+        self.move_elapsed = 0.0;
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::rendering::pieces::PieceColor;
 
     #[test]
     fn test_game_timer_default() {
@@ -125,6 +171,7 @@ mod tests {
             black_time_left: 300.0,
             increment: 5.0,
             is_running: true,
+            ..Default::default()
         };
 
         timer.apply_increment(PieceColor::White);
@@ -144,6 +191,7 @@ mod tests {
             black_time_left: 300.0,
             increment: 5.0,
             is_running: true,
+            ..Default::default()
         };
 
         timer.apply_increment(PieceColor::Black);
@@ -163,6 +211,7 @@ mod tests {
             black_time_left: 300.0,
             increment: 0.0, // No increment
             is_running: true,
+            ..Default::default()
         };
 
         timer.apply_increment(PieceColor::White);
@@ -186,6 +235,7 @@ mod tests {
             black_time_left: 100.0,
             increment: 3.0,
             is_running: true,
+            ..Default::default()
         };
 
         // Simulate 3 complete moves (6 half-moves)
@@ -214,6 +264,7 @@ mod tests {
             black_time_left: 300.0,
             increment: 5.0, // But 5 second increment
             is_running: true,
+            ..Default::default()
         };
 
         timer.apply_increment(PieceColor::White);
@@ -223,4 +274,64 @@ mod tests {
             "Increment should save player from timeout"
         );
     }
+
+    #[test]
+    fn test_fischer_time_can_grow() {
+        //! A quick move under Fischer leaves more time than the move started with
+        let mut timer = GameTimer {
+            white_time_left: 60.0,
+            increment: 5.0,
+            is_running: true,
+            ..Default::default()
+        };
+
+        timer.consume(PieceColor::White, 2.0);
+        timer.apply_increment(PieceColor::White);
+
+        assert_eq!(timer.white_time_left, 63.0, "60 - 2 + 5");
+    }
+
+    #[test]
+    fn test_bronstein_caps_at_move_start() {
+        //! Bronstein only refunds time used, so a quick move ends where it began
+        let mut timer = GameTimer {
+            white_time_left: 60.0,
+            increment: 5.0,
+            is_running: true,
+            mode: IncrementMode::Bronstein,
+            ..Default::default()
+        };
+
+        timer.consume(PieceColor::White, 2.0);
+        timer.apply_increment(PieceColor::White);
+        assert_eq!(timer.white_time_left, 60.0, "2s used, 2s refunded");
+
+        // A slow move is refunded only up to the increment
+        timer.consume(PieceColor::White, 8.0);
+        timer.apply_increment(PieceColor::White);
+        assert_eq!(timer.white_time_left, 57.0, "60 - 8 + 5");
+    }
+
+    #[test]
+    fn test_simple_delay_holds_clock() {
+        //! Simple delay charges nothing until the delay runs out and adds nothing after
+        let mut timer = GameTimer {
+            black_time_left: 60.0,
+            increment: 5.0,
+            is_running: true,
+            mode: IncrementMode::SimpleDelay,
+            ..Default::default()
+        };
+
+        timer.consume(PieceColor::Black, 3.0);
+        assert_eq!(timer.black_time_left, 60.0, "inside the delay");
+        timer.consume(PieceColor::Black, 4.0);
+        assert_eq!(timer.black_time_left, 58.0, "2s past the delay");
+        timer.apply_increment(PieceColor::Black);
+        assert_eq!(timer.black_time_left, 58.0, "unused delay is not banked");
+
+        // The next move gets a fresh delay
+        timer.consume(PieceColor::Black, 1.0);
+        assert_eq!(timer.black_time_left, 58.0);
+    }
 }
@@ -0,0 +1,1700 @@
+//! Named save slots for games in progress.
+//!
+//! Each slot is a small JSON file in a `saves/` directory under the user's
+//! data directory (e.g. `%APPDATA%\trilltino\XFChess\data\saves\autosave.json`).
+//! A slot stores everything needed to continue the game: the current FEN, the
+//! move list (with SAN), captured material, both clocks, each piece's move
+//! state and who the opponent was, with the engine's settings. The board
+//! itself is rebuilt from the FEN, the same way puzzles are.
+//!
+//! # Slots
+//!
+//! - `autosave` — written every [`GameSettings::autosave_interval_secs`]
+//!   seconds while a saveable game is running (0 disables it).
+//! - `quicksave` — F5 saves, F8 loads. "Save & Exit" in the exit dialog
+//!   writes it too.
+//! - [`SAVE_SLOTS`] — the three numbered slots at the top of the "Saved
+//!   Games" window, reachable from the main menu and in game. Each shows the
+//!   move number, side to move, material balance and when it was saved;
+//!   saving over an occupied one asks first.
+//! - Any other name — created from the same window, which also lists every
+//!   slot with a board thumbnail, its FEN and when it was saved.
+//!
+//! # Slot index
+//!
+//! [`INDEX_FILE`] keeps a [`SlotSummary`] per slot so the numbered slots can
+//! be shown without reading each game. [`write_slot`] and [`delete_slot`]
+//! keep it current; if it is missing or unreadable [`read_index`] rebuilds
+//! it from the slot files.
+//!
+//! Only offline games (vs AI / local hotseat) are saveable; online, spectator,
+//! replay and puzzle sessions are driven by someone else's state.
+//!
+//! # Corrupt slots
+//!
+//! A slot that fails to parse, has an unknown format version or carries a
+//! malformed FEN is listed as corrupt (with the reason) and can be deleted,
+//! but never loaded. So is one whose FEN parses but is not a legal position:
+//! a side without exactly one king, pawns on the back ranks, the side not to
+//! move in check, or castling rights with no king and rook at home.
+//!
+//! # Custom positions
+//!
+//! The same window takes a pasted FEN and starts a new game from it, against
+//! a friend or the computer. It goes through the slot restore path as a save
+//! with no moves, after the same checks; a bad FEN is reported in the window.
+
+use crate::core::keybindings::key_label;
+use crate::core::{GameAction, GameMode as CoreGameMode, GameSettings, GameState, IncrementMode};
+use crate::engine::board_state::ChessEngine;
+use crate::game::ai::resource::{AIDifficulty, ChessAIResource, GameMode as AIGameMode};
+use crate::game::components::{HasMoved, MoveRecord};
+use crate::game::resources::{CapturedPieces, CurrentTurn, GameOverState, GameTimer, MoveHistory};
+use crate::game::system_sets::GameSystems;
+use crate::game::systems::input::InGameExitConfirmation;
+use crate::rendering::pieces::{Piece, PieceColor, PieceType, PiecesSpawned};
+use crate::ui::menus::popup::{GamePopup, GamePopupQueue};
+use crate::ui::styles::*;
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use bevy_egui::{egui, EguiContexts, EguiPrimaryContextPass};
+use directories::ProjectDirs;
+use nimzovich_engine::{game_from_fen_no_tt, is_in_check, COLOR_BLACK, COLOR_WHITE};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Current on-disk save format. Bump when [`SavedGame`] changes incompatibly.
+pub const SAVE_FORMAT_VERSION: u32 = 1;
+
+/// Slot written by the periodic auto-save.
+pub const AUTOSAVE_SLOT: &str = "autosave";
+
+/// Slot used by the quick-save / quick-load hotkeys.
+pub const QUICKSAVE_SLOT: &str = "quicksave";
+
+/// The numbered slots offered at the top of the "Saved Games" window.
+pub const SAVE_SLOTS: [&str; 3] = ["Slot 1", "Slot 2", "Slot 3"];
+
+/// Summary of every slot, kept next to them. Not `.json`, so it is never
+/// listed as a slot itself.
+pub const INDEX_FILE: &str = "slots.index";
+
+/// Errors raised while reading or writing a save slot.
+#[derive(Debug, thiserror::Error)]
+pub enum SaveError {
+    /// The file could not be read, written or removed.
+    #[error("save I/O failed: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// The file exists but does not describe a usable game.
+    #[error("save slot is corrupt: {0}")]
+    Corrupt(String),
+
+    /// A FEN typed in by the player is malformed.
+    #[error("invalid FEN: {0}")]
+    InvalidFen(String),
+
+    /// The FEN is well formed but no game of chess could reach it.
+    #[error("illegal position: {0}")]
+    IllegalPosition(String),
+
+    /// The file was written by an incompatible version of the game.
+    #[error("save format v{found} is not supported (expected v{expected})")]
+    Version { found: u32, expected: u32 },
+
+    /// Slot names become file names, so they are restricted to a safe set.
+    #[error("invalid slot name {0:?} (use letters, digits, spaces, '-' or '_')")]
+    InvalidName(String),
+}
+
+/// Who the saved game was being played against.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum SavedOpponent {
+    /// Local hotseat — both sides human.
+    Local,
+    /// Versus the engine.
+    Ai {
+        ai_plays_white: bool,
+        difficulty: u8,
+        /// Approximate rating played at instead of `difficulty`, if any.
+        #[serde(default)]
+        target_elo: Option<u16>,
+        /// Whether the engine played from its opening book. Older saves
+        /// leave it to the difficulty's default.
+        #[serde(default)]
+        opening_book: Option<bool>,
+    },
+    /// The engine against itself, each side at its own level.
+    AiVsAi { white: u8, black: u8 },
+}
+
+/// A piece's move state, so castling rights and move counts come back
+/// exactly rather than guessed from the FEN.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SavedPiece {
+    pub square: (u8, u8),
+    pub moved: bool,
+    pub move_count: u32,
+}
+
+/// Serializable mirror of [`MoveRecord`] plus its SAN.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SavedMove {
+    pub piece_type: PieceType,
+    pub white: bool,
+    pub from: (u8, u8),
+    pub to: (u8, u8),
+    pub captured: Option<PieceType>,
+    /// Piece a pawn became, for promotions.
+    #[serde(default)]
+    pub promotion: Option<PieceType>,
+    pub is_castling: bool,
+    pub is_en_passant: bool,
+    pub is_check: bool,
+    pub is_checkmate: bool,
+    #[serde(default)]
+    pub san: String,
+}
+
+impl SavedMove {
+    fn from_record(record: &MoveRecord, san: Option<&str>) -> Self {
+        Self {
+            piece_type: record.piece_type,
+            white: record.piece_color == PieceColor::White,
+            from: record.from,
+            to: record.to,
+            captured: record.captured,
+            promotion: record.promotion,
+            is_castling: record.is_castling,
+            is_en_passant: record.is_en_passant,
+            is_check: record.is_check,
+            is_checkmate: record.is_checkmate,
+            san: san.unwrap_or_default().to_string(),
+        }
+    }
+
+    fn to_record(&self) -> MoveRecord {
+        MoveRecord {
+            piece_type: self.piece_type,
+            piece_color: if self.white {
+                PieceColor::White
+            } else {
+                PieceColor::Black
+            },
+            from: self.from,
+            to: self.to,
+            captured: self.captured,
+            promotion: self.promotion,
+            is_castling: self.is_castling,
+            is_en_passant: self.is_en_passant,
+            is_check: self.is_check,
+            is_checkmate: self.is_checkmate,
+        }
+    }
+}
+
+/// One save slot's contents.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SavedGame {
+    /// On-disk format version ([`SAVE_FORMAT_VERSION`] when written).
+    pub version: u32,
+    /// Slot name (also the file stem).
+    pub name: String,
+    /// Unix timestamp (seconds) of when the slot was written.
+    pub saved_at: i64,
+    /// Current position.
+    pub fen: String,
+    /// Every move played so far, oldest first.
+    pub moves: Vec<SavedMove>,
+    /// Black pieces taken by White.
+    pub white_captured: Vec<PieceType>,
+    /// White pieces taken by Black.
+    pub black_captured: Vec<PieceType>,
+    pub white_time_left: f32,
+    pub black_time_left: f32,
+    pub increment: f32,
+    /// Older saves predate delay modes and were always Fischer.
+    #[serde(default)]
+    pub increment_mode: IncrementMode,
+    pub opponent: SavedOpponent,
+    /// Move state of every piece on the board. Older saves have none and
+    /// fall back to the FEN's castling rights.
+    #[serde(default)]
+    pub pieces: Vec<SavedPiece>,
+    /// Position the game started from, for a game set up from a FEN. Older
+    /// saves have none and started from the initial position.
+    #[serde(default)]
+    pub start_fen: Option<String>,
+}
+
+impl SavedGame {
+    /// A fresh game from `fen` (already checked by [`parse_position_fen`]),
+    /// with no moves played and default clocks.
+    pub fn from_position(fen: String, opponent: SavedOpponent) -> Self {
+        let timer = GameTimer::default();
+        Self {
+            version: SAVE_FORMAT_VERSION,
+            name: "Custom position".to_string(),
+            saved_at: chrono::Utc::now().timestamp(),
+            start_fen: Some(fen.clone()),
+            fen,
+            moves: Vec::new(),
+            white_captured: Vec::new(),
+            black_captured: Vec::new(),
+            white_time_left: timer.white_time_left,
+            black_time_left: timer.black_time_left,
+            increment: timer.increment,
+            increment_mode: timer.mode,
+            opponent,
+            pieces: Vec::new(),
+        }
+    }
+
+    /// Side to move, read from the FEN.
+    pub fn side_to_move(&self) -> PieceColor {
+        match self.fen.split_whitespace().nth(1) {
+            Some("b") => PieceColor::Black,
+            _ => PieceColor::White,
+        }
+    }
+
+    /// Full-move number, read from the FEN.
+    pub fn fullmove_number(&self) -> u32 {
+        self.fen
+            .split_whitespace()
+            .nth(5)
+            .and_then(|n| n.parse().ok())
+            .unwrap_or(1)
+    }
+
+    /// White's material minus Black's in pawns (1/3/3/5/9), read from the
+    /// FEN's piece placement.
+    pub fn material_balance(&self) -> i32 {
+        let placement = self.fen.split_whitespace().next().unwrap_or("");
+        placement
+            .chars()
+            .map(|c| {
+                let value = match c.to_ascii_lowercase() {
+                    'p' => 1,
+                    'n' | 'b' => 3,
+                    'r' => 5,
+                    'q' => 9,
+                    _ => 0,
+                };
+                if c.is_ascii_uppercase() {
+                    value
+                } else {
+                    -value
+                }
+            })
+            .sum()
+    }
+
+    /// What the slot index records for this game.
+    pub fn summary(&self) -> SlotSummary {
+        SlotSummary {
+            move_number: self.fullmove_number(),
+            white_to_move: self.side_to_move() == PieceColor::White,
+            material_balance: self.material_balance(),
+            saved_at: self.saved_at,
+        }
+    }
+
+    /// Reject saves that could not be turned back into a game.
+    pub fn validate(&self) -> Result<(), SaveError> {
+        if self.version != SAVE_FORMAT_VERSION {
+            return Err(SaveError::Version {
+                found: self.version,
+                expected: SAVE_FORMAT_VERSION,
+            });
+        }
+        validate_fen_shape(&self.fen).map_err(SaveError::Corrupt)?;
+        if let Some(piece) = self
+            .pieces
+            .iter()
+            .find(|p| p.square.0 > 7 || p.square.1 > 7)
+        {
+            return Err(SaveError::Corrupt(format!(
+                "piece state for square {:?} is off the board",
+                piece.square
+            )));
+        }
+        validate_fen_position(&self.fen).map_err(SaveError::IllegalPosition)
+    }
+}
+
+/// Structural FEN check: six fields, eight ranks of eight files, known piece
+/// letters and a valid side to move. Chess legality is left to
+/// [`validate_fen_position`].
+pub(crate) fn validate_fen_shape(fen: &str) -> Result<(), String> {
+    let fields: Vec<&str> = fen.split_whitespace().collect();
+    if fields.len() != 6 {
+        return Err(format!("FEN has {} fields, expected 6", fields.len()));
+    }
+    let ranks: Vec<&str> = fields[0].split('/').collect();
+    if ranks.len() != 8 {
+        return Err(format!("FEN has {} ranks, expected 8", ranks.len()));
+    }
+    for rank in &ranks {
+        let mut files = 0u32;
+        for ch in rank.chars() {
+            match ch {
+                '1'..='8' => files += ch.to_digit(10).unwrap_or(0),
+                'p' | 'n' | 'b' | 'r' | 'q' | 'k' | 'P' | 'N' | 'B' | 'R' | 'Q' | 'K' => files += 1,
+                _ => return Err(format!("unexpected '{}' in FEN rank {:?}", ch, rank)),
+            }
+        }
+        if files != 8 {
+            return Err(format!("FEN rank {:?} covers {} files", rank, files));
+        }
+    }
+    if fields[1] != "w" && fields[1] != "b" {
+        return Err(format!("invalid side to move {:?}", fields[1]));
+    }
+    if fields[2] != "-" && !fields[2].chars().all(|c| "KQkq".contains(c)) {
+        return Err(format!("invalid castling field {:?}", fields[2]));
+    }
+    let ep = fields[3].as_bytes();
+    let ep_ok = fields[3] == "-"
+        || (ep.len() == 2 && (b'a'..=b'h').contains(&ep[0]) && (ep[1] == b'3' || ep[1] == b'6'));
+    if !ep_ok {
+        return Err(format!("invalid en-passant square {:?}", fields[3]));
+    }
+    if fields[4].parse::<u32>().is_err() || fields[5].parse::<u32>().is_err() {
+        return Err(format!(
+            "move counters {:?} {:?} are not numbers",
+            fields[4], fields[5]
+        ));
+    }
+    Ok(())
+}
+
+/// Tidy a pasted FEN and check it describes a position that can be played:
+/// surrounding whitespace is dropped and the move counters default to
+/// "0 1" when only the first four fields are given.
+pub fn parse_position_fen(input: &str) -> Result<String, SaveError> {
+    let mut fields: Vec<&str> = input.split_whitespace().collect();
+    if fields.is_empty() {
+        return Err(SaveError::InvalidFen("nothing entered".to_string()));
+    }
+    if fields.len() == 4 {
+        fields.extend(["0", "1"]);
+    }
+    let fen = fields.join(" ");
+    validate_fen_shape(&fen).map_err(SaveError::InvalidFen)?;
+    validate_fen_position(&fen).map_err(SaveError::IllegalPosition)?;
+    Ok(fen)
+}
+
+/// Chess legality of a FEN that passed [`validate_fen_shape`]: one king per
+/// side, no pawns on the first or eighth rank, the side not to move not in
+/// check, and every castling right backed by its king and rook on their home
+/// squares. Every problem found is listed in the error.
+pub(crate) fn validate_fen_position(fen: &str) -> Result<(), String> {
+    let fields: Vec<&str> = fen.split_whitespace().collect();
+    // board[rank][file], rank 0 being White's first rank
+    let mut board = [[' '; 8]; 8];
+    for (row, line) in fields[0].split('/').enumerate() {
+        let mut file = 0;
+        for ch in line.chars() {
+            match ch.to_digit(10) {
+                Some(skip) => file += skip as usize,
+                None => {
+                    board[7 - row][file] = ch;
+                    file += 1;
+                }
+            }
+        }
+    }
+
+    let mut problems = Vec::new();
+    let mut kings_ok = true;
+    for (king, side) in [('K', "White"), ('k', "Black")] {
+        let kings = board.iter().flatten().filter(|&&sq| sq == king).count();
+        if kings != 1 {
+            problems.push(format!("{} has {} kings", side, kings));
+            kings_ok = false;
+        }
+    }
+    for (rank, name) in [(0, "first"), (7, "eighth")] {
+        if board[rank].iter().any(|&sq| sq == 'P' || sq == 'p') {
+            problems.push(format!("pawn on the {} rank", name));
+        }
+    }
+    for right in fields[2].chars().filter(|&c| c != '-') {
+        let (rank, king, rook, rook_file) = match right {
+            'K' => (0, 'K', 'R', 7),
+            'Q' => (0, 'K', 'R', 0),
+            'k' => (7, 'k', 'r', 7),
+            'q' => (7, 'k', 'r', 0),
+            other => {
+                problems.push(format!("unknown castling right '{}'", other));
+                continue;
+            }
+        };
+        if board[rank][4] != king || board[rank][rook_file] != rook {
+            problems.push(format!(
+                "castling right '{}' without its king and rook at home",
+                right
+            ));
+        }
+    }
+    // Check detection needs exactly one king a side to look at.
+    if kings_ok {
+        let (waiting, side, mover) = if fields[1] == "w" {
+            (COLOR_BLACK, "Black", "White")
+        } else {
+            (COLOR_WHITE, "White", "Black")
+        };
+        if is_in_check(&game_from_fen_no_tt(fen), waiting) {
+            problems.push(format!("{} is in check with {} to move", side, mover));
+        }
+    }
+
+    if problems.is_empty() {
+        Ok(())
+    } else {
+        Err(problems.join("; "))
+    }
+}
+
+/// One row of the slot browser: a readable game or the reason it isn't.
+#[derive(Debug, Clone)]
+pub struct SlotEntry {
+    pub name: String,
+    pub game: Result<SavedGame, String>,
+}
+
+/// A slot as the index records it: enough to describe it in a menu.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SlotSummary {
+    pub move_number: u32,
+    pub white_to_move: bool,
+    /// White's material minus Black's, in pawns.
+    pub material_balance: i32,
+    /// Unix timestamp (seconds) of when the slot was written.
+    pub saved_at: i64,
+}
+
+impl SlotSummary {
+    /// One line for a menu, e.g. "Move 12 · Black to play · +3 · 2024-05-01 18:20".
+    pub fn describe(&self) -> String {
+        let balance = match self.material_balance {
+            0 => "even".to_string(),
+            n => format!("{:+}", n),
+        };
+        format!(
+            "Move {} · {} to play · {} · {}",
+            self.move_number,
+            if self.white_to_move { "White" } else { "Black" },
+            balance,
+            format_saved_at(self.saved_at)
+        )
+    }
+}
+
+/// Contents of [`INDEX_FILE`]: a summary per readable slot, by name.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SlotIndex {
+    pub slots: BTreeMap<String, SlotSummary>,
+}
+
+/// Directory holding the slot files.
+///
+/// Falls back to a local `saves/` directory if the system data dir cannot be
+/// resolved.
+pub fn saves_dir() -> PathBuf {
+    ProjectDirs::from("com", "trilltino", "XFChess")
+        .map(|dirs| dirs.data_dir().join("saves"))
+        .unwrap_or_else(|| PathBuf::from("saves"))
+}
+
+pub(crate) fn slot_path(dir: &Path, name: &str) -> Result<PathBuf, SaveError> {
+    let trimmed = name.trim();
+    let valid = !trimmed.is_empty()
+        && trimmed.len() <= 48
+        && trimmed
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, ' ' | '-' | '_'));
+    if !valid {
+        return Err(SaveError::InvalidName(name.to_string()));
+    }
+    Ok(dir.join(format!("{}.json", trimmed)))
+}
+
+/// Write `value` as JSON to `path`, replacing any previous contents.
+///
+/// Writes to a temporary file first and renames it over the target so a
+/// crash mid-write can't leave a half-written (corrupt) file behind.
+pub(crate) fn write_json_file<T: Serialize>(path: &Path, value: &T) -> Result<(), SaveError> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let json = serde_json::to_string_pretty(value)
+        .map_err(|e| SaveError::Corrupt(format!("serialize failed: {}", e)))?;
+    let mut tmp = path.as_os_str().to_owned();
+    tmp.push(".tmp");
+    fs::write(&tmp, json)?;
+    fs::rename(&tmp, path)?;
+    Ok(())
+}
+
+/// Read the JSON file at `path`; one that doesn't parse as a `T` is
+/// [`SaveError::Corrupt`].
+pub(crate) fn read_json_file<T: DeserializeOwned>(path: &Path) -> Result<T, SaveError> {
+    let contents = fs::read_to_string(path)?;
+    serde_json::from_str(&contents).map_err(|e| SaveError::Corrupt(e.to_string()))
+}
+
+/// Remove the file named `name` from `dir`.
+pub(crate) fn delete_named_file(dir: &Path, name: &str) -> Result<(), SaveError> {
+    fs::remove_file(slot_path(dir, name)?)?;
+    Ok(())
+}
+
+/// Read every named JSON file in `dir` (slots, studies) with `read`, newest
+/// first by `saved_at`. Files `read` refuses sort last, with the reason.
+pub(crate) fn list_named_files<T>(
+    dir: &Path,
+    read: impl Fn(&str) -> Result<T, SaveError>,
+    saved_at: impl Fn(&T) -> i64,
+) -> Vec<(String, Result<T, String>)> {
+    let Ok(read_dir) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut entries: Vec<(String, Result<T, String>)> = read_dir
+        .flatten()
+        .map(|e| e.path())
+        .filter(|p| p.extension().and_then(|e| e.to_str()) == Some("json"))
+        .filter_map(|p| p.file_stem().and_then(|s| s.to_str()).map(str::to_string))
+        .map(|name| {
+            let read = read(&name).map_err(|e| e.to_string());
+            (name, read)
+        })
+        .collect();
+    entries.sort_by(|(a_name, a), (b_name, b)| match (a, b) {
+        (Ok(x), Ok(y)) => saved_at(y).cmp(&saved_at(x)),
+        (Ok(_), Err(_)) => std::cmp::Ordering::Less,
+        (Err(_), Ok(_)) => std::cmp::Ordering::Greater,
+        (Err(_), Err(_)) => a_name.cmp(b_name),
+    });
+    entries
+}
+
+/// Whether a slot file with this name already exists.
+pub fn slot_exists(dir: &Path, name: &str) -> bool {
+    slot_path(dir, name).map(|p| p.exists()).unwrap_or(false)
+}
+
+/// Write `game` as JSON to `path`, replacing any previous contents, through
+/// a temporary file (see [`write_json_file`]).
+pub fn save_game(path: &Path, game: &SavedGame) -> Result<(), SaveError> {
+    write_json_file(path, game)
+}
+
+/// Read and validate the save at `path`. A save from another format version
+/// is refused with [`SaveError::Version`].
+pub fn load_game(path: &Path) -> Result<SavedGame, SaveError> {
+    let value: serde_json::Value = read_json_file(path)?;
+    // Check the version before the layout: a save from another version may
+    // not parse as this one at all, and that is not corruption.
+    if let Some(found) = value.get("version").and_then(serde_json::Value::as_u64) {
+        if found != u64::from(SAVE_FORMAT_VERSION) {
+            return Err(SaveError::Version {
+                found: u32::try_from(found).unwrap_or(u32::MAX),
+                expected: SAVE_FORMAT_VERSION,
+            });
+        }
+    }
+    let game: SavedGame =
+        serde_json::from_value(value).map_err(|e| SaveError::Corrupt(e.to_string()))?;
+    game.validate()?;
+    Ok(game)
+}
+
+/// Write `game` to its slot in `dir`, as [`save_game`], and record it in
+/// the index.
+pub fn write_slot(dir: &Path, game: &SavedGame) -> Result<PathBuf, SaveError> {
+    let path = slot_path(dir, &game.name)?;
+    save_game(&path, game)?;
+    let mut index = read_index(dir);
+    index
+        .slots
+        .insert(game.name.trim().to_string(), game.summary());
+    // The slot itself is safe; a stale index is rebuilt on the next read.
+    if let Err(e) = write_index(dir, &index) {
+        warn!("[SAVE] Could not update the slot index: {}", e);
+    }
+    Ok(path)
+}
+
+/// Read and validate a slot.
+pub fn read_slot(dir: &Path, name: &str) -> Result<SavedGame, SaveError> {
+    load_game(&slot_path(dir, name)?)
+}
+
+/// Remove a slot file and its index entry.
+pub fn delete_slot(dir: &Path, name: &str) -> Result<(), SaveError> {
+    delete_named_file(dir, name)?;
+    let mut index = read_index(dir);
+    if index.slots.remove(name.trim()).is_some() {
+        if let Err(e) = write_index(dir, &index) {
+            warn!("[SAVE] Could not update the slot index: {}", e);
+        }
+    }
+    Ok(())
+}
+
+/// Read the slot index in `dir`. A missing or unreadable index is rebuilt
+/// from the slot files, which does read every game, and written back.
+pub fn read_index(dir: &Path) -> SlotIndex {
+    let stored = fs::read_to_string(dir.join(INDEX_FILE))
+        .ok()
+        .and_then(|json| serde_json::from_str(&json).ok());
+    if let Some(index) = stored {
+        return index;
+    }
+    let index = SlotIndex {
+        slots: list_slots(dir)
+            .into_iter()
+            .filter_map(|slot| Some((slot.name, slot.game.ok()?.summary())))
+            .collect(),
+    };
+    if !index.slots.is_empty() {
+        if let Err(e) = write_index(dir, &index) {
+            warn!("[SAVE] Could not write the slot index: {}", e);
+        }
+    }
+    index
+}
+
+fn write_index(dir: &Path, index: &SlotIndex) -> Result<(), SaveError> {
+    write_json_file(&dir.join(INDEX_FILE), index)
+}
+
+/// List every slot in `dir`, newest first; corrupt slots sort last.
+pub fn list_slots(dir: &Path) -> Vec<SlotEntry> {
+    list_named_files(dir, |name| read_slot(dir, name), |game| game.saved_at)
+        .into_iter()
+        .map(|(name, game)| SlotEntry { name, game })
+        .collect()
+}
+
+/// Inserted to restore a slot: `create_pieces` spawns its FEN and
+/// [`apply_pending_restore`] fills in the rest once the pieces exist.
+#[derive(Resource, Debug, Clone)]
+pub struct PendingSaveRestore(pub SavedGame);
+
+/// State of the "Saved Games" window.
+#[derive(Resource, Default)]
+pub struct SaveSlotBrowser {
+    pub open: bool,
+    /// Name typed into the "save as" field.
+    pub name_input: String,
+    /// Slot awaiting overwrite confirmation.
+    pub confirm_overwrite: Option<String>,
+    /// Last save/load outcome, shown at the bottom of the window.
+    pub status: Option<String>,
+    /// FEN typed into the "start from a position" field.
+    pub fen_input: String,
+    /// Play the position against the computer rather than locally.
+    pub fen_vs_ai: bool,
+    /// Why the typed FEN was refused.
+    pub fen_error: Option<String>,
+    slots: Vec<SlotEntry>,
+    index: SlotIndex,
+    needs_refresh: bool,
+}
+
+impl SaveSlotBrowser {
+    /// Open the window and rescan the saves directory.
+    pub fn show(&mut self) {
+        self.open = true;
+        self.needs_refresh = true;
+        self.confirm_overwrite = None;
+    }
+}
+
+/// Everything needed to snapshot the running game or start a restored one.
+#[derive(SystemParam)]
+pub struct SaveGameParams<'w, 's> {
+    pub commands: Commands<'w, 's>,
+    pub next_state: ResMut<'w, NextState<GameState>>,
+    pub core_mode: ResMut<'w, CoreGameMode>,
+    pub ai_config: ResMut<'w, ChessAIResource>,
+    pub engine: Res<'w, ChessEngine>,
+    pub history: Res<'w, MoveHistory>,
+    pub captured: Res<'w, CapturedPieces>,
+    pub timer: Res<'w, GameTimer>,
+    pub game_over: Res<'w, GameOverState>,
+    pub puzzle_board: Option<Res<'w, crate::puzzle::PuzzleBoard>>,
+    pub pieces: Query<'w, 's, (&'static Piece, &'static HasMoved)>,
+}
+
+impl SaveGameParams<'_, '_> {
+    /// Offline games that are still in progress can be saved.
+    pub fn can_save(&self) -> bool {
+        let offline = matches!(
+            *self.core_mode,
+            CoreGameMode::SinglePlayer | CoreGameMode::MultiplayerLocal
+        );
+        let puzzle = self.puzzle_board.as_ref().is_some_and(|pb| pb.active);
+        offline && !puzzle && !self.game_over.is_game_over()
+    }
+
+    /// Capture the running game as a slot named `name`.
+    pub fn snapshot(&self, name: &str) -> SavedGame {
+        let opponent = match (*self.core_mode, self.ai_config.mode) {
+            (CoreGameMode::SinglePlayer, AIGameMode::VsAI { ai_color }) => SavedOpponent::Ai {
+                ai_plays_white: ai_color == PieceColor::White,
+                difficulty: self.ai_config.difficulty.to_u8(),
+                target_elo: self.ai_config.target_elo,
+                opening_book: Some(self.ai_config.opening_book),
+            },
+            (CoreGameMode::SinglePlayer, AIGameMode::AIvsAI { white, black }) => {
+                SavedOpponent::AiVsAi {
+                    white: white.to_u8(),
+                    black: black.to_u8(),
+                }
+            }
+            _ => SavedOpponent::Local,
+        };
+        let pieces = self
+            .pieces
+            .iter()
+            .filter(|(piece, _)| piece.is_on_board())
+            .map(|(piece, has_moved)| SavedPiece {
+                square: (piece.x, piece.y),
+                moved: has_moved.moved,
+                move_count: has_moved.move_count,
+            })
+            .collect();
+        SavedGame {
+            version: SAVE_FORMAT_VERSION,
+            name: name.trim().to_string(),
+            saved_at: chrono::Utc::now().timestamp(),
+            fen: self.engine.current_fen().to_string(),
+            moves: self
+                .history
+                .iter()
+                .enumerate()
+                .map(|(i, record)| SavedMove::from_record(record, self.history.san_at(i)))
+                .collect(),
+            white_captured: self.captured.white_captured.clone(),
+            black_captured: self.captured.black_captured.clone(),
+            white_time_left: self.timer.white_time_left,
+            black_time_left: self.timer.black_time_left,
+            increment: self.timer.increment,
+            increment_mode: self.timer.mode,
+            opponent,
+            pieces,
+            start_fen: self.history.start_fen.clone(),
+        }
+    }
+
+    /// Configure the game mode for `game` and (re-)enter `InGame` with it.
+    pub fn restore(&mut self, game: SavedGame) {
+        match game.opponent {
+            SavedOpponent::Local => {
+                *self.core_mode = CoreGameMode::MultiplayerLocal;
+                self.ai_config.mode = AIGameMode::Multiplayer;
+            }
+            SavedOpponent::Ai {
+                ai_plays_white,
+                difficulty,
+                target_elo,
+                opening_book,
+            } => {
+                *self.core_mode = CoreGameMode::SinglePlayer;
+                self.ai_config.mode = AIGameMode::VsAI {
+                    ai_color: if ai_plays_white {
+                        PieceColor::White
+                    } else {
+                        PieceColor::Black
+                    },
+                };
+                self.ai_config.difficulty = AIDifficulty::from_u8(difficulty);
+                self.ai_config.target_elo = target_elo;
+                self.ai_config.opening_book =
+                    opening_book.unwrap_or_else(|| self.ai_config.difficulty.plays_book());
+            }
+            SavedOpponent::AiVsAi { white, black } => {
+                *self.core_mode = CoreGameMode::SinglePlayer;
+                self.ai_config.mode = AIGameMode::AIvsAI {
+                    white: AIDifficulty::from_u8(white),
+                    black: AIDifficulty::from_u8(black),
+                };
+                self.ai_config.target_elo = None;
+                self.ai_config.opening_book = true;
+            }
+        }
+        info!(
+            "[SAVE] Restoring slot '{}' ({} moves)",
+            game.name,
+            game.moves.len()
+        );
+        self.commands.insert_resource(PendingSaveRestore(game));
+        self.next_state.set(GameState::InGame);
+    }
+}
+
+/// Plugin wiring auto-save, the quick-save hotkeys and the slot browser.
+pub struct SavePlugin;
+
+impl Plugin for SavePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SaveSlotBrowser>()
+            .add_systems(
+                Update,
+                apply_pending_restore
+                    .in_set(GameSystems::Validation)
+                    .run_if(resource_exists::<PendingSaveRestore>),
+            )
+            .add_systems(
+                Update,
+                (autosave_system, quick_save_load_system).run_if(in_state(GameState::InGame)),
+            )
+            .add_systems(
+                Update,
+                save_before_exit
+                    .before(crate::game::systems::input::confirm_exit_game)
+                    .run_if(in_state(GameState::InGame)),
+            )
+            .add_systems(
+                EguiPrimaryContextPass,
+                save_browser_ui
+                    .run_if(in_state(GameState::InGame).or(in_state(GameState::MainMenu))),
+            )
+            .add_systems(OnExit(GameState::InGame), close_save_browser);
+    }
+}
+
+/// Apply a [`PendingSaveRestore`] once its pieces are in the world.
+///
+/// `reset_game_resources` has already cleared everything on entering
+/// `InGame`; this puts back the engine position, move list, captures, clocks
+/// and each piece's `HasMoved`. Saves without piece state get it from the
+/// FEN's castling rights, so later ECS→engine syncs agree with the FEN.
+#[allow(clippy::too_many_arguments)]
+fn apply_pending_restore(
+    mut commands: Commands,
+    pending: Res<PendingSaveRestore>,
+    pieces_spawned: Res<PiecesSpawned>,
+    mut pieces: Query<(&Piece, &mut HasMoved)>,
+    mut engine: ResMut<ChessEngine>,
+    mut current_turn: ResMut<CurrentTurn>,
+    mut history: ResMut<MoveHistory>,
+    mut captured: ResMut<CapturedPieces>,
+    mut timer: ResMut<GameTimer>,
+) {
+    if !pieces_spawned.spawned || pieces.is_empty() {
+        return;
+    }
+    let game = &pending.0;
+
+    if let Err(e) = engine.set_from_fen(&game.fen) {
+        error!("[SAVE] Slot '{}' has an unusable FEN: {}", game.name, e);
+        commands.remove_resource::<PendingSaveRestore>();
+        return;
+    }
+    // Engine already holds the exact position (incl. en passant / clocks);
+    // update_game_phase only needs to rebuild the cache and evaluate check.
+    engine.synced_this_move = true;
+
+    let castling = game.fen.split_whitespace().nth(2).unwrap_or("-");
+    for (piece, mut has_moved) in pieces.iter_mut() {
+        let lost = |rights: &[char]| !rights.iter().any(|c| castling.contains(*c));
+        has_moved.moved = match (piece.piece_type, piece.color, piece.x, piece.y) {
+            (PieceType::King, PieceColor::White, 4, 0) => lost(&['K', 'Q']),
+            (PieceType::King, PieceColor::Black, 4, 7) => lost(&['k', 'q']),
+            (PieceType::Rook, PieceColor::White, 0, 0) => lost(&['Q']),
+            (PieceType::Rook, PieceColor::White, 7, 0) => lost(&['K']),
+            (PieceType::Rook, PieceColor::Black, 0, 7) => lost(&['q']),
+            (PieceType::Rook, PieceColor::Black, 7, 7) => lost(&['k']),
+            (PieceType::Pawn, PieceColor::White, _, 1)
+            | (PieceType::Pawn, PieceColor::Black, _, 6) => false,
+            _ => true,
+        };
+        if let Some(saved) = game
+            .pieces
+            .iter()
+            .find(|saved| saved.square == (piece.x, piece.y))
+        {
+            has_moved.moved = saved.moved;
+            has_moved.move_count = saved.move_count;
+        }
+    }
+
+    *current_turn = CurrentTurn {
+        color: game.side_to_move(),
+        move_number: game.fullmove_number(),
+    };
+
+    history.clear();
+    history.start_fen = game.start_fen.clone();
+    for mv in &game.moves {
+        history.add_move_with_san(mv.to_record(), mv.san.clone());
+    }
+
+    captured.white_captured = game.white_captured.clone();
+    captured.black_captured = game.black_captured.clone();
+
+    timer.white_time_left = game.white_time_left;
+    timer.black_time_left = game.black_time_left;
+    timer.increment = game.increment;
+    timer.mode = game.increment_mode;
+    timer.move_elapsed = 0.0;
+    // Clocks only run once a move has been played (see start_timer_when_ready).
+    timer.is_running = !game.moves.is_empty();
+
+    info!(
+        "[SAVE] Restored '{}' — {:?} to move, move {} | FEN: {}",
+        game.name, current_turn.color, current_turn.move_number, game.fen
+    );
+    commands.remove_resource::<PendingSaveRestore>();
+}
+
+/// Periodically write the running game to the [`AUTOSAVE_SLOT`].
+///
+/// Only writes when the move list has changed since the last auto-save, so an
+/// idle board doesn't keep touching the disk.
+fn autosave_system(
+    time: Res<Time>,
+    settings: Res<GameSettings>,
+    params: SaveGameParams,
+    mut elapsed: Local<f32>,
+    mut last_saved_len: Local<Option<usize>>,
+) {
+    let interval = settings.autosave_interval_secs;
+    if interval == 0 || !params.can_save() || params.history.is_empty() {
+        *elapsed = 0.0;
+        return;
+    }
+    *elapsed += time.delta_secs();
+    if *elapsed < interval as f32 {
+        return;
+    }
+    *elapsed = 0.0;
+    if *last_saved_len == Some(params.history.len()) {
+        return;
+    }
+
+    match write_slot(&saves_dir(), &params.snapshot(AUTOSAVE_SLOT)) {
+        Ok(path) => {
+            *last_saved_len = Some(params.history.len());
+            info!("[SAVE] Auto-saved to {:?}", path);
+        }
+        Err(e) => warn!("[SAVE] Auto-save failed: {}", e),
+    }
+}
+
+/// F5 quick-saves, F8 quick-loads (unless remapped).
+fn quick_save_load_system(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    settings: Res<GameSettings>,
+    mut params: SaveGameParams,
+    mut browser: ResMut<SaveSlotBrowser>,
+    mut popups: Option<ResMut<GamePopupQueue>>,
+) {
+    let bindings = &settings.key_bindings;
+    if bindings.just_pressed(&keyboard, GameAction::QuickSave) {
+        let status = if !params.can_save() {
+            "This game can't be saved".to_string()
+        } else {
+            browser.needs_refresh = true;
+            match write_slot(&saves_dir(), &params.snapshot(QUICKSAVE_SLOT)) {
+                Ok(_) => "Quick-saved".to_string(),
+                Err(e) => {
+                    warn!("[SAVE] Quick-save failed: {}", e);
+                    format!("Quick-save failed: {}", e)
+                }
+            }
+        };
+        if let Some(popups) = popups.as_mut() {
+            popups.push(GamePopup::notice("Save", status.clone()));
+        }
+        browser.status = Some(status);
+    } else if bindings.just_pressed(&keyboard, GameAction::QuickLoad) {
+        match read_slot(&saves_dir(), QUICKSAVE_SLOT) {
+            Ok(game) => params.restore(game),
+            Err(e) => {
+                warn!("[SAVE] Quick-load failed: {}", e);
+                browser.status = Some(format!("Quick-load failed: {}", e));
+            }
+        }
+    }
+}
+
+/// "Save & Exit": write the [`QUICKSAVE_SLOT`] before the exit goes ahead.
+/// If the save fails the player stays in the game and is told why.
+fn save_before_exit(
+    mut confirmation: ResMut<InGameExitConfirmation>,
+    params: SaveGameParams,
+    mut popups: Option<ResMut<GamePopupQueue>>,
+) {
+    if !confirmation.pending_exit || !confirmation.save_before_exit {
+        return;
+    }
+    confirmation.save_before_exit = false;
+    if !params.can_save() {
+        return;
+    }
+    match write_slot(&saves_dir(), &params.snapshot(QUICKSAVE_SLOT)) {
+        Ok(path) => info!("[SAVE] Saved to {:?} before leaving", path),
+        Err(e) => {
+            warn!("[SAVE] Save before exit failed: {}", e);
+            confirmation.pending_exit = false;
+            if let Some(popups) = popups.as_mut() {
+                popups.push(GamePopup::warning(
+                    "Save",
+                    format!("The game could not be saved: {}", e),
+                ));
+            }
+        }
+    }
+}
+
+fn close_save_browser(mut browser: ResMut<SaveSlotBrowser>) {
+    browser.open = false;
+    browser.confirm_overwrite = None;
+}
+
+/// The "Saved Games" window: save-as (in game), and load/delete per slot.
+fn save_browser_ui(
+    mut contexts: EguiContexts,
+    mut browser: ResMut<SaveSlotBrowser>,
+    state: Res<State<GameState>>,
+    mut settings: ResMut<GameSettings>,
+    mut params: SaveGameParams,
+) {
+    if !browser.open {
+        return;
+    }
+    let Ok(ctx) = contexts.ctx_mut() else {
+        return;
+    };
+    let dir = saves_dir();
+    if browser.needs_refresh {
+        browser.slots = list_slots(&dir);
+        browser.index = read_index(&dir);
+        browser.needs_refresh = false;
+    }
+
+    let in_game = *state.get() == GameState::InGame;
+    let mut open = browser.open;
+    let mut to_load: Option<SavedGame> = None;
+    let mut to_load_slot: Option<String> = None;
+    let mut to_delete: Option<String> = None;
+    let mut to_save: Option<String> = None;
+    let mut to_start: Option<String> = None;
+    // Slid on a copy so the settings are only marked changed (and saved)
+    // when the interval actually moves.
+    let mut autosave_interval = settings.autosave_interval_secs;
+
+    egui::Window::new("Saved Games")
+        .open(&mut open)
+        .collapsible(false)
+        .resizable(false)
+        .fixed_size(egui::vec2(460.0, 500.0))
+        .anchor(egui::Align2::CENTER_CENTER, egui::Vec2::ZERO)
+        .frame(StyledPanel::popup())
+        .show(ctx, |ui| {
+            ui.label(TextStyle::popup_title("SAVED GAMES"));
+            Layout::small_space(ui);
+
+            let can_save = in_game && params.can_save();
+            for name in SAVE_SLOTS {
+                let summary = browser.index.slots.get(name).copied();
+                ui.horizontal(|ui| {
+                    ui.label(TextStyle::heading(name, TextSize::BODY));
+                    ui.label(TextStyle::caption(
+                        summary.map_or_else(|| "Empty".to_string(), |s| s.describe()),
+                    ));
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        if summary.is_some() && StyledButton::small(ui, "Load").clicked() {
+                            to_load_slot = Some(name.to_string());
+                        }
+                        if can_save && StyledButton::small(ui, "Save").clicked() {
+                            if summary.is_some() || slot_exists(&dir, name) {
+                                browser.confirm_overwrite = Some(name.to_string());
+                            } else {
+                                to_save = Some(name.to_string());
+                            }
+                        }
+                    });
+                });
+            }
+            Layout::item_space(ui);
+
+            if can_save {
+                ui.horizontal(|ui| {
+                    ui.add(
+                        egui::TextEdit::singleline(&mut browser.name_input)
+                            .hint_text("Slot name")
+                            .desired_width(260.0),
+                    );
+                    if StyledButton::small(ui, "Save").clicked() {
+                        let name = browser.name_input.trim().to_string();
+                        if slot_exists(&dir, &name) {
+                            browser.confirm_overwrite = Some(name);
+                        } else {
+                            to_save = Some(name);
+                        }
+                    }
+                });
+                Layout::item_space(ui);
+            }
+
+            egui::ScrollArea::vertical()
+                .max_height(240.0)
+                .show(ui, |ui| {
+                    if browser.slots.is_empty() {
+                        ui.label(TextStyle::caption("No saved games yet."));
+                    }
+                    for slot in &browser.slots {
+                        StyledPanel::card().show(ui, |ui| {
+                            ui.set_width(410.0);
+                            ui.horizontal(|ui| match &slot.game {
+                                Ok(game) => {
+                                    paint_fen_thumbnail(ui, &game.fen, 72.0);
+                                    ui.vertical(|ui| {
+                                        ui.label(TextStyle::heading(&slot.name, TextSize::BODY));
+                                        ui.label(TextStyle::caption(format!(
+                                            "Move {}, {} to play · {}",
+                                            game.fullmove_number(),
+                                            match game.side_to_move() {
+                                                PieceColor::White => "White",
+                                                PieceColor::Black => "Black",
+                                            },
+                                            format_saved_at(game.saved_at)
+                                        )));
+                                        ui.label(
+                                            egui::RichText::new(&game.fen)
+                                                .size(10.0)
+                                                .monospace()
+                                                .color(UiColors::TEXT_TERTIARY),
+                                        );
+                                        ui.horizontal(|ui| {
+                                            if StyledButton::small(ui, "Load").clicked() {
+                                                to_load = Some(game.clone());
+                                            }
+                                            if StyledButton::small(ui, "Delete").clicked() {
+                                                to_delete = Some(slot.name.clone());
+                                            }
+                                        });
+                                    });
+                                }
+                                Err(reason) => {
+                                    ui.vertical(|ui| {
+                                        ui.label(TextStyle::heading(&slot.name, TextSize::BODY));
+                                        ui.label(
+                                            TextStyle::caption(format!("Corrupt: {}", reason))
+                                                .color(UiColors::DANGER),
+                                        );
+                                        if StyledButton::small(ui, "Delete").clicked() {
+                                            to_delete = Some(slot.name.clone());
+                                        }
+                                    });
+                                }
+                            });
+                        });
+                        Layout::small_space(ui);
+                    }
+                });
+
+            Layout::small_space(ui);
+            ui.label(TextStyle::body("Start from a position"));
+            ui.add(
+                egui::TextEdit::singleline(&mut browser.fen_input)
+                    .hint_text("Paste a FEN")
+                    .font(egui::TextStyle::Monospace)
+                    .desired_width(430.0),
+            );
+            ui.horizontal(|ui| {
+                ui.checkbox(&mut browser.fen_vs_ai, "Against the computer");
+                if StyledButton::small(ui, "Play").clicked() {
+                    match parse_position_fen(&browser.fen_input) {
+                        Ok(fen) => {
+                            browser.fen_error = None;
+                            to_start = Some(fen);
+                        }
+                        Err(e) => browser.fen_error = Some(e.to_string()),
+                    }
+                }
+            });
+            if let Some(error) = &browser.fen_error {
+                ui.label(TextStyle::caption(error).color(UiColors::DANGER));
+            }
+
+            Layout::small_space(ui);
+            ui.horizontal(|ui| {
+                ui.label(TextStyle::caption("Auto-save every"));
+                ui.add(egui::Slider::new(&mut autosave_interval, 0..=600).suffix(" s"));
+            });
+            if let Some(status) = &browser.status {
+                ui.label(TextStyle::caption(status));
+            }
+            let bindings = &settings.key_bindings;
+            ui.label(TextStyle::caption(format!(
+                "{} quick-save · {} quick-load",
+                key_label(bindings.key(GameAction::QuickSave)),
+                key_label(bindings.key(GameAction::QuickLoad)),
+            )));
+        });
+
+    if autosave_interval != settings.autosave_interval_secs {
+        settings.autosave_interval_secs = autosave_interval;
+    }
+
+    if let Some(name) = browser.confirm_overwrite.clone() {
+        egui::Window::new("Overwrite save?")
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, egui::Vec2::ZERO)
+            .frame(StyledPanel::popup())
+            .show(ctx, |ui| {
+                ui.label(TextStyle::body(format!("Overwrite '{}'?", name)));
+                if let Some(summary) = browser.index.slots.get(&name) {
+                    ui.label(TextStyle::caption(summary.describe()));
+                }
+                ui.label(
+                    TextStyle::caption("The game saved there will be lost.")
+                        .color(UiColors::WARNING),
+                );
+                Layout::small_space(ui);
+                ui.horizontal(|ui| {
+                    if StyledButton::danger(ui, "Overwrite").clicked() {
+                        to_save = Some(name.clone());
+                        browser.confirm_overwrite = None;
+                    }
+                    if StyledButton::small(ui, "Cancel").clicked() {
+                        browser.confirm_overwrite = None;
+                    }
+                });
+            });
+    }
+
+    browser.open = open;
+    if !open {
+        browser.confirm_overwrite = None;
+    }
+
+    if let Some(name) = to_save {
+        let game = params.snapshot(&name);
+        browser.status = Some(match write_slot(&dir, &game) {
+            Ok(_) => format!("Saved '{}'", game.name),
+            Err(e) => format!("Save failed: {}", e),
+        });
+        browser.needs_refresh = true;
+    }
+    if let Some(name) = to_delete {
+        if let Err(e) = delete_slot(&dir, &name) {
+            browser.status = Some(format!("Delete failed: {}", e));
+        }
+        browser.needs_refresh = true;
+    }
+    if let Some(name) = to_load_slot {
+        match read_slot(&dir, &name) {
+            Ok(game) => to_load = Some(game),
+            Err(e) => {
+                browser.status = Some(format!("Load failed: {}", e));
+                browser.needs_refresh = true;
+            }
+        }
+    }
+    if let Some(game) = to_load {
+        browser.open = false;
+        params.restore(game);
+    }
+    if let Some(fen) = to_start {
+        // The player moves first; the computer takes the other side.
+        let opponent = if browser.fen_vs_ai {
+            SavedOpponent::Ai {
+                ai_plays_white: fen.split_whitespace().nth(1) == Some("b"),
+                difficulty: params.ai_config.difficulty.to_u8(),
+                target_elo: params.ai_config.target_elo,
+                opening_book: None,
+            }
+        } else {
+            SavedOpponent::Local
+        };
+        browser.open = false;
+        params.restore(SavedGame::from_position(fen, opponent));
+    }
+}
+
+pub(crate) fn format_saved_at(timestamp: i64) -> String {
+    chrono::DateTime::from_timestamp(timestamp, 0)
+        .map(|dt| {
+            dt.with_timezone(&chrono::Local)
+                .format("%Y-%m-%d %H:%M")
+                .to_string()
+        })
+        .unwrap_or_else(|| "unknown time".to_string())
+}
+
+/// Draw a small board for the FEN's piece placement, White at the bottom.
+pub(crate) fn paint_fen_thumbnail(ui: &mut egui::Ui, fen: &str, size: f32) {
+    let (rect, _) = ui.allocate_exact_size(egui::vec2(size, size), egui::Sense::hover());
+    let painter = ui.painter_at(rect);
+    let sq = size / 8.0;
+    let light = egui::Color32::from_rgb(240, 217, 181);
+    let dark = egui::Color32::from_rgb(181, 136, 99);
+
+    for rank in 0..8 {
+        for file in 0..8 {
+            let min = rect.min + egui::vec2(file as f32 * sq, (7 - rank) as f32 * sq);
+            let color = if (rank + file) % 2 == 0 { dark } else { light };
+            painter.rect_filled(
+                egui::Rect::from_min_size(min, egui::vec2(sq, sq)),
+                0.0,
+                color,
+            );
+        }
+    }
+
+    let placement = fen.split_whitespace().next().unwrap_or("");
+    for (row, rank_str) in placement.split('/').enumerate().take(8) {
+        let mut file = 0usize;
+        for ch in rank_str.chars() {
+            if let Some(d) = ch.to_digit(10) {
+                file += d as usize;
+                continue;
+            }
+            if file < 8 {
+                let center =
+                    rect.min + egui::vec2((file as f32 + 0.5) * sq, (row as f32 + 0.5) * sq);
+                let (fill, text) = if ch.is_ascii_uppercase() {
+                    (egui::Color32::WHITE, egui::Color32::BLACK)
+                } else {
+                    (egui::Color32::BLACK, egui::Color32::WHITE)
+                };
+                painter.circle_filled(center, sq * 0.42, fill);
+                painter.text(
+                    center,
+                    egui::Align2::CENTER_CENTER,
+                    ch.to_ascii_uppercase(),
+                    egui::FontId::monospace(sq * 0.6),
+                    text,
+                );
+            }
+            file += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_saves_dir(tag: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!(
+            "xfchess_saves_{}_{}_{}",
+            tag,
+            std::process::id(),
+            chrono::Utc::now().timestamp_nanos_opt().unwrap_or_default()
+        ));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    fn sample_game(name: &str) -> SavedGame {
+        SavedGame {
+            version: SAVE_FORMAT_VERSION,
+            name: name.to_string(),
+            saved_at: 1_700_000_000,
+            fen: "rnbqkbnr/pppp1ppp/8/4p3/4P3/8/PPPP1PPP/RNBQKBNR w KQkq e6 0 2".to_string(),
+            moves: vec![
+                SavedMove {
+                    piece_type: PieceType::Pawn,
+                    white: true,
+                    from: (4, 1),
+                    to: (4, 3),
+                    captured: None,
+                    promotion: None,
+                    is_castling: false,
+                    is_en_passant: false,
+                    is_check: false,
+                    is_checkmate: false,
+                    san: "e4".to_string(),
+                },
+                SavedMove {
+                    piece_type: PieceType::Pawn,
+                    white: false,
+                    from: (4, 6),
+                    to: (4, 4),
+                    captured: None,
+                    promotion: None,
+                    is_castling: false,
+                    is_en_passant: false,
+                    is_check: false,
+                    is_checkmate: false,
+                    san: "e5".to_string(),
+                },
+            ],
+            white_captured: vec![],
+            black_captured: vec![PieceType::Knight],
+            white_time_left: 287.5,
+            black_time_left: f32::MAX,
+            increment: 2.0,
+            increment_mode: IncrementMode::Bronstein,
+            opponent: SavedOpponent::Ai {
+                ai_plays_white: false,
+                difficulty: 6,
+                target_elo: Some(1400),
+                opening_book: Some(false),
+            },
+            pieces: vec![
+                SavedPiece {
+                    square: (4, 0),
+                    moved: false,
+                    move_count: 0,
+                },
+                SavedPiece {
+                    square: (4, 3),
+                    moved: true,
+                    move_count: 1,
+                },
+            ],
+            start_fen: None,
+        }
+    }
+
+    #[test]
+    fn named_slot_round_trips_game_state() {
+        //! Saving to a named slot and loading it back yields the same game
+
+        let dir = temp_saves_dir("roundtrip");
+        let game = sample_game("Sicilian practice");
+
+        write_slot(&dir, &game).expect("write slot");
+        assert!(slot_exists(&dir, "Sicilian practice"));
+
+        let loaded = read_slot(&dir, "Sicilian practice").expect("read slot");
+        assert_eq!(loaded, game);
+        assert_eq!(loaded.side_to_move(), PieceColor::White);
+        assert_eq!(loaded.fullmove_number(), 2);
+        assert_eq!(loaded.moves[0].to_record().piece_color, PieceColor::White);
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn restore_puts_back_each_pieces_move_state() {
+        //! Saved move counts are restored per square; pieces the save doesn't cover fall back to the FEN's castling rights
+
+        use bevy::ecs::system::RunSystemOnce;
+
+        let mut app = App::new();
+        app.init_resource::<ChessEngine>()
+            .init_resource::<CurrentTurn>()
+            .init_resource::<MoveHistory>()
+            .init_resource::<CapturedPieces>()
+            .init_resource::<GameTimer>()
+            .insert_resource(PiecesSpawned { spawned: true })
+            .insert_resource(PendingSaveRestore(sample_game("restore")));
+        let pawn = app
+            .world_mut()
+            .spawn((
+                Piece::new(PieceColor::White, PieceType::Pawn, 4, 3),
+                HasMoved::default(),
+            ))
+            .id();
+        let rook = app
+            .world_mut()
+            .spawn((
+                Piece::new(PieceColor::White, PieceType::Rook, 7, 0),
+                HasMoved {
+                    moved: true,
+                    move_count: 0,
+                },
+            ))
+            .id();
+        app.world_mut()
+            .run_system_once(apply_pending_restore)
+            .unwrap();
+
+        let world = app.world();
+        let pawn = world.get::<HasMoved>(pawn).unwrap();
+        assert!(pawn.moved);
+        assert_eq!(pawn.move_count, 1);
+        assert!(!world.get::<HasMoved>(rook).unwrap().moved);
+        assert_eq!(world.resource::<MoveHistory>().len(), 2);
+        assert!(!world.contains_resource::<PendingSaveRestore>());
+    }
+
+    #[test]
+    fn corrupt_and_outdated_slots_are_detected() {
+        //! Unparseable files, bad FENs and unknown versions are listed as corrupt
+
+        let dir = temp_saves_dir("corrupt");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("garbage.json"), "{ not json").unwrap();
+
+        let mut bad_fen = sample_game("bad fen");
+        bad_fen.fen = "rnbqkbnr/pppppppp/8/8 w KQkq - 0 1".to_string();
+        write_slot(&dir, &bad_fen).unwrap();
+
+        let mut future = sample_game("future");
+        future.version = SAVE_FORMAT_VERSION + 1;
+        write_slot(&dir, &future).unwrap();
+
+        write_slot(&dir, &sample_game("good")).unwrap();
+
+        // A later version's layout doesn't parse here, but reads as a
+        // version mismatch rather than corruption.
+        fs::write(
+            dir.join("newer.json"),
+            r#"{ "version": 7, "board": { "squares": [] } }"#,
+        )
+        .unwrap();
+
+        assert!(matches!(
+            read_slot(&dir, "garbage"),
+            Err(SaveError::Corrupt(_))
+        ));
+        assert!(matches!(
+            read_slot(&dir, "bad fen"),
+            Err(SaveError::Corrupt(_))
+        ));
+        assert!(matches!(
+            read_slot(&dir, "future"),
+            Err(SaveError::Version { .. })
+        ));
+        assert!(matches!(
+            read_slot(&dir, "newer"),
+            Err(SaveError::Version { found: 7, .. })
+        ));
+
+        let slots = list_slots(&dir);
+        assert_eq!(slots.len(), 5);
+        assert_eq!(slots[0].name, "good");
+        assert!(slots[0].game.is_ok());
+        assert!(slots[1..].iter().all(|s| s.game.is_err()));
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn pasted_fens_are_tidied_or_refused() {
+        //! A four-field FEN gets its move counters and plays; malformed fields are refused as invalid FEN, not a panic
+
+        let fen = parse_position_fen("  8/8/8/3k4/3Pp3/8/8/4K3   b - d3 ").unwrap();
+        assert_eq!(fen, "8/8/8/3k4/3Pp3/8/8/4K3 b - d3 0 1");
+        let game = SavedGame::from_position(fen, SavedOpponent::Local);
+        assert!(game.validate().is_ok());
+        assert_eq!(game.start_fen.as_deref(), Some(game.fen.as_str()));
+        assert_eq!(game.side_to_move(), PieceColor::Black);
+        assert!(game.moves.is_empty());
+
+        for bad in [
+            "",
+            "8/8/8/3k4/8/8/8/4K3 w -",
+            "8/8/8/3k4/8/8/8/4K3 x - - 0 1",
+            "8/8/8/3k4/8/8/8/4K3 w KX - 0 1",
+            "8/8/8/3k4/8/8/8/4K3 w - e5 0 1",
+            "8/8/8/3k4/8/8/8/4K3 w - - zero 1",
+            "8/8/8/3k4/8/8/8/4K w - - 0 1",
+        ] {
+            assert!(
+                matches!(parse_position_fen(bad), Err(SaveError::InvalidFen(_))),
+                "{:?} was accepted",
+                bad
+            );
+        }
+        assert!(matches!(
+            parse_position_fen("8/8/8/8/8/8/8/4K3 w - - 0 1"),
+            Err(SaveError::IllegalPosition(_))
+        ));
+    }
+
+    #[test]
+    fn illegal_positions_are_rejected_with_the_reason() {
+        //! Two white kings, a pawn on the first rank, a side in check out of turn and a castling right with no rook each fail validation, naming the problem
+
+        let cases = [
+            ("4k3/8/8/8/8/8/8/3KK3 w - - 0 1", "White has 2 kings"),
+            ("4k3/8/8/8/8/8/8/P3K3 w - - 0 1", "pawn on the first rank"),
+            (
+                "4k3/8/8/8/8/8/8/4R1K1 w - - 0 1",
+                "Black is in check with White to move",
+            ),
+            (
+                "4k3/8/8/8/8/8/8/4K3 w K - 0 1",
+                "castling right 'K' without its king and rook at home",
+            ),
+        ];
+        for (fen, reason) in cases {
+            let mut game = sample_game("illegal");
+            game.fen = fen.to_string();
+            match game.validate() {
+                Err(SaveError::IllegalPosition(problems)) => {
+                    assert!(problems.contains(reason), "{}: {}", fen, problems)
+                }
+                other => panic!("{} was not rejected: {:?}", fen, other),
+            }
+        }
+
+        let mut checked = sample_game("checked");
+        checked.fen = "4k3/8/8/8/8/8/8/4R1K1 b - - 0 1".to_string();
+        assert!(
+            checked.validate().is_ok(),
+            "the side to move may be in check"
+        );
+    }
+
+    #[test]
+    fn slot_index_tracks_writes_and_deletes() {
+        //! Saving and deleting keep the index's summaries current, and a lost index is rebuilt from the slot files
+
+        let dir = temp_saves_dir("index");
+        let mut up_a_knight = sample_game(SAVE_SLOTS[0]);
+        up_a_knight.fen =
+            "r1bqkbnr/pppp1ppp/8/4p3/4P3/8/PPPP1PPP/RNBQKBNR b KQkq - 0 5".to_string();
+        write_slot(&dir, &up_a_knight).unwrap();
+        write_slot(&dir, &sample_game(SAVE_SLOTS[1])).unwrap();
+
+        let index = read_index(&dir);
+        assert_eq!(
+            index.slots[SAVE_SLOTS[0]],
+            SlotSummary {
+                move_number: 5,
+                white_to_move: false,
+                material_balance: 3,
+                saved_at: 1_700_000_000,
+            }
+        );
+        assert_eq!(index.slots[SAVE_SLOTS[1]].material_balance, 0);
+        assert!(!index.slots.contains_key(SAVE_SLOTS[2]));
+        assert_eq!(list_slots(&dir).len(), 2, "the index is not a slot");
+
+        delete_slot(&dir, SAVE_SLOTS[1]).unwrap();
+        let remaining = read_index(&dir);
+        assert_eq!(remaining.slots.len(), 1);
+
+        fs::remove_file(dir.join(INDEX_FILE)).unwrap();
+        assert_eq!(read_index(&dir), remaining);
+        assert!(dir.join(INDEX_FILE).exists());
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn slot_names_are_restricted_to_safe_file_names() {
+        //! Path separators and empty names are rejected before touching disk
+
+        let dir = temp_saves_dir("names");
+        assert!(matches!(
+            write_slot(&dir, &sample_game("../escape")),
+            Err(SaveError::InvalidName(_))
+        ));
+        assert!(matches!(
+            write_slot(&dir, &sample_game("   ")),
+            Err(SaveError::InvalidName(_))
+        ));
+        assert!(!dir.exists());
+    }
+}
@@ -0,0 +1,494 @@
+//! Saved analysis sessions ("studies").
+//!
+//! A study is a position worth coming back to: its FEN, the arrows and
+//! circled squares drawn on it, and free-form notes. Each one is a JSON file
+//! in a `studies/` directory next to the save slots, and follows the same
+//! rules as a slot: names are restricted to safe file names, writes go
+//! through a temporary file, and a file that fails to parse, has an unknown
+//! format version or a malformed or illegal FEN is listed as corrupt but
+//! never opened.
+//!
+//! The "Studies" window saves the position on the board with its marks —
+//! the 3D board's [`Annotations`] and the 2D board's [`BoardArrows`] — and
+//! the notes typed into it, and lists the studies on disk. Opening one
+//! starts a local game from its FEN and puts its marks back once the pieces
+//! are set up.
+
+use crate::core::GameState;
+use crate::game::annotations::{clear_annotations_on_move, Annotations};
+use crate::game::save::{
+    delete_named_file, format_saved_at, list_named_files, paint_fen_thumbnail, read_json_file,
+    slot_path, validate_fen_position, validate_fen_shape, write_json_file, PendingSaveRestore,
+    SaveError, SaveGameParams, SavedGame, SavedOpponent,
+};
+use crate::rendering::pieces::PiecesSpawned;
+use crate::ui::game::game_2d::BoardArrows;
+use crate::ui::styles::*;
+use bevy::prelude::*;
+use bevy_egui::{egui, EguiContexts, EguiPrimaryContextPass};
+use directories::ProjectDirs;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// Current on-disk study format. Bump when [`Study`] changes incompatibly.
+pub const STUDY_FORMAT_VERSION: u32 = 1;
+
+/// Colours an arrow or circled square can be drawn in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum MarkColor {
+    #[default]
+    Green,
+    Red,
+    Blue,
+    Yellow,
+}
+
+impl MarkColor {
+    /// Colour of a 2D board arrow's `color_kind` (see [`BoardArrows`]).
+    pub fn from_arrow_kind(kind: u8) -> Self {
+        match kind {
+            1 => Self::Red,
+            2 => Self::Blue,
+            _ => Self::Green,
+        }
+    }
+
+    /// The 2D board's `color_kind` closest to this colour; it has no yellow.
+    pub fn arrow_kind(self) -> u8 {
+        match self {
+            Self::Green => 0,
+            Self::Red | Self::Yellow => 1,
+            Self::Blue => 2,
+        }
+    }
+}
+
+/// An arrow between two squares, as `(file, rank)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StudyArrow {
+    pub from: (u8, u8),
+    pub to: (u8, u8),
+    pub color: MarkColor,
+}
+
+/// A circled square, as `(file, rank)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StudySquare {
+    pub square: (u8, u8),
+    pub color: MarkColor,
+}
+
+/// One study file's contents.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Study {
+    /// On-disk format version ([`STUDY_FORMAT_VERSION`] when written).
+    pub version: u32,
+    /// Study name (also the file stem).
+    pub name: String,
+    /// Unix timestamp (seconds) of when the study was written.
+    pub saved_at: i64,
+    /// Position being studied.
+    pub fen: String,
+    #[serde(default)]
+    pub arrows: Vec<StudyArrow>,
+    #[serde(default)]
+    pub squares: Vec<StudySquare>,
+    #[serde(default)]
+    pub notes: String,
+}
+
+impl Study {
+    /// A study of `fen` with no marks or notes yet, stamped now.
+    pub fn new(name: &str, fen: &str) -> Self {
+        Self {
+            version: STUDY_FORMAT_VERSION,
+            name: name.trim().to_string(),
+            saved_at: chrono::Utc::now().timestamp(),
+            fen: fen.to_string(),
+            arrows: Vec::new(),
+            squares: Vec::new(),
+            notes: String::new(),
+        }
+    }
+
+    /// Reject studies whose position could not be set up again.
+    pub fn validate(&self) -> Result<(), SaveError> {
+        if self.version != STUDY_FORMAT_VERSION {
+            return Err(SaveError::Version {
+                found: self.version,
+                expected: STUDY_FORMAT_VERSION,
+            });
+        }
+        validate_fen_shape(&self.fen).map_err(SaveError::Corrupt)?;
+        validate_fen_position(&self.fen).map_err(SaveError::IllegalPosition)?;
+        let on_board = |(file, rank): (u8, u8)| file < 8 && rank < 8;
+        let marks_on_board = self
+            .arrows
+            .iter()
+            .all(|a| on_board(a.from) && on_board(a.to))
+            && self.squares.iter().all(|s| on_board(s.square));
+        if !marks_on_board {
+            return Err(SaveError::Corrupt("mark off the board".to_string()));
+        }
+        Ok(())
+    }
+}
+
+/// One row of the study list: a readable study or the reason it isn't.
+#[derive(Debug, Clone)]
+pub struct StudyEntry {
+    pub name: String,
+    pub study: Result<Study, String>,
+}
+
+/// Directory holding the study files.
+///
+/// Falls back to a local `studies/` directory if the system data dir cannot
+/// be resolved.
+pub fn studies_dir() -> PathBuf {
+    ProjectDirs::from("com", "trilltino", "XFChess")
+        .map(|dirs| dirs.data_dir().join("studies"))
+        .unwrap_or_else(|| PathBuf::from("studies"))
+}
+
+/// Write `study`, replacing any previous study of the same name.
+pub fn write_study(dir: &Path, study: &Study) -> Result<PathBuf, SaveError> {
+    let path = slot_path(dir, &study.name)?;
+    write_json_file(&path, study)?;
+    Ok(path)
+}
+
+/// Read and validate a study.
+pub fn read_study(dir: &Path, name: &str) -> Result<Study, SaveError> {
+    let study: Study = read_json_file(&slot_path(dir, name)?)?;
+    study.validate()?;
+    Ok(study)
+}
+
+/// Remove a study file.
+pub fn delete_study(dir: &Path, name: &str) -> Result<(), SaveError> {
+    delete_named_file(dir, name)
+}
+
+/// List every study in `dir`, newest first; corrupt ones sort last.
+pub fn list_studies(dir: &Path) -> Vec<StudyEntry> {
+    list_named_files(dir, |name| read_study(dir, name), |study| study.saved_at)
+        .into_iter()
+        .map(|(name, study)| StudyEntry { name, study })
+        .collect()
+}
+
+/// State of the "Studies" window.
+#[derive(Resource, Default)]
+pub struct StudyBrowser {
+    pub open: bool,
+    /// Name typed into the "save as" field.
+    pub name_input: String,
+    /// Notes on the position, saved with the study.
+    pub notes: String,
+    /// Last save/open outcome, shown at the bottom of the window.
+    pub status: Option<String>,
+    studies: Vec<StudyEntry>,
+    needs_refresh: bool,
+}
+
+impl StudyBrowser {
+    /// Open the window and rescan the studies directory.
+    pub fn show(&mut self) {
+        self.open = true;
+        self.needs_refresh = true;
+    }
+}
+
+/// Marks of an opened study, waiting for its position to be set up.
+#[derive(Resource, Debug, Clone)]
+pub struct PendingStudyMarks {
+    pub arrows: Vec<StudyArrow>,
+    pub squares: Vec<StudySquare>,
+}
+
+/// Plugin wiring the "Studies" window.
+pub struct StudyPlugin;
+
+impl Plugin for StudyPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<StudyBrowser>()
+            .add_systems(
+                Update,
+                apply_pending_study_marks
+                    .after(clear_annotations_on_move)
+                    .run_if(in_state(GameState::InGame))
+                    .run_if(resource_exists::<PendingStudyMarks>)
+                    .run_if(not(resource_exists::<PendingSaveRestore>)),
+            )
+            .add_systems(
+                EguiPrimaryContextPass,
+                study_browser_ui
+                    .run_if(in_state(GameState::InGame).or(in_state(GameState::MainMenu))),
+            )
+            .add_systems(OnExit(GameState::InGame), close_study_browser);
+    }
+}
+
+/// Put an opened study's marks on the board once its game is restored.
+///
+/// Runs after [`clear_annotations_on_move`], which would otherwise take them
+/// straight off again for the restore's fresh move list.
+fn apply_pending_study_marks(
+    mut commands: Commands,
+    pending: Res<PendingStudyMarks>,
+    pieces_spawned: Res<PiecesSpawned>,
+    mut annotations: ResMut<Annotations>,
+    mut board_arrows: ResMut<BoardArrows>,
+) {
+    if !pieces_spawned.spawned {
+        return;
+    }
+    annotations.arrows = pending.arrows.clone();
+    annotations.squares = pending.squares.clone();
+    board_arrows.arrows = pending
+        .arrows
+        .iter()
+        .map(|a| (a.from.0, a.from.1, a.to.0, a.to.1, a.color.arrow_kind()))
+        .collect();
+    commands.remove_resource::<PendingStudyMarks>();
+}
+
+fn close_study_browser(mut browser: ResMut<StudyBrowser>) {
+    browser.open = false;
+}
+
+/// The "Studies" window: notes and "save as" for the position on the board
+/// (in a game), and the list of studies to open or delete.
+fn study_browser_ui(
+    mut contexts: EguiContexts,
+    mut browser: ResMut<StudyBrowser>,
+    state: Res<State<GameState>>,
+    annotations: Res<Annotations>,
+    board_arrows: Res<BoardArrows>,
+    mut params: SaveGameParams,
+) {
+    if !browser.open {
+        return;
+    }
+    let Ok(ctx) = contexts.ctx_mut() else {
+        return;
+    };
+    let dir = studies_dir();
+    if browser.needs_refresh {
+        browser.studies = list_studies(&dir);
+        browser.needs_refresh = false;
+    }
+
+    let in_game = *state.get() == GameState::InGame;
+    // Arrows from both boards, each once.
+    let mut arrows = annotations.arrows.clone();
+    for &(ff, fr, tf, tr, kind) in &board_arrows.arrows {
+        let (from, to) = ((ff, fr), (tf, tr));
+        if !arrows.iter().any(|a| a.from == from && a.to == to) {
+            arrows.push(StudyArrow {
+                from,
+                to,
+                color: MarkColor::from_arrow_kind(kind),
+            });
+        }
+    }
+    let mut open = browser.open;
+    let mut to_save: Option<String> = None;
+    let mut to_open: Option<Study> = None;
+    let mut to_delete: Option<String> = None;
+
+    egui::Window::new("Studies")
+        .open(&mut open)
+        .collapsible(false)
+        .resizable(false)
+        .fixed_size(egui::vec2(460.0, 540.0))
+        .anchor(egui::Align2::CENTER_CENTER, egui::Vec2::ZERO)
+        .frame(StyledPanel::popup())
+        .show(ctx, |ui| {
+            ui.label(TextStyle::popup_title("STUDIES"));
+            Layout::small_space(ui);
+
+            if in_game {
+                ui.label(TextStyle::body("Notes"));
+                ui.add(
+                    egui::TextEdit::multiline(&mut browser.notes)
+                        .hint_text("Ideas, plans, lines to remember…")
+                        .desired_rows(5)
+                        .desired_width(430.0),
+                );
+                ui.horizontal(|ui| {
+                    ui.add(
+                        egui::TextEdit::singleline(&mut browser.name_input)
+                            .hint_text("Study name")
+                            .desired_width(260.0),
+                    );
+                    if StyledButton::small(ui, "Save").clicked() {
+                        to_save = Some(browser.name_input.trim().to_string());
+                    }
+                });
+                ui.label(TextStyle::caption(format!(
+                    "Saves this position, its {} arrow(s) and {} circled square(s), and the notes.",
+                    arrows.len(),
+                    annotations.squares.len()
+                )));
+                Layout::item_space(ui);
+            }
+
+            egui::ScrollArea::vertical()
+                .max_height(260.0)
+                .show(ui, |ui| {
+                    if browser.studies.is_empty() {
+                        ui.label(TextStyle::caption("No studies yet."));
+                    }
+                    for entry in &browser.studies {
+                        StyledPanel::card().show(ui, |ui| {
+                            ui.set_width(410.0);
+                            ui.horizontal(|ui| match &entry.study {
+                                Ok(study) => {
+                                    paint_fen_thumbnail(ui, &study.fen, 72.0);
+                                    ui.vertical(|ui| {
+                                        ui.label(TextStyle::heading(&entry.name, TextSize::BODY));
+                                        ui.label(TextStyle::caption(format!(
+                                            "{} mark(s) · {}",
+                                            study.arrows.len() + study.squares.len(),
+                                            format_saved_at(study.saved_at)
+                                        )));
+                                        if let Some(line) = study.notes.lines().next() {
+                                            ui.label(
+                                                egui::RichText::new(line)
+                                                    .size(11.0)
+                                                    .color(UiColors::TEXT_TERTIARY),
+                                            );
+                                        }
+                                        ui.horizontal(|ui| {
+                                            if StyledButton::small(ui, "Open").clicked() {
+                                                to_open = Some(study.clone());
+                                            }
+                                            if StyledButton::small(ui, "Delete").clicked() {
+                                                to_delete = Some(entry.name.clone());
+                                            }
+                                        });
+                                    });
+                                }
+                                Err(reason) => {
+                                    ui.vertical(|ui| {
+                                        ui.label(TextStyle::heading(&entry.name, TextSize::BODY));
+                                        ui.label(
+                                            TextStyle::caption(format!("Corrupt: {}", reason))
+                                                .color(UiColors::DANGER),
+                                        );
+                                        if StyledButton::small(ui, "Delete").clicked() {
+                                            to_delete = Some(entry.name.clone());
+                                        }
+                                    });
+                                }
+                            });
+                        });
+                        Layout::small_space(ui);
+                    }
+                });
+
+            if let Some(status) = &browser.status {
+                Layout::small_space(ui);
+                ui.label(TextStyle::caption(status));
+            }
+        });
+    browser.open = open;
+
+    if let Some(name) = to_save {
+        let mut study = Study::new(&name, params.engine.current_fen());
+        study.arrows = arrows;
+        study.squares = annotations.squares.clone();
+        study.notes = browser.notes.clone();
+        browser.status = Some(
+            match study.validate().and_then(|_| write_study(&dir, &study)) {
+                Ok(_) => format!("Saved '{}'", study.name),
+                Err(e) => format!("Save failed: {}", e),
+            },
+        );
+        browser.needs_refresh = true;
+    }
+    if let Some(name) = to_delete {
+        if let Err(e) = delete_study(&dir, &name) {
+            browser.status = Some(format!("Delete failed: {}", e));
+        }
+        browser.needs_refresh = true;
+    }
+    if let Some(study) = to_open {
+        info!("[STUDY] Opening '{}' | FEN: {}", study.name, study.fen);
+        browser.open = false;
+        browser.name_input = study.name.clone();
+        browser.notes = study.notes.clone();
+        browser.status = None;
+        params.commands.insert_resource(PendingStudyMarks {
+            arrows: study.arrows,
+            squares: study.squares,
+        });
+        params.restore(SavedGame::from_position(study.fen, SavedOpponent::Local));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn study_round_trips_position_marks_and_notes() {
+        //! A study written to disk reads back with its position, arrows, circles and notes intact
+
+        let dir = std::env::temp_dir().join(format!(
+            "xfchess_studies_{}_{}",
+            std::process::id(),
+            chrono::Utc::now().timestamp_nanos_opt().unwrap_or_default()
+        ));
+        let mut study = Study::new(
+            "Najdorf ideas",
+            "rnbqkb1r/1p2pppp/p2p1n2/8/3NP3/2N5/PPP2PPP/R1BQKB1R w KQkq - 0 6",
+        );
+        study.arrows = vec![
+            StudyArrow {
+                from: (2, 0),
+                to: (6, 4),
+                color: MarkColor::Green,
+            },
+            StudyArrow {
+                from: (5, 0),
+                to: (2, 3),
+                color: MarkColor::Blue,
+            },
+        ];
+        study.squares = vec![StudySquare {
+            square: (3, 4),
+            color: MarkColor::Red,
+        }];
+        study.notes = "Bg5 pins the knight.\nd5 is the square to fight for.".to_string();
+
+        write_study(&dir, &study).expect("write study");
+        let loaded = read_study(&dir, "Najdorf ideas").expect("read study");
+        assert_eq!(loaded, study);
+
+        let mut off_board = study.clone();
+        off_board.name = "off board".to_string();
+        off_board.squares[0].square = (8, 2);
+        write_study(&dir, &off_board).unwrap();
+
+        let listed = list_studies(&dir);
+        assert_eq!(listed.len(), 2);
+        assert_eq!(listed[0].name, "Najdorf ideas");
+        assert!(listed[1].study.is_err());
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_2d_arrow_kinds_map_to_mark_colors() {
+        //! The 2D board's arrow kinds come back unchanged through a study's mark colours
+
+        for kind in 0..3 {
+            assert_eq!(MarkColor::from_arrow_kind(kind).arrow_kind(), kind);
+        }
+        assert_eq!(MarkColor::Yellow.arrow_kind(), MarkColor::Red.arrow_kind());
+    }
+}
@@ -0,0 +1,177 @@
+//! Debug-build board invariants
+//!
+//! The board is held twice — `Piece` entities in the ECS and the engine's
+//! bitboards, synced ECS → engine after each move. A capture that never
+//! takes the victim off its square, or a network move applied to only one
+//! side, leaves two pieces on one square; the engine then silently keeps
+//! whichever it iterated last. [`check_piece_square_invariant`] runs after
+//! every move in debug builds and reports any such clash.
+
+use crate::engine::board_state::ChessEngine;
+use crate::game::components::{Captured, FadingCapture, HasMoved, Piece, PieceColor};
+use crate::game::events::MoveMadeEvent;
+use bevy::prelude::*;
+
+/// Controls the post-move square invariant check (debug builds only).
+#[derive(Resource, Debug, Clone)]
+pub struct BoardInvariantCheck {
+    /// On a clash at the square just moved to, keep the mover's piece, drop
+    /// the stale one(s) as the missed capture and re-sync the engine.
+    pub auto_resync: bool,
+}
+
+impl Default for BoardInvariantCheck {
+    fn default() -> Self {
+        Self { auto_resync: true }
+    }
+}
+
+/// Two or more on-board pieces sharing one square.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SquareConflict {
+    pub square: (u8, u8),
+    pub entities: Vec<Entity>,
+}
+
+/// Every square held by more than one on-board piece. Pieces already moved
+/// off the board (coordinates past 7) are ignored.
+pub fn find_square_conflicts<'a>(
+    pieces: impl IntoIterator<Item = (Entity, &'a Piece)>,
+) -> Vec<SquareConflict> {
+    let mut occupants: [[Vec<Entity>; 8]; 8] = Default::default();
+    for (entity, piece) in pieces {
+        if piece.x > 7 || piece.y > 7 {
+            continue;
+        }
+        occupants[piece.x as usize][piece.y as usize].push(entity);
+    }
+
+    let mut conflicts = Vec::new();
+    for (x, file) in occupants.into_iter().enumerate() {
+        for (y, entities) in file.into_iter().enumerate() {
+            if entities.len() > 1 {
+                conflicts.push(SquareConflict {
+                    square: (x as u8, y as u8),
+                    entities,
+                });
+            }
+        }
+    }
+    conflicts
+}
+
+/// After each move, assert no two live `Piece` entities share a square.
+///
+/// Pieces mid-capture (`FadingCapture`) or in the tray (`Captured`) don't
+/// count. A clash is logged with the entities involved; with
+/// [`BoardInvariantCheck::auto_resync`] a clash on the destination square is
+/// repaired by treating the other pieces there as the capture that was missed.
+pub fn check_piece_square_invariant(
+    mut commands: Commands,
+    mut moves: MessageReader<MoveMadeEvent>,
+    mut pieces: Query<(Entity, &mut Piece, &mut HasMoved)>,
+    off_board: Query<(), Or<(With<FadingCapture>, With<Captured>)>>,
+    mut engine: ResMut<ChessEngine>,
+    check: Res<BoardInvariantCheck>,
+) {
+    let Some(last_move) = moves.read().last() else {
+        return;
+    };
+
+    let conflicts = find_square_conflicts(
+        pieces
+            .iter()
+            .filter(|(entity, _, _)| !off_board.contains(*entity))
+            .map(|(entity, piece, _)| (entity, piece)),
+    );
+    if conflicts.is_empty() {
+        return;
+    }
+
+    let mover = if last_move.player.eq_ignore_ascii_case("white") {
+        PieceColor::White
+    } else {
+        PieceColor::Black
+    };
+    let mut repaired = false;
+
+    for conflict in &conflicts {
+        let described: Vec<String> = conflict
+            .entities
+            .iter()
+            .filter_map(|e| pieces.get(*e).ok())
+            .map(|(e, p, _)| format!("{:?} {:?} {:?}", e, p.color, p.piece_type))
+            .collect();
+        error!(
+            "[INVARIANT] {} pieces on {} after {}: {}",
+            conflict.entities.len(),
+            ChessEngine::coords_to_uci(conflict.square.0, conflict.square.1),
+            last_move.player,
+            described.join(", ")
+        );
+
+        if !check.auto_resync || conflict.square != last_move.to {
+            continue;
+        }
+        for entity in &conflict.entities {
+            let Ok((_, mut piece, _)) = pieces.get_mut(*entity) else {
+                continue;
+            };
+            if piece.color != mover {
+                piece.x = u8::MAX;
+                piece.y = u8::MAX;
+                commands.entity(*entity).despawn();
+                repaired = true;
+            }
+        }
+    }
+
+    if repaired {
+        warn!("[INVARIANT] Dropped stale piece(s) and re-synced the engine from the board");
+        engine.sync_ecs_to_engine_mut(&mut pieces);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::components::PieceType;
+
+    #[test]
+    fn test_two_pieces_on_one_square_detected() {
+        //! A piece forced onto an occupied square is reported with both entities
+
+        let mut world = World::new();
+        let knight = world
+            .spawn(Piece::new(PieceColor::White, PieceType::Knight, 4, 3))
+            .id();
+        let pawn = world
+            .spawn(Piece::new(PieceColor::Black, PieceType::Pawn, 4, 3))
+            .id();
+        let bishop = world
+            .spawn(Piece::new(PieceColor::Black, PieceType::Bishop, 2, 5))
+            .id();
+        // Already taken off the board: must not count.
+        world.spawn(Piece::new(
+            PieceColor::Black,
+            PieceType::Rook,
+            u8::MAX,
+            u8::MAX,
+        ));
+        world.spawn(Piece::new(
+            PieceColor::White,
+            PieceType::Rook,
+            u8::MAX,
+            u8::MAX,
+        ));
+
+        let mut query = world.query::<(Entity, &Piece)>();
+        let conflicts = find_square_conflicts(query.iter(&world));
+
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].square, (4, 3));
+        assert!(conflicts[0].entities.contains(&knight));
+        assert!(conflicts[0].entities.contains(&pawn));
+        assert!(!conflicts[0].entities.contains(&bishop));
+    }
+}
@@ -34,6 +34,8 @@
 //! - Total War series camera controls - RTS standard
 
 use crate::core::states::GameMode;
+use crate::core::{GameAction, GameSettings, StartingView};
+use crate::game::annotations::Annotations;
 use crate::game::camera_modes::{
     CameraControlsDisabled, CameraViewMode, CinematicSequence, TransitionType,
 };
@@ -244,6 +246,25 @@ pub fn camera_zoom_input_system(
     }
 }
 
+/// Apply the zoom range from [`GameSettings::camera_zoom`] to the 3D board
+/// camera, pulling the zoom target back inside it. The 2D view keeps its own
+/// fixed range.
+pub fn sync_camera_zoom_limits(
+    settings: Res<GameSettings>,
+    view_mode: Res<crate::game::view_mode::ViewMode>,
+    mut query: Query<&mut CameraController, With<BoardCamera>>,
+) {
+    if *view_mode != crate::game::view_mode::ViewMode::Standard3D {
+        return;
+    }
+    let zoom = settings.camera_zoom;
+    for mut controller in query.iter_mut() {
+        controller.min_zoom = zoom.min;
+        controller.max_zoom = zoom.max.max(zoom.min);
+        controller.target_zoom = zoom.clamp(controller.target_zoom);
+    }
+}
+
 /// System that smoothly interpolates camera zoom to target level
 ///
 /// Adjusts the camera's Y position (height) to match the target zoom level
@@ -281,7 +302,7 @@ pub fn camera_zoom_system(mut query: Query<(&mut Transform, &mut CameraControlle
 /// 1. Get camera's forward and right vectors from Transform
 /// 2. Project vectors onto XZ plane (zero out Y component)
 /// 3. Normalize projected vectors to ensure consistent speed
-/// 4. Calculate movement direction based on WASD input
+/// 4. Calculate movement direction from the held camera keys (WASD by default)
 /// 5. Compute target position = current + (direction * speed * delta_time)
 /// 6. Smoothly interpolate current position toward target using lerp
 ///
@@ -293,6 +314,7 @@ pub fn camera_zoom_system(mut query: Query<(&mut Transform, &mut CameraControlle
 pub fn camera_movement_system(
     time: Res<Time>,
     keyboard: Res<ButtonInput<KeyCode>>,
+    settings: Res<GameSettings>,
     selection: Res<Selection>,
     mut query: Query<(&mut Transform, &CameraController)>,
 ) {
@@ -326,16 +348,17 @@ pub fn camera_movement_system(
         let right_xz = Vec3::new(right.x, 0.0, right.z).normalize_or_zero();
 
         // Accumulate movement direction based on pressed keys
-        if keyboard.pressed(KeyCode::KeyW) {
+        let bindings = &settings.key_bindings;
+        if bindings.pressed(&keyboard, GameAction::CameraForward) {
             direction += forward_xz;
         }
-        if keyboard.pressed(KeyCode::KeyS) {
+        if bindings.pressed(&keyboard, GameAction::CameraBack) {
             direction -= forward_xz;
         }
-        if keyboard.pressed(KeyCode::KeyD) {
+        if bindings.pressed(&keyboard, GameAction::CameraRight) {
             direction += right_xz;
         }
-        if keyboard.pressed(KeyCode::KeyA) {
+        if bindings.pressed(&keyboard, GameAction::CameraLeft) {
             direction -= right_xz;
         }
 
@@ -367,7 +390,8 @@ pub const RADIANS_PER_DOT: f32 = 1.0 / 180.0;
 /// - Uses `AccumulatedMouseMotion` (NOT multiplied by delta_time - already frame-accumulated)
 /// - Pitch is clamped to prevent gimbal lock (-PI/2 to PI/2)
 /// - Yaw wraps naturally at 2*PI
-/// - Right mouse button must be pressed to rotate
+/// - Right mouse button must be pressed to rotate, unless the drag started on
+///   the board, where it draws an annotation instead
 ///
 /// # Modern Pattern (Bevy 0.17+)
 ///
@@ -382,13 +406,15 @@ pub const RADIANS_PER_DOT: f32 = 1.0 / 180.0;
 pub fn camera_rotation_system(
     time: Res<Time>,
     keyboard: Res<ButtonInput<KeyCode>>,
+    settings: Res<GameSettings>,
     mouse_motion: Res<AccumulatedMouseMotion>,
     mouse_button: Res<ButtonInput<MouseButton>>,
     selection: Res<Selection>,
+    annotations: Option<Res<Annotations>>,
     mut query: Query<(&mut Transform, &mut CameraController)>,
 ) {
-    // Disable camera rotation while dragging a piece
-    if selection.is_dragging {
+    // Disable camera rotation while dragging a piece or drawing an annotation
+    if selection.is_dragging || annotations.is_some_and(|a| a.is_drawing()) {
         return;
     }
 
@@ -408,17 +434,23 @@ pub fn camera_rotation_system(
 
         let mut modified = false;
 
-        // Keyboard Rotation (Q/E)
-        // Q = Rotate Left (Increase Yaw)
-        // E = Rotate Right (Decrease Yaw)
+        // Keyboard Rotation (Q/E by default)
+        // Turn left = Increase Yaw
+        // Turn right = Decrease Yaw
         // Speed: 2.0 radians per second (adjust as needed)
         const KEYBOARD_ROTATION_SPEED: f32 = 2.0;
 
-        if keyboard.pressed(KeyCode::KeyQ) {
+        if settings
+            .key_bindings
+            .pressed(&keyboard, GameAction::CameraTurnLeft)
+        {
             controller.yaw += KEYBOARD_ROTATION_SPEED * time.delta_secs();
             modified = true;
         }
-        if keyboard.pressed(KeyCode::KeyE) {
+        if settings
+            .key_bindings
+            .pressed(&keyboard, GameAction::CameraTurnRight)
+        {
             controller.yaw -= KEYBOARD_ROTATION_SPEED * time.delta_secs();
             modified = true;
         }
@@ -449,13 +481,17 @@ pub fn camera_rotation_system(
 }
 
 /// Helper to determine if the camera should show the Black player's perspective
+///
+/// Local multiplayer auto-flips to the side to move, but only when a human is
+/// on move: on a computer's turn the view stays with the human side, so the
+/// board never turns towards the AI.
 pub fn get_is_black_view(
     players: &Players,
     current_turn: &CurrentTurn,
     game_mode: GameMode,
 ) -> bool {
     // In local multiplayer, flip based on whose turn it is
-    if game_mode == GameMode::MultiplayerLocal {
+    if game_mode == GameMode::MultiplayerLocal && players.current(current_turn.color).is_human {
         return current_turn.color == crate::rendering::pieces::PieceColor::Black;
     }
 
@@ -474,6 +510,103 @@ pub fn get_is_black_view(
     false
 }
 
+/// Which side of the board is nearest the player
+///
+/// Set when a game starts (Black at the bottom for a human playing Black, or
+/// as [`GameSettings::starting_view`] asks), turned every move in local
+/// two-player games, and flipped by hand with [`GameAction::FlipBoard`] or the
+/// Flip Board button. The 3D camera turns to match it and the 2D board is
+/// drawn from it.
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum BoardOrientation {
+    #[default]
+    WhiteBottom,
+    BlackBottom,
+}
+
+impl BoardOrientation {
+    pub fn from_black_view(black_view: bool) -> Self {
+        if black_view {
+            Self::BlackBottom
+        } else {
+            Self::WhiteBottom
+        }
+    }
+
+    pub fn is_black_bottom(self) -> bool {
+        self == Self::BlackBottom
+    }
+
+    /// The other way up.
+    pub fn flipped(self) -> Self {
+        Self::from_black_view(!self.is_black_bottom())
+    }
+
+    /// Camera yaw around the board centre for this side: 0 for White, π for
+    /// Black.
+    pub fn yaw(self) -> f32 {
+        if self.is_black_bottom() {
+            PI
+        } else {
+            0.0
+        }
+    }
+}
+
+/// Ask to turn the board round.
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FlipBoardRequest;
+
+/// Pick the side the new game is seen from. Runs on entering `InGame`, once
+/// the players are set up and before the camera is placed.
+pub fn reset_board_orientation(
+    players: Res<Players>,
+    current_turn: Res<CurrentTurn>,
+    game_mode: Res<GameMode>,
+    settings: Res<GameSettings>,
+    mut orientation: ResMut<BoardOrientation>,
+) {
+    let black_view = match settings.starting_view {
+        StartingView::WhiteSide => false,
+        StartingView::BlackSide => true,
+        StartingView::Angled | StartingView::TopDown => {
+            get_is_black_view(&players, &current_turn, *game_mode)
+        }
+    };
+    *orientation = BoardOrientation::from_black_view(black_view);
+}
+
+/// Send a [`FlipBoardRequest`] on the [`GameAction::FlipBoard`] key.
+pub fn flip_board_hotkey(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    settings: Res<GameSettings>,
+    mut requests: MessageWriter<FlipBoardRequest>,
+) {
+    if settings
+        .key_bindings
+        .just_pressed(&keyboard, GameAction::FlipBoard)
+    {
+        requests.write(FlipBoardRequest);
+    }
+}
+
+/// Turn the board round on request; the camera swings to the other side.
+pub fn apply_board_flip(
+    mut requests: MessageReader<FlipBoardRequest>,
+    mut orientation: ResMut<BoardOrientation>,
+    mut rotation_state: ResMut<CameraRotationState>,
+) {
+    // Two presses in one frame cancel out.
+    if requests.read().count() % 2 == 0 {
+        return;
+    }
+    *orientation = orientation.flipped();
+    rotation_state.target_yaw = orientation.yaw();
+    rotation_state.rotation_speed = CameraRotationState::DEFAULT_ROTATION_SPEED;
+    rotation_state.is_rotating = true;
+    info!("[CAMERA] Board flipped to {:?}", *orientation);
+}
+
 /// Resource tracking camera rotation state for turn-based rotation
 ///
 /// When a turn switches, the camera should rotate 180° around the board center
@@ -508,12 +641,20 @@ impl CameraRotationState {
 ///
 /// In local PvP mode, rotates camera 180° so each player sees the board from their side.
 /// In AI or online multiplayer mode, camera stays fixed on the human player's perspective.
+/// Nothing turns while the computer is thinking; a pending flip waits until
+/// its move has landed and a human is on move again.
 pub fn camera_rotate_on_turn_detection_system(
     current_turn: Res<CurrentTurn>,
     players: Res<Players>,
     game_mode: Res<GameMode>,
+    pending_ai: Option<Res<crate::game::ai::PendingAIMove>>,
     mut rotation_state: ResMut<CameraRotationState>,
+    mut orientation: ResMut<BoardOrientation>,
 ) {
+    if pending_ai.is_some() {
+        return;
+    }
+
     // Detect turn change or initial setup
     let turn_color = current_turn.color;
     if rotation_state.last_turn_color == Some(turn_color) {
@@ -525,13 +666,15 @@ pub fn camera_rotate_on_turn_detection_system(
 
     if is_local_pvp {
         // In local PvP, rotate camera every turn
-        rotation_state.target_yaw = if is_black_view { PI } else { 0.0 };
+        *orientation = BoardOrientation::from_black_view(is_black_view);
+        rotation_state.target_yaw = orientation.yaw();
         rotation_state.last_turn_color = Some(turn_color);
         rotation_state.rotation_speed = CameraRotationState::DEFAULT_ROTATION_SPEED;
         rotation_state.is_rotating = true;
     } else if rotation_state.last_turn_color.is_none() {
         // At start of game in other modes, rotate to human player's side
-        let target_yaw = if is_black_view { PI } else { 0.0 };
+        *orientation = BoardOrientation::from_black_view(is_black_view);
+        let target_yaw = orientation.yaw();
 
         rotation_state.target_yaw = target_yaw;
         rotation_state.last_turn_color = Some(turn_color);
@@ -550,80 +693,44 @@ pub fn camera_rotate_on_turn_detection_system(
 /// System that smoothly rotates camera around board center when turn switches
 ///
 /// Rotates the camera 180° around the Y-axis (board center) so each player
-/// sees the board from their perspective. Uses smooth interpolation for
-/// a cinematic rotation effect.
+/// sees the board from their perspective. Each frame turns the camera's
+/// position and facing by a share of the angle left, which eases it in.
 pub fn camera_rotate_on_turn_system(
     time: Res<Time>,
     mut rotation_state: ResMut<CameraRotationState>,
-    mut camera_query: Query<&mut Transform, (With<Camera3d>, With<CameraController>)>,
+    mut camera_query: Query<(&mut Transform, &mut CameraController), With<Camera3d>>,
 ) {
     if !rotation_state.is_rotating {
         return;
     }
 
-    // Calculate rotation delta
+    // Calculate rotation delta, normalized to the shortest path (-PI to PI)
     let delta_yaw = rotation_state.target_yaw - rotation_state.current_yaw;
+    let normalized_delta = (delta_yaw + PI).rem_euclid(2.0 * PI) - PI;
 
-    // Normalize delta to shortest path (-PI to PI range)
-    let normalized_delta = {
-        let mut d = delta_yaw;
-        while d > PI {
-            d -= 2.0 * PI;
-        }
-        while d < -PI {
-            d += 2.0 * PI;
-        }
-        d
-    };
-
-    // Check if we're close enough to target (within 0.01 radians)
-    if normalized_delta.abs() < 0.01 {
+    // Within 0.01 radians the rest of the turn is made at once
+    let step = if normalized_delta.abs() < 0.01 {
         rotation_state.current_yaw = rotation_state.target_yaw;
         rotation_state.is_rotating = false;
         info!(
             "[CAMERA] Rotation complete - yaw: {:.2}°",
             rotation_state.current_yaw.to_degrees()
         );
-        return;
-    }
-
-    // Smoothly interpolate toward target
-    let rotation_delta = normalized_delta * rotation_state.rotation_speed * time.delta_secs();
-    rotation_state.current_yaw += rotation_delta;
-
-    // Normalize current_yaw to 0-2PI range
-    rotation_state.current_yaw = rotation_state.current_yaw % (2.0 * PI);
-    if rotation_state.current_yaw < 0.0 {
-        rotation_state.current_yaw += 2.0 * PI;
-    }
+        normalized_delta
+    } else {
+        let step = normalized_delta * rotation_state.rotation_speed * time.delta_secs();
+        rotation_state.current_yaw = (rotation_state.current_yaw + step).rem_euclid(2.0 * PI);
+        step
+    };
 
-    // Apply rotation to all game cameras
-    for mut transform in camera_query.iter_mut() {
-        // Store original position relative to board center
+    // Turn every game camera by this frame's step around the board center
+    let turn = Quat::from_rotation_y(step);
+    for (mut transform, mut controller) in camera_query.iter_mut() {
         let relative_pos = transform.translation - CameraRotationState::BOARD_CENTER;
-
-        // Calculate total rotation from initial position
-        // Initial yaw is 0, so we rotate by current_yaw
-        let rotation_quat = Quat::from_rotation_y(rotation_state.current_yaw);
-
-        // Rotate position around board center
-        let rotated_pos = rotation_quat * relative_pos;
-
-        // Update position
-        transform.translation = CameraRotationState::BOARD_CENTER + rotated_pos;
-
-        // Update rotation to look at board center with the new yaw
-        // Extract current pitch from transform
-        let (_, current_pitch, _) = transform.rotation.to_euler(EulerRot::YXZ);
-
-        // Create new rotation with updated yaw and same pitch
-        // This maintains the viewing angle while rotating around the board
-        transform.rotation = Quat::from_euler(
-            EulerRot::YXZ,
-            rotation_state.current_yaw,
-            current_pitch,
-            0.0,
-        );
+        transform.translation = CameraRotationState::BOARD_CENTER + turn * relative_pos;
+        transform.rotation = turn * transform.rotation;
+        // Mouse look picks its pitch and yaw up again from the transform.
+        controller.initialized = false;
     }
 }
 
@@ -740,6 +847,145 @@ mod tests {
         assert_eq!(clamped, controller.max_zoom);
     }
 
+    #[test]
+    fn test_zoom_input_clamped_to_configured_max() {
+        //! Scrolling far out stops at the max zoom set in settings, not the controller default
+
+        use bevy::ecs::system::RunSystemOnce;
+
+        let mut world = World::new();
+        let mut settings = GameSettings::default();
+        settings.camera_zoom.max = 12.0;
+        world.insert_resource(settings);
+        world.init_resource::<crate::game::view_mode::ViewMode>();
+        let mut scroll = AccumulatedMouseScroll::default();
+        scroll.delta = Vec2::new(0.0, -100.0);
+        world.insert_resource(scroll);
+        let camera = world.spawn((CameraController::default(), BoardCamera)).id();
+
+        world
+            .run_system_once(sync_camera_zoom_limits)
+            .expect("system runs");
+        world
+            .run_system_once(camera_zoom_input_system)
+            .expect("system runs");
+
+        let controller = world.get::<CameraController>(camera).unwrap();
+        assert_eq!(controller.max_zoom, 12.0);
+        assert_eq!(controller.target_zoom, 12.0);
+    }
+
+    #[test]
+    fn test_auto_flip_only_turns_to_a_human_on_move() {
+        //! In hotseat with a computer side, the board faces the human on every turn and holds while the AI thinks
+
+        use crate::game::ai::PendingAIMove;
+        use crate::game::resources::player::Player;
+        use crate::rendering::pieces::PieceColor::{Black, White};
+        use bevy::ecs::system::RunSystemOnce;
+        use bevy::tasks::AsyncComputeTaskPool;
+
+        let mut world = World::new();
+        world.insert_resource(GameMode::MultiplayerLocal);
+        world.insert_resource(Players {
+            player_1: Player::new(1, "Human".to_string(), White, true),
+            player_2: Player::new(2, "AI".to_string(), Black, false),
+        });
+        world.init_resource::<CurrentTurn>();
+        world.init_resource::<CameraRotationState>();
+        world.init_resource::<BoardOrientation>();
+
+        let view_on = |world: &mut World, color| {
+            world.resource_mut::<CurrentTurn>().color = color;
+            world
+                .run_system_once(camera_rotate_on_turn_detection_system)
+                .expect("system runs");
+            let state = world.resource::<CameraRotationState>();
+            (state.target_yaw, state.last_turn_color)
+        };
+
+        assert_eq!(view_on(&mut world, White), (0.0, Some(White)));
+        // The AI is thinking: the turn change is not even looked at yet.
+        let pool = AsyncComputeTaskPool::get_or_init(Default::default);
+        world.insert_resource(PendingAIMove(pool.spawn(async { Err(String::new()) })));
+        assert_eq!(view_on(&mut world, Black), (0.0, Some(White)));
+        world.remove_resource::<PendingAIMove>();
+        // Its turn seen afterwards still faces the human, as does the next.
+        assert_eq!(view_on(&mut world, Black), (0.0, Some(Black)));
+        assert_eq!(view_on(&mut world, White), (0.0, Some(White)));
+
+        // Two humans keep flipping every turn.
+        world.resource_mut::<Players>().player_2.is_human = true;
+        assert_eq!(view_on(&mut world, Black), (PI, Some(Black)));
+        assert_eq!(
+            *world.resource::<BoardOrientation>(),
+            BoardOrientation::BlackBottom
+        );
+    }
+
+    #[test]
+    fn test_flip_board_swings_the_camera_to_the_other_side() {
+        //! Playing Black against the computer starts with Black at the bottom, and a flip turns the camera round the board centre to face it from White's side
+
+        use crate::game::resources::player::Player;
+        use crate::rendering::pieces::PieceColor;
+        use bevy::ecs::system::RunSystemOnce;
+        use std::time::Duration;
+
+        let mut world = World::new();
+        world.insert_resource(Players {
+            player_1: Player::new(1, "Computer".to_string(), PieceColor::White, false),
+            player_2: Player::new(2, "You".to_string(), PieceColor::Black, true),
+        });
+        world.init_resource::<CurrentTurn>();
+        world.insert_resource(GameMode::SinglePlayer);
+        world.init_resource::<GameSettings>();
+        world.init_resource::<BoardOrientation>();
+        world.init_resource::<CameraRotationState>();
+        world.init_resource::<Time>();
+        world.init_resource::<Messages<FlipBoardRequest>>();
+
+        world.run_system_once(reset_board_orientation).unwrap();
+        assert_eq!(
+            *world.resource::<BoardOrientation>(),
+            BoardOrientation::BlackBottom
+        );
+        world.resource_mut::<CameraRotationState>().current_yaw = PI;
+        let center = CameraRotationState::BOARD_CENTER;
+        let camera = world
+            .spawn((
+                Camera3d::default(),
+                Transform::from_xyz(3.5, 12.0, 15.0).looking_at(center, Vec3::Y),
+                CameraController::default(),
+            ))
+            .id();
+
+        world.write_message(FlipBoardRequest);
+        world.run_system_once(apply_board_flip).unwrap();
+        assert_eq!(
+            *world.resource::<BoardOrientation>(),
+            BoardOrientation::WhiteBottom
+        );
+        for _ in 0..100 {
+            world
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_millis(50));
+            world.run_system_once(camera_rotate_on_turn_system).unwrap();
+        }
+        assert!(!world.resource::<CameraRotationState>().is_rotating);
+
+        let transform = world.get::<Transform>(camera).unwrap();
+        assert!(
+            transform
+                .translation
+                .abs_diff_eq(Vec3::new(3.5, 12.0, -8.0), 0.01),
+            "{:?}",
+            transform.translation
+        );
+        let to_center = (center - transform.translation).normalize();
+        assert!(transform.forward().dot(to_center) > 0.999);
+    }
+
     #[test]
     fn test_zoom_direction_scroll_up() {
         //! Scroll wheel up should decrease target (zoom in / lower camera)
@@ -853,10 +1099,63 @@ mod tests {
 
         assert_eq!(target_zoom, 13.0);
     }
+
+    #[test]
+    fn test_top_down_starting_view_spawns_the_top_down_preset() {
+        //! With the top-down starting view the board camera spawns at the top-down preset, from the human's side
+
+        use crate::game::resources::player::Player;
+        use crate::rendering::pieces::PieceColor;
+        use bevy::ecs::system::RunSystemOnce;
+
+        let mut world = World::new();
+        world.init_resource::<crate::PersistentEguiCamera>();
+        world.init_resource::<crate::game::view_mode::ViewMode>();
+        world.insert_resource(Players {
+            player_1: Player::new(1, "Computer".to_string(), PieceColor::White, false),
+            player_2: Player::new(2, "You".to_string(), PieceColor::Black, true),
+        });
+        world.init_resource::<CurrentTurn>();
+        world.insert_resource(GameMode::SinglePlayer);
+        world.init_resource::<CameraRotationState>();
+        world.init_resource::<BoardOrientation>();
+        world.insert_resource(GameSettings {
+            starting_view: StartingView::TopDown,
+            ..default()
+        });
+
+        world.run_system_once(reset_board_orientation).unwrap();
+        world.run_system_once(setup_game_camera).unwrap();
+
+        let transform = *world
+            .query_filtered::<&Transform, With<BoardCamera>>()
+            .single(&world)
+            .expect("board camera spawned");
+        assert_eq!(transform, top_down_preset_transform(true));
+    }
+}
+
+/// Height of the near-overhead camera presets.
+const TOP_DOWN_HEIGHT: f32 = 14.0;
+
+/// Transform of the near-overhead preset ([`CameraViewMode::TopDownWhite`],
+/// or [`CameraViewMode::TopDownBlack`] when `from_black`). It sits a little
+/// behind the back rank, so the near rank is at the bottom.
+pub fn top_down_preset_transform(from_black: bool) -> Transform {
+    let board_center = Vec3::new(3.5, 0.0, 3.5);
+    let z_behind = 2.0;
+    let z = if from_black {
+        7.0 + z_behind
+    } else {
+        -z_behind
+    };
+    Transform::from_translation(Vec3::new(3.5, TOP_DOWN_HEIGHT, z))
+        .looking_at(board_center, Vec3::Y)
 }
 
 /// Configure the dedicated board camera for gameplay, spawning it on first
-/// use. Also demotes the persistent camera to UI-only for the duration of
+/// use. The 3D view starts from [`GameSettings::starting_view`].
+/// Also demotes the persistent camera to UI-only for the duration of
 /// `GameState::InGame` (higher `order`, transparent clear) so it no longer
 /// competes with the board camera for the same pixels — see [`BoardCamera`].
 pub fn setup_game_camera(
@@ -865,10 +1164,10 @@ pub fn setup_game_camera(
     view_mode: Res<crate::game::view_mode::ViewMode>,
     mut ui_cam_query: Query<&mut Camera, Without<BoardCamera>>,
     mut board_cam_query: Query<(Entity, &mut Transform, &mut Camera), With<BoardCamera>>,
-    players: Res<Players>,
     current_turn: Res<CurrentTurn>,
-    game_mode: Res<GameMode>,
+    orientation: Res<BoardOrientation>,
     mut rotation_state: ResMut<CameraRotationState>,
+    settings: Res<GameSettings>,
 ) {
     // Only configure for standard views (TempleOS handles its own camera/view)
     if view_mode.is_templeos() {
@@ -876,22 +1175,18 @@ pub fn setup_game_camera(
     }
 
     let is_2d = *view_mode == crate::game::view_mode::ViewMode::Standard2D;
-    let is_black_view = get_is_black_view(&players, &current_turn, *game_mode);
+    let is_black_view = orientation.is_black_bottom();
     let board_center = Vec3::new(3.5, 0.0, 3.5);
 
     // Pre-initialize CameraRotationState to the correct side so the rotation
     // system doesn't animate from the wrong position on game start.
-    let initial_yaw = if is_black_view {
-        std::f32::consts::PI
-    } else {
-        0.0
-    };
+    let initial_yaw = orientation.yaw();
     rotation_state.current_yaw = initial_yaw;
     rotation_state.target_yaw = initial_yaw;
     rotation_state.is_rotating = false;
     rotation_state.last_turn_color = Some(current_turn.color);
 
-    let initial_height = 16.0;
+    let initial_height = settings.camera_zoom.initial_height();
     let distance_behind = 8.0;
 
     let xz_dist = distance_behind + 3.5;
@@ -912,6 +1207,8 @@ pub fn setup_game_camera(
             Vec3::new(3.5, height, -z_behind) // behind white's rank 1
         };
         Transform::from_translation(camera_pos_2d).looking_at(board_center, Vec3::Y)
+    } else if settings.starting_view == StartingView::TopDown {
+        top_down_preset_transform(is_black_view)
     } else {
         Transform::from_translation(camera_pos).looking_at(board_center, Vec3::Y)
     };
@@ -958,8 +1255,12 @@ pub fn setup_game_camera(
     commands.entity(board_entity).insert(CameraController {
         current_zoom: new_transform.translation.y,
         target_zoom: new_transform.translation.y,
-        min_zoom: if is_2d { 5.0 } else { 3.0 },
-        max_zoom: if is_2d { 20.0 } else { 30.0 },
+        min_zoom: if is_2d { 5.0 } else { settings.camera_zoom.min },
+        max_zoom: if is_2d {
+            20.0
+        } else {
+            settings.camera_zoom.max.max(settings.camera_zoom.min)
+        },
         initialized: false,
         ..Default::default()
     });
@@ -1022,25 +1323,27 @@ pub fn sync_board_camera_viewport(
     });
 }
 
-/// System to reset camera to default "Standard Perspective" when 'N' is pressed
+/// System to reset camera to default "Standard Perspective" on the
+/// [`GameAction::ResetCamera`] key ('N' by default)
 pub fn camera_reset_system(
     keyboard: Res<ButtonInput<KeyCode>>,
-    players: Res<Players>,
-    current_turn: Res<CurrentTurn>,
-    game_mode: Res<GameMode>,
+    orientation: Res<BoardOrientation>,
+    settings: Res<GameSettings>,
     mut query: Query<(&mut Transform, &mut CameraController)>,
 ) {
-    if keyboard.just_pressed(KeyCode::KeyN) {
-        // Player color detection enabled
-        let is_black_view = get_is_black_view(&players, &current_turn, *game_mode);
+    if settings
+        .key_bindings
+        .just_pressed(&keyboard, GameAction::ResetCamera)
+    {
+        let is_black_view = orientation.is_black_bottom();
 
         for (mut transform, mut controller) in query.iter_mut() {
             // Standard Perspective defaults
             // Position camera along X-axis for proper chess board orientation
-            let initial_height = 16.0;
+            let initial_height = settings.camera_zoom.initial_height();
             let distance_behind = 8.0;
             let board_center = Vec3::new(3.5, 0.0, 3.5);
-            let default_zoom = 16.0;
+            let default_zoom = initial_height;
 
             let default_pos = if is_black_view {
                 // Black view: camera on +Z side looking toward -Z
@@ -1065,7 +1368,8 @@ pub fn camera_reset_system(
     }
 }
 
-/// System to handle 'V' key for toggling view mode during gameplay
+/// System to handle the [`GameAction::ToggleView`] key ('V' by default) for
+/// toggling view mode during gameplay
 pub fn view_mode_toggle_input_system(
     keyboard: Res<ButtonInput<KeyCode>>,
     mut view_mode: ResMut<crate::game::view_mode::ViewMode>,
@@ -1073,12 +1377,15 @@ pub fn view_mode_toggle_input_system(
     persistent_camera: Res<crate::PersistentEguiCamera>,
     ui_cam_query: Query<&mut Camera, Without<BoardCamera>>,
     board_cam_query: Query<(Entity, &mut Transform, &mut Camera), With<BoardCamera>>,
-    players: Res<Players>,
     current_turn: Res<CurrentTurn>,
-    game_mode: Res<GameMode>,
+    orientation: Res<BoardOrientation>,
     rotation_state: ResMut<CameraRotationState>,
+    settings: Res<GameSettings>,
 ) {
-    if keyboard.just_pressed(KeyCode::KeyV) {
+    if settings
+        .key_bindings
+        .just_pressed(&keyboard, GameAction::ToggleView)
+    {
         view_mode.toggle();
         info!("[VIEW] Toggled view mode to {:?}", *view_mode);
 
@@ -1088,26 +1395,29 @@ pub fn view_mode_toggle_input_system(
             view_mode.into(),
             ui_cam_query,
             board_cam_query,
-            players,
             current_turn,
-            game_mode,
+            orientation,
             rotation_state,
+            settings,
         );
     }
 }
 
-/// System to cycle through camera view modes with 'R' key
+/// System to cycle through camera view modes with the
+/// [`GameAction::CycleCameraMode`] key ('R' by default)
 pub fn camera_mode_cycle_system(
     keyboard: Res<ButtonInput<KeyCode>>,
     mut camera_view_mode: ResMut<CameraViewMode>,
     mut cinematic_sequence: ResMut<CinematicSequence>,
     mut commands: Commands,
     mut query: Query<(Entity, &mut Transform, &mut CameraController), With<Camera3d>>,
-    players: Res<Players>,
-    current_turn: Res<CurrentTurn>,
-    game_mode: Res<GameMode>,
+    orientation: Res<BoardOrientation>,
+    settings: Res<GameSettings>,
 ) {
-    if keyboard.just_pressed(KeyCode::KeyR) {
+    if settings
+        .key_bindings
+        .just_pressed(&keyboard, GameAction::CycleCameraMode)
+    {
         let next_mode = camera_view_mode.next();
         *camera_view_mode = next_mode;
         info!("[CAMERA_MODE] Switched to {:?}", next_mode);
@@ -1125,26 +1435,18 @@ pub fn camera_mode_cycle_system(
                 match next_mode {
                     CameraViewMode::TopDownWhite => {
                         // Nearly overhead from white's side — rank 1 at bottom, a–h left to right
-                        let height = 14.0;
-                        let z_behind = 2.0;
-                        let translation = Vec3::new(3.5, height, -z_behind);
-                        *transform = Transform::from_translation(translation)
-                            .looking_at(board_center, Vec3::Y);
-                        controller.target_zoom = height;
-                        controller.current_zoom = height;
+                        *transform = top_down_preset_transform(false);
+                        controller.target_zoom = TOP_DOWN_HEIGHT;
+                        controller.current_zoom = TOP_DOWN_HEIGHT;
                         commands
                             .entity(camera_entity)
                             .remove::<CameraControlsDisabled>();
                     }
                     CameraViewMode::TopDownBlack => {
                         // Nearly overhead from black's side — rank 8 at bottom, h–a left to right
-                        let height = 14.0;
-                        let z_behind = 2.0;
-                        let translation = Vec3::new(3.5, height, 7.0 + z_behind);
-                        *transform = Transform::from_translation(translation)
-                            .looking_at(board_center, Vec3::Y);
-                        controller.target_zoom = height;
-                        controller.current_zoom = height;
+                        *transform = top_down_preset_transform(true);
+                        controller.target_zoom = TOP_DOWN_HEIGHT;
+                        controller.current_zoom = TOP_DOWN_HEIGHT;
                         commands
                             .entity(camera_entity)
                             .remove::<CameraControlsDisabled>();
@@ -1154,8 +1456,7 @@ pub fn camera_mode_cycle_system(
                         let height = 14.0;
                         let distance = 6.0;
 
-                        // Determine player color for orientation
-                        let is_black_view = get_is_black_view(&players, &current_turn, *game_mode);
+                        let is_black_view = orientation.is_black_bottom();
 
                         let camera_pos = if is_black_view {
                             Vec3::new(3.5, height, 7.0 + distance)
@@ -1176,7 +1477,7 @@ pub fn camera_mode_cycle_system(
                         let initial_height = 16.0;
                         let distance_behind = 8.0;
 
-                        let is_black_view = get_is_black_view(&players, &current_turn, *game_mode);
+                        let is_black_view = orientation.is_black_bottom();
 
                         let camera_pos = if is_black_view {
                             Vec3::new(3.5, initial_height, 7.0 + distance_behind)
@@ -83,6 +83,8 @@ pub fn reset_game_resources(
     mut turn_context: ResMut<TurnStateContext>,
     mut engine: ResMut<ChessEngine>,
     active_tc: Res<crate::game::resources::active_time_control::ActiveTimeControl>,
+    settings: Res<crate::core::GameSettings>,
+    game_mode: Res<crate::core::GameMode>,
 ) {
     info!("[GAME_INIT] Resetting all game resources for new game");
 
@@ -111,12 +113,21 @@ pub fn reset_game_resources(
     // Reset timer from the chosen time control; start is deferred until pieces are present.
     let base = active_tc.control.base_seconds() as f32;
     let inc = active_tc.control.increment_seconds() as f32;
+    // Networked clocks must agree with the opponent's, so only offline games
+    // honour the player's increment mode.
+    let mode = match *game_mode {
+        crate::core::GameMode::OnlineMultiplayer
+        | crate::core::GameMode::MultiplayerCompetitive => crate::core::IncrementMode::Fischer,
+        _ => settings.increment_mode,
+    };
     *game_timer = if base > 0.0 {
         GameTimer {
             white_time_left: base,
             black_time_left: base,
             increment: inc,
             is_running: false,
+            mode,
+            move_elapsed: 0.0,
         }
     } else {
         GameTimer {
@@ -124,11 +135,12 @@ pub fn reset_game_resources(
             black_time_left: f32::MAX,
             increment: 0.0,
             is_running: false,
+            ..Default::default()
         }
     };
     info!(
-        "[GAME_INIT] Timer reset: {}s per player (+{}s inc), waiting for pieces",
-        game_timer.white_time_left, game_timer.increment
+        "[GAME_INIT] Timer reset: {}s per player (+{}s {:?}), waiting for pieces",
+        game_timer.white_time_left, game_timer.increment, game_timer.mode
     );
 
     // Clear captured pieces
@@ -157,6 +169,7 @@ pub fn reset_game_resources(
         .insert(bevy::camera::visibility::RenderLayers::layer(
             crate::game::systems::camera::BOARD_LAYER,
         ))
+        .insert(crate::rendering::graphics_quality::ShadowCastingLight) // follows the Shadows setting
         .insert(Name::new("Overhead Light")); // Helpful for debugging
     info!(
         "[GAME_INIT] Turn state context reset: {:?}",
@@ -211,6 +224,16 @@ pub fn initialize_players(
             "[GAME_INIT] OnlineMultiplayer players initialized: local={:?}",
             my_color
         );
+    } else if let crate::game::ai::GameMode::AIvsAI { white, black } = ai_config.mode {
+        *players = Players {
+            player_1: Player::new(1, "AI".to_string(), PieceColor::White, false),
+            player_2: Player::new(2, "AI".to_string(), PieceColor::Black, false),
+        };
+        info!(
+            "[GAME_INIT] AI vs AI players initialized: {} vs {}",
+            white.description(),
+            black.description()
+        );
     } else {
         // VsAI mode: One human, one AI
         let ai_color = ai_config.mode.ai_color();
@@ -311,6 +334,7 @@ pub fn warmup_game_audio(
                 volume: bevy::audio::Volume::Linear(0.0),
                 ..default()
             },
+            crate::presentation::audio::SfxChannel,
         ));
     }
 }
@@ -12,10 +12,13 @@
 //! rendering changes.
 
 use crate::engine::board_state::ChessEngine;
-use crate::game::components::{FadingCapture, GamePhase, PieceMoveAnimation};
+use crate::game::components::{
+    CaptureFlight, FadingCapture, GamePhase, PieceMoveAnimation, PromotionAnimation,
+};
 use crate::game::resources::*;
 use crate::rendering::pieces::PieceColor;
 use bevy::prelude::*;
+use nimzovich_engine::GameStatus;
 
 /// System to update game phase (check, checkmate, etc.)
 ///
@@ -23,6 +26,10 @@ use bevy::prelude::*;
 /// - **Check**: King is under attack
 /// - **Checkmate**: King is under attack with no legal moves
 /// - **Stalemate**: No legal moves available but not in check
+/// - **Draws**: fifty-move rule, threefold repetition, insufficient material
+///
+/// Every ending comes from [`ChessEngine::game_status`], so the engine is the
+/// one place the rules live.
 ///
 /// # Execution Order
 ///
@@ -87,22 +94,18 @@ pub fn update_game_phase(
     }
 
     let in_check = engine.is_check();
-    let has_legal_moves = engine.has_legal_moves();
+    let status = engine.game_status();
 
-    if !has_legal_moves && in_check {
-        // Checkmate
-        game_phase.0 = GamePhase::Checkmate;
-        *game_over = match current_turn.color {
-            PieceColor::White => GameOverState::BlackWon,
-            PieceColor::Black => GameOverState::WhiteWon,
-        };
-        info!("[GAME] ========== CHECKMATE! ==========");
-        info!("[GAME] {:?} is in checkmate!", current_turn.color);
-    } else if !has_legal_moves {
-        // Stalemate
-        game_phase.0 = GamePhase::Stalemate;
-        *game_over = GameOverState::Stalemate;
-        info!("[GAME] ========== STALEMATE! ==========");
+    if let Some(result) = board_result(status, current_turn.color) {
+        *game_over = result;
+        if status == GameStatus::Checkmate {
+            game_phase.0 = GamePhase::Checkmate;
+            info!("[GAME] ========== CHECKMATE! ==========");
+            info!("[GAME] {:?} is in checkmate!", current_turn.color);
+        } else {
+            game_phase.0 = GamePhase::Stalemate;
+            info!("[GAME] ========== DRAW: {} ==========", game_over.message());
+        }
     } else if in_check {
         if previous_phase != GamePhase::Check {
             game_phase.0 = GamePhase::Check;
@@ -119,6 +122,26 @@ pub fn update_game_phase(
     }
 }
 
+/// The result an on-board ending gives, with `to_move` the side to move.
+///
+/// `status` already carries the priority between endings that hold at once
+/// (checkmate, stalemate, threefold, fifty-move, insufficient material; see
+/// [`nimzovich_engine::get_game_status`]), so this is a plain mapping and the
+/// only place a board ending becomes a [`GameOverState`].
+pub fn board_result(status: GameStatus, to_move: PieceColor) -> Option<GameOverState> {
+    Some(match status {
+        GameStatus::Playing => return None,
+        GameStatus::Checkmate => match to_move {
+            PieceColor::White => GameOverState::BlackWon,
+            PieceColor::Black => GameOverState::WhiteWon,
+        },
+        GameStatus::Stalemate => GameOverState::Stalemate,
+        GameStatus::ThreefoldRepetition => GameOverState::ThreefoldRepetition,
+        GameStatus::FiftyMoveRule => GameOverState::FiftyMoveRule,
+        GameStatus::InsufficientMaterial => GameOverState::InsufficientMaterial,
+    })
+}
+
 /// System to update game timer with Fischer increment support
 ///
 /// Decrements the current player's time each frame and checks for timeout.
@@ -166,7 +189,7 @@ pub fn update_game_timer(
     match current_turn.color {
         PieceColor::White => {
             let time_before = timer.white_time_left;
-            timer.white_time_left -= delta;
+            timer.consume(PieceColor::White, delta);
 
             // Log time warnings
             if time_before > 10.0 && timer.white_time_left <= 10.0 {
@@ -204,7 +227,7 @@ pub fn update_game_timer(
         }
         PieceColor::Black => {
             let time_before = timer.black_time_left;
-            timer.black_time_left -= delta;
+            timer.consume(PieceColor::Black, delta);
 
             // Log time warnings
             if time_before > 10.0 && timer.black_time_left <= 10.0 {
@@ -253,13 +276,16 @@ pub fn check_game_over_state(
     mut next_state: ResMut<NextState<crate::core::GameState>>,
     animations: Query<(), (With<PieceMoveAnimation>, Without<FadingCapture>)>,
     fades: Query<(), With<FadingCapture>>,
+    effects: Query<(), Or<(With<CaptureFlight>, With<PromotionAnimation>)>>,
 ) {
     // Only transition if we are currently InGame and the game is effectively over
-    // Wait for active animations and capture fades to finish so the final move is visible
+    // Wait for active animations, capture fades and flights, and a promotion's
+    // flourish to finish so the final move is visible
     if *state.get() == crate::core::GameState::InGame
         && game_over.is_game_over()
         && animations.is_empty()
         && fades.is_empty()
+        && effects.is_empty()
     {
         info!(
             "[GAME] Game over condition met ({:?}) - transitioning to GameOver state",
@@ -268,3 +294,112 @@ pub fn check_game_over_state(
         next_state.set(crate::core::GameState::GameOver);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::test_support::GameHarness;
+
+    #[test]
+    fn test_mate_on_fiftieth_move_is_checkmate() {
+        //! Mating with a quiet move that brings the halfmove clock to 100 is a win, not a draw
+
+        // After Qf1-f8# from a position with the clock on 99
+        let mut engine = ChessEngine::default();
+        engine
+            .set_from_fen("5Q1k/8/6K1/8/8/8/8/8 b - - 100 80")
+            .unwrap();
+
+        assert_eq!(
+            board_result(engine.game_status(), PieceColor::Black),
+            Some(GameOverState::WhiteWon)
+        );
+    }
+
+    #[test]
+    fn test_queen_move_that_leaves_no_reply_is_stalemate() {
+        //! Qf7 takes every square from the cornered king without checking it, so the game is drawn by stalemate
+
+        let mut game = GameHarness::builder()
+            .fen("7k/8/6K1/8/2Q5/8/8/8 w - - 0 1")
+            .build();
+        assert!(game.play("c4f7"));
+        assert_eq!(*game.resource::<GameOverState>(), GameOverState::Stalemate);
+        assert_eq!(game.resource::<CurrentGamePhase>().0, GamePhase::Stalemate);
+    }
+
+    #[test]
+    fn test_capture_down_to_king_and_bishop_is_a_draw() {
+        //! Taking Black's last rook leaves king and bishop against king, which ends the game at once
+
+        let mut game = GameHarness::builder()
+            .fen("4k3/8/8/8/8/8/3r4/3BK3 w - - 0 50")
+            .build();
+        assert!(game.play("e1d2"));
+        assert_eq!(
+            *game.resource::<GameOverState>(),
+            GameOverState::InsufficientMaterial
+        );
+    }
+
+    #[test]
+    fn test_repetition_counts_castling_rights() {
+        //! Kings walking out and back draw by repetition, but the start, which still had castling rights, isn't one of the repeats
+
+        let mut game = GameHarness::builder()
+            .fen("r3k2r/pppppppp/8/8/8/8/PPPPPPPP/R3K2R w KQkq - 0 1")
+            .build();
+        let shuffle = ["e1f1", "e8f8", "f1e1", "f8e8"];
+        // Without the rights in the hash the start would repeat on ply 8;
+        // the first true threefold is the position after ...Kf8 on ply 10.
+        for (ply, uci) in shuffle.iter().cycle().take(10).enumerate() {
+            assert!(game.play(uci), "{} not played", uci);
+            let expected = if ply == 9 {
+                GameOverState::ThreefoldRepetition
+            } else {
+                GameOverState::Playing
+            };
+            assert_eq!(
+                *game.resource::<GameOverState>(),
+                expected,
+                "ply {}",
+                ply + 1
+            );
+        }
+    }
+
+    #[test]
+    fn test_fifty_move_clock_resets_and_draws_on_the_hundredth_ply() {
+        //! Pawn moves and captures restart the clock; the hundredth quiet ply draws the game
+
+        let mut game = GameHarness::builder()
+            .fen("4k2r/8/8/8/8/8/4P3/R3K3 w - - 10 40")
+            .build();
+        let clock = |game: &GameHarness| game.resource::<ChessEngine>().halfmove_clock;
+        for (uci, expected) in [
+            ("a1a2", 11),
+            ("h8h7", 12),
+            ("e2e4", 0),
+            ("h7h4", 1),
+            ("a2a3", 2),
+            ("h4e4", 0),
+        ] {
+            assert!(game.play(uci), "{} not played", uci);
+            assert_eq!(clock(&game), expected, "after {}", uci);
+        }
+
+        let mut game = GameHarness::builder()
+            .fen("4k2r/8/8/8/8/8/8/R3K3 w - - 97 80")
+            .build();
+        for uci in ["a1a2", "h8h7"] {
+            assert!(game.play(uci));
+            assert_eq!(*game.resource::<GameOverState>(), GameOverState::Playing);
+        }
+        assert!(game.play("a2a1"));
+        assert_eq!(clock(&game), 100);
+        assert_eq!(
+            *game.resource::<GameOverState>(),
+            GameOverState::FiftyMoveRule
+        );
+    }
+}
@@ -7,7 +7,7 @@
 //!
 //! - **Observers**: `on_piece_click` and `on_square_click` capture pointer events.
 //! - **System Params**: `InputSystemParams` groups common resources/queries to reduce argument bloat.
-//! - **Helper Logic**: `try_select_piece` and `try_move_sequence` handle the core game state updates.
+//! - **Helper Logic**: `try_select_piece`, `try_move_sequence` and `drop_selected_at` handle the core game state updates.
 //!
 //! # Selection Logic
 //!
@@ -15,24 +15,35 @@
 //! 2. If valid locally owned piece -> Select it.
 //! 3. If valid target square/piece -> Attempt move.
 //! 4. If invalid -> Clear selection.
+//!
+//! With touch-move on ([`GameSettings::touch_move`]) steps 2 and 4 stop at a
+//! selected piece that has a legal move: it stays selected until it moves.
+//!
+//! While the computer is to move, clicks go to [`crate::game::premove`]
+//! instead.
 
 use crate::core::states::GameMode;
+use crate::core::{GameAction, GameSettings};
 use crate::engine::board_state::ChessEngine;
-use crate::game::components::{HasMoved, SelectedPiece};
+use crate::game::blunder_check::{BlunderCheck, HoldReason};
+use crate::game::components::{DraggedPiece, HasMoved, SelectedPiece};
+use crate::game::premove::{click_premove, Premove};
 use crate::game::resources::player::Players;
 use crate::game::resources::{
-    CapturedPieces, CurrentTurn, GameOverState, GameSounds, MoveHistory, PendingPromotion,
-    PendingTurnAdvance, Selection,
+    AnimationSettings, CapturedPieces, CurrentTurn, DragTarget, GameOverState, GameSounds,
+    MoveHistory, PendingPromotion, PendingTurnAdvance, Selection,
 };
 use crate::game::systems::shared::{
-    execute_move, find_piece_on_square, CapturedTarget, MoveContext,
+    execute_move, find_piece_on_square, move_sounds, CapturedTarget, MoveContext,
 };
 use crate::multiplayer::network::protocol::NetworkMessage;
 #[cfg(feature = "solana")]
 use crate::multiplayer::solana::addon::{CompetitiveMatchState, SolanaGameSync};
 use crate::multiplayer::types::OnlineNetworkState;
+use crate::presentation::audio::SfxChannel;
 use crate::rendering::pieces::{Piece, PieceColor};
 use crate::rendering::utils::Square;
+use crate::ui::menus::popup::{GamePopup, GamePopupQueue};
 use bevy::ecs::system::SystemParam;
 use bevy::picking::events::{Click, Drag, DragEnd, DragStart, Pointer};
 use bevy::picking::pointer::PointerButton;
@@ -58,11 +69,14 @@ pub type PieceReadOnlyQuery<'w, 's> = Query<
 pub struct InGameExitConfirmation {
     pub visible: bool,
     pub pending_exit: bool,
+    /// Quick-save the game before leaving ("Save & Exit").
+    pub save_before_exit: bool,
 }
 
 pub fn reset_in_game_exit_confirmation(mut confirmation: ResMut<InGameExitConfirmation>) {
     confirmation.visible = false;
     confirmation.pending_exit = false;
+    confirmation.save_before_exit = false;
 }
 
 /// Grouped system parameters for input handling to reduce argument count
@@ -83,6 +97,11 @@ pub struct InputSystemParams<'w, 's> {
     pub players: Res<'w, Players>,
     pub game_mode: Res<'w, crate::core::states::GameMode>,
     pub pending_promotion: Res<'w, PendingPromotion>,
+    pub settings: Res<'w, GameSettings>,
+    pub animation: Res<'w, AnimationSettings>,
+    pub blunder_check: ResMut<'w, BlunderCheck>,
+    pub premove: ResMut<'w, Premove>,
+    pub popups: Option<ResMut<'w, GamePopupQueue>>,
     #[cfg(feature = "solana")]
     pub game_sync: Option<Res<'w, SolanaGameSync>>,
     // pub connection_state: Option<Res<'w, crate::multiplayer::network::p2p::P2PConnectionState>>, // Temporarily disabled
@@ -155,18 +174,87 @@ pub fn clear_selection_state(
     debug!("[INPUT] Selection cleared");
 }
 
+/// Title of the toast shown when touch-move keeps a piece selected
+const TOUCH_MOVE_TITLE: &str = "Touch-move";
+
+/// Returns true if touch-move pins the current selection: the rule is on and
+/// the selected piece has at least one legal move.
+pub fn touch_move_holds(params: &InputSystemParams) -> bool {
+    params.settings.touch_move
+        && params.selection.is_selected()
+        && !params.selection.possible_moves.is_empty()
+}
+
+/// Tell the player touch-move kept their piece selected: the illegal-move
+/// sound and a short toast, not repeated while one is still showing.
+fn refuse_for_touch_move(params: &mut InputSystemParams) {
+    debug!("[INPUT] Touch-move: selected piece must move");
+    if let Some(sounds) = &params.game_sounds {
+        if !params.settings.muted {
+            params
+                .commands
+                .spawn((AudioPlayer::new(sounds.illegal.clone()), SfxChannel));
+        }
+    }
+
+    let touched = params
+        .selection
+        .selected_entity
+        .and_then(|entity| params.pieces.p1().get(entity).ok().map(|(_, p, _, _)| *p));
+    let Some(popups) = params.popups.as_mut() else {
+        return;
+    };
+    if popups.entries.iter().any(|p| p.title == TOUCH_MOVE_TITLE) {
+        return;
+    }
+    let message = match touched {
+        Some(piece) => format!(
+            "You touched the {:?} on {}, so it has to move.",
+            piece.piece_type,
+            ChessEngine::coords_to_uci(piece.x, piece.y)
+        ),
+        None => "You touched a piece, so it has to move.".to_string(),
+    };
+    popups.push(GamePopup::warning(TOUCH_MOVE_TITLE, message).with_lifetime(3.0));
+}
+
+/// Clears the selection after a click that neither selects nor moves, unless
+/// touch-move holds it, in which case the player is told why it stays.
+pub fn release_selection(params: &mut InputSystemParams) {
+    if touch_move_holds(params) {
+        refuse_for_touch_move(params);
+        return;
+    }
+    clear_selection_state(
+        &mut params.commands,
+        &mut params.selection,
+        &params.selected_pieces,
+    );
+}
+
 // === Helpers ===
 
 /// Attempts to select a piece
 ///
 /// Validates ownership (current turn) and updates selection state.
-/// Also calculates legal moves for the selected piece.
+/// Also calculates legal moves for the selected piece. Under touch-move a
+/// selected piece with a legal move can be neither swapped nor put down.
 pub fn try_select_piece(
     params: &mut InputSystemParams,
     entity: Entity,
     piece: Piece,
     is_square_click: bool,
 ) {
+    // A held move keeps the board still until it is played or taken back
+    if params.blunder_check.is_busy() {
+        return;
+    }
+
+    // Captured pieces parked beside the board are out of play
+    if !piece.is_on_board() {
+        return;
+    }
+
     // Validate that the piece belongs to the current player
     if piece.color != params.current_turn.color {
         warn!(
@@ -176,6 +264,19 @@ pub fn try_select_piece(
         return;
     }
 
+    // Touch-move: the touched piece stays in hand until it moves. Picking it
+    // up again (e.g. to drag it) is not a change and needs no warning.
+    if touch_move_holds(params) {
+        if params.selection.selected_entity != Some(entity) {
+            info!(
+                "[INPUT] Touch-move: cannot switch to {:?} at ({}, {})",
+                piece.piece_type, piece.x, piece.y
+            );
+            refuse_for_touch_move(params);
+        }
+        return;
+    }
+
     // If already selected, deselect
     if params.selection.selected_entity == Some(entity) {
         clear_selection_state(
@@ -227,7 +328,7 @@ pub fn try_move_sequence(
     capture_info: Option<CapturedTarget>,
     context_name: &str,
 ) {
-    if !params.selection.is_selected() {
+    if !params.selection.is_selected() || params.blunder_check.is_busy() {
         return;
     }
     let Some(selected_entity) = params.selection.selected_entity else {
@@ -238,17 +339,10 @@ pub fn try_move_sequence(
         if context_name == "piece_click_capture" {
             debug!("[INPUT] Invalid capture attempt");
         }
-        clear_selection_state(
-            &mut params.commands,
-            &mut params.selection,
-            &params.selected_pieces,
-        );
+        release_selection(params);
         return;
     }
 
-    let move_sound = params.game_sounds.as_ref().map(|s| s.move_piece.clone());
-    let capture_sound = params.game_sounds.as_ref().map(|s| s.capture_piece.clone());
-
     let (selected_piece_data, was_first_move) = {
         let q = params.pieces.p1();
         if let Ok((_, p, hm, _)) = q.get(selected_entity) {
@@ -271,11 +365,55 @@ pub fn try_move_sequence(
 
     // Multiplayer Interception removed for Pure Braid Transition
 
+    // Beginner checks (offline only). A capture that loses the exchange is
+    // held for confirmation at once; otherwise the blunder check holds the
+    // move until the engine has looked at it. `resolve_blunder_check` plays
+    // it or asks the player first.
+    let approved = params
+        .blunder_check
+        .take_approval(selected_entity, target_pos);
+    let offline = matches!(
+        *params.game_mode,
+        GameMode::SinglePlayer | GameMode::MultiplayerLocal
+    );
+    if !approved
+        && offline
+        && capture_info.is_some()
+        && params.settings.confirm_losing_captures
+        && params
+            .engine
+            .exchange_value((selected_piece_data.x, selected_piece_data.y), target_pos)
+            < 0
+    {
+        params.blunder_check.hold(
+            selected_entity,
+            target_pos,
+            capture_info,
+            context_name,
+            HoldReason::LosingCapture,
+        );
+        return;
+    }
+    if !approved && offline && params.settings.confirm_blunders {
+        let fen = params.engine.current_fen().to_string();
+        params.blunder_check.start(
+            fen,
+            selected_piece_data.color,
+            (selected_piece_data.x, selected_piece_data.y),
+            selected_entity,
+            target_pos,
+            capture_info,
+            context_name,
+        );
+        return;
+    }
+
     #[cfg(feature = "solana")]
     let game_id = params.game_sync.as_ref().and_then(|s| s.game_id);
     #[cfg(not(feature = "solana"))]
     let game_id = None;
 
+    let sounds = move_sounds(params.game_sounds.as_deref());
     let ctx = MoveContext {
         origin: context_name,
         entity: selected_entity,
@@ -285,9 +423,10 @@ pub fn try_move_sequence(
         promotion: None,
         was_first_move,
         remote: false,
-        move_sound,
-        capture_sound,
+        sounds,
         game_id,
+        capture_animation: params.settings.capture_animation,
+        animation: *params.animation,
     };
 
     let success = execute_move(
@@ -330,7 +469,15 @@ pub fn on_piece_click(click: On<Pointer<Click>>, mut params: InputSystemParams)
         return;
     }
 
+    let entity = click.entity;
+
+    let piece_data = params.pieces.p1().get(entity).ok().map(|(_, p, _, _)| *p);
+
     if !is_human_turn(&params) {
+        // While the computer thinks, clicks queue a premove instead.
+        if let Some(piece) = piece_data.filter(Piece::is_on_board) {
+            click_premove(&mut params, (piece.x, piece.y));
+        }
         return;
     }
 
@@ -339,10 +486,6 @@ pub fn on_piece_click(click: On<Pointer<Click>>, mut params: InputSystemParams)
         return;
     }
 
-    let entity = click.entity;
-
-    let piece_data = params.pieces.p1().get(entity).ok().map(|(_, p, _, _)| *p);
-
     let Some(clicked_piece) = piece_data else {
         debug!(
             "[INPUT] Clicked entity {:?} has no Piece component – ignoring",
@@ -414,6 +557,10 @@ pub fn on_piece_click(click: On<Pointer<Click>>, mut params: InputSystemParams)
 ///
 /// Initiates drag-and-drop by selecting the piece and marking it as dragging.
 pub fn on_piece_drag_start(drag_start: On<Pointer<DragStart>>, mut params: InputSystemParams) {
+    // Right drags draw annotations.
+    if !is_primary(drag_start.event.button) {
+        return;
+    }
     if params.game_over.is_game_over() {
         return;
     }
@@ -434,7 +581,7 @@ pub fn on_piece_drag_start(drag_start: On<Pointer<DragStart>>, mut params: Input
         }
     };
 
-    let Some(piece) = piece_data else {
+    let Some(piece) = piece_data.filter(Piece::is_on_board) else {
         return;
     };
 
@@ -450,6 +597,7 @@ pub fn on_piece_drag_start(drag_start: On<Pointer<DragStart>>, mut params: Input
     // Select the piece and mark as dragging
     try_select_piece(&mut params, entity, piece, false);
     params.selection.begin_drag();
+    params.commands.entity(entity).insert(DraggedPiece);
 
     debug!(
         "[INPUT] Started dragging piece at ({}, {})",
@@ -459,11 +607,67 @@ pub fn on_piece_drag_start(drag_start: On<Pointer<DragStart>>, mut params: Input
 
 /// Observer system: Handle drag on a piece
 ///
-/// Currently just tracks that dragging is in progress. Visual feedback
-/// could be added here (e.g., lifting the piece, showing ghost).
-pub fn on_piece_drag(_: On<Pointer<Drag>>, _params: InputSystemParams) {
-    // Dragging is handled by the selection state
-    // Visual feedback (like lifting the piece) could be added here
+/// Holds the piece `GameSettings::drag_lift_height` above the board under the
+/// pointer and records the square beneath it in [`DragTarget`], which the
+/// board highlights as the drop target.
+pub fn on_piece_drag(
+    drag: On<Pointer<Drag>>,
+    selection: Res<Selection>,
+    settings: Res<crate::core::GameSettings>,
+    cameras: Query<(&Camera, &GlobalTransform), With<crate::game::systems::camera::BoardCamera>>,
+    mut dragged: Query<&mut Transform, With<DraggedPiece>>,
+    square_query: Query<
+        (&Square, &GlobalTransform),
+        With<crate::rendering::board::BoardSquareHitPlane>,
+    >,
+    mut drag_target: ResMut<DragTarget>,
+) {
+    if !selection.is_dragging || selection.selected_entity != Some(drag.entity) {
+        return;
+    }
+    let Ok(mut transform) = dragged.get_mut(drag.entity) else {
+        return;
+    };
+    let Ok((camera, camera_transform)) = cameras.single() else {
+        return;
+    };
+
+    let lift_y = crate::rendering::pieces::PIECE_ON_BOARD_Y + settings.drag_lift_height.max(0.0);
+    let Some(point) = pointer_at_height(
+        camera,
+        camera_transform,
+        drag.pointer_location.position,
+        lift_y,
+    ) else {
+        return;
+    };
+    transform.translation = point;
+
+    let square = crate::rendering::board::square_at_world_point(square_query.iter(), point)
+        .map(|square| (square.x, square.y));
+    if drag_target.square != square {
+        drag_target.square = square;
+    }
+}
+
+/// Where the pointer's ray through `camera` meets the horizontal plane at
+/// height `y`. `pointer` is in window coordinates; the board camera renders
+/// into a sub-viewport, so its origin is taken off first.
+fn pointer_at_height(
+    camera: &Camera,
+    camera_transform: &GlobalTransform,
+    pointer: Vec2,
+    y: f32,
+) -> Option<Vec3> {
+    let origin = camera
+        .logical_viewport_rect()
+        .map(|rect| rect.min)
+        .unwrap_or_default();
+    let ray = camera
+        .viewport_to_world(camera_transform, pointer - origin)
+        .ok()?;
+    let distance = ray.intersect_plane(Vec3::Y * y, InfinitePlane3d::new(Vec3::Y))?;
+    Some(ray.get_point(distance))
 }
 
 /// Observer system: Handle drag end on a piece
@@ -472,70 +676,85 @@ pub fn on_piece_drag(_: On<Pointer<Drag>>, _params: InputSystemParams) {
 pub fn on_piece_drag_end(
     drag_end: On<Pointer<DragEnd>>,
     mut params: InputSystemParams,
-    square_query: Query<(Entity, &Square, &Transform)>,
+    square_query: Query<
+        (&Square, &GlobalTransform),
+        With<crate::rendering::board::BoardSquareHitPlane>,
+    >,
+    mut drag_target: ResMut<DragTarget>,
 ) {
+    if !is_primary(drag_end.event.button) {
+        return;
+    }
+    // Drop or cancel, the piece goes back under animate_piece_movement's
+    // control and the target highlight goes away.
+    params
+        .commands
+        .entity(drag_end.entity)
+        .remove::<DraggedPiece>();
+    if drag_target.square.is_some() {
+        drag_target.square = None;
+    }
+
     if !params.selection.is_dragging {
         return;
     }
 
     params.selection.end_drag();
 
-    // Get the piece that was dragged from the readonly query in params
-    let dragged_entity = drag_end.entity;
-    let readonly_pieces = params.pieces.p1();
-    let _dragged_piece = readonly_pieces.get(dragged_entity).ok();
-
-    // Find which square the piece was dropped on by checking the piece's current position
-    // or finding the square under the pointer
-    let target_square = if let Ok((_, _, _, transform)) = readonly_pieces.get(dragged_entity) {
-        // Calculate board position from world position.
-        // X is mirrored (world_x = 7 - file), so invert: file = 7 - round(world_x).
-        let world_pos = transform.translation;
-        let file = (7.0 - world_pos.x).round() as i32;
-        let rank = world_pos.z.round() as i32;
+    let dropped_at = params
+        .pieces
+        .p1()
+        .get(drag_end.entity)
+        .map(|(_, _, _, transform)| transform.translation);
+    let Ok(world_pos) = dropped_at else {
+        release_selection(&mut params);
+        return;
+    };
+    drop_selected_at(&mut params, square_query.iter(), world_pos);
+}
 
-        debug!(
-            "[3D_DRAG] World pos: {:?}, Calculated: file={}, rank={}",
-            world_pos, file, rank
-        );
+/// Play the selected piece onto the square under `point`, capturing
+/// whatever enemy piece stands there.
+///
+/// The square comes from the board colliders, not from the entity the
+/// pointer was over on release, so an enemy piece covering the target square
+/// can't swallow the drop; the colliders also follow any board scale or
+/// flip. A drop off the board releases the selection.
+pub fn drop_selected_at<'a>(
+    params: &mut InputSystemParams,
+    squares: impl IntoIterator<Item = (&'a Square, &'a GlobalTransform)>,
+    point: Vec3,
+) {
+    let target_square = crate::rendering::board::square_at_world_point(squares, point);
+    debug!(
+        "[3D_DRAG] World pos: {:?}, resolved: {:?}",
+        point, target_square
+    );
 
-        // Find square at this board position
-        square_query
-            .iter()
-            .find(|(_, square, _)| square.x as i32 == file && square.y as i32 == rank)
-    } else {
-        None
+    let Some(square) = target_square else {
+        debug!("[INPUT] Dropped on invalid location - cancelling drag");
+        release_selection(params);
+        return;
     };
+    let target_pos = (square.x, square.y);
+    debug!(
+        "[INPUT] Dropped piece on square ({}, {})",
+        square.x, square.y
+    );
 
-    if let Some((_, square, _)) = target_square {
-        let target_pos = (square.x, square.y);
-        debug!(
-            "[INPUT] Dropped piece on square ({}, {})",
-            square.x, square.y
-        );
-
-        // Check if there's a piece on this square (capture)
-        let occupant = {
-            let q = params.pieces.p1();
-            find_piece_on_square(&q, target_pos)
-        };
+    // Check if there's a piece on this square (capture)
+    let occupant = {
+        let q = params.pieces.p1();
+        find_piece_on_square(&q, target_pos)
+    };
 
-        let capture_info = occupant.map(|(e, p)| CapturedTarget {
-            entity: e,
-            piece_type: p.piece_type,
-            color: p.color,
-        });
+    let capture_info = occupant.map(|(e, p)| CapturedTarget {
+        entity: e,
+        piece_type: p.piece_type,
+        color: p.color,
+    });
 
-        try_move_sequence(&mut params, target_pos, capture_info, "drag_drop");
-    } else {
-        // Dropped on invalid location - cancel drag
-        debug!("[INPUT] Dropped on invalid location - cancelling drag");
-        clear_selection_state(
-            &mut params.commands,
-            &mut params.selection,
-            &params.selected_pieces,
-        );
-    }
+    try_move_sequence(params, target_pos, capture_info, "drag_drop");
 }
 
 /// Observer system: Handle click on a square
@@ -557,7 +776,12 @@ pub fn on_square_click(
         return;
     }
 
+    let Ok(square) = square_query.get(click.entity) else {
+        return;
+    };
+
     if !is_human_turn(&params) {
+        click_premove(&mut params, (square.x, square.y));
         return;
     }
 
@@ -566,10 +790,6 @@ pub fn on_square_click(
         return;
     }
 
-    let Ok(square) = square_query.get(click.entity) else {
-        return;
-    };
-
     let target_pos = (square.x, square.y);
     debug!("[INPUT] Clicked square at ({}, {})", square.x, square.y);
 
@@ -596,16 +816,14 @@ pub fn on_square_click(
 }
 
 /// System: Toggle fullscreen mode when F11 is pressed
-pub fn toggle_fullscreen(mut window_query: Query<&mut Window>) {
-    for mut window in window_query.iter_mut() {
-        window.mode = match window.mode {
-            bevy::window::WindowMode::Windowed => bevy::window::WindowMode::Fullscreen(
-                bevy::window::MonitorSelection::Current,
-                bevy::window::VideoModeSelection::Current,
-            ),
-            _ => bevy::window::WindowMode::Windowed,
-        };
-    }
+///
+/// Flips the saved display mode, which `apply_window_settings_system` then
+/// applies to the window, so the choice survives a restart.
+pub fn toggle_fullscreen(mut settings: ResMut<GameSettings>) {
+    settings.display_mode = match settings.display_mode {
+        crate::core::DisplayMode::Windowed => crate::core::DisplayMode::Fullscreen,
+        _ => crate::core::DisplayMode::Windowed,
+    };
 }
 
 /// System: Render a small "F11 to minimise" hint in the bottom-right corner when fullscreen.
@@ -634,12 +852,22 @@ pub fn render_fullscreen_hint(mut contexts: bevy_egui::EguiContexts, window_quer
         });
 }
 
-/// System: Handle ESC key to exit to main menu (forfeit/leave game)
+/// System: Handle the [`GameAction::LeaveGame`] key (ESC by default) to exit
+/// to main menu (forfeit/leave game)
 pub fn handle_escape_key(
     keyboard: Res<ButtonInput<KeyCode>>,
+    settings: Res<GameSettings>,
     mut confirmation: ResMut<InGameExitConfirmation>,
+    pending_promotion: Option<Res<PendingPromotion>>,
 ) {
-    if keyboard.just_pressed(KeyCode::Escape) {
+    // Escape in the promotion dialog picks the queen instead.
+    if pending_promotion.is_some_and(|p| p.is_active()) {
+        return;
+    }
+    if settings
+        .key_bindings
+        .just_pressed(&keyboard, GameAction::LeaveGame)
+    {
         confirmation.visible = !confirmation.visible;
         if !confirmation.visible {
             confirmation.pending_exit = false;
@@ -741,3 +969,75 @@ pub fn confirm_exit_game(
         remote: false,
     });
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::components::FadingCapture;
+    use crate::game::test_support::GameHarness;
+    use crate::rendering::pieces::PieceType;
+
+    fn touch_move_game(touch_move: bool) -> GameHarness {
+        GameHarness::builder()
+            .settings(GameSettings {
+                touch_move,
+                ..default()
+            })
+            .build()
+    }
+
+    #[test]
+    fn test_touch_move_keeps_the_touched_piece() {
+        //! With touch-move on, clicking a second piece is refused while the first can move
+
+        let mut game = touch_move_game(true);
+        let knight = game.piece_at("g1");
+
+        game.select("g1");
+        game.select("e2");
+        game.select("e2");
+
+        assert_eq!(game.resource::<Selection>().selected_entity, knight);
+        assert_eq!(game.resource::<GamePopupQueue>().entries.len(), 1);
+    }
+
+    #[test]
+    fn test_touch_move_releases_a_piece_that_cannot_move() {
+        //! A touched piece with no legal move can be swapped, and without touch-move any piece can
+
+        let mut game = touch_move_game(true);
+        let knight = game.piece_at("g1");
+        game.select("a1");
+        game.select("g1");
+        assert_eq!(game.resource::<Selection>().selected_entity, knight);
+
+        let mut game = touch_move_game(false);
+        let pawn = game.piece_at("e2");
+        game.select("g1");
+        game.select("e2");
+        assert_eq!(game.resource::<Selection>().selected_entity, pawn);
+        assert!(game.resource::<GamePopupQueue>().entries.is_empty());
+    }
+
+    #[test]
+    fn test_drop_onto_enemy_piece_captures_it() {
+        //! Dropping a rook over an enemy rook resolves the square beneath and takes it
+
+        let mut game = GameHarness::builder()
+            .fen("r3k3/8/8/8/8/8/8/R3K3 w - - 0 1")
+            .build();
+        let rook = game.piece_at("a1");
+        let enemy = game.piece_at("a8").unwrap();
+
+        game.select("a1");
+        game.drop_on("a8");
+        game.update();
+
+        assert_eq!(game.piece_at("a8"), rook);
+        assert_eq!(
+            game.resource::<CapturedPieces>().white_captured,
+            vec![PieceType::Rook]
+        );
+        assert!(game.app.world().get::<FadingCapture>(enemy).is_some());
+    }
+}
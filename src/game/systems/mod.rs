@@ -1,5 +1,6 @@
 //! Chess game systems - ECS logic implementation.
 
+pub mod board_invariants;
 pub mod camera;
 pub mod debug_transform;
 pub mod debug_visuals;
@@ -12,6 +13,7 @@ pub mod picking_debug;
 pub mod promotion;
 pub mod shared;
 pub mod spectate_sync;
+pub mod takeback;
 pub mod visual;
 
 // Re-export all public systems for convenience
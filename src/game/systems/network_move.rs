@@ -1,11 +1,12 @@
+use crate::core::GameSettings;
 use crate::engine::board_state::ChessEngine;
 use crate::game::components::{HasMoved, Piece, PieceType};
 use crate::game::events::{NetworkMoveEvent, RemoteMoveApplied, ResignEvent};
 use crate::game::resources::{
-    CapturedPieces, CurrentTurn, GameOverState, GameSounds, MoveHistory, PendingTurnAdvance,
-    Selection,
+    AnimationSettings, CapturedPieces, CurrentTurn, GameOverState, GameSounds, MoveHistory,
+    PendingTurnAdvance, Selection,
 };
-use crate::game::systems::shared::{execute_move, CapturedTarget, MoveContext};
+use crate::game::systems::shared::{execute_move, move_sounds, CapturedTarget, MoveContext};
 use crate::multiplayer::network::online_game_session::OnlineGameSession;
 use crate::multiplayer::network::protocol::NetworkMessage;
 use crate::multiplayer::OnlineNetworkState;
@@ -22,6 +23,8 @@ pub fn handle_network_moves(
     mut captured_pieces: ResMut<CapturedPieces>,
     mut engine: ResMut<ChessEngine>,
     game_sounds: Option<Res<GameSounds>>,
+    settings: Res<GameSettings>,
+    animation: Res<AnimationSettings>,
     current_turn: Res<CurrentTurn>,
     mut remote_applied: MessageWriter<RemoteMoveApplied>,
     network_state: Option<Res<OnlineNetworkState>>,
@@ -91,6 +94,7 @@ pub fn handle_network_moves(
             let promotion_type = event.promotion.and_then(PieceType::from_char);
 
             // 7. Execute Move
+            let sounds = move_sounds(game_sounds.as_deref());
             let ctx = MoveContext {
                 origin: "network_move",
                 entity,
@@ -100,9 +104,10 @@ pub fn handle_network_moves(
                 promotion: promotion_type,
                 was_first_move,
                 remote: true,
-                move_sound: game_sounds.as_ref().map(|s| s.move_piece.clone()),
-                capture_sound: game_sounds.as_ref().map(|s| s.capture_piece.clone()),
+                sounds,
                 game_id: None, // Remote moves don't need game_id for rollup submission
+                capture_animation: settings.capture_animation,
+                animation: *animation,
             };
 
             execute_move(
@@ -1,15 +1,112 @@
 //! Pawn promotion system.
+//!
+//! In bullet and blitz games the dialog is skipped and the pawn becomes the
+//! piece picked in [`GameSettings::auto_promotion`], unless Alt is held as it
+//! lands.
+//!
+//! With [`GameSettings::realistic_promotion`] on, a pawn may only become a
+//! piece its side has lost and not already promoted back, as with a physical
+//! set. The dialog greys out the rest and auto-promotion falls back to the
+//! strongest piece still allowed.
+//!
+//! [`GameSettings::auto_promotion`]: crate::core::GameSettings::auto_promotion
+//! [`GameSettings::realistic_promotion`]: crate::core::GameSettings::realistic_promotion
 
-use crate::game::resources::{is_promotion_move, PendingPromotion, PromotionSelected};
-use crate::rendering::pieces::{Piece, PieceColor, PieceType, PIECE_MESH_SCALE};
+use crate::core::{AutoPromotion, GameSettings, PieceFinish};
+use crate::engine::board_state::ChessEngine;
+use crate::game::board_coords::file_world_x;
+use crate::game::components::{HasMoved, PromotionAnimation};
+use crate::game::resources::active_time_control::ActiveTimeControl;
+use crate::game::resources::{
+    is_promotion_move, AnimationSettings, CapturedPieces, GameSounds, MoveHistory,
+    PendingPromotion, PromotionSelected,
+};
+use crate::game::time_control::{TimeCategory, TimeControl};
+use crate::presentation::audio::{MoveSoundOrigin, SfxChannel};
+use crate::rendering::pieces::{
+    Piece, Piece3DVisual, PieceColor, PieceMaterialPreset, PieceType, PIECE_MESH_SCALE,
+    PIECE_ON_BOARD_Y,
+};
+use bevy::audio::AudioPlayer;
 use bevy::prelude::*;
 
+/// Whether `keyboard` holds the modifier that asks for the dialog anyway.
+fn under_promotion_held(keyboard: &ButtonInput<KeyCode>) -> bool {
+    keyboard.any_pressed([KeyCode::AltLeft, KeyCode::AltRight])
+}
+
+/// The piece to promote to without asking, if `control` is fast enough for
+/// `setting` to apply and the player isn't asking for the dialog.
+pub fn auto_promotion_piece(
+    setting: AutoPromotion,
+    control: TimeControl,
+    dialog_requested: bool,
+) -> Option<PieceType> {
+    if dialog_requested {
+        return None;
+    }
+    match control.category() {
+        TimeCategory::UltraBullet | TimeCategory::Bullet | TimeCategory::Blitz => setting.piece(),
+        TimeCategory::Rapid | TimeCategory::Classical | TimeCategory::Unlimited => None,
+    }
+}
+
+/// Promotion choices, strongest first.
+const PROMOTION_PIECES: [PieceType; 4] = [
+    PieceType::Queen,
+    PieceType::Rook,
+    PieceType::Bishop,
+    PieceType::Knight,
+];
+
+/// Pieces `color` may not promote to under realistic promotion.
+///
+/// A type is available while `color` has lost more of it than it has
+/// promoted back; promotions show up as pieces beyond the starting count
+/// once the losses are put back. Captured pieces that are still entities
+/// (off the board, in the tray or mid-flight) are not counted. If nothing at
+/// all is available the rule is waived, since the pawn has to become
+/// something.
+pub fn unavailable_promotions<'a>(
+    color: PieceColor,
+    captured: &CapturedPieces,
+    pieces: impl IntoIterator<Item = &'a Piece>,
+) -> Vec<PieceType> {
+    let lost = match color {
+        PieceColor::White => &captured.black_captured,
+        PieceColor::Black => &captured.white_captured,
+    };
+    let on_board: Vec<PieceType> = pieces
+        .into_iter()
+        .filter(|p| p.color == color && p.is_on_board())
+        .map(|p| p.piece_type)
+        .collect();
+    let count = |list: &[PieceType], piece_type| list.iter().filter(|&&p| p == piece_type).count();
+
+    let unavailable: Vec<PieceType> = PROMOTION_PIECES
+        .into_iter()
+        .filter(|&piece_type| {
+            let starting = if piece_type == PieceType::Queen { 1 } else { 2 };
+            let lost = count(lost, piece_type);
+            let promoted = (count(&on_board, piece_type) + lost).saturating_sub(starting);
+            lost <= promoted
+        })
+        .collect();
+    if unavailable.len() == PROMOTION_PIECES.len() {
+        Vec::new()
+    } else {
+        unavailable
+    }
+}
+
 fn apply_selected_promotion(
     commands: &mut Commands,
     pieces: &mut Query<(&mut Piece, &Children)>,
     piece_meshes: &Res<crate::rendering::pieces::PieceMeshes>,
     materials: &mut ResMut<Assets<StandardMaterial>>,
     event: PromotionSelected,
+    finish: PieceFinish,
+    animation_secs: f32,
 ) {
     if let Ok((mut piece, children)) = pieces.get_mut(event.entity) {
         info!(
@@ -18,47 +115,67 @@ fn apply_selected_promotion(
         );
 
         piece.piece_type = event.promoted_to;
+        replace_piece_mesh(
+            commands,
+            event.entity,
+            &piece,
+            children,
+            piece_meshes,
+            materials,
+            finish,
+        );
+        commands
+            .entity(event.entity)
+            .insert(PromotionAnimation::new(animation_secs));
+    }
+}
 
-        for child in children.iter() {
-            commands.entity(child).despawn();
-        }
-
-        let material = if piece.color == PieceColor::White {
-            materials.add(StandardMaterial {
-                base_color: Color::WHITE,
-                ..default()
-            })
-        } else {
-            materials.add(StandardMaterial {
-                base_color: Color::BLACK,
-                ..default()
-            })
-        };
-
-        commands.entity(event.entity).with_children(|parent| {
-            let mesh = piece_meshes.get(event.promoted_to, piece.color);
-            match event.promoted_to {
-                PieceType::Queen | PieceType::Rook | PieceType::Bishop | PieceType::Knight => {
-                    parent.spawn((
-                        Mesh3d(mesh),
-                        MeshMaterial3d(material),
-                        Transform::from_scale(Vec3::splat(PIECE_MESH_SCALE)),
-                        bevy::picking::Pickable::default(),
-                        bevy::camera::visibility::RenderLayers::layer(
-                            crate::game::systems::camera::BOARD_LAYER,
-                        ),
-                    ));
-                }
-                _ => {}
-            }
-        });
+/// Swap the mesh under a piece entity for the one of its current type, as a
+/// promotion does (and a takeback of one undoes). The new mesh gets the
+/// chosen [`PieceFinish`] and is a [`Piece3DVisual`] like the ones it
+/// replaces, so finish changes and selection tints still reach it.
+pub fn replace_piece_mesh(
+    commands: &mut Commands,
+    entity: Entity,
+    piece: &Piece,
+    children: &Children,
+    piece_meshes: &crate::rendering::pieces::PieceMeshes,
+    materials: &mut Assets<StandardMaterial>,
+    finish: PieceFinish,
+) {
+    for child in children.iter() {
+        commands.entity(child).despawn();
     }
+
+    let material = materials.add(PieceMaterialPreset::for_finish(finish).material(piece.color));
+    let mesh = piece_meshes.get(piece.piece_type, piece.color);
+    commands.entity(entity).with_children(|parent| {
+        parent.spawn((
+            Mesh3d(mesh),
+            MeshMaterial3d(material),
+            Transform::from_scale(Vec3::splat(PIECE_MESH_SCALE)),
+            Piece3DVisual,
+            bevy::picking::Pickable::default(),
+            bevy::camera::visibility::RenderLayers::layer(
+                crate::game::systems::camera::BOARD_LAYER,
+            ),
+        ));
+    });
 }
 
 /// Detects pawns that need promotion.
+///
+/// Asks the player through [`PendingPromotion`], or promotes straight away
+/// when [`auto_promotion_piece`] picks a piece.
 pub fn detect_pawn_promotion(
     pieces: Query<(Entity, &Piece), Changed<Piece>>,
+    all_pieces: Query<&Piece>,
+    captured: Res<CapturedPieces>,
     mut pending_promotion: ResMut<PendingPromotion>,
+    settings: Res<GameSettings>,
+    active_tc: Res<ActiveTimeControl>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut promotion_messages: MessageWriter<PromotionSelected>,
 ) {
     if pending_promotion.is_active() {
         return;
@@ -70,7 +187,34 @@ pub fn detect_pawn_promotion(
                 "[PROMOTION] Pawn at ({}, {}) needs promotion",
                 piece.x, piece.y
             );
+            let unavailable = if settings.realistic_promotion {
+                unavailable_promotions(piece.color, &captured, all_pieces.iter())
+            } else {
+                Vec::new()
+            };
+            if let Some(preferred) = auto_promotion_piece(
+                settings.auto_promotion,
+                active_tc.control,
+                under_promotion_held(&keyboard),
+            ) {
+                let promoted_to = if unavailable.contains(&preferred) {
+                    PROMOTION_PIECES
+                        .into_iter()
+                        .find(|p| !unavailable.contains(p))
+                        .unwrap_or(preferred)
+                } else {
+                    preferred
+                };
+                info!("[PROMOTION] Auto-promoting to {:?}", promoted_to);
+                promotion_messages.write(PromotionSelected {
+                    entity,
+                    position: (piece.x, piece.y),
+                    promoted_to,
+                });
+                return;
+            }
             pending_promotion.start(entity, (piece.x, piece.y), piece.color);
+            pending_promotion.unavailable = unavailable;
             return;
         }
     }
@@ -80,26 +224,276 @@ pub fn detect_pawn_promotion(
 ///
 /// The promotion UI (promotion_ui_system) sends a PromotionSelected message when the
 /// player picks a piece. This system applies it. If no message arrives this frame,
-/// nothing happens — the UI stays open until the player chooses.
+/// nothing happens — the UI stays open until the player chooses. The new
+/// piece then plays its [`PromotionAnimation`].
 ///
-/// AI promotions are handled inline in MoveContext (promotion field), so they never
-/// reach detect_pawn_promotion and do not require this path.
+/// AI promotions are decided inline in MoveContext (promotion field), so they never
+/// reach detect_pawn_promotion; the AI sends the message only to swap the mesh.
 pub fn apply_pawn_promotion(
     mut commands: Commands,
     mut promotion_messages: MessageReader<PromotionSelected>,
     mut pieces: Query<(&mut Piece, &Children)>,
     mut pending_promotion: ResMut<PendingPromotion>,
+    mut history: ResMut<MoveHistory>,
     piece_meshes: Res<crate::rendering::pieces::PieceMeshes>,
     mut materials: ResMut<Assets<StandardMaterial>>,
+    settings: Res<GameSettings>,
+    animation: Res<AnimationSettings>,
 ) {
     for event in promotion_messages.read() {
+        history.record_promotion(event.position, event.promoted_to);
         apply_selected_promotion(
             &mut commands,
             &mut pieces,
             &piece_meshes,
             &mut materials,
             event.clone(),
+            settings.piece_finish,
+            animation.effect_secs(),
         );
         pending_promotion.clear();
     }
 }
+
+/// Look for check again once a promotion is applied.
+///
+/// A promotion picked in the dialog lands after `execute_move` synced the
+/// engine with a pawn still on the last rank, so a promotion that gives
+/// check (e8=Q+) wasn't seen as one. Sync again with the new piece, mark the
+/// move, and play the check sound if the move didn't already give check.
+/// The turn has passed, so `update_game_phase` picks up the check or mate
+/// from the re-synced engine.
+pub fn recheck_after_promotion(
+    mut commands: Commands,
+    mut promotion_messages: MessageReader<PromotionSelected>,
+    pieces: Query<(Entity, &Piece, &HasMoved)>,
+    mut engine: ResMut<ChessEngine>,
+    mut history: ResMut<MoveHistory>,
+    sounds: Option<Res<GameSounds>>,
+) {
+    for event in promotion_messages.read() {
+        let Some(was_check) = history
+            .last_move()
+            .filter(|record| record.to == event.position)
+            .map(|record| record.is_check)
+        else {
+            continue;
+        };
+        engine.sync_ecs_to_engine(&pieces);
+        engine.synced_this_move = true;
+        let check = engine.is_check();
+        let mate = check && engine.game_status() == nimzovich_engine::GameStatus::Checkmate;
+        history.mark_check(check, mate);
+
+        if check && !mate && !was_check {
+            if let Some(sounds) = sounds.as_ref() {
+                commands.spawn((
+                    AudioPlayer::new(sounds.check.clone()),
+                    MoveSoundOrigin(Vec3::new(
+                        file_world_x(event.position.0),
+                        PIECE_ON_BOARD_Y,
+                        event.position.1 as f32,
+                    )),
+                    SfxChannel,
+                ));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::message::Messages;
+    use bevy::ecs::system::RunSystemOnce;
+
+    fn promotion_world(control: TimeControl, settings: GameSettings) -> World {
+        let mut world = World::new();
+        world.init_resource::<PendingPromotion>();
+        world.init_resource::<CapturedPieces>();
+        world.insert_resource(settings);
+        world.insert_resource(ActiveTimeControl {
+            control,
+            ai_game: false,
+        });
+        world.init_resource::<ButtonInput<KeyCode>>();
+        world.init_resource::<Messages<PromotionSelected>>();
+        world
+    }
+
+    fn promote_in_blitz(alt_held: bool) -> (bool, Vec<PieceType>) {
+        let mut world = promotion_world(TimeControl::BlitzThree, GameSettings::default());
+        if alt_held {
+            world
+                .resource_mut::<ButtonInput<KeyCode>>()
+                .press(KeyCode::AltLeft);
+        }
+        world.spawn(Piece::new(PieceColor::White, PieceType::Pawn, 4, 7));
+
+        world
+            .run_system_once(detect_pawn_promotion)
+            .expect("system runs");
+        let promoted = world
+            .run_system_once(|mut messages: MessageReader<PromotionSelected>| {
+                messages.read().map(|m| m.promoted_to).collect::<Vec<_>>()
+            })
+            .expect("system runs");
+        (world.resource::<PendingPromotion>().is_active(), promoted)
+    }
+
+    #[test]
+    fn test_blitz_promotes_without_asking_unless_alt_held() {
+        //! With auto-promotion on, a blitz pawn queens with nothing pending; Alt brings the dialog back
+
+        assert_eq!(GameSettings::default().auto_promotion, AutoPromotion::Queen);
+        assert_eq!(promote_in_blitz(false), (false, vec![PieceType::Queen]));
+        assert_eq!(promote_in_blitz(true), (true, vec![]));
+    }
+
+    #[test]
+    fn test_realistic_promotion_greys_out_pieces_not_lost() {
+        //! With only a rook lost, the dialog refuses a queen and blitz auto-queening takes the rook
+
+        let settings = GameSettings {
+            realistic_promotion: true,
+            ..default()
+        };
+        for control in [TimeControl::Rapid, TimeControl::BlitzThree] {
+            let mut world = promotion_world(control, settings.clone());
+            world
+                .resource_mut::<CapturedPieces>()
+                .add_capture(PieceColor::White, PieceType::Rook);
+            world.spawn(Piece::new(PieceColor::White, PieceType::Pawn, 4, 7));
+
+            world
+                .run_system_once(detect_pawn_promotion)
+                .expect("system runs");
+            let promoted = world
+                .run_system_once(|mut messages: MessageReader<PromotionSelected>| {
+                    messages.read().map(|m| m.promoted_to).collect::<Vec<_>>()
+                })
+                .expect("system runs");
+
+            let pending = world.resource::<PendingPromotion>();
+            if control == TimeControl::Rapid {
+                assert!(pending.is_active());
+                assert!(!pending.allows(PieceType::Queen));
+                assert!(pending.allows(PieceType::Rook));
+                assert!(!pending.allows(PieceType::Knight));
+            } else {
+                assert_eq!(promoted, vec![PieceType::Rook]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_captured_piece_entities_are_not_counted_as_on_the_board() {
+        //! A lost rook whose entity lingers off the board can still be promoted to
+
+        let settings = GameSettings {
+            realistic_promotion: true,
+            ..default()
+        };
+        let mut world = promotion_world(TimeControl::Rapid, settings);
+        world
+            .resource_mut::<CapturedPieces>()
+            .add_capture(PieceColor::White, PieceType::Rook);
+        for (piece_type, x) in [
+            (PieceType::Rook, 0),
+            (PieceType::Knight, 1),
+            (PieceType::Bishop, 2),
+            (PieceType::Queen, 3),
+            (PieceType::Bishop, 5),
+            (PieceType::Knight, 6),
+        ] {
+            world.spawn(Piece::new(PieceColor::White, piece_type, x, 0));
+        }
+        world.spawn(Piece::new(
+            PieceColor::White,
+            PieceType::Rook,
+            u8::MAX,
+            u8::MAX,
+        ));
+        world.spawn(Piece::new(PieceColor::White, PieceType::Pawn, 4, 7));
+
+        world
+            .run_system_once(detect_pawn_promotion)
+            .expect("system runs");
+
+        let pending = world.resource::<PendingPromotion>();
+        assert!(pending.is_active());
+        assert!(pending.allows(PieceType::Rook));
+        assert!(!pending.allows(PieceType::Queen));
+        assert!(!pending.allows(PieceType::Bishop));
+        assert!(!pending.allows(PieceType::Knight));
+    }
+
+    #[test]
+    fn test_promotion_that_gives_check_is_marked_and_sounded() {
+        //! e8=Q+ picked in the dialog marks the move as check and plays the check sound once
+
+        use crate::core::SoundTheme;
+        use crate::game::test_support::GameHarness;
+
+        let mut game = GameHarness::builder()
+            .fen("k7/4P3/8/8/8/8/8/K7 w - - 0 1")
+            .build();
+        let pawn = game.piece_at("e7").unwrap();
+        assert!(game.play("e7e8"));
+        assert!(!game.resource::<MoveHistory>().last_move().unwrap().is_check);
+
+        // The dialog's pick, as apply_pawn_promotion applies it.
+        game.app.insert_resource(GameSounds {
+            move_piece: Handle::default(),
+            capture_piece: Handle::default(),
+            check: Handle::default(),
+            illegal: Handle::default(),
+            theme: SoundTheme::Classic,
+        });
+        let world = game.app.world_mut();
+        world.get_mut::<Piece>(pawn).unwrap().piece_type = PieceType::Queen;
+        world
+            .resource_mut::<MoveHistory>()
+            .record_promotion((4, 7), PieceType::Queen);
+        world.write_message(PromotionSelected {
+            entity: pawn,
+            position: (4, 7),
+            promoted_to: PieceType::Queen,
+        });
+        world
+            .run_system_once(recheck_after_promotion)
+            .expect("system runs");
+
+        let history = game.resource::<MoveHistory>();
+        let record = history.last_move().unwrap();
+        assert!(record.is_check);
+        assert!(!record.is_checkmate);
+        assert_eq!(history.san_at(0), Some("e8=Q+"));
+        let sounds = game
+            .app
+            .world_mut()
+            .query::<&AudioPlayer>()
+            .iter(game.app.world())
+            .count();
+        assert_eq!(sounds, 1);
+        assert!(game.resource::<ChessEngine>().is_check());
+    }
+
+    #[test]
+    fn test_rapid_always_asks() {
+        //! Slower time controls keep the dialog whatever the setting
+
+        assert_eq!(
+            auto_promotion_piece(AutoPromotion::Knight, TimeControl::Bullet, false),
+            Some(PieceType::Knight)
+        );
+        assert_eq!(
+            auto_promotion_piece(AutoPromotion::Queen, TimeControl::Rapid, false),
+            None
+        );
+        assert_eq!(
+            auto_promotion_piece(AutoPromotion::Off, TimeControl::Blitz, false),
+            None
+        );
+    }
+}
@@ -1,15 +1,30 @@
+use crate::core::CaptureAnimation;
 use crate::engine::board_state::ChessEngine;
+use crate::game::board_coords::file_world_x;
 use crate::game::components::{
-    FadingCapture, HasMoved, MoveRecord, Piece, PieceColor, PieceMoveAnimation, PieceType,
+    CaptureFlight, Captured, FadingCapture, HasMoved, MoveRecord, Piece, PieceColor,
+    PieceMoveAnimation, PieceType,
 };
 use crate::game::events::MoveMadeEvent;
+use crate::game::resources::sounds::GameSounds;
 use crate::game::resources::turn::CurrentTurn;
-use crate::game::resources::{CapturedPieces, MoveHistory, PendingTurnAdvance};
+use crate::game::resources::{
+    AnimationSettings, CapturedPieces, MoveHistory, PendingTurnAdvance, PieceSnapshot, Takeback,
+    CASTLING_ROOK_ANIMATION_SECS,
+};
 use crate::game::sync::board_state::{BoardMove, BoardStateSync, ChessEngineExt};
+use crate::presentation::audio::{MoveSoundOrigin, SfxChannel};
 use crate::rendering::pieces::PIECE_ON_BOARD_Y;
 use bevy::audio::{AudioPlayer, AudioSource};
 use bevy::prelude::*;
 
+/// Gap between the board's edge and the first capture-tray column.
+const TRAY_GAP: f32 = 1.2;
+/// Distance between neighbouring tray slots, and between tray columns.
+const TRAY_SPACING: f32 = 0.9;
+/// Tray slots per column.
+const TRAY_COLUMN_SLOTS: usize = 8;
+
 /// Data required to identify a captured piece target.
 #[derive(Clone, Copy, Debug)]
 pub struct CapturedTarget {
@@ -45,19 +60,47 @@ pub struct MoveContext<'a> {
     pub was_first_move: bool,
     /// `true` when the move originated from a remote peer.
     pub remote: bool,
-    /// Move sound handle (optional).
-    pub move_sound: Option<Handle<AudioSource>>,
-    /// Capture sound handle (optional).
-    pub capture_sound: Option<Handle<AudioSource>>,
+    /// Sounds to play for the move.
+    pub sounds: MoveSounds,
     /// Game ID for rollup submission.
     pub game_id: Option<u64>,
+    /// What happens to the captured piece (`GameSettings::capture_animation`).
+    pub capture_animation: CaptureAnimation,
+    /// How long the move, capture and promotion animations take.
+    pub animation: AnimationSettings,
+}
+
+/// Sound handles [`execute_move`] plays; each is skipped when `None`.
+#[derive(Clone, Debug, Default)]
+pub struct MoveSounds {
+    /// Played for a move that doesn't capture.
+    pub move_sound: Option<Handle<AudioSource>>,
+    /// Played for a capture, instead of the move sound.
+    pub capture_sound: Option<Handle<AudioSource>>,
+    /// Played on top of the move or capture sound when the move gives check.
+    pub check_sound: Option<Handle<AudioSource>>,
+}
+
+/// The game's move, capture and check sounds, for a [`MoveContext`].
+pub fn move_sounds(sounds: Option<&GameSounds>) -> MoveSounds {
+    let Some(sounds) = sounds else {
+        return MoveSounds::default();
+    };
+    MoveSounds {
+        move_sound: Some(sounds.move_piece.clone()),
+        capture_sound: Some(sounds.capture_piece.clone()),
+        check_sound: Some(sounds.check.clone()),
+    }
 }
 
 /// Helper to handle audio playback for moves
+///
+/// `at` is the destination's world position, used to pan the sound.
 pub fn play_move_audio(
     commands: &mut Commands,
     move_sound: Option<Handle<AudioSource>>,
     capture_happened: bool,
+    at: Vec3,
 ) {
     if capture_happened {
         if let Some(_sound) = move_sound {
@@ -66,14 +109,39 @@ pub fn play_move_audio(
     }
     // Only play move sound if NOT a capture
     if let Some(sound) = move_sound {
-        commands.spawn(AudioPlayer::new(sound));
+        commands.spawn((AudioPlayer::new(sound), MoveSoundOrigin(at), SfxChannel));
     }
 }
 
+/// World position of the `slot`th captured piece of `color` in the capture
+/// tray beside the board.
+///
+/// Black pieces (taken by White) line up beyond the h-file from White's end
+/// of the board, white pieces beyond the a-file from Black's end. A column
+/// holds eight; the next starts further out.
+pub fn calculate_capture_position(color: PieceColor, slot: usize) -> Vec3 {
+    let (edge_file, first_rank, rank_step) = match color {
+        PieceColor::Black => (7, 0.0, 1.0),
+        PieceColor::White => (0, 7.0, -1.0),
+    };
+    let edge_x = file_world_x(edge_file);
+    let outward = (edge_x - file_world_x(7 - edge_file)).signum();
+    let column = (slot / TRAY_COLUMN_SLOTS) as f32;
+    let row = (slot % TRAY_COLUMN_SLOTS) as f32;
+    Vec3::new(
+        edge_x + outward * (TRAY_GAP + column * TRAY_SPACING),
+        PIECE_ON_BOARD_Y,
+        first_rank + rank_step * row * TRAY_SPACING,
+    )
+}
+
 /// Apply visual and logical state for a captured piece.
 ///
-/// Inserts a [`FadingCapture`] component that drives a parabolic arc + spin +
-/// scale-to-zero animation before the entity is despawned.
+/// With [`CaptureAnimation::Fade`], inserts a [`FadingCapture`] component
+/// that drives a knock-back, tilt and sink before the piece is hidden.
+/// Otherwise the piece is marked [`Captured`] and kept, parked in its
+/// [`calculate_capture_position`] slot: flown there by a [`CaptureFlight`],
+/// or moved there at once. Either animation lasts `duration` seconds.
 ///
 /// `current_pos` should be the piece's current world `Transform.translation`.
 pub fn apply_capture(
@@ -83,12 +151,37 @@ pub fn apply_capture(
     target: CapturedTarget,
     current_pos: Vec3,
     move_dir: Vec3,
+    animation: CaptureAnimation,
+    duration: f32,
 ) {
     if let Some(sound) = capture_sound {
-        commands.spawn(AudioPlayer::new(sound));
+        commands.spawn((
+            AudioPlayer::new(sound),
+            MoveSoundOrigin(current_pos),
+            SfxChannel,
+        ));
     }
     captured_pieces.add_capture(target.color, target.piece_type);
 
+    if animation != CaptureAnimation::Fade {
+        // Slots follow capture order, so the piece just recorded takes the last.
+        let taken = match target.color {
+            PieceColor::White => captured_pieces.black_captured.len(),
+            PieceColor::Black => captured_pieces.white_captured.len(),
+        };
+        let tray = calculate_capture_position(target.color, taken.saturating_sub(1));
+        let mut entity = commands.entity(target.entity);
+        entity.insert(Captured);
+        if animation == CaptureAnimation::Slide {
+            entity.insert(CaptureFlight::new(current_pos, tray, duration));
+        } else {
+            entity
+                .entry::<Transform>()
+                .and_modify(move |mut transform| transform.translation = tray);
+        }
+        return;
+    }
+
     // Calculate knockback direction: slide away from the attacker's trajectory
     // We use the move direction but flatten it to the board plane
     let mut knockback = move_dir.normalize_or_zero();
@@ -103,7 +196,7 @@ pub fn apply_capture(
     let tilt_axis = Vec3::new(knockback.z, 0.0, -knockback.x).normalize();
 
     commands.entity(target.entity).insert(FadingCapture {
-        timer: bevy::time::Timer::from_seconds(0.75, bevy::time::TimerMode::Once),
+        timer: bevy::time::Timer::from_seconds(duration, bevy::time::TimerMode::Once),
         initial_pos: current_pos,
         knockback_dir: knockback,
         tilt_axis,
@@ -121,6 +214,7 @@ pub fn update_piece_state(
     is_castling: bool,
     capture: Option<CapturedTarget>,
     promotion: Option<PieceType>,
+    move_secs: f32,
     commands: &mut Commands,
     pieces: &mut Query<(Entity, &mut Piece, &mut HasMoved)>,
     move_history: &mut MoveHistory,
@@ -135,33 +229,39 @@ pub fn update_piece_state(
     // applied (engine.game is only advanced later, in execute_move's step 7).
     let san = engine.move_to_san(from_pos, target, promotion);
 
-    // Apply promotion if applicable
-    if let Some(new_type) = promotion {
-        debug!("[SHARED] {origin}: Promoting piece to {:?}", new_type);
-        piece_component.piece_type = new_type;
-    }
-
     let move_record = MoveRecord {
         piece_type: piece_component.piece_type,
         piece_color: piece_component.color,
         from: from_pos,
         to: target,
         captured: capture.map(|data| data.piece_type),
+        promotion,
         is_castling,
         is_en_passant: false,
+        // Set by execute_move once the engine has the new position.
         is_check: false,
         is_checkmate: false,
     };
     move_history.add_move_with_san(move_record, san);
+
+    // Apply promotion if applicable
+    if let Some(new_type) = promotion {
+        debug!("[SHARED] {origin}: Promoting piece to {:?}", new_type);
+        piece_component.piece_type = new_type;
+    }
     piece_component.x = target.0;
     piece_component.y = target.1;
     // Use PIECE_ON_BOARD_Y so the animation stays on the board surface (y=0.05),
     // matching the spawn position and the snap target in animate_piece_movement.
     // Integer coordinates match GLB mesh design and board square positions.
     commands.entity(entity).insert(PieceMoveAnimation::new(
-        Vec3::new(7.0 - from_pos.0 as f32, PIECE_ON_BOARD_Y, from_pos.1 as f32),
-        Vec3::new(7.0 - target.0 as f32, PIECE_ON_BOARD_Y, target.1 as f32),
-        0.4,
+        Vec3::new(
+            file_world_x(from_pos.0),
+            PIECE_ON_BOARD_Y,
+            from_pos.1 as f32,
+        ),
+        Vec3::new(file_world_x(target.0), PIECE_ON_BOARD_Y, target.1 as f32),
+        move_secs,
     ));
 
     has_moved.moved = true;
@@ -191,6 +291,7 @@ fn apply_castling_rook_move(
     pieces_query: &mut Query<(Entity, &mut Piece, &mut HasMoved)>,
     from: (u8, u8),
     to: (u8, u8),
+    animation: &AnimationSettings,
 ) {
     let Some((rook_from, rook_to)) = castling_rook_move(from, to) else {
         return;
@@ -222,12 +323,12 @@ fn apply_castling_rook_move(
 
         commands.entity(rook_entity).insert(PieceMoveAnimation::new(
             Vec3::new(
-                7.0 - rook_from.0 as f32,
+                file_world_x(rook_from.0),
                 PIECE_ON_BOARD_Y,
                 rook_from.1 as f32,
             ),
-            Vec3::new(7.0 - rook_to.0 as f32, PIECE_ON_BOARD_Y, rook_to.1 as f32),
-            0.25,
+            Vec3::new(file_world_x(rook_to.0), PIECE_ON_BOARD_Y, rook_to.1 as f32),
+            animation.duration(CASTLING_ROOK_ANIMATION_SECS),
         ));
 
         debug!(
@@ -252,38 +353,63 @@ pub fn execute_move(
     pieces_query: &mut Query<(Entity, &mut Piece, &mut HasMoved)>,
     move_events: Option<&mut MessageWriter<MoveMadeEvent>>,
     board_sync: Option<&mut BoardStateSync>,
-    _current_turn: &CurrentTurn,
+    current_turn: &CurrentTurn,
 ) -> bool {
+    let target_world_pos = Vec3::new(
+        file_world_x(ctx.target.0),
+        PIECE_ON_BOARD_Y,
+        ctx.target.1 as f32,
+    );
+
     // 1. Play Audio
-    play_move_audio(commands, ctx.move_sound.clone(), ctx.capture.is_some());
+    play_move_audio(
+        commands,
+        ctx.sounds.move_sound.clone(),
+        ctx.capture.is_some(),
+        target_world_pos,
+    );
 
     // Derive from_pos early — needed by both the capture and update steps.
     let from_pos = (ctx.piece.x, ctx.piece.y);
 
+    // 1b. Remember everything the move is about to change, for takebacks.
+    let castling = is_castling_move(ctx.piece.piece_type, from_pos, ctx.target);
+    if let Some(takeback) = takeback_before_move(
+        ctx,
+        castling,
+        move_history.len(),
+        current_turn,
+        engine,
+        pieces_query,
+    ) {
+        move_history.record_takeback(takeback);
+    }
+
     // 2. Handle Capture
     if let Some(target_cap) = ctx.capture {
         // The captured piece stands on ctx.target — derive world position
-        // using the same formula as piece spawning: X is mirrored (7 - file)
-        // so the a-file renders on White's left; Z = rank, Y = board surface.
-        let cap_world_pos = Vec3::new(
-            7.0 - ctx.target.0 as f32,
-            PIECE_ON_BOARD_Y,
-            ctx.target.1 as f32,
-        );
-        let move_dir =
-            cap_world_pos - Vec3::new(7.0 - from_pos.0 as f32, PIECE_ON_BOARD_Y, from_pos.1 as f32);
+        // using the same formula as piece spawning: X from file_world_x,
+        // Z = rank, Y = board surface.
+        let cap_world_pos = target_world_pos;
+        let move_dir = cap_world_pos
+            - Vec3::new(
+                file_world_x(from_pos.0),
+                PIECE_ON_BOARD_Y,
+                from_pos.1 as f32,
+            );
         apply_capture(
             commands,
             captured_pieces,
-            ctx.capture_sound.clone(),
+            ctx.sounds.capture_sound.clone(),
             target_cap,
             cap_world_pos,
             move_dir,
+            ctx.capture_animation,
+            ctx.animation.effect_secs(),
         );
     }
 
     // 3. Update Piece State
-    let castling = is_castling_move(ctx.piece.piece_type, from_pos, ctx.target);
     if !update_piece_state(
         ctx.origin,
         ctx.entity,
@@ -293,6 +419,7 @@ pub fn execute_move(
         castling,
         ctx.capture,
         ctx.promotion,
+        ctx.animation.move_secs(),
         commands,
         pieces_query,
         move_history,
@@ -306,7 +433,7 @@ pub fn execute_move(
 
     // 4b. Move the rook as part of castling so the windowed board animates both pieces.
     if castling {
-        apply_castling_rook_move(commands, pieces_query, from_pos, ctx.target);
+        apply_castling_rook_move(commands, pieces_query, from_pos, ctx.target, &ctx.animation);
     }
 
     // 5. Update Engine State (for P2P sync and FEN export)
@@ -358,6 +485,25 @@ pub fn execute_move(
     engine.sync_ecs_to_engine_mut(pieces_query);
     engine.synced_this_move = true;
 
+    // 7b. The engine now holds the position after the move, with the
+    //     opponent to play: mark the record if that side is in check or mated.
+    let gives_check = engine.is_check();
+    let gives_mate = gives_check && engine.game_status() == nimzovich_engine::GameStatus::Checkmate;
+    if let Some(record) = move_history.last_move_mut() {
+        record.is_check = gives_check;
+        record.is_checkmate = gives_mate;
+    }
+    // The check sound layers over the move sound; mate has its own ending.
+    if gives_check && !gives_mate {
+        if let Some(sound) = ctx.sounds.check_sound.clone() {
+            commands.spawn((
+                AudioPlayer::new(sound),
+                MoveSoundOrigin(target_world_pos),
+                SfxChannel,
+            ));
+        }
+    }
+
     // 8. Trigger Event with correct FEN
     if let Some(writer) = move_events {
         let fen_after = engine.current_fen().to_string();
@@ -377,6 +523,73 @@ pub fn execute_move(
     true
 }
 
+/// The state [`execute_move`] is about to change for `ctx`, as the
+/// [`Takeback`] for ply `ply`. `None` if the moving piece is missing, in
+/// which case the move won't be played either.
+fn takeback_before_move(
+    ctx: &MoveContext<'_>,
+    castling: bool,
+    ply: usize,
+    current_turn: &CurrentTurn,
+    engine: &ChessEngine,
+    pieces_query: &Query<(Entity, &mut Piece, &mut HasMoved)>,
+) -> Option<Takeback> {
+    let snapshot = |entity| {
+        pieces_query
+            .get(entity)
+            .ok()
+            .map(|(entity, piece, has_moved)| PieceSnapshot {
+                entity,
+                piece: *piece,
+                has_moved: *has_moved,
+            })
+    };
+    let from = (ctx.piece.x, ctx.piece.y);
+    let rook = castling_rook_move(from, ctx.target)
+        .filter(|_| castling)
+        .and_then(|(rook_from, _)| {
+            pieces_query.iter().find(|(_, piece, has_moved)| {
+                piece.piece_type == PieceType::Rook
+                    && (piece.x, piece.y) == rook_from
+                    && !has_moved.moved
+            })
+        })
+        .and_then(|(entity, _, _)| snapshot(entity));
+    Some(Takeback {
+        ply,
+        turn: *current_turn,
+        engine: engine.snapshot(),
+        moved: snapshot(ctx.entity)?,
+        rook,
+        captured: ctx.capture.and_then(|cap| snapshot(cap.entity)),
+    })
+}
+
+/// Find entity, piece data, and potential capture target for a move
+pub fn find_move_entities(
+    pieces_query: &Query<(Entity, &mut Piece, &mut HasMoved)>,
+    from: (u8, u8),
+    to: (u8, u8),
+) -> Option<(Entity, Piece, bool, Option<CapturedTarget>)> {
+    let mut move_data = None;
+    let mut capture_target = None;
+
+    for (entity, piece, has_moved) in pieces_query.iter() {
+        if piece.x == from.0 && piece.y == from.1 {
+            move_data = Some((entity, *piece, !has_moved.moved));
+        }
+        if piece.x == to.0 && piece.y == to.1 {
+            capture_target = Some(CapturedTarget {
+                entity,
+                piece_type: piece.piece_type,
+                color: piece.color,
+            });
+        }
+    }
+
+    move_data.map(|(e, p, first)| (e, p, first, capture_target))
+}
+
 /// Helper to find a piece entity at a specific board coordinate
 pub fn find_piece_on_square(
     pieces: &Query<(Entity, &Piece, &HasMoved, &Transform)>,
@@ -496,3 +709,137 @@ fn update_castling_rights(
         engine.castling_rights = rights.into_iter().collect();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::GameSettings;
+    use crate::game::resources::Selection;
+    use crate::game::test_support::GameHarness;
+    use bevy::ecs::system::RunSystemOnce;
+
+    #[test]
+    fn test_slide_capture_flies_the_piece_to_its_tray_slot() {
+        //! With the slide option a captured piece is kept, not faded, and flies to the first tray slot
+
+        let settings = GameSettings {
+            capture_animation: CaptureAnimation::Slide,
+            ..default()
+        };
+        let mut game = GameHarness::builder().settings(settings).build();
+        assert!(game.play("e2e4"));
+        assert!(game.play("d7d5"));
+        let pawn = game.piece_at("d5").expect("black pawn on d5");
+        assert!(game.play("e4d5"));
+
+        let world = game.app.world();
+        assert!(world.get::<Captured>(pawn).is_some());
+        assert!(world.get::<FadingCapture>(pawn).is_none());
+        let flight = world.get::<CaptureFlight>(pawn).expect("capture flight");
+        assert_eq!(flight.end, calculate_capture_position(PieceColor::Black, 0));
+        assert_eq!(flight.duration, AnimationSettings::default().effect_secs());
+        assert!(!world.get::<Piece>(pawn).unwrap().is_on_board());
+    }
+
+    #[test]
+    fn test_castling_moves_king_and_rook_on_both_wings() {
+        //! White castles short and Black long: both rooks follow their kings in the ECS and the engine, and the history records castling
+
+        let mut game = GameHarness::builder()
+            .fen("r3k2r/pppppppp/8/8/8/8/PPPPPPPP/R3K2R w KQkq - 0 1")
+            .build();
+        let white_rook = game.piece_at("h1").unwrap();
+        let black_rook = game.piece_at("a8").unwrap();
+
+        game.select("e1");
+        let targets = &game.resource::<Selection>().possible_moves;
+        assert!(targets.contains(&(6, 0)) && targets.contains(&(2, 0)));
+        game.click_square("g1");
+        game.update();
+        assert!(game.play("e8c8"));
+
+        let world = game.app.world();
+        let rook = world.get::<Piece>(white_rook).unwrap();
+        assert_eq!((rook.x, rook.y), (5, 0));
+        let rook = world.get::<Piece>(black_rook).unwrap();
+        assert_eq!((rook.x, rook.y), (3, 7));
+        let history = game.resource::<MoveHistory>();
+        assert!(history.moves.iter().all(|record| record.is_castling));
+
+        let board = game.resource::<ChessEngine>().board();
+        let at = |file: u8, rank: u8| board[ChessEngine::square_to_index(file, rank) as usize];
+        let white_rook_code = ChessEngine::piece_code(PieceColor::White, PieceType::Rook);
+        let black_rook_code = ChessEngine::piece_code(PieceColor::Black, PieceType::Rook);
+        assert_eq!((at(5, 0), at(7, 0)), (white_rook_code, 0));
+        assert_eq!((at(3, 7), at(0, 7)), (black_rook_code, 0));
+    }
+
+    #[test]
+    fn test_king_cannot_castle_through_check() {
+        //! A rook covering f1 takes away short castling but leaves the long side
+
+        let mut game = GameHarness::builder()
+            .fen("4k3/8/8/8/8/8/5r2/R3K2R w KQ - 0 1")
+            .build();
+        game.select("e1");
+        let targets = &game.resource::<Selection>().possible_moves;
+        assert!(!targets.contains(&(6, 0)));
+        assert!(targets.contains(&(2, 0)));
+    }
+
+    #[test]
+    fn test_move_records_mark_check_and_mate() {
+        //! Quiet moves, a check the king walks out of and a back-rank mate carry the flags the board gives them
+
+        let fen = "6k1/5ppp/8/8/8/8/5PPP/R5K1 w - - 0 1";
+        let flags = |game: &GameHarness| -> Vec<(bool, bool)> {
+            game.resource::<MoveHistory>()
+                .iter()
+                .map(|record| (record.is_check, record.is_checkmate))
+                .collect()
+        };
+
+        let mut game = GameHarness::builder().fen(fen).build();
+        assert!(game.play("g1f1"));
+        assert!(game.play("g8f8"));
+        assert!(game.play("a1a8"));
+        assert_eq!(
+            flags(&game),
+            [(false, false), (false, false), (true, false)]
+        );
+
+        let mut game = GameHarness::builder().fen(fen).build();
+        assert!(game.play("a1a8"));
+        assert_eq!(flags(&game), [(true, true)]);
+    }
+
+    #[test]
+    fn test_live_fen_round_trips_and_follows_moves() {
+        //! The FEN rebuilt from the pieces matches the one loaded, then tracks castling rights, en passant and both counters as moves are played
+
+        let fen = "r3k2r/pppq1ppp/2n5/3pP3/8/8/PPP2PPP/R3K2R w Kq d6 0 12";
+        let mut game = GameHarness::builder().fen(fen).build();
+        game.app
+            .world_mut()
+            .run_system_once(
+                |mut engine: ResMut<ChessEngine>, pieces: Query<(Entity, &Piece, &HasMoved)>| {
+                    engine.sync_ecs_to_engine(&pieces)
+                },
+            )
+            .unwrap();
+        assert_eq!(game.resource::<ChessEngine>().current_fen(), fen);
+
+        // A rook move gives up White's last castling right.
+        assert!(game.play("h1g1"));
+        assert_eq!(
+            game.resource::<ChessEngine>().current_fen(),
+            "r3k2r/pppq1ppp/2n5/3pP3/8/8/PPP2PPP/R3K1R1 b q - 1 12"
+        );
+
+        assert!(game.play("e8c8"));
+        assert_eq!(
+            game.resource::<ChessEngine>().current_fen(),
+            "2kr3r/pppq1ppp/2n5/3pP3/8/8/PPP2PPP/R3K1R1 w - - 2 13"
+        );
+    }
+}
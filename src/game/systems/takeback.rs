@@ -0,0 +1,631 @@
+//! Taking moves back, and replaying them
+//!
+//! The Undo button and the [`GameAction::Undo`] key send a
+//! [`TakebackRequest`]. In an offline game [`apply_takeback`] then undoes the
+//! last move from the [`Takeback`] recorded when it was played: the piece
+//! goes back to its square with its old `HasMoved`, a castling rook returns,
+//! a captured piece comes back from the tray (or from under the board), a
+//! promoted piece is a pawn again, and the engine is put back on the earlier
+//! position, repetition history included.
+//!
+//! Against the computer the takeback goes back to the player's last move, so
+//! both the computer's reply and the player's move are undone; a search for
+//! the computer's move still running is dropped first.
+//!
+//! Moves taken back wait in [`MoveHistory::redo_moves`]. Redo (button or
+//! [`GameAction::Redo`]) plays them again through [`execute_move`], one per
+//! frame so each turn passes as usual, with the normal animations and
+//! sounds; against the computer the computer's reply comes back with the
+//! player's move. Playing any other move forgets them.
+//!
+//! Clicking a move in the move list sends a [`JumpToMove`], which does
+//! either as many times as it takes to reach the position after that move.
+
+use crate::core::{GameAction, GameMode, GameSettings, PieceFinish};
+use crate::engine::board_state::ChessEngine;
+use crate::game::ai::hint::PendingHint;
+use crate::game::ai::PendingAIMove;
+use crate::game::board_coords::file_world_x;
+use crate::game::components::{
+    CaptureFlight, Captured, FadingCapture, HasMoved, MoveRecord, PieceMoveAnimation,
+};
+use crate::game::events::MoveMadeEvent;
+use crate::game::premove::Premove;
+use crate::game::resources::{
+    AnimationSettings, CapturedPieces, CurrentTurn, GameOverState, GameSounds, MoveHistory,
+    PendingPromotion, PendingTurnAdvance, PieceSnapshot, Players, PromotionSelected, Selection,
+    Takeback,
+};
+use crate::game::systems::promotion::replace_piece_mesh;
+use crate::game::systems::shared::{execute_move, find_move_entities, move_sounds, MoveContext};
+use crate::rendering::pieces::{
+    resting_rotation, Piece, PieceColor, PieceMeshes, PIECE_ON_BOARD_Y,
+};
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+
+/// Seconds a piece takes to slide back to where it came from.
+const TAKEBACK_SLIDE_SECS: f32 = 0.3;
+
+/// Ask to take back the last move (the last two against the computer).
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TakebackRequest;
+
+/// Whether takebacks are allowed in `mode`: not against an online opponent,
+/// who would have to agree, nor in a replay.
+pub fn takebacks_allowed(mode: GameMode) -> bool {
+    matches!(mode, GameMode::SinglePlayer | GameMode::MultiplayerLocal)
+}
+
+/// How many plies a takeback undoes: back to and including the last move a
+/// human made, so a human is to move afterwards. Zero when no human has moved
+/// (a computer-vs-computer game) or that far back can't be taken back.
+pub fn plies_to_take_back(history: &MoveHistory, players: &Players) -> usize {
+    let plies = history
+        .moves
+        .iter()
+        .rev()
+        .position(|record| players.current(record.piece_color).is_human)
+        .map_or(0, |index| index + 1);
+    if plies <= history.takeback_depth() {
+        plies
+    } else {
+        0
+    }
+}
+
+/// Ask for the position just after the move at `index`, counting the moves
+/// waiting for redo after the last one played.
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct JumpToMove {
+    pub index: usize,
+}
+
+/// The move at `index` of the game, counting the moves waiting for redo.
+pub fn move_at(history: &MoveHistory, index: usize) -> Option<&MoveRecord> {
+    if let Some(record) = history.moves.get(index) {
+        return Some(record);
+    }
+    let redo = history.redo_moves();
+    let ahead = index - history.len();
+    redo.len()
+        .checked_sub(ahead + 1)
+        .map(|position| &redo[position])
+}
+
+/// Whether a human is to move after the move at `index`, so the game can
+/// wait there rather than the computer playing straight on.
+fn human_to_move_after(history: &MoveHistory, players: &Players, index: usize) -> bool {
+    move_at(history, index).is_some_and(|record| {
+        let next = match record.piece_color {
+            PieceColor::White => PieceColor::Black,
+            PieceColor::Black => PieceColor::White,
+        };
+        players.current(next).is_human
+    })
+}
+
+/// How many plies to take back for [`JumpToMove`] `index`. Zero when that
+/// move isn't behind the last one played, can't be taken back to, or leaves
+/// the computer to move.
+pub fn plies_back_to(history: &MoveHistory, players: &Players, index: usize) -> usize {
+    let plies = history.len().saturating_sub(index + 1);
+    if plies <= history.takeback_depth() && human_to_move_after(history, players, index) {
+        plies
+    } else {
+        0
+    }
+}
+
+/// How many moves to redo for [`JumpToMove`] `index`. Zero when that move
+/// isn't waiting for redo or leaves the computer to move.
+pub fn plies_forward_to(history: &MoveHistory, players: &Players, index: usize) -> usize {
+    if human_to_move_after(history, players, index) {
+        (index + 1).saturating_sub(history.len())
+    } else {
+        0
+    }
+}
+
+/// Send a [`TakebackRequest`] on the [`GameAction::Undo`] key.
+pub fn takeback_hotkey(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    settings: Res<GameSettings>,
+    mut requests: MessageWriter<TakebackRequest>,
+) {
+    if settings
+        .key_bindings
+        .just_pressed(&keyboard, GameAction::Undo)
+    {
+        requests.write(TakebackRequest);
+    }
+}
+
+/// What [`apply_takeback`] changes besides the pieces.
+#[derive(SystemParam)]
+pub struct TakebackState<'w> {
+    history: ResMut<'w, MoveHistory>,
+    captured_pieces: ResMut<'w, CapturedPieces>,
+    engine: ResMut<'w, ChessEngine>,
+    current_turn: ResMut<'w, CurrentTurn>,
+    selection: ResMut<'w, Selection>,
+    pending_promotion: ResMut<'w, PendingPromotion>,
+    premove: ResMut<'w, Premove>,
+    pending_turn: Res<'w, PendingTurnAdvance>,
+    game_over: Res<'w, GameOverState>,
+    game_mode: Res<'w, GameMode>,
+    players: Res<'w, Players>,
+    settings: Res<'w, GameSettings>,
+    animation: Res<'w, AnimationSettings>,
+}
+
+/// Undo moves on request, or to go back to a move in the list. See the
+/// module docs.
+pub fn apply_takeback(
+    mut commands: Commands,
+    mut requests: MessageReader<TakebackRequest>,
+    mut jumps: MessageReader<JumpToMove>,
+    mut state: TakebackState,
+    mut pieces: Query<(&mut Piece, &mut HasMoved, &mut Transform)>,
+    children: Query<&Children>,
+    piece_meshes: Option<Res<PieceMeshes>>,
+    mut materials: Option<ResMut<Assets<StandardMaterial>>>,
+) {
+    let undo = requests.read().count() > 0;
+    let jump = jumps.read().last().copied();
+    if !undo && jump.is_none() {
+        return;
+    }
+    if !takebacks_allowed(*state.game_mode)
+        || state.game_over.is_game_over()
+        || state.pending_turn.is_pending()
+    {
+        return;
+    }
+    let plies = match jump {
+        Some(JumpToMove { index }) => plies_back_to(&state.history, &state.players, index),
+        None => plies_to_take_back(&state.history, &state.players),
+    };
+    if plies == 0 {
+        return;
+    }
+
+    // Whatever was being worked out is about a position that is going away.
+    commands.remove_resource::<PendingAIMove>();
+    commands.remove_resource::<PendingHint>();
+    commands.remove_resource::<PendingRedo>();
+    state.pending_promotion.clear();
+    state.premove.clear();
+    state.selection.clear();
+
+    for _ in 0..plies {
+        let Some((record, takeback)) = state.history.pop_move() else {
+            break;
+        };
+        info!(
+            "[TAKEBACK] Taking back {:?} {:?} {:?} -> {:?}",
+            record.piece_color, record.piece_type, record.from, record.to
+        );
+        restore_pieces(
+            &mut commands,
+            &takeback,
+            &mut pieces,
+            &children,
+            piece_meshes.as_deref(),
+            materials.as_deref_mut(),
+            state.settings.piece_finish,
+            state.animation.duration(TAKEBACK_SLIDE_SECS),
+        );
+        if let Some(captured) = &takeback.captured {
+            let list = match captured.piece.color {
+                PieceColor::White => &mut state.captured_pieces.black_captured,
+                PieceColor::Black => &mut state.captured_pieces.white_captured,
+            };
+            if let Some(index) = list.iter().rposition(|&t| t == captured.piece.piece_type) {
+                list.remove(index);
+            }
+        }
+        state.engine.restore(&takeback.engine);
+        *state.current_turn = takeback.turn;
+    }
+}
+
+/// Put the pieces a move changed back as `takeback` recorded them.
+fn restore_pieces(
+    commands: &mut Commands,
+    takeback: &Takeback,
+    pieces: &mut Query<(&mut Piece, &mut HasMoved, &mut Transform)>,
+    children: &Query<&Children>,
+    piece_meshes: Option<&PieceMeshes>,
+    mut materials: Option<&mut Assets<StandardMaterial>>,
+    finish: PieceFinish,
+    slide_secs: f32,
+) {
+    for snapshot in [Some(&takeback.moved), takeback.rook.as_ref()]
+        .into_iter()
+        .flatten()
+    {
+        let Ok((mut piece, mut has_moved, transform)) = pieces.get_mut(snapshot.entity) else {
+            continue;
+        };
+        // Undoing a promotion: the pawn's mesh comes back too.
+        let promoted = piece.piece_type != snapshot.piece.piece_type;
+        *piece = snapshot.piece;
+        *has_moved = snapshot.has_moved;
+        if promoted {
+            if let (Some(meshes), Some(materials), Ok(children)) = (
+                piece_meshes,
+                materials.as_deref_mut(),
+                children.get(snapshot.entity),
+            ) {
+                replace_piece_mesh(
+                    commands,
+                    snapshot.entity,
+                    &snapshot.piece,
+                    children,
+                    meshes,
+                    materials,
+                    finish,
+                );
+            }
+        }
+        commands
+            .entity(snapshot.entity)
+            .insert(PieceMoveAnimation::new(
+                transform.translation,
+                square_position(snapshot),
+                slide_secs,
+            ));
+    }
+
+    if let Some(captured) = &takeback.captured {
+        if let Ok((mut piece, mut has_moved, mut transform)) = pieces.get_mut(captured.entity) {
+            *piece = captured.piece;
+            *has_moved = captured.has_moved;
+            *transform = Transform::from_translation(square_position(captured)).with_rotation(
+                resting_rotation(captured.piece.piece_type, captured.piece.color),
+            );
+        }
+        commands
+            .entity(captured.entity)
+            .remove::<(Captured, FadingCapture, CaptureFlight, PieceMoveAnimation)>()
+            .insert(Visibility::Inherited);
+    }
+}
+
+/// World position of the square a snapshot's piece stood on.
+fn square_position(snapshot: &PieceSnapshot) -> Vec3 {
+    Vec3::new(
+        file_world_x(snapshot.piece.x),
+        PIECE_ON_BOARD_Y,
+        snapshot.piece.y as f32,
+    )
+}
+
+/// Ask to replay the next move taken back (with the computer's reply
+/// against the computer).
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RedoRequest;
+
+/// Moves still to replay for a redo in progress. While it exists the
+/// computer doesn't start a search of its own.
+#[derive(Resource, Debug)]
+pub struct PendingRedo {
+    pub plies: usize,
+}
+
+/// How many moves a redo replays: the next one, and the computer's replies
+/// after it, so a human is to move again afterwards.
+pub fn plies_to_redo(history: &MoveHistory, players: &Players) -> usize {
+    let mut upcoming = history.redo_moves().iter().rev();
+    if upcoming.next().is_none() {
+        return 0;
+    }
+    1 + upcoming
+        .take_while(|record| !players.current(record.piece_color).is_human)
+        .count()
+}
+
+/// Send a [`RedoRequest`] on the [`GameAction::Redo`] key.
+pub fn redo_hotkey(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    settings: Res<GameSettings>,
+    mut requests: MessageWriter<RedoRequest>,
+) {
+    if settings
+        .key_bindings
+        .just_pressed(&keyboard, GameAction::Redo)
+    {
+        requests.write(RedoRequest);
+    }
+}
+
+/// Start replaying moves on request, or to go forward to a move in the list.
+pub fn handle_redo_requests(
+    mut commands: Commands,
+    mut requests: MessageReader<RedoRequest>,
+    mut jumps: MessageReader<JumpToMove>,
+    history: Res<MoveHistory>,
+    players: Res<Players>,
+    game_mode: Res<GameMode>,
+    game_over: Res<GameOverState>,
+    pending: Option<Res<PendingRedo>>,
+    mut premove: ResMut<Premove>,
+) {
+    let redo = requests.read().count() > 0;
+    let jump = jumps.read().last().copied();
+    if (!redo && jump.is_none()) || pending.is_some() {
+        return;
+    }
+    if !takebacks_allowed(*game_mode) || game_over.is_game_over() {
+        return;
+    }
+    let plies = match jump {
+        Some(JumpToMove { index }) => plies_forward_to(&history, &players, index),
+        None => plies_to_redo(&history, &players),
+    };
+    if plies > 0 {
+        commands.remove_resource::<PendingAIMove>();
+        commands.remove_resource::<PendingHint>();
+        premove.clear();
+        commands.insert_resource(PendingRedo { plies });
+    }
+}
+
+/// What [`apply_redo`] needs to play a move.
+#[derive(SystemParam)]
+pub struct RedoParams<'w, 's> {
+    pending: Option<ResMut<'w, PendingRedo>>,
+    history: ResMut<'w, MoveHistory>,
+    captured_pieces: ResMut<'w, CapturedPieces>,
+    engine: ResMut<'w, ChessEngine>,
+    pending_turn: ResMut<'w, PendingTurnAdvance>,
+    current_turn: Res<'w, CurrentTurn>,
+    game_over: Res<'w, GameOverState>,
+    settings: Res<'w, GameSettings>,
+    animation: Res<'w, AnimationSettings>,
+    sounds: Option<Res<'w, GameSounds>>,
+    pieces: Query<'w, 's, (Entity, &'static mut Piece, &'static mut HasMoved)>,
+    move_events: MessageWriter<'w, MoveMadeEvent>,
+    promotion_messages: MessageWriter<'w, PromotionSelected>,
+}
+
+/// Replay the next move of a [`PendingRedo`], once the previous one's turn
+/// has passed.
+pub fn apply_redo(mut commands: Commands, mut params: RedoParams) {
+    let Some(mut pending) = params.pending.take() else {
+        return;
+    };
+    if params.pending_turn.is_pending() {
+        return;
+    }
+    let next = params.history.redo_moves().last().copied();
+    let Some(record) = next.filter(|_| pending.plies > 0 && !params.game_over.is_game_over())
+    else {
+        commands.remove_resource::<PendingRedo>();
+        return;
+    };
+
+    let uci = format!(
+        "{}{}",
+        ChessEngine::coords_to_uci(record.from.0, record.from.1),
+        ChessEngine::coords_to_uci(record.to.0, record.to.1)
+    );
+    let found = find_move_entities(&params.pieces, record.from, record.to);
+    let Some((entity, piece, is_first_move, capture)) = found.filter(|(_, piece, _, _)| {
+        piece.color == params.current_turn.color && params.engine.is_move_legal_by_uci(&uci)
+    }) else {
+        warn!("[REDO] {} can't be replayed here", uci);
+        commands.remove_resource::<PendingRedo>();
+        return;
+    };
+
+    let sounds = move_sounds(params.sounds.as_deref());
+    let ctx = MoveContext {
+        origin: "redo",
+        entity,
+        piece,
+        target: record.to,
+        capture,
+        promotion: record.promotion,
+        was_first_move: is_first_move,
+        remote: false,
+        sounds,
+        game_id: None,
+        capture_animation: params.settings.capture_animation,
+        animation: *params.animation,
+    };
+    let moved = execute_move(
+        &ctx,
+        &mut commands,
+        &mut params.pending_turn,
+        &mut params.history,
+        &mut params.captured_pieces,
+        &mut params.engine,
+        &mut params.pieces,
+        Some(&mut params.move_events),
+        None,
+        &params.current_turn,
+    );
+    // execute_move already changed the piece type; this swaps the mesh.
+    if let (true, Some(promoted_to)) = (moved, record.promotion) {
+        params.promotion_messages.write(PromotionSelected {
+            entity,
+            position: record.to,
+            promoted_to,
+        });
+    }
+
+    pending.plies -= 1;
+    if !moved || pending.plies == 0 {
+        commands.remove_resource::<PendingRedo>();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::test_support::GameHarness;
+    use crate::rendering::pieces::PieceType;
+
+    /// Everything a takeback has to put back: the engine's FEN and each
+    /// piece's type, square and movement record, in entity order.
+    fn board_state(game: &mut GameHarness) -> (String, Vec<(Entity, String)>) {
+        let world = game.app.world_mut();
+        let mut pieces: Vec<(Entity, String)> = world
+            .query::<(Entity, &Piece, &HasMoved)>()
+            .iter(world)
+            .map(|(entity, piece, has_moved)| {
+                let state = format!(
+                    "{:?} {:?} {},{} {} {}",
+                    piece.color,
+                    piece.piece_type,
+                    piece.x,
+                    piece.y,
+                    has_moved.moved,
+                    has_moved.move_count
+                );
+                (entity, state)
+            })
+            .collect();
+        pieces.sort();
+        (world.resource::<ChessEngine>().to_fen(), pieces)
+    }
+
+    #[test]
+    fn test_takeback_restores_a_capture_and_a_castle() {
+        //! Taking back a capture and a castle leaves the board, the engine and the tray as they were before each
+
+        let mut game = GameHarness::builder()
+            .fen("r3k2r/pppq1ppp/8/3p4/4P3/8/PPPQ1PPP/R3K2R w KQkq - 0 1")
+            .build();
+        let before_capture = board_state(&mut game);
+        let taken = game.piece_at("d5").unwrap();
+        assert!(game.play("e4d5"));
+        assert_eq!(game.resource::<CapturedPieces>().white_captured.len(), 1);
+
+        game.take_back();
+        assert_eq!(board_state(&mut game), before_capture);
+        assert_eq!(game.resource::<MoveHistory>().len(), 0);
+        assert_eq!(game.resource::<CurrentTurn>().color, PieceColor::White);
+        assert!(game.resource::<CapturedPieces>().white_captured.is_empty());
+        let world = game.app.world();
+        assert!(world.get::<Captured>(taken).is_none());
+        assert!(world.get::<FadingCapture>(taken).is_none());
+
+        // The castle and its rook, then the same move again.
+        let before_castle = board_state(&mut game);
+        assert!(game.play("e1c1"));
+        assert!(game.piece_at("d1").is_some(), "the rook stayed on a1");
+        game.take_back();
+        assert_eq!(board_state(&mut game), before_castle);
+        assert!(game.play("e1c1"), "castling was refused after the takeback");
+        assert!(game.play("e8g8"));
+    }
+
+    #[test]
+    fn test_takeback_against_the_computer_undoes_its_reply_too() {
+        //! With Black played by the computer, one takeback undoes Black's reply and White's move, and a promotion reverts to a pawn
+
+        let mut game = GameHarness::builder()
+            .fen("7k/1P6/8/8/8/8/6p1/K7 w - - 0 1")
+            .build();
+        let start = board_state(&mut game);
+        let pawn = game.piece_at("b7").unwrap();
+        assert!(game.play("b7b8"));
+        // The promotion dialog's pick.
+        game.app
+            .world_mut()
+            .get_mut::<Piece>(pawn)
+            .unwrap()
+            .piece_type = PieceType::Queen;
+        assert!(game.play("h8g7"));
+        game.app
+            .world_mut()
+            .resource_mut::<Players>()
+            .player_2
+            .is_human = false;
+
+        assert_eq!(
+            plies_to_take_back(game.resource::<MoveHistory>(), game.resource::<Players>()),
+            2
+        );
+        game.take_back();
+        assert_eq!(board_state(&mut game), start);
+        assert_eq!(
+            game.app.world().get::<Piece>(pawn).unwrap().piece_type,
+            PieceType::Pawn
+        );
+        assert!(game.resource::<MoveHistory>().is_empty());
+        assert_eq!(
+            plies_to_take_back(game.resource::<MoveHistory>(), game.resource::<Players>()),
+            0
+        );
+    }
+
+    #[test]
+    fn test_redo_replays_the_move_taken_back() {
+        //! Move, take back, redo: the board and engine are as they were after the move, and a different move forgets the redo
+
+        let mut game = GameHarness::builder().build();
+        for uci in ["e2e4", "d7d5", "e4d5"] {
+            assert!(game.play(uci), "{} was refused", uci);
+        }
+        let after = board_state(&mut game);
+
+        game.take_back();
+        assert_eq!(game.resource::<MoveHistory>().redo_moves().len(), 1);
+        game.redo();
+        assert_eq!(board_state(&mut game), after);
+        assert_eq!(game.resource::<MoveHistory>().len(), 3);
+        assert!(game.resource::<MoveHistory>().redo_moves().is_empty());
+        assert_eq!(game.resource::<CapturedPieces>().white_captured.len(), 1);
+        assert_eq!(game.resource::<CurrentTurn>().color, PieceColor::Black);
+
+        game.take_back();
+        game.take_back();
+        assert_eq!(game.resource::<MoveHistory>().redo_moves().len(), 2);
+        assert!(game.play("d7d6"));
+        assert!(game.resource::<MoveHistory>().redo_moves().is_empty());
+    }
+
+    #[test]
+    fn test_jump_to_a_move_in_the_list_and_back() {
+        //! Clicking an earlier move takes back to just after it, and clicking a later one replays up to it
+
+        let mut game = GameHarness::builder().build();
+        let mut after = Vec::new();
+        for uci in ["e2e4", "e7e5", "g1f3", "b8c6"] {
+            assert!(game.play(uci), "{} was refused", uci);
+            after.push(board_state(&mut game));
+        }
+
+        game.jump_to(0);
+        assert_eq!(board_state(&mut game), after[0]);
+        let history = game.resource::<MoveHistory>();
+        assert_eq!((history.len(), history.redo_moves().len()), (1, 3));
+        assert_eq!(move_at(history, 3).map(|record| record.to), Some((2, 5)));
+        assert_eq!(move_at(history, 4), None);
+
+        game.jump_to(2);
+        assert_eq!(board_state(&mut game), after[2]);
+        assert_eq!(game.resource::<CurrentTurn>().color, PieceColor::Black);
+        assert_eq!(game.resource::<MoveHistory>().redo_san_at(0), Some("Nc6"));
+
+        // Against the computer, only positions with the player to move.
+        game.app
+            .world_mut()
+            .resource_mut::<Players>()
+            .player_2
+            .is_human = false;
+        let history = game.resource::<MoveHistory>();
+        let players = game.resource::<Players>();
+        assert_eq!(plies_back_to(history, players, 1), 1);
+        assert_eq!(
+            plies_back_to(history, players, 0),
+            0,
+            "Black would reply at once"
+        );
+        assert_eq!(plies_forward_to(history, players, 3), 1);
+    }
+}
@@ -1,6 +1,16 @@
-use crate::game::components::{FadingCapture, PieceMoveAnimation};
-use crate::game::resources::{CurrentTurn, GameTimer, PendingTurnAdvance, Selection};
-use crate::rendering::pieces::{Piece, PIECE_ON_BOARD_Y};
+use crate::core::GameSettings;
+use crate::game::board_coords::file_world_x;
+use crate::game::components::{
+    CaptureFlight, Captured, DraggedPiece, FadingCapture, GamePhase, PieceMoveAnimation,
+    PromotionAnimation, SelectedPiece,
+};
+use crate::game::resources::{
+    CurrentGamePhase, CurrentTurn, GameTimer, PendingTurnAdvance, Selection,
+};
+use crate::rendering::effects::{spawn_square_overlay, CHECK_SQUARE_ALPHA};
+use crate::rendering::pieces::{
+    Piece, Piece3DVisual, PieceMaterialPreset, PieceType, PIECE_ON_BOARD_Y,
+};
 use crate::rendering::utils::{Square, SquareMaterials};
 use bevy::prelude::*;
 
@@ -43,8 +53,9 @@ pub struct MoveHint;
 /// or event-based updates if this becomes a bottleneck.
 pub fn highlight_possible_moves(
     selection: Res<Selection>,
+    settings: Res<crate::core::GameSettings>,
     square_materials: Res<SquareMaterials>,
-    squares_query: Query<(&Square, &Children)>,
+    squares_query: Query<&Square>,
     mut commands: Commands,
     marker_query: Query<Entity, Or<(With<SelectedBorder>, With<MoveHint>)>>,
 ) {
@@ -54,23 +65,22 @@ pub fn highlight_possible_moves(
     }
 
     // Spawn new markers based on current selection.
-    for (square, _children) in squares_query.iter() {
+    for square in squares_query.iter() {
         let pos = (square.x, square.y);
         let is_selected = selection.selected_position == Some(pos);
         let is_valid_move = selection.is_selected() && selection.possible_moves.contains(&pos);
 
         if is_selected {
-            commands.spawn((
-                Mesh3d(square_materials.highlight_mesh.clone()),
-                MeshMaterial3d(square_materials.selected_border_matl.clone()),
-                Transform::from_translation(Vec3::new(square.x as f32, 0.03, square.y as f32)),
-                SelectedBorder,
-                Name::new("Selected Border"),
-                crate::core::DespawnOnExit(crate::core::GameState::InGame),
-                bevy::camera::visibility::RenderLayers::layer(
-                    crate::game::systems::camera::BOARD_LAYER,
-                ),
-            ));
+            crate::rendering::effects::spawn_square_overlay(
+                &mut commands,
+                &square_materials,
+                settings.highlight_style,
+                &square_materials.selected_border_matl,
+                Vec3::new(square.x as f32, 0.03, square.y as f32),
+                |entity| {
+                    entity.insert((SelectedBorder, Name::new("Selected Border")));
+                },
+            );
         }
 
         if is_valid_move {
@@ -90,6 +100,71 @@ pub fn highlight_possible_moves(
     }
 }
 
+/// Emissive glow added to the selected piece's material.
+const SELECTED_PIECE_EMISSIVE: LinearRgba = LinearRgba::rgb(0.22, 0.18, 0.06);
+
+/// Tinted material swapped onto a selected piece's meshes.
+///
+/// Holds the piece's own material so it can be put back on deselection. The
+/// tinted copy is a separate asset, so other pieces that share `original`
+/// (promotions, replays) never light up with it.
+#[derive(Component)]
+pub struct SelectionTint {
+    pub original: Handle<StandardMaterial>,
+    pub tinted: Handle<StandardMaterial>,
+}
+
+/// System to give the selected piece a subtle emissive tint.
+///
+/// On `SelectedPiece` being added, clones the piece's material with an
+/// emissive glow and points its [`Piece3DVisual`] meshes at the clone. When
+/// `SelectedPiece` goes away the original handle is restored and the clone is
+/// dropped from `Assets`.
+pub fn tint_selected_piece(
+    mut commands: Commands,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    newly_selected: Query<(Entity, &Children), (With<SelectedPiece>, Without<SelectionTint>)>,
+    deselected: Query<(Entity, &Children, &SelectionTint), Without<SelectedPiece>>,
+    mut visuals: Query<&mut MeshMaterial3d<StandardMaterial>, With<Piece3DVisual>>,
+) {
+    for (entity, children, tint) in deselected.iter() {
+        for child in children.iter() {
+            if let Ok(mut material) = visuals.get_mut(child) {
+                if material.0 == tint.tinted {
+                    material.0 = tint.original.clone();
+                }
+            }
+        }
+        materials.remove(&tint.tinted);
+        commands.entity(entity).remove::<SelectionTint>();
+    }
+
+    for (entity, children) in newly_selected.iter() {
+        let Some(original) = children
+            .iter()
+            .find_map(|child| visuals.get(child).ok().map(|m| m.0.clone()))
+        else {
+            continue;
+        };
+        let Some(mut tinted) = materials.get(&original).cloned() else {
+            continue;
+        };
+        tinted.emissive = tinted.emissive + SELECTED_PIECE_EMISSIVE;
+        let tinted = materials.add(tinted);
+
+        for child in children.iter() {
+            if let Ok(mut material) = visuals.get_mut(child) {
+                if material.0 == original {
+                    material.0 = tinted.clone();
+                }
+            }
+        }
+        commands
+            .entity(entity)
+            .insert(SelectionTint { original, tinted });
+    }
+}
+
 /// System to animate piece movement with a smooth arc.
 ///
 /// Each frame, increments `PieceMoveAnimation::elapsed` and interpolates
@@ -99,16 +174,21 @@ pub fn highlight_possible_moves(
 ///
 /// The component is removed once `elapsed >= duration`, at which point the
 /// piece snaps exactly to `end`.  Pieces without an active animation are
-/// kept in sync with their `Piece` logical position each frame.
+/// kept in sync with their `Piece` logical position each frame, except a
+/// [`DraggedPiece`], which follows the pointer until it is dropped, and
+/// captured pieces, which stay wherever their capture left them.
 pub fn animate_piece_movement(
     time: Res<Time>,
     mut commands: Commands,
-    mut query: Query<(
-        Entity,
-        &mut Transform,
-        &Piece,
-        Option<&mut PieceMoveAnimation>,
-    )>,
+    mut query: Query<
+        (
+            Entity,
+            &mut Transform,
+            &Piece,
+            Option<&mut PieceMoveAnimation>,
+        ),
+        Without<DraggedPiece>,
+    >,
 ) {
     let dt = time.delta_secs();
     for (entity, mut transform, piece, animation) in query.iter_mut() {
@@ -133,8 +213,8 @@ pub fn animate_piece_movement(
 
                 transform.translation = Vec3::new(base.x, base.y + arc_y, base.z);
             }
-        } else {
-            let target = Vec3::new(7.0 - piece.x as f32, PIECE_ON_BOARD_Y, piece.y as f32);
+        } else if piece.is_on_board() {
+            let target = Vec3::new(file_world_x(piece.x), PIECE_ON_BOARD_Y, piece.y as f32);
             if (transform.translation - target).length() > 0.01 {
                 transform.translation = target;
             }
@@ -144,12 +224,15 @@ pub fn animate_piece_movement(
 
 /// System to animate captured pieces with a parabolic arc, spin, and scale-to-zero.
 ///
-/// # Animation phases (all simultaneous over the `FadingCapture` timer's duration, 0.75 s)
+/// # Animation phases (all simultaneous over the `FadingCapture` timer's duration)
 ///
 /// - **Arc**: piece rises to `arc_height` at t=0.5, then falls back toward the board.
 ///   Uses a parabolic curve: `y_offset = arc_height * 4t(1-t)`.
 /// - **Spin**: piece rotates `spin_radians` around its `spin_axis` using smooth-step t.
 /// - **Scale**: piece shrinks to zero using smooth-step easing.
+///
+/// When the timer runs out the piece is hidden and marked [`Captured`] rather
+/// than despawned, so a takeback can bring it back.
 pub fn animate_capture_fade(
     time: Res<Time>,
     mut commands: Commands,
@@ -181,9 +264,185 @@ pub fn animate_capture_fade(
         transform.scale = Vec3::splat(scale);
 
         if fading.timer.just_finished() {
+            commands
+                .entity(entity)
+                .remove::<FadingCapture>()
+                .insert((Captured, Visibility::Hidden));
+        }
+    }
+}
+
+/// Colour a promoted piece glows at the height of its [`PromotionAnimation`].
+const PROMOTION_GLOW: LinearRgba = LinearRgba::rgb(2.0, 1.6, 0.6);
+
+/// Flies captured pieces along their [`CaptureFlight`] to the tray.
+///
+/// The piece's materials are put back to the chosen piece finish each frame
+/// and alpha-blended at [`CaptureFlight::alpha`] of its opacity, so the
+/// piece lands in the tray exactly as it looked on the board.
+pub fn animate_capture_flight(
+    time: Res<Time>,
+    mut commands: Commands,
+    settings: Res<GameSettings>,
+    mut query: Query<(
+        Entity,
+        &mut Transform,
+        &mut CaptureFlight,
+        &Piece,
+        &Children,
+    )>,
+    visuals: Query<&MeshMaterial3d<StandardMaterial>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    let preset = PieceMaterialPreset::for_finish(settings.piece_finish);
+    for (entity, mut transform, mut flight, piece, children) in query.iter_mut() {
+        flight.elapsed += time.delta_secs();
+        transform.translation = flight.position();
+        let finished = flight.is_finished();
+
+        for child in children.iter() {
+            let Ok(visual) = visuals.get(child) else {
+                continue;
+            };
+            if let Some(mut material) = materials.get_mut(&visual.0) {
+                preset.apply(&mut material, piece.color);
+                if !finished {
+                    material.base_color = material
+                        .base_color
+                        .with_alpha(preset.alpha * flight.alpha());
+                    material.alpha_mode = AlphaMode::Blend;
+                }
+            }
+        }
+
+        if finished {
+            commands.entity(entity).remove::<CaptureFlight>();
+        }
+    }
+}
+
+/// Plays the [`PromotionAnimation`] on freshly promoted pieces: the piece
+/// swells and glows, then settles at its normal size in the chosen finish.
+pub fn animate_promotion(
+    time: Res<Time>,
+    mut commands: Commands,
+    settings: Res<GameSettings>,
+    mut query: Query<(
+        Entity,
+        &mut Transform,
+        &mut PromotionAnimation,
+        &Piece,
+        &Children,
+    )>,
+    visuals: Query<&MeshMaterial3d<StandardMaterial>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    let preset = PieceMaterialPreset::for_finish(settings.piece_finish);
+    for (entity, mut transform, mut animation, piece, children) in query.iter_mut() {
+        animation.elapsed += time.delta_secs();
+        let finished = animation.is_finished();
+        transform.scale = Vec3::splat(if finished { 1.0 } else { animation.scale() });
+
+        for child in children.iter() {
+            let Ok(visual) = visuals.get(child) else {
+                continue;
+            };
+            if let Some(mut material) = materials.get_mut(&visual.0) {
+                preset.apply(&mut material, piece.color);
+                if !finished {
+                    material.emissive += PROMOTION_GLOW * animation.pulse();
+                }
+            }
+        }
+
+        if finished {
+            commands.entity(entity).remove::<PromotionAnimation>();
+        }
+    }
+}
+
+/// Marks the king of the side to move while it is in check.
+#[derive(Component, Debug)]
+pub struct KingInCheck;
+
+/// The square overlay under a [`KingInCheck`], on the king's square.
+#[derive(Component, Debug)]
+pub struct CheckSquareHighlight {
+    pub square: (u8, u8),
+}
+
+/// Pulses of the check square per second.
+const CHECK_PULSE_HZ: f32 = 0.75;
+
+/// Puts [`KingInCheck`] on the king of the side to move once
+/// `update_game_phase` finds it in check (or mated), and takes it off when
+/// the check is over.
+pub fn mark_king_in_check(
+    mut commands: Commands,
+    game_phase: Res<CurrentGamePhase>,
+    current_turn: Res<CurrentTurn>,
+    pieces: Query<(Entity, &Piece, Has<KingInCheck>), Without<Captured>>,
+) {
+    let in_check = matches!(game_phase.0, GamePhase::Check | GamePhase::Checkmate);
+    for (entity, piece, marked) in pieces.iter() {
+        let checked =
+            in_check && piece.piece_type == PieceType::King && piece.color == current_turn.color;
+        if checked && !marked {
+            commands.entity(entity).insert(KingInCheck);
+        } else if marked && !checked {
+            commands.entity(entity).remove::<KingInCheck>();
+        }
+    }
+}
+
+/// Keeps a [`CheckSquareHighlight`] on the square of the [`KingInCheck`] and
+/// pulses its colour, dimmed by the highlight opacity setting.
+pub fn pulse_check_highlight(
+    mut commands: Commands,
+    time: Res<Time>,
+    settings: Res<crate::core::GameSettings>,
+    square_materials: Res<SquareMaterials>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    king: Query<&Piece, With<KingInCheck>>,
+    highlights: Query<(Entity, &CheckSquareHighlight)>,
+) {
+    let square = king.iter().next().map(|piece| (piece.x, piece.y));
+    let stale = highlights
+        .iter()
+        .any(|(_, highlight)| Some(highlight.square) != square);
+    if stale {
+        for (entity, _) in highlights.iter() {
             commands.entity(entity).despawn();
         }
     }
+    let Some((x, y)) = square else {
+        return;
+    };
+    if stale || highlights.is_empty() {
+        spawn_square_overlay(
+            &mut commands,
+            &square_materials,
+            settings.highlight_style,
+            &square_materials.check_matl,
+            // Above the last-move and engine hint squares it may share.
+            Vec3::new(file_world_x(x), 0.03, y as f32),
+            |entity| {
+                entity.insert((
+                    CheckSquareHighlight { square: (x, y) },
+                    Name::new("Check Square Highlight"),
+                ));
+            },
+        );
+    }
+
+    // Between 55% and full strength.
+    let phase = time.elapsed_secs() * CHECK_PULSE_HZ * std::f32::consts::TAU;
+    let pulse = 0.775 + 0.225 * phase.sin();
+    if let Some(mut material) = materials.get_mut(&square_materials.check_matl) {
+        material
+            .base_color
+            .set_alpha(CHECK_SQUARE_ALPHA * pulse * settings.highlight_opacity);
+    }
 }
 
 /// Setup global scene elements (persistent background, ambient light)
@@ -225,6 +484,9 @@ pub fn setup_global_scene(
 #[derive(Component)]
 pub(crate) struct CameraFollowLight;
 
+/// Background behind the board in the standard views.
+pub(crate) const STANDARD_CLEAR_COLOR: Color = Color::srgb(0.0, 0.0, 0.0);
+
 /// Setup game scene when entering InGame state
 ///
 /// Spawns the game camera, lighting, and chess board.
@@ -233,9 +495,6 @@ pub fn setup_game_scene(
     view_mode: Res<crate::game::view_mode::ViewMode>,
     mut global_ambient: ResMut<bevy::light::GlobalAmbientLight>,
 ) {
-    use crate::core::DespawnOnExit;
-    use crate::core::GameState;
-
     // Reset global ambient so menu lighting never bleeds into the game.
     global_ambient.color = Color::WHITE;
     global_ambient.brightness = 0.0;
@@ -246,7 +505,7 @@ pub fn setup_game_scene(
         commands.insert_resource(ClearColor(Color::srgb(1.0, 1.0, 0.0))); // Pure yellow #FFFF00
     } else {
         // Default dark background for standard view
-        commands.insert_resource(ClearColor(Color::srgb(0.0, 0.0, 0.0))); // Black
+        commands.insert_resource(ClearColor(STANDARD_CLEAR_COLOR));
     }
 
     // Setup camera based on view mode
@@ -258,33 +517,40 @@ pub fn setup_game_scene(
 
     // Skip lights for TempleOS mode (unlit rendering)
     if !view_mode.is_templeos() {
-        // Key light is the overhead "Angel Light" (2M, shadows on, spawned in
-        // `game_init`) — camera-independent. This is the *fill*: a camera-following
-        // "headlamp" so the viewer-facing side of every piece stays evenly lit no
-        // matter how the player orbits/zooms. Its position is updated each frame by
-        // `update_board_fill_light`. No fixed directional/fill (those over-brightened
-        // the board and lit unevenly as the camera moved).
-        commands.spawn((
-            PointLight {
-                intensity: 600_000.0,
-                range: 80.0,
-                color: Color::srgb(0.95, 0.96, 1.0),
-                shadow_maps_enabled: false,
-                ..default()
-            },
-            Transform::from_xyz(3.5, 12.0, 3.5),
-            CameraFollowLight,
-            DespawnOnExit(GameState::InGame),
-            bevy::camera::visibility::RenderLayers::layer(
-                crate::game::systems::camera::BOARD_LAYER,
-            ),
-            Name::new("Board Fill Light (camera-follow)"),
-        ));
+        spawn_board_fill_light(&mut commands);
     }
 
     // Note: Ambient light is set globally in setup_global_scene (Startup)
 }
 
+/// Spawn the board's camera-following fill light.
+///
+/// Key light is the overhead "Angel Light" (2M, shadows on, spawned in
+/// `game_init`) — camera-independent. This is the *fill*: a camera-following
+/// "headlamp" so the viewer-facing side of every piece stays evenly lit no
+/// matter how the player orbits/zooms. Its position is updated each frame by
+/// `update_board_fill_light`. No fixed directional/fill (those over-brightened
+/// the board and lit unevenly as the camera moved).
+pub(crate) fn spawn_board_fill_light(commands: &mut Commands) {
+    use crate::core::DespawnOnExit;
+    use crate::core::GameState;
+
+    commands.spawn((
+        PointLight {
+            intensity: 600_000.0,
+            range: 80.0,
+            color: Color::srgb(0.95, 0.96, 1.0),
+            shadow_maps_enabled: false,
+            ..default()
+        },
+        Transform::from_xyz(3.5, 12.0, 3.5),
+        CameraFollowLight,
+        DespawnOnExit(GameState::InGame),
+        bevy::camera::visibility::RenderLayers::layer(crate::game::systems::camera::BOARD_LAYER),
+        Name::new("Board Fill Light (camera-follow)"),
+    ));
+}
+
 /// Keeps the board fill light at the viewer's position so pieces are lit evenly
 /// from the camera's side no matter how the player orbits or zooms. The overhead
 /// "Angel Light" and the ambient stay camera-independent; this is the moving fill.
@@ -311,3 +577,128 @@ pub fn update_board_fill_light(
         t.translation = pos;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rendering::pieces::PieceColor;
+    use crate::rendering::pieces::PieceType;
+    use bevy::ecs::system::RunSystemOnce;
+
+    fn visual_material(world: &mut World, piece: Entity) -> Handle<StandardMaterial> {
+        let child = world.get::<Children>(piece).unwrap()[0];
+        world
+            .get::<MeshMaterial3d<StandardMaterial>>(child)
+            .unwrap()
+            .0
+            .clone()
+    }
+
+    #[test]
+    fn king_in_check_is_marked_until_the_check_ends() {
+        //! Only the checked side's king carries KingInCheck, and it loses it when the phase goes back to playing
+
+        let mut world = World::new();
+        world.insert_resource(CurrentGamePhase(GamePhase::Check));
+        world.insert_resource(CurrentTurn {
+            color: PieceColor::Black,
+            ..default()
+        });
+        let black_king = world
+            .spawn(Piece::new(PieceColor::Black, PieceType::King, 4, 7))
+            .id();
+        let white_king = world
+            .spawn(Piece::new(PieceColor::White, PieceType::King, 4, 0))
+            .id();
+        let checker = world
+            .spawn(Piece::new(PieceColor::White, PieceType::Queen, 4, 4))
+            .id();
+
+        world.run_system_once(mark_king_in_check).unwrap();
+        assert!(world.get::<KingInCheck>(black_king).is_some());
+        assert!(world.get::<KingInCheck>(white_king).is_none());
+        assert!(world.get::<KingInCheck>(checker).is_none());
+
+        world.resource_mut::<CurrentGamePhase>().0 = GamePhase::Playing;
+        world.run_system_once(mark_king_in_check).unwrap();
+        assert!(world.get::<KingInCheck>(black_king).is_none());
+    }
+
+    #[test]
+    fn selected_piece_gets_its_own_tinted_material() {
+        //! Selecting clones the material; deselecting restores the shared handle
+
+        let mut world = World::new();
+        world.init_resource::<Assets<StandardMaterial>>();
+        let shared = world
+            .resource_mut::<Assets<StandardMaterial>>()
+            .add(StandardMaterial::default());
+
+        let spawn_piece = |world: &mut World, file: u8| {
+            world
+                .spawn(Piece::new(PieceColor::White, PieceType::Pawn, file, 1))
+                .with_children(|parent| {
+                    parent.spawn((MeshMaterial3d(shared.clone()), Piece3DVisual));
+                })
+                .id()
+        };
+        let selected = spawn_piece(&mut world, 0);
+        let other = spawn_piece(&mut world, 1);
+
+        world.entity_mut(selected).insert(SelectedPiece {
+            entity: selected,
+            position: (0, 1),
+        });
+        world.run_system_once(tint_selected_piece).unwrap();
+
+        let tinted = visual_material(&mut world, selected);
+        assert_ne!(
+            tinted, shared,
+            "selected piece should get a unique material"
+        );
+        assert_eq!(visual_material(&mut world, other), shared);
+        let materials = world.resource::<Assets<StandardMaterial>>();
+        assert_ne!(materials.get(&tinted).unwrap().emissive, LinearRgba::BLACK);
+        assert_eq!(materials.get(&shared).unwrap().emissive, LinearRgba::BLACK);
+
+        world.entity_mut(selected).remove::<SelectedPiece>();
+        world.run_system_once(tint_selected_piece).unwrap();
+
+        assert_eq!(visual_material(&mut world, selected), shared);
+        assert!(world.get::<SelectionTint>(selected).is_none());
+        assert!(world
+            .resource::<Assets<StandardMaterial>>()
+            .get(&tinted)
+            .is_none());
+    }
+
+    #[test]
+    fn dragged_piece_is_not_snapped_back() {
+        //! The snap-to-square pass leaves a piece being dragged where it is
+
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        let lifted = Vec3::new(3.4, PIECE_ON_BOARD_Y + 0.5, 2.6);
+        let dragged = world
+            .spawn((
+                Piece::new(PieceColor::White, PieceType::Knight, 1, 0),
+                Transform::from_translation(lifted),
+                DraggedPiece,
+            ))
+            .id();
+        let resting = world
+            .spawn((
+                Piece::new(PieceColor::White, PieceType::Knight, 6, 0),
+                Transform::from_translation(lifted),
+            ))
+            .id();
+
+        world.run_system_once(animate_piece_movement).unwrap();
+
+        assert_eq!(world.get::<Transform>(dragged).unwrap().translation, lifted);
+        assert_eq!(
+            world.get::<Transform>(resting).unwrap().translation,
+            Vec3::new(1.0, PIECE_ON_BOARD_Y, 0.0)
+        );
+    }
+}
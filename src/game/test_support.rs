@@ -0,0 +1,369 @@
+//! Headless game harness for tests
+//!
+//! Built for the crate's own unit tests and, with the `test-support`
+//! feature, for the integration tests under `tests/`.
+//!
+//! [`GameHarness`] runs the rules side of a game without a window or
+//! renderer: the resources [`InputSystemParams`] needs, one entity per piece,
+//! the 64 square colliders, and the turn flush followed by
+//! [`update_game_phase`] and [`play_premove`] each update. Moves go through the same helpers the
+//! pointer observers call (`try_select_piece`, `try_move_sequence`,
+//! `drop_selected_at`), so a test exercises selection, legality, the
+//! beginner checks and `execute_move` exactly as a click or drag would.
+//!
+//! ```ignore
+//! let mut game = GameHarness::builder().build();
+//! assert!(game.play("e2e4"));
+//! assert_eq!(game.resource::<MoveHistory>().len(), 1);
+//! ```
+
+use crate::core::states::GameMode;
+use crate::core::GameSettings;
+use crate::engine::board_state::ChessEngine;
+use crate::game::blunder_check::BlunderCheck;
+use crate::game::components::HasMoved;
+use crate::game::events::MoveMadeEvent;
+use crate::game::premove::{click_premove, play_premove, Premove};
+use crate::game::resources::player::Players;
+use crate::game::resources::{
+    AnimationSettings, CapturedPieces, CurrentGamePhase, CurrentTurn, GameOverState, GameTimer,
+    MoveHistory, PendingPromotion, PendingTurnAdvance, PromotionSelected, Selection,
+};
+use crate::game::systems::input::{
+    drop_selected_at, is_human_turn, try_move_sequence, try_select_piece, InputSystemParams,
+};
+use crate::game::systems::shared::CapturedTarget;
+use crate::game::systems::takeback::{
+    apply_redo, apply_takeback, handle_redo_requests, JumpToMove, PendingRedo, RedoRequest,
+    TakebackRequest,
+};
+use crate::game::systems::{flush_pending_turn, update_game_phase};
+use crate::game::view_mode::ViewMode;
+use crate::rendering::board::{square_collider_transform, BoardSquareHitPlane};
+use crate::rendering::pieces::{Piece, PieceColor, PieceType};
+use crate::rendering::utils::Square;
+use crate::ui::menus::popup::GamePopupQueue;
+use bevy::ecs::system::RunSystemOnce;
+use bevy::prelude::*;
+
+const STARTING_FEN: &str = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1";
+
+/// Height a dragged piece is dropped from, above its target square.
+const DROP_HEIGHT: f32 = 0.8;
+
+/// Frames [`GameHarness::redo`] and [`GameHarness::jump_to`] wait for the
+/// moves they replay.
+const REDO_FRAMES: usize = 8;
+
+/// Configures a [`GameHarness`].
+pub struct GameHarnessBuilder {
+    fen: String,
+    settings: GameSettings,
+}
+
+impl GameHarnessBuilder {
+    /// Start from `fen` instead of the initial position.
+    pub fn fen(mut self, fen: &str) -> Self {
+        self.fen = fen.to_string();
+        self
+    }
+
+    /// Play with `settings` instead of the defaults.
+    pub fn settings(mut self, settings: GameSettings) -> Self {
+        self.settings = settings;
+        self
+    }
+
+    pub fn build(self) -> GameHarness {
+        let mut engine = ChessEngine::default();
+        engine
+            .set_from_fen(&self.fen)
+            .unwrap_or_else(|e| panic!("bad harness FEN {:?}: {}", self.fen, e));
+        engine.rebuild_legal_move_cache();
+        let to_move = if self.fen.split_whitespace().nth(1) == Some("b") {
+            PieceColor::Black
+        } else {
+            PieceColor::White
+        };
+
+        let mut app = App::new();
+        app.add_message::<MoveMadeEvent>()
+            .add_message::<TakebackRequest>()
+            .add_message::<RedoRequest>()
+            .add_message::<JumpToMove>()
+            .add_message::<PromotionSelected>()
+            .insert_resource(engine)
+            .insert_resource(AnimationSettings::from_settings(&self.settings))
+            .insert_resource(self.settings)
+            .init_resource::<GameMode>()
+            .insert_resource(CurrentTurn {
+                color: to_move,
+                move_number: 1,
+            })
+            .init_resource::<Selection>()
+            .init_resource::<GameOverState>()
+            .init_resource::<CurrentGamePhase>()
+            .init_resource::<PendingTurnAdvance>()
+            .init_resource::<GameTimer>()
+            .init_resource::<MoveHistory>()
+            .init_resource::<CapturedPieces>()
+            .init_resource::<Players>()
+            .init_resource::<PendingPromotion>()
+            .init_resource::<BlunderCheck>()
+            .init_resource::<Premove>()
+            .init_resource::<GamePopupQueue>()
+            .init_resource::<ViewMode>()
+            .add_systems(
+                Update,
+                (flush_pending_turn, update_game_phase, play_premove).chain(),
+            );
+
+        let castling = self.fen.split_whitespace().nth(2).unwrap_or("-");
+        for (piece, square) in pieces_from_fen(&self.fen) {
+            let moved = !has_castling_right(castling, &piece, square);
+            app.world_mut()
+                .spawn((piece, HasMoved { moved, ..default() }, Transform::default()));
+        }
+        for x in 0..8 {
+            for y in 0..8 {
+                app.world_mut().spawn((
+                    Square { x, y },
+                    GlobalTransform::from(square_collider_transform(x, y)),
+                    BoardSquareHitPlane,
+                ));
+            }
+        }
+
+        GameHarness { app }
+    }
+}
+
+/// A game with no window, driven through the input helpers.
+pub struct GameHarness {
+    pub app: App,
+}
+
+impl GameHarness {
+    /// A harness for the initial position with default settings.
+    pub fn builder() -> GameHarnessBuilder {
+        GameHarnessBuilder {
+            fen: STARTING_FEN.to_string(),
+            settings: GameSettings::default(),
+        }
+    }
+
+    pub fn resource<R: Resource>(&self) -> &R {
+        self.app.world().resource::<R>()
+    }
+
+    /// The piece standing on `square` ("e4"), ignoring captured ones.
+    pub fn piece_at(&mut self, square: &str) -> Option<Entity> {
+        let (x, y) = coords(square);
+        self.app
+            .world_mut()
+            .query::<(Entity, &Piece)>()
+            .iter(self.app.world())
+            .find(|(_, piece)| piece.x == x && piece.y == y)
+            .map(|(entity, _)| entity)
+    }
+
+    /// Click the piece on `square`, as `on_piece_click` does for a piece
+    /// of the side to move.
+    pub fn select(&mut self, square: &str) {
+        let entity = self
+            .piece_at(square)
+            .unwrap_or_else(|| panic!("no piece on {}", square));
+        let piece = *self.app.world().get::<Piece>(entity).unwrap();
+        self.run(move |mut params: InputSystemParams| {
+            if accepts_input(&params) {
+                try_select_piece(&mut params, entity, piece, false)
+            }
+        });
+    }
+
+    /// Click `square` with a piece selected, moving or capturing there as
+    /// `on_square_click` does.
+    pub fn click_square(&mut self, square: &str) {
+        let target = coords(square);
+        let capture = self.capture_on(square);
+        self.run(move |mut params: InputSystemParams| {
+            if accepts_input(&params) {
+                try_move_sequence(&mut params, target, capture, "square_click_move")
+            }
+        });
+    }
+
+    /// Drop the selected piece from above `square`, as `on_piece_drag_end`
+    /// does at the end of a drag.
+    pub fn drop_on(&mut self, square: &str) {
+        let (x, y) = coords(square);
+        let point = square_collider_transform(x, y).translation + Vec3::Y * DROP_HEIGHT;
+        self.run(
+            move |mut params: InputSystemParams,
+                  squares: Query<(&Square, &GlobalTransform), With<BoardSquareHitPlane>>| {
+                if accepts_input(&params) {
+                    drop_selected_at(&mut params, squares.iter(), point)
+                }
+            },
+        );
+    }
+
+    /// Click `square` while the computer is to move, queueing a premove
+    /// as the pointer observers do.
+    pub fn premove_click(&mut self, square: &str) {
+        let square = coords(square);
+        self.run(move |mut params: InputSystemParams| {
+            click_premove(&mut params, square);
+        });
+    }
+
+    /// Play a move in UCI form ("e2e4") by clicking its two squares, then
+    /// run a frame so the turn passes. Returns whether it was played.
+    pub fn play(&mut self, uci: &str) -> bool {
+        let before = self.resource::<MoveHistory>().len();
+        self.select(&uci[0..2]);
+        self.click_square(&uci[2..4]);
+        self.update();
+        self.resource::<MoveHistory>().len() > before
+    }
+
+    /// Press Undo, then run a frame so the turn settles.
+    pub fn take_back(&mut self) {
+        self.run(|mut requests: MessageWriter<TakebackRequest>| {
+            requests.write(TakebackRequest);
+        });
+        self.run(apply_takeback);
+        self.update();
+    }
+
+    /// Press Redo, then run frames until the moves it replays are played.
+    pub fn redo(&mut self) {
+        self.run(|mut requests: MessageWriter<RedoRequest>| {
+            requests.write(RedoRequest);
+        });
+        self.run(handle_redo_requests);
+        self.finish_redo();
+    }
+
+    /// Click the move at `index` in the move list, then run frames until the
+    /// board gets there.
+    pub fn jump_to(&mut self, index: usize) {
+        self.run(move |mut jumps: MessageWriter<JumpToMove>| {
+            jumps.write(JumpToMove { index });
+        });
+        self.run(apply_takeback);
+        self.update();
+        self.run(handle_redo_requests);
+        self.finish_redo();
+    }
+
+    /// Run frames until a redo in progress has played its moves.
+    fn finish_redo(&mut self) {
+        for _ in 0..REDO_FRAMES {
+            if !self.app.world().contains_resource::<PendingRedo>() {
+                break;
+            }
+            self.run(apply_redo);
+            self.update();
+        }
+    }
+
+    /// Run one frame of the game systems.
+    pub fn update(&mut self) {
+        self.app.update();
+    }
+
+    fn capture_on(&mut self, square: &str) -> Option<CapturedTarget> {
+        let entity = self.piece_at(square)?;
+        let piece = self.app.world().get::<Piece>(entity)?;
+        Some(CapturedTarget {
+            entity,
+            piece_type: piece.piece_type,
+            color: piece.color,
+        })
+    }
+
+    fn run<M>(&mut self, system: impl IntoSystem<(), (), M>) {
+        self.app
+            .world_mut()
+            .run_system_once(system)
+            .expect("harness system runs");
+    }
+}
+
+/// The guards every pointer observer checks before acting.
+fn accepts_input(params: &InputSystemParams) -> bool {
+    !params.game_over.is_game_over()
+        && !params.pending_promotion.is_active()
+        && is_human_turn(params)
+}
+
+/// `(file, rank)` of a square name such as "e4".
+fn coords(square: &str) -> (u8, u8) {
+    ChessEngine::uci_to_coords(square).unwrap_or_else(|| panic!("bad square {:?}", square))
+}
+
+/// The pieces of a FEN's placement field with their squares.
+fn pieces_from_fen(fen: &str) -> Vec<(Piece, (u8, u8))> {
+    let placement = fen.split_whitespace().next().unwrap_or("");
+    let mut pieces = Vec::new();
+    for (row, line) in placement.split('/').enumerate() {
+        let rank = 7 - row as u8;
+        let mut file = 0u8;
+        for ch in line.chars() {
+            if let Some(skip) = ch.to_digit(10) {
+                file += skip as u8;
+                continue;
+            }
+            let color = if ch.is_ascii_uppercase() {
+                PieceColor::White
+            } else {
+                PieceColor::Black
+            };
+            let piece_type = match ch.to_ascii_lowercase() {
+                'k' => PieceType::King,
+                'p' => PieceType::Pawn,
+                other => {
+                    PieceType::from_char(other).unwrap_or_else(|| panic!("bad FEN piece {:?}", ch))
+                }
+            };
+            pieces.push((Piece::new(color, piece_type, file, rank), (file, rank)));
+            file += 1;
+        }
+    }
+    pieces
+}
+
+/// Whether `castling` ("KQkq") keeps `piece` unmoved for castling purposes.
+fn has_castling_right(castling: &str, piece: &Piece, (file, _): (u8, u8)) -> bool {
+    let rights: &[char] = match (piece.color, piece.piece_type, file) {
+        (PieceColor::White, PieceType::King, _) => &['K', 'Q'],
+        (PieceColor::White, PieceType::Rook, 7) => &['K'],
+        (PieceColor::White, PieceType::Rook, 0) => &['Q'],
+        (PieceColor::Black, PieceType::King, _) => &['k', 'q'],
+        (PieceColor::Black, PieceType::Rook, 7) => &['k'],
+        (PieceColor::Black, PieceType::Rook, 0) => &['q'],
+        _ => return true,
+    };
+    rights.iter().any(|&right| castling.contains(right))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fools_mate_ends_the_game() {
+        //! Four clicked moves reach checkmate: the history, the turn and the result all follow
+
+        let mut game = GameHarness::builder().build();
+        for uci in ["f2f3", "e7e5", "g2g4", "d8h4"] {
+            assert!(game.play(uci), "{} was refused", uci);
+        }
+
+        assert_eq!(game.resource::<MoveHistory>().len(), 4);
+        assert_eq!(game.resource::<CurrentTurn>().color, PieceColor::White);
+        assert_eq!(*game.resource::<GameOverState>(), GameOverState::BlackWon);
+        assert!(!game.play("e2e4"), "a move after mate was accepted");
+    }
+}
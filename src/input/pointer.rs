@@ -1,7 +1,9 @@
 //! Advanced pointer interaction system with hover effects and cursor management
 
+use crate::core::GameSettings;
+use crate::engine::board_state::ChessEngine;
 use crate::game::components::GamePhase;
-use crate::game::resources::{CurrentGamePhase, CurrentTurn, Selection};
+use crate::game::resources::{CurrentGamePhase, CurrentTurn, HoverPreview, Players, Selection};
 use crate::rendering::pieces::Piece;
 use crate::rendering::utils::Square;
 use bevy::picking::events::{Out, Over, Pointer};
@@ -68,12 +70,21 @@ impl Default for HoverMaterials {
 }
 
 /// Observer function for piece hover events (Pointer<Over>)
+///
+/// With [`GameSettings::preview_moves_on_hover`] on, hovering one of the
+/// side to move's pieces while nothing is selected fills [`HoverPreview`]
+/// from the engine's cached legal moves.
 pub fn on_piece_hover(
     hover: On<Pointer<Over>>,
     piece_query: Query<&Piece>,
     current_turn: Res<CurrentTurn>,
     game_phase: Res<CurrentGamePhase>,
     mut cursor_style: Option<ResMut<CursorStyle>>,
+    settings: Res<GameSettings>,
+    selection: Res<Selection>,
+    players: Res<Players>,
+    engine: Res<ChessEngine>,
+    mut preview: ResMut<HoverPreview>,
 ) {
     let entity = hover.entity;
     if !matches!(game_phase.0, GamePhase::Playing | GamePhase::Check) {
@@ -87,15 +98,33 @@ pub fn on_piece_hover(
             cs.active_hovers.insert(entity);
             cs.update();
         }
+        if settings.preview_moves_on_hover
+            && !selection.is_selected()
+            && players.current(current_turn.color).is_human
+        {
+            let square = (piece.x, piece.y);
+            preview.show(
+                entity,
+                square,
+                engine.get_legal_moves_for_square(square, piece.color),
+            );
+        }
     }
 }
 
 /// Observer function for piece unhover events (Pointer<Out>)
-pub fn on_piece_unhover(unhover: On<Pointer<Out>>, mut cursor_style: Option<ResMut<CursorStyle>>) {
+pub fn on_piece_unhover(
+    unhover: On<Pointer<Out>>,
+    mut cursor_style: Option<ResMut<CursorStyle>>,
+    mut preview: ResMut<HoverPreview>,
+) {
     if let Some(ref mut cs) = cursor_style {
         cs.active_hovers.remove(&unhover.entity);
         cs.update();
     }
+    if preview.entity == Some(unhover.entity) {
+        preview.clear_for(unhover.entity);
+    }
 }
 
 /// Observer function for square hover events (Pointer<Over>)
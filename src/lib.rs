@@ -223,6 +223,8 @@ pub fn build_app(game_config: GameConfig) -> App {
             mode: GameMode::VsAI { ai_color },
             difficulty,
             engine: crate::game::ai::resource::AIEngine::Stockfish,
+            target_elo: None,
+            opening_book: difficulty.plays_book(),
         });
     }
 
@@ -1273,7 +1273,7 @@ fn apply_pgn_export_result(
             // Update CachedGamePgn with the authoritative VPS-fetched PGN so that
             // the Review / Analyze / Save PGN buttons use the full Braid move log.
             if let Some(ref mut cached) = cached_pgn {
-                let pgn_str = crate::ui::menus::game_over_popup::pgn_to_string(&pgn);
+                let pgn_str = crate::game::pgn::pgn_to_string(&pgn);
                 cached.pgn_string = pgn_str;
                 cached.pgn = Some(pgn.clone());
                 cached.braid_pgn_ready = true;
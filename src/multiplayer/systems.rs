@@ -787,6 +787,8 @@ pub fn emit_game_ended_event(
         GameOverState::BlackWonByAbandonment => (Some("black".to_string()), "abandonment"),
         GameOverState::Stalemate => (None, "stalemate"),
         GameOverState::InsufficientMaterial => (None, "insufficient_material"),
+        GameOverState::FiftyMoveRule => (None, "fifty_move_rule"),
+        GameOverState::ThreefoldRepetition => (None, "threefold_repetition"),
         GameOverState::Aborted => (None, "aborted"),
         GameOverState::Playing => return,
     };
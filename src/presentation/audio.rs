@@ -1,52 +1,232 @@
 //! Presentation layer - Audio systems
 //!
 //! Handles all audio playback and volume management for the application.
+//!
+//! Every sound is tagged with the channel it belongs to: [`SfxChannel`] for
+//! moves, captures and clicks, [`MusicChannel`] for music. A channel plays at
+//! its own volume times the master volume (see
+//! [`GameSettings::sfx_output_volume`]); untagged sounds get the master
+//! volume alone. New sounds start at their channel's volume, and changing a
+//! volume updates the sounds already playing.
+//!
+//! Move and capture sounds can also be panned towards the side of the board
+//! they happen on ([`GameSettings::spatial_move_sounds`]). The pan is worked
+//! out from the destination square and the board camera, and the sound is
+//! placed just beside the camera's [`SpatialListener`] so panning never
+//! changes its loudness. With the setting off, or no listener to pan
+//! against, sounds play in mono as before.
 
 use crate::core::GameSettings;
-use bevy::audio::Volume;
+use crate::game::systems::camera::BoardCamera;
+use bevy::audio::{AudioPlaybackSystems, Volume};
 use bevy::prelude::*;
 
+/// Gap between the listener's ears, in world units.
+const EAR_GAP: f32 = 1.0;
+
+/// Sideways distance from the camera, in world units, at which a sound is
+/// panned fully to one side: half the board's width.
+const FULL_PAN_OFFSET: f32 = 4.0;
+
+/// World position of the square a move or capture sound belongs to.
+///
+/// Added alongside the sound's `AudioPlayer`; [`pan_move_sounds`] turns it
+/// into a spatial position before playback starts.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct MoveSoundOrigin(pub Vec3);
+
+/// Marks a sound effect, played at [`GameSettings::sfx_volume`].
+#[derive(Component, Debug, Default, Clone, Copy)]
+pub struct SfxChannel;
+
+/// Marks music, played at [`GameSettings::music_volume`].
+#[derive(Component, Debug, Default, Clone, Copy)]
+pub struct MusicChannel;
+
+/// Volume a sound on the given channel plays at under `settings`.
+pub fn channel_volume(settings: &GameSettings, sfx: bool, music: bool) -> f32 {
+    if sfx {
+        settings.sfx_output_volume()
+    } else if music {
+        settings.music_output_volume()
+    } else {
+        settings.output_volume()
+    }
+}
+
 /// Plugin for the presentation layer audio systems
 pub struct AudioPresentationPlugin;
 
 impl Plugin for AudioPresentationPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Update, apply_master_volume_system);
+        app.add_systems(Update, (apply_master_volume_system, sync_spatial_listener))
+            .add_systems(
+                PostUpdate,
+                (volume_new_sounds, pan_move_sounds).before(AudioPlaybackSystems),
+            );
     }
 }
 
-/// System that applies master volume to all audio sinks
+/// System that applies the channel volumes to all audio sinks
 ///
-/// Watches for changes to `GameSettings.master_volume` and updates all AudioSink components.
+/// Watches the master, effects and music volumes and mute in
+/// `GameSettings` and updates every playing sound to its channel's volume.
 pub fn apply_master_volume_system(
     settings: Res<GameSettings>,
-    mut audio_sinks: Query<&mut AudioSink>,
-    mut last_settings: Local<Option<(f32, bool)>>,
+    mut audio_sinks: Query<(&mut AudioSink, Has<SfxChannel>, Has<MusicChannel>)>,
+    mut spatial_sinks: Query<(&mut SpatialAudioSink, Has<SfxChannel>, Has<MusicChannel>)>,
+    mut last_settings: Local<Option<[f32; 3]>>,
 ) {
-    // Check if volume or mute changed
-    let current_volume = settings.master_volume;
-    let current_muted = settings.muted;
-
-    if let Some((prev_volume, prev_muted)) = *last_settings {
-        if (prev_volume - current_volume).abs() < 0.001 && prev_muted == current_muted {
+    // Check if any channel's volume changed
+    let current = [
+        settings.output_volume(),
+        settings.sfx_output_volume(),
+        settings.music_output_volume(),
+    ];
+    if let Some(previous) = *last_settings {
+        if previous
+            .iter()
+            .zip(current)
+            .all(|(prev, now)| (prev - now).abs() < 0.001)
+        {
             return; // No significant change
         }
     }
-    *last_settings = Some((current_volume, current_muted));
+    *last_settings = Some(current);
 
-    // Calculate effective volume
-    let effective_volume = if current_muted { 0.0 } else { current_volume };
-
-    // Apply volume to all audio sinks
-    for mut sink in audio_sinks.iter_mut() {
-        sink.set_volume(Volume::Linear(effective_volume));
+    for (mut sink, sfx, music) in audio_sinks.iter_mut() {
+        sink.set_volume(Volume::Linear(channel_volume(&settings, sfx, music)));
+    }
+    for (mut sink, sfx, music) in spatial_sinks.iter_mut() {
+        sink.set_volume(Volume::Linear(channel_volume(&settings, sfx, music)));
     }
 
     if !audio_sinks.is_empty() {
         info!(
-            "[AUDIO] Applied volume: {:.0}% (Muted: {})",
-            effective_volume * 100.0,
-            current_muted
+            "[AUDIO] Applied volume: {:.0}% (effects {:.0}%, music {:.0}%, muted: {})",
+            settings.master_volume * 100.0,
+            settings.sfx_volume * 100.0,
+            settings.music_volume * 100.0,
+            settings.muted
         );
     }
 }
+
+/// Scale each newly spawned sound by its channel's volume before it starts
+/// playing, so it doesn't play at full volume until the next volume change.
+pub fn volume_new_sounds(
+    settings: Res<GameSettings>,
+    mut sounds: Query<
+        (&mut PlaybackSettings, Has<SfxChannel>, Has<MusicChannel>),
+        Added<AudioPlayer>,
+    >,
+) {
+    for (mut playback, sfx, music) in &mut sounds {
+        let volume = playback.volume.to_linear() * channel_volume(&settings, sfx, music);
+        playback.volume = Volume::Linear(volume);
+    }
+}
+
+/// Give the board camera a [`SpatialListener`] while move-sound panning is
+/// on, and take it away when it is turned off.
+pub fn sync_spatial_listener(
+    mut commands: Commands,
+    settings: Res<GameSettings>,
+    cameras: Query<(Entity, Has<SpatialListener>), With<BoardCamera>>,
+) {
+    for (entity, has_listener) in &cameras {
+        if settings.spatial_move_sounds && !has_listener {
+            commands
+                .entity(entity)
+                .insert(SpatialListener::new(EAR_GAP));
+        } else if !settings.spatial_move_sounds && has_listener {
+            commands.entity(entity).remove::<SpatialListener>();
+        }
+    }
+}
+
+/// Stereo pan of a sound at `point` heard from `listener`: -1 is fully
+/// left, 1 fully right.
+pub fn pan_for(listener: &Transform, point: Vec3) -> f32 {
+    let offset = (point - listener.translation).dot(*listener.right());
+    (offset / FULL_PAN_OFFSET).clamp(-1.0, 1.0)
+}
+
+/// Where to place a sound at `point` so it pans by [`pan_for`]: beside the
+/// listener, between its ears, where distance attenuation can't touch it.
+pub fn panned_source_position(listener: &Transform, point: Vec3) -> Vec3 {
+    listener.translation + *listener.right() * pan_for(listener, point) * EAR_GAP * 0.5
+}
+
+/// Turn newly spawned move sounds into spatial sounds panned towards their
+/// square, just before they start playing. Leaves them in mono when panning
+/// is off or there is no listener.
+pub fn pan_move_sounds(
+    mut commands: Commands,
+    settings: Res<GameSettings>,
+    listeners: Query<&GlobalTransform, With<SpatialListener>>,
+    mut sounds: Query<(Entity, &MoveSoundOrigin, &mut PlaybackSettings), Added<MoveSoundOrigin>>,
+) {
+    if sounds.is_empty() || !settings.spatial_move_sounds {
+        return;
+    }
+    let Ok(listener) = listeners.single() else {
+        return;
+    };
+    let listener = listener.compute_transform();
+
+    for (entity, origin, mut playback) in &mut sounds {
+        let position = panned_source_position(&listener, origin.0);
+        playback.spatial = true;
+        commands.entity(entity).insert((
+            Transform::from_translation(position),
+            GlobalTransform::from_translation(position),
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::board_coords::file_world_x_for;
+
+    #[test]
+    fn test_far_right_file_pans_right() {
+        //! From White's camera the h-file sounds on the right and the a-file on the left
+        let board_center = Vec3::new(3.5, 0.0, 3.5);
+        let white_camera = Transform::from_xyz(3.5, 16.0, -11.5).looking_at(board_center, Vec3::Y);
+        let h4 = Vec3::new(file_world_x_for(7.0, false), 0.05, 3.0);
+        let a4 = Vec3::new(file_world_x_for(0.0, false), 0.05, 3.0);
+
+        assert!(pan_for(&white_camera, h4) > 0.5);
+        assert!(pan_for(&white_camera, a4) < -0.5);
+        assert!(pan_for(&white_camera, board_center).abs() < 0.01);
+
+        let source = panned_source_position(&white_camera, h4);
+        assert!((source - white_camera.translation).dot(*white_camera.right()) > 0.0);
+        assert!(source.distance(white_camera.translation) <= EAR_GAP * 0.5);
+    }
+
+    #[test]
+    fn test_channels_scale_under_the_master_volume() {
+        //! Effects and music each take their own share of the master volume, turning effects off silences only them, and mute silences both
+
+        let mut settings = GameSettings {
+            master_volume: 0.5,
+            sfx_volume: 0.8,
+            music_volume: 0.2,
+            ..Default::default()
+        };
+        assert_eq!(channel_volume(&settings, true, false), 0.4);
+        assert_eq!(channel_volume(&settings, false, true), 0.1);
+        assert_eq!(channel_volume(&settings, false, false), 0.5);
+
+        settings.sound_effects = false;
+        assert_eq!(channel_volume(&settings, true, false), 0.0);
+        assert_eq!(channel_volume(&settings, false, true), 0.1);
+
+        settings.muted = true;
+        assert_eq!(channel_volume(&settings, true, false), 0.0);
+        assert_eq!(channel_volume(&settings, false, true), 0.0);
+    }
+}
@@ -3,6 +3,8 @@
 //! Uses batch spawning pattern from Bevy examples (many_sprites.rs, bevymark.rs)
 //! to efficiently create all 64 board squares in a single operation.
 
+use crate::game::annotations::{on_annotation_press, on_annotation_release};
+use crate::game::board_coords::file_world_x;
 use crate::game::systems::camera::BOARD_LAYER;
 use crate::game::systems::input::on_square_click;
 use crate::game::view_mode::ViewMode;
@@ -25,17 +27,66 @@ pub struct BoardSquare2DVisual;
 
 /// Invisible flat hit plane — sole pick target for a board square in all view modes.
 /// Sits just above the cuboid top (Y=0.06), covers the full 1×1 cell.
+///
+/// The collider carries the [`Square`] and the click/hover observers itself
+/// and has no visual children, so input never depends on how the visible
+/// board is built (per-square cuboids, a merged mesh, scaled or flipped).
 #[derive(Component)]
 pub struct BoardSquareHitPlane;
 
+/// Root of one square's visible geometry (the 3D cuboid and 2D tile).
+/// Not pickable; the matching [`BoardSquareHitPlane`] handles input.
+#[derive(Component)]
+pub struct BoardSquareVisual;
+
+/// Half the side of a square collider in its local XY plane.
+const SQUARE_COLLIDER_HALF_EXTENT: f32 = 0.5;
+
+/// Board-space centre of a square's visible geometry. X follows
+/// [`file_world_x`], so file a sits at x = 7 unless files are mirrored.
+pub fn square_world_position(file: u8, rank: u8) -> Vec3 {
+    Vec3::new(file_world_x(file), 0., rank as f32)
+}
+
+/// Transform of a square's picking collider: a 1×1 plane lying flat just
+/// above the cuboid top.
+pub fn square_collider_transform(file: u8, rank: u8) -> Transform {
+    Transform::from_translation(square_world_position(file, rank) + Vec3::Y * 0.06)
+        .with_rotation(Quat::from_rotation_x(-std::f32::consts::FRAC_PI_2))
+}
+
+/// Find the square whose collider lies under `point`.
+///
+/// The point is taken into each collider's local space, so the answer
+/// follows the colliders through any scale, rotation or flip applied to the
+/// board. Height above or below the plane is ignored.
+pub fn square_at_world_point<'a>(
+    colliders: impl IntoIterator<Item = (&'a Square, &'a GlobalTransform)>,
+    point: Vec3,
+) -> Option<Square> {
+    colliders.into_iter().find_map(|(square, transform)| {
+        let local = transform.affine().inverse().transform_point3(point);
+        (local.x.abs() <= SQUARE_COLLIDER_HALF_EXTENT
+            && local.y.abs() <= SQUARE_COLLIDER_HALF_EXTENT)
+            .then(|| square.clone())
+    })
+}
+
 pub fn create_board(
     mut commands: Commands,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
     square_materials: Res<crate::rendering::utils::SquareMaterials>,
+    settings: Res<crate::core::GameSettings>,
 ) {
     use crate::core::{DespawnOnExit, GameState};
 
+    super::board_theme::apply_board_theme(
+        settings.board_theme_3d,
+        &mut materials,
+        &square_materials,
+    );
+
     let boardmesh_3d = meshes.add(Cuboid::new(1.0, 0.1, 1.0));
     let boardmesh_2d = meshes.add(Rectangle::new(1.0, 1.0));
     let boardmesh_hit = meshes.add(Rectangle::new(1.0, 1.0));
@@ -93,10 +144,10 @@ pub fn create_board(
 
                 let file_char = (b'a' + file) as char;
                 let square_name = format!("Square {}{}", file_char, rank + 1);
-                let world_pos = Vec3::new(7.0 - file as f32, 0., rank as f32);
 
                 (
-                    Transform::from_translation(world_pos),
+                    square_collider_transform(file, rank),
+                    Transform::from_translation(square_world_position(file, rank)),
                     square,
                     Board,
                     Name::new(square_name),
@@ -112,14 +163,31 @@ pub fn create_board(
         })
         .collect();
 
-    for (transform, square, board, name, exit, m3d, mat3d, m2d, mat2d, m_hit, mat_hit_cell) in
-        squares
+    for (
+        collider_transform,
+        visual_transform,
+        square,
+        board,
+        name,
+        exit,
+        m3d,
+        mat3d,
+        m2d,
+        mat2d,
+        m_hit,
+        mat_hit_cell,
+    ) in squares
     {
+        let visual_name = Name::new(format!("{} Visual", name.as_str()));
+
+        // Invisible flat plane at Y=0.06 (just above cuboid top).
+        // Full 1×1 surface — sole pick target for this square in all view modes.
         commands
             .spawn((
-                transform,
-                Visibility::default(),
-                InheritedVisibility::default(),
+                Mesh3d(m_hit),
+                MeshMaterial3d(mat_hit_cell),
+                collider_transform,
+                BoardSquareHitPlane,
                 PointerInteraction::default(),
                 bevy::picking::Pickable::default(),
                 RenderLayers::layer(BOARD_LAYER),
@@ -129,8 +197,22 @@ pub fn create_board(
                 exit,
             ))
             .observe(on_square_click)
+            .observe(on_annotation_press)
+            .observe(on_annotation_release)
             .observe(on_square_hover)
-            .observe(on_square_unhover)
+            .observe(on_square_unhover);
+
+        commands
+            .spawn((
+                visual_transform,
+                Visibility::default(),
+                InheritedVisibility::default(),
+                BoardSquareVisual,
+                bevy::picking::Pickable::IGNORE,
+                RenderLayers::layer(BOARD_LAYER),
+                visual_name,
+                exit,
+            ))
             .with_children(|parent| {
                 // Visual only — not the pick target.
                 parent.spawn((
@@ -150,18 +232,6 @@ pub fn create_board(
                     bevy::picking::Pickable::IGNORE,
                     RenderLayers::layer(BOARD_LAYER),
                 ));
-
-                // Invisible flat plane at Y=0.06 (just above cuboid top).
-                // Full 1×1 surface — sole pick target for this square in all view modes.
-                parent.spawn((
-                    Mesh3d(m_hit),
-                    MeshMaterial3d(mat_hit_cell),
-                    Transform::from_xyz(0.0, 0.06, 0.0)
-                        .with_rotation(Quat::from_rotation_x(-std::f32::consts::FRAC_PI_2)),
-                    BoardSquareHitPlane,
-                    bevy::picking::Pickable::default(),
-                    RenderLayers::layer(BOARD_LAYER),
-                ));
             });
     }
 }
@@ -202,17 +272,36 @@ pub struct BoardPlugin;
 impl Plugin for BoardPlugin {
     fn build(&self, app: &mut App) {
         use crate::core::GameState;
-        use crate::rendering::effects::{init_arrow_assets, update_check_highlight_system};
+        use crate::rendering::effects::{
+            apply_highlight_opacity_system, init_annotation_assets, init_arrow_assets,
+            update_annotation_marks_system, update_check_highlight_system,
+            update_engine_hint_highlight_system, update_premove_highlight_system,
+        };
         use crate::rendering::update_last_move_highlight_system;
-        use crate::rendering::update_move_hints_system;
-        app.add_systems(Startup, init_arrow_assets)
+        use crate::rendering::{update_drag_target_highlight_system, update_move_hints_system};
+        app.add_systems(Startup, (init_arrow_assets, init_annotation_assets))
             .add_systems(OnEnter(GameState::InGame), create_board)
             .add_systems(
                 Update,
                 (
                     update_move_hints_system.run_if(in_state(GameState::InGame)),
+                    update_drag_target_highlight_system.run_if(
+                        in_state(GameState::InGame)
+                            .and(resource_changed::<crate::game::resources::DragTarget>),
+                    ),
                     update_last_move_highlight_system.run_if(in_state(GameState::InGame)),
                     update_check_highlight_system.run_if(in_state(GameState::InGame)),
+                    update_engine_hint_highlight_system.run_if(in_state(GameState::InGame)),
+                    update_annotation_marks_system.run_if(in_state(GameState::InGame)),
+                    update_premove_highlight_system.run_if(in_state(GameState::InGame)),
+                    apply_highlight_opacity_system,
+                    super::board_theme::update_board_theme_system,
+                    (
+                        super::coordinates::sync_coordinate_labels,
+                        super::coordinates::position_coordinate_labels,
+                    )
+                        .chain()
+                        .run_if(in_state(GameState::InGame)),
                     board_view_mode_toggle_system.run_if(
                         in_state(GameState::InGame)
                             .and(resource_changed::<crate::game::view_mode::ViewMode>),
@@ -234,7 +323,13 @@ impl Plugin for BoardPlugin {
         )
         .add_systems(
             Update,
-            crate::rendering::templeos_camera_movement_system.run_if(in_state(GameState::InGame)),
+            (
+                crate::rendering::templeos_camera_movement_system,
+                crate::rendering::templeos_camera_zoom_system,
+                crate::rendering::leave_templeos_view
+                    .run_if(resource_changed::<crate::game::view_mode::ViewMode>),
+            )
+                .run_if(in_state(GameState::InGame)),
         );
         // Debug markers removed - they were showing colored spheres on the board corners
         // app.add_systems(
@@ -243,3 +338,52 @@ impl Plugin for BoardPlugin {
         // );
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Colliders for all 64 squares as they end up under a board transform.
+    fn colliders_under(board: Transform) -> Vec<(Square, GlobalTransform)> {
+        let board = GlobalTransform::from(board);
+        (0..8)
+            .flat_map(|rank| (0..8).map(move |file| (file, rank)))
+            .map(|(file, rank)| {
+                (
+                    Square::new(file, rank),
+                    board.mul_transform(square_collider_transform(file, rank)),
+                )
+            })
+            .collect()
+    }
+
+    fn pick(colliders: &[(Square, GlobalTransform)], point: Vec3) -> Option<Square> {
+        square_at_world_point(colliders.iter().map(|(s, t)| (s, t)), point)
+    }
+
+    #[test]
+    fn test_picking_resolves_square_at_unit_scale() {
+        //! A point over e4's centre resolves to e4
+
+        let colliders = colliders_under(Transform::IDENTITY);
+        let e4 = square_world_position(4, 3);
+        assert_eq!(pick(&colliders, e4), Some(Square::new(4, 3)));
+        assert_eq!(pick(&colliders, Vec3::new(20.0, 0.0, 20.0)), None);
+    }
+
+    #[test]
+    fn test_picking_follows_board_scale_change() {
+        //! After scaling the board, points resolve through the scaled colliders
+
+        let scale = 1.75;
+        let colliders = colliders_under(Transform::from_scale(Vec3::splat(scale)));
+
+        for (file, rank) in [(0, 0), (4, 3), (7, 7), (2, 6)] {
+            let centre = square_world_position(file, rank) * scale;
+            // Off-centre but inside the scaled cell, which the unscaled
+            // rounding would assign to a neighbour.
+            let point = centre + Vec3::new(0.4 * scale, 0.3, -0.4 * scale);
+            assert_eq!(pick(&colliders, point), Some(Square::new(file, rank)));
+        }
+    }
+}
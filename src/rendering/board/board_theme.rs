@@ -1,42 +1,83 @@
 //! Board theme application system
 //!
-//! Updates board square materials when the board theme changes in GameSettings.
+//! Every light square shares one material and every dark square the other
+//! (see [`SquareMaterials`]), so a [`BoardTheme`] is applied by recolouring
+//! those two. `create_board` applies the chosen theme when a game starts and
+//! [`update_board_theme_system`] whenever it changes, so the settings screen
+//! previews it straight away.
 
-use crate::core::GameSettings;
+use crate::core::{BoardTheme, GameSettings};
 use crate::rendering::utils::SquareMaterials;
 use bevy::prelude::*;
 
+/// Give the shared square materials `theme`'s colours and finish.
+pub fn apply_board_theme(
+    theme: BoardTheme,
+    materials: &mut Assets<StandardMaterial>,
+    square_materials: &SquareMaterials,
+) {
+    let (light, dark) = theme.colors();
+    let (roughness, metallic, reflectance) = theme.finish();
+    // `black_color` is the light squares' material, `white_color` the dark's.
+    for (handle, color) in [
+        (&square_materials.black_color, light),
+        (&square_materials.white_color, dark),
+    ] {
+        if let Some(mut material) = materials.get_mut(handle) {
+            material.base_color = color;
+            material.perceptual_roughness = roughness;
+            material.metallic = metallic;
+            material.reflectance = reflectance;
+        }
+    }
+}
+
 /// System that updates board square materials when theme changes
 ///
-/// Watches for changes to `GameSettings.board_theme` and updates all board squares
-/// to use the new theme colors. This runs in Update schedule to respond to settings changes.
+/// Watches `GameSettings.board_theme_3d` and recolours the squares the
+/// frame it changes, in game or not.
 pub fn update_board_theme_system(
     settings: Res<GameSettings>,
     mut materials: ResMut<Assets<StandardMaterial>>,
     square_materials: Res<SquareMaterials>,
-    mut last_theme: Local<Option<crate::core::BoardTheme>>,
+    mut last_theme: Local<Option<BoardTheme>>,
 ) {
-    // Check if theme changed
-    let current_theme = settings.board_theme;
-    if let Some(prev_theme) = *last_theme {
-        if prev_theme == current_theme {
-            return; // No change
-        }
+    let theme = settings.board_theme_3d;
+    if *last_theme == Some(theme) {
+        return;
     }
-    *last_theme = Some(current_theme);
+    *last_theme = Some(theme);
 
-    let (light_color, dark_color) = settings.board_theme.colors();
+    apply_board_theme(theme, &mut materials, &square_materials);
+    info!("[BOARD_THEME] Updated board theme to {}", theme.label());
+}
 
-    // Update material assets
-    if let Some(light_mat) = materials.get_mut(&square_materials.black_color) {
-        light_mat.base_color = light_color;
-    }
-    if let Some(dark_mat) = materials.get_mut(&square_materials.white_color) {
-        dark_mat.base_color = dark_color;
-    }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::system::RunSystemOnce;
 
-    info!(
-        "[BOARD_THEME] Updated board theme to {:?}",
-        settings.board_theme.name()
-    );
+    #[test]
+    fn test_theme_change_recolours_both_square_materials() {
+        //! Choosing Marble gives the light and dark squares its colours and polish
+
+        let mut world = World::new();
+        world.init_resource::<Assets<StandardMaterial>>();
+        world.init_resource::<Assets<Mesh>>();
+        world.init_resource::<SquareMaterials>();
+        world.insert_resource(GameSettings {
+            board_theme_3d: BoardTheme::Marble,
+            ..Default::default()
+        });
+        world.run_system_once(update_board_theme_system).unwrap();
+
+        let square_materials = world.resource::<SquareMaterials>();
+        let materials = world.resource::<Assets<StandardMaterial>>();
+        let light = materials.get(&square_materials.black_color).unwrap();
+        let dark = materials.get(&square_materials.white_color).unwrap();
+        let (marble_light, marble_dark) = BoardTheme::Marble.colors();
+        assert_eq!(light.base_color, marble_light);
+        assert_eq!(dark.base_color, marble_dark);
+        assert_eq!(dark.perceptual_roughness, BoardTheme::Marble.finish().0);
+    }
 }
@@ -1,17 +1,155 @@
-//! Coordinate label system for chess board
+//! Coordinate labels around the chess board
 //!
-//! Spawns floating coordinate labels (numbers 1-8 and letters A-H) around the chess board
-//! for the TempleOS view mode. Labels are positioned at the edges of the board and
-//! float above it for visibility.
+//! On the 3D board the file letters and rank numbers are UI text over the
+//! board camera, placed each frame at the screen position of a point just
+//! off the board's edge. They sit on the viewer's side: letters along the
+//! near edge and numbers beside the a-file with White at the bottom, and
+//! round the other side with Black at the bottom, so they follow the board
+//! when it is flipped. [`GameSettings::show_coordinates`] spawns or
+//! despawns them as it changes.
+//!
+//! The TempleOS view has its own floating labels, spawned with the view by
+//! `create_coordinate_labels`.
 
-use crate::core::{DespawnOnExit, GameState};
+use crate::core::{DespawnOnExit, GameSettings, GameState};
+use crate::game::board_coords::{file_world_x_for, files_mirrored};
+use crate::game::systems::camera::{BoardCamera, BoardOrientation};
 use crate::game::view_mode::ViewMode;
 use bevy::prelude::*;
 
-/// Marker component for coordinate labels
+/// Marker component for the TempleOS view's coordinate labels
+#[cfg(feature = "templeos")]
 #[derive(Component)]
 pub struct CoordinateLabel;
 
+/// Distance of the 3D labels from the board's edge, in board units.
+const LABEL_EDGE_OFFSET: f32 = 0.75;
+/// Side of the box each 3D label is centred in, in pixels.
+const LABEL_BOX_PX: f32 = 24.0;
+
+/// What a 3D coordinate label names.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BoardCoordinateLabel {
+    /// A file letter, 0 = a.
+    File(u8),
+    /// A rank number, 0 = 1.
+    Rank(u8),
+}
+
+impl BoardCoordinateLabel {
+    /// All sixteen labels.
+    pub fn all() -> impl Iterator<Item = Self> {
+        (0..8).map(Self::File).chain((0..8).map(Self::Rank))
+    }
+
+    pub fn text(self) -> String {
+        match self {
+            Self::File(file) => ((b'a' + file) as char).to_string(),
+            Self::Rank(rank) => (rank + 1).to_string(),
+        }
+    }
+
+    /// World position the label is drawn at, on the edges nearest the
+    /// viewer for `orientation`.
+    pub fn anchor(self, orientation: BoardOrientation, mirrored: bool) -> Vec3 {
+        let black_bottom = orientation.is_black_bottom();
+        match self {
+            Self::File(file) => {
+                let z = if black_bottom {
+                    7.0 + LABEL_EDGE_OFFSET
+                } else {
+                    -LABEL_EDGE_OFFSET
+                };
+                Vec3::new(file_world_x_for(file as f32, mirrored), 0.0, z)
+            }
+            Self::Rank(rank) => {
+                // Beside the a-file from White's side, the h-file from Black's.
+                let (near, far) = if black_bottom { (7.0, 0.0) } else { (0.0, 7.0) };
+                let near_x = file_world_x_for(near, mirrored);
+                let outward = (near_x - file_world_x_for(far, mirrored)).signum();
+                Vec3::new(near_x + outward * LABEL_EDGE_OFFSET, 0.0, rank as f32)
+            }
+        }
+    }
+}
+
+/// Spawn the 3D board's coordinate labels while [`GameSettings::show_coordinates`]
+/// is on and the 3D board is shown, and despawn them otherwise.
+pub fn sync_coordinate_labels(
+    mut commands: Commands,
+    settings: Res<GameSettings>,
+    view_mode: Res<ViewMode>,
+    board_camera: Query<Entity, With<BoardCamera>>,
+    labels: Query<Entity, With<BoardCoordinateLabel>>,
+) {
+    let camera = board_camera.single().ok();
+    let wanted =
+        settings.show_coordinates && *view_mode == ViewMode::Standard3D && camera.is_some();
+    match (wanted, labels.is_empty()) {
+        (true, true) => {
+            let Some(camera) = camera else {
+                return;
+            };
+            for label in BoardCoordinateLabel::all() {
+                commands.spawn((
+                    Text::new(label.text()),
+                    TextFont {
+                        font_size: FontSize::from(16.0),
+                        ..default()
+                    },
+                    TextColor(Color::srgba(0.92, 0.92, 0.88, 0.85)),
+                    TextLayout::justify(bevy::text::Justify::Center),
+                    Node {
+                        position_type: PositionType::Absolute,
+                        width: px(LABEL_BOX_PX),
+                        height: px(LABEL_BOX_PX),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        ..default()
+                    },
+                    Visibility::Hidden,
+                    UiTargetCamera(camera),
+                    label,
+                    DespawnOnExit(GameState::InGame),
+                    Name::new(format!("Coordinate {}", label.text())),
+                ));
+            }
+        }
+        (false, false) => {
+            for entity in labels.iter() {
+                commands.entity(entity).despawn();
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Keep each 3D coordinate label over its [`BoardCoordinateLabel::anchor`]
+/// as the camera moves, hiding any whose anchor is off screen.
+pub fn position_coordinate_labels(
+    orientation: Res<BoardOrientation>,
+    camera: Query<(&Camera, &GlobalTransform), With<BoardCamera>>,
+    mut labels: Query<(&BoardCoordinateLabel, &mut Node, &mut Visibility)>,
+) {
+    let Ok((camera, camera_transform)) = camera.single() else {
+        return;
+    };
+    let mirrored = files_mirrored();
+    for (label, mut node, mut visibility) in labels.iter_mut() {
+        let anchor = label.anchor(*orientation, mirrored);
+        match camera.world_to_viewport(camera_transform, anchor) {
+            Ok(position) => {
+                node.left = px(position.x - LABEL_BOX_PX / 2.0);
+                node.top = px(position.y - LABEL_BOX_PX / 2.0);
+                visibility.set_if_neq(Visibility::Inherited);
+            }
+            Err(_) => {
+                visibility.set_if_neq(Visibility::Hidden);
+            }
+        }
+    }
+}
+
 /// System to create coordinate labels for TempleOS view
 ///
 /// Spawns:
@@ -19,13 +157,15 @@ pub struct CoordinateLabel;
 /// - Letters A-H along the front and back edges (horizontal axis)
 ///
 /// Uses Text2d positioned in 3D space with black color to match reference image.
+#[cfg(feature = "templeos")]
 pub fn create_coordinate_labels(
     mut commands: Commands,
     view_mode: Res<ViewMode>,
+    settings: Res<GameSettings>,
     _asset_server: Res<AssetServer>,
 ) {
     // Only create labels in TempleOS mode
-    if *view_mode != ViewMode::TempleOS {
+    if *view_mode != ViewMode::TempleOS || !settings.show_coordinates {
         return;
     }
 
@@ -65,9 +205,10 @@ pub fn create_coordinate_labels(
     }
 
     // Spawn letter labels (A-H) along the horizontal X-axis (Files)
-    // Files are mirrored: a-file is at X=7, h-file is at X=0.
+    // Labels follow the square mapping: a-file at X=7 by default, X=0 when
+    // files are mirrored.
     for (file_idx, file_char) in ('a'..='h').enumerate() {
-        let x_pos = 7.0 - file_idx as f32;
+        let x_pos = file_world_x_for(file_idx as f32, files_mirrored());
         let label = file_char.to_uppercase().to_string();
 
         // Front edge labels (near Rank 1, Z = -0.7)
@@ -95,3 +236,42 @@ pub fn create_coordinate_labels(
 
     info!("[COORDINATES] Created {} black coordinate labels for TempleOS view (32 total: 16 numbers + 16 letters)", 32);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_labels_follow_the_board_when_flipped() {
+        //! Letters run along the near edge and numbers down the viewer's left, whichever side is at the bottom
+
+        let white = BoardOrientation::WhiteBottom;
+        let black = BoardOrientation::BlackBottom;
+
+        // Files are laid out a→h towards -X unless mirrored.
+        assert_eq!(
+            BoardCoordinateLabel::File(0).anchor(white, false),
+            Vec3::new(7.0, 0.0, -LABEL_EDGE_OFFSET)
+        );
+        assert_eq!(
+            BoardCoordinateLabel::File(0).anchor(black, false).z,
+            7.0 + LABEL_EDGE_OFFSET
+        );
+        assert_eq!(
+            BoardCoordinateLabel::Rank(2).anchor(white, false),
+            Vec3::new(7.0 + LABEL_EDGE_OFFSET, 0.0, 2.0)
+        );
+        assert_eq!(
+            BoardCoordinateLabel::Rank(2).anchor(black, false).x,
+            -LABEL_EDGE_OFFSET
+        );
+        assert_eq!(
+            BoardCoordinateLabel::Rank(2).anchor(white, true).x,
+            -LABEL_EDGE_OFFSET
+        );
+
+        assert_eq!(BoardCoordinateLabel::all().count(), 16);
+        assert_eq!(BoardCoordinateLabel::File(7).text(), "h");
+        assert_eq!(BoardCoordinateLabel::Rank(7).text(), "8");
+    }
+}
@@ -3,8 +3,7 @@
 //! Manages chess board creation and coordinate labeling.
 
 pub mod board;
-/// Floating board coordinate labels — only used by the TempleOS theme.
-#[cfg(feature = "templeos")]
+pub mod board_theme;
 pub mod coordinates;
 
 // Re-export all public items
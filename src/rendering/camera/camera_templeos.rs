@@ -2,14 +2,36 @@
 //!
 //! Provides an isometric orthographic camera view for the TempleOS chess board mode.
 //! The camera uses orthographic projection to create a true 2D isometric view.
+//! WASD or a right-button drag pans it, the scroll wheel zooms it, and
+//! leaving TempleOS mid-game (from the pause menu or with `V`) swaps it for the
+//! standard board camera and lights.
 
 use crate::core::states::GameMode;
-use crate::core::{DespawnOnExit, GameState};
+use crate::core::{DespawnOnExit, GameAction, GameSettings, GameState};
 use crate::game::resources::{CurrentTurn, Players};
 use crate::game::systems::camera::get_is_black_view;
+use crate::game::systems::{
+    initialize_engine_from_ecs, setup_game_camera, spawn_board_fill_light, STANDARD_CLEAR_COLOR,
+};
 use crate::game::view_mode::ViewMode;
+use crate::rendering::board::coordinates::CoordinateLabel;
+use crate::rendering::board::templeos_ui::TempleOSQuote;
 use bevy::camera::ScalingMode;
+use bevy::input::mouse::{AccumulatedMouseMotion, AccumulatedMouseScroll};
 use bevy::prelude::*;
+use std::ops::RangeInclusive;
+
+/// Visible board height (world units) the camera starts at.
+pub const TEMPLEOS_VIEW_HEIGHT: f32 = 12.0;
+
+/// Visible heights the scroll wheel can zoom between.
+pub const TEMPLEOS_ZOOM_RANGE: RangeInclusive<f32> = 5.0..=20.0;
+
+/// Visible height change per scroll-wheel notch.
+const ZOOM_STEP: f32 = 1.0;
+
+/// World units panned per pixel of right-button drag at the starting zoom.
+const DRAG_PAN_PER_PIXEL: f32 = 0.02;
 
 /// Marker component for TempleOS camera
 #[derive(Component)]
@@ -70,7 +92,7 @@ pub fn setup_templeos_camera(
             // Set viewport height to show the entire 8x8 board
             // Adjust this value to zoom in/out
             scaling_mode: ScalingMode::FixedVertical {
-                viewport_height: 12.0,
+                viewport_height: TEMPLEOS_VIEW_HEIGHT,
             },
             ..OrthographicProjection::default_3d()
         }),
@@ -91,14 +113,19 @@ pub fn setup_templeos_camera(
 
 /// System to handle WASD camera movement for TempleOS mode
 ///
-/// Allows panning the camera around the board using WASD keys.
-/// Movement is smooth and maintains the isometric viewing angle.
+/// Allows panning the camera around the board using the camera keys (WASD
+/// by default) or by dragging
+/// with the right mouse button. Movement is smooth and maintains the isometric
+/// viewing angle.
 pub fn templeos_camera_movement_system(
     time: Res<Time>,
     keyboard: Res<ButtonInput<KeyCode>>,
+    settings: Res<GameSettings>,
+    mouse_buttons: Res<ButtonInput<MouseButton>>,
+    mouse_motion: Res<AccumulatedMouseMotion>,
     view_mode: Res<ViewMode>,
     mut query: Query<
-        (&mut Transform, &TempleOSCameraLookAt),
+        (&mut Transform, &TempleOSCameraLookAt, &Projection),
         (With<TempleOSCamera>, With<Camera3d>),
     >,
 ) {
@@ -107,7 +134,7 @@ pub fn templeos_camera_movement_system(
         return;
     }
 
-    for (mut transform, look_at) in query.iter_mut() {
+    for (mut transform, look_at, projection) in query.iter_mut() {
         // Movement speed
         let move_speed = 5.0;
 
@@ -122,17 +149,18 @@ pub fn templeos_camera_movement_system(
         let forward_xz = Vec3::new(forward.x, 0.0, forward.z).normalize_or_zero();
         let right_xz = Vec3::new(right.x, 0.0, right.z).normalize_or_zero();
 
-        // WASD movement
-        if keyboard.pressed(KeyCode::KeyW) {
+        // Camera keys (WASD by default)
+        let bindings = &settings.key_bindings;
+        if bindings.pressed(&keyboard, GameAction::CameraForward) {
             direction += forward_xz;
         }
-        if keyboard.pressed(KeyCode::KeyS) {
+        if bindings.pressed(&keyboard, GameAction::CameraBack) {
             direction -= forward_xz;
         }
-        if keyboard.pressed(KeyCode::KeyD) {
+        if bindings.pressed(&keyboard, GameAction::CameraRight) {
             direction += right_xz;
         }
-        if keyboard.pressed(KeyCode::KeyA) {
+        if bindings.pressed(&keyboard, GameAction::CameraLeft) {
             direction -= right_xz;
         }
 
@@ -140,7 +168,16 @@ pub fn templeos_camera_movement_system(
         direction = direction.normalize_or_zero();
 
         // Apply movement
-        let movement = direction * move_speed * time.delta_secs();
+        let mut movement = direction * move_speed * time.delta_secs();
+
+        // Right-button drag: the board follows the cursor, scaled so a drag
+        // covers the same stretch of board at any zoom.
+        if mouse_buttons.pressed(MouseButton::Right) {
+            let zoom =
+                view_height(projection).unwrap_or(TEMPLEOS_VIEW_HEIGHT) / TEMPLEOS_VIEW_HEIGHT;
+            let drag = mouse_motion.delta * DRAG_PAN_PER_PIXEL * zoom;
+            movement += forward_xz * drag.y - right_xz * drag.x;
+        }
         transform.translation += movement;
 
         // Maintain isometric viewing angle by updating look-at target
@@ -149,3 +186,115 @@ pub fn templeos_camera_movement_system(
         *transform = transform.looking_at(look_at_point, Vec3::Y);
     }
 }
+
+/// Scroll-wheel zoom for the TempleOS camera.
+///
+/// The camera is orthographic, so zooming changes how much of the board fits
+/// on screen rather than moving the camera.
+pub fn templeos_camera_zoom_system(
+    mouse_scroll: Res<AccumulatedMouseScroll>,
+    view_mode: Res<ViewMode>,
+    mut query: Query<&mut Projection, With<TempleOSCamera>>,
+) {
+    if *view_mode != ViewMode::TempleOS || mouse_scroll.delta.y == 0.0 {
+        return;
+    }
+
+    for mut projection in query.iter_mut() {
+        let Projection::Orthographic(ortho) = projection.as_mut() else {
+            continue;
+        };
+        if let ScalingMode::FixedVertical { viewport_height } = &mut ortho.scaling_mode {
+            // Scroll up zooms in, i.e. shows less of the board.
+            *viewport_height = (*viewport_height - mouse_scroll.delta.y * ZOOM_STEP)
+                .clamp(*TEMPLEOS_ZOOM_RANGE.start(), *TEMPLEOS_ZOOM_RANGE.end());
+        }
+    }
+}
+
+/// Visible height of an orthographic TempleOS projection.
+fn view_height(projection: &Projection) -> Option<f32> {
+    match projection {
+        Projection::Orthographic(OrthographicProjection {
+            scaling_mode: ScalingMode::FixedVertical { viewport_height },
+            ..
+        }) => Some(*viewport_height),
+        _ => None,
+    }
+}
+
+/// Switch a running game out of TempleOS once [`ViewMode`] has left it.
+///
+/// TempleOS skipped the standard scene on entering the game, so this removes
+/// its camera, quote and labels, then adds what was skipped: the dark
+/// background, the camera-following fill light, the board camera, and the
+/// engine's copy of the pieces (the game rules were idle until now). The
+/// overhead light is spawned for every view and is already there.
+pub fn leave_templeos_view(
+    mut commands: Commands,
+    view_mode: Res<ViewMode>,
+    templeos_entities: Query<
+        Entity,
+        Or<(
+            With<TempleOSCamera>,
+            With<TempleOSQuote>,
+            With<CoordinateLabel>,
+        )>,
+    >,
+) {
+    if view_mode.is_templeos() || templeos_entities.is_empty() {
+        return;
+    }
+
+    for entity in templeos_entities.iter() {
+        commands.entity(entity).despawn();
+    }
+    commands.insert_resource(ClearColor(STANDARD_CLEAR_COLOR));
+    spawn_board_fill_light(&mut commands);
+    commands.run_system_cached(setup_game_camera);
+    commands.run_system_cached(initialize_engine_from_ecs);
+
+    info!("[TEMPLEOS_CAMERA] Left TempleOS view for {:?}", *view_mode);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::board_state::ChessEngine;
+    use crate::game::systems::camera::{BoardCamera, BoardOrientation, CameraRotationState};
+    use crate::game::systems::CameraFollowLight;
+    use crate::PersistentEguiCamera;
+    use bevy::ecs::system::RunSystemOnce;
+
+    #[test]
+    fn test_leaving_templeos_mid_game_restores_the_standard_scene() {
+        //! Switching to the standard view drops the TempleOS camera and brings back the fill light, board camera and dark background
+
+        let mut world = World::new();
+        world.insert_resource(ViewMode::TempleOS);
+        world.insert_resource(ClearColor(Color::srgb(1.0, 1.0, 0.0)));
+        world.init_resource::<PersistentEguiCamera>();
+        world.init_resource::<Players>();
+        world.init_resource::<CurrentTurn>();
+        world.init_resource::<GameMode>();
+        world.init_resource::<CameraRotationState>();
+        world.init_resource::<BoardOrientation>();
+        world.init_resource::<GameSettings>();
+        world.init_resource::<ChessEngine>();
+        let templeos_camera = world.spawn(TempleOSCamera).id();
+
+        // Still in TempleOS: nothing changes.
+        world.run_system_once(leave_templeos_view).unwrap();
+        assert!(world.get_entity(templeos_camera).is_ok());
+
+        *world.resource_mut::<ViewMode>() = ViewMode::Standard3D;
+        world.run_system_once(leave_templeos_view).unwrap();
+
+        assert!(world.get_entity(templeos_camera).is_err());
+        assert_eq!(world.resource::<ClearColor>().0, STANDARD_CLEAR_COLOR);
+        let mut fill_lights = world.query_filtered::<&PointLight, With<CameraFollowLight>>();
+        assert_eq!(fill_lights.iter(&world).count(), 1);
+        let mut board_cameras = world.query_filtered::<(), With<BoardCamera>>();
+        assert_eq!(board_cameras.iter(&world).count(), 1);
+    }
+}
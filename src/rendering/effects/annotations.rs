@@ -0,0 +1,146 @@
+//! Arrows and circles for the board annotations.
+
+use crate::game::annotations::Annotations;
+use crate::game::board_coords::file_world_x;
+use crate::game::study::MarkColor;
+use bevy::prelude::*;
+
+/// Opacity of annotation arrows and circles.
+pub const ANNOTATION_ALPHA: f32 = 0.8;
+
+/// Marker component for every mesh drawn for [`Annotations`].
+#[derive(Component)]
+pub struct AnnotationMark;
+
+/// Meshes and one material per colour, made once at startup.
+#[derive(Resource)]
+pub struct AnnotationAssets {
+    /// Unit-length shaft, stretched along X to the arrow's length.
+    pub shaft: Handle<Mesh>,
+    /// Arrow head, pointing along +Y until rotated.
+    pub head: Handle<Mesh>,
+    /// Ring lying flat on the square.
+    pub ring: Handle<Mesh>,
+    /// Green, red, blue, yellow.
+    pub matls: [Handle<StandardMaterial>; 4],
+}
+
+impl AnnotationAssets {
+    fn matl(&self, color: MarkColor) -> Handle<StandardMaterial> {
+        let index = match color {
+            MarkColor::Green => 0,
+            MarkColor::Red => 1,
+            MarkColor::Blue => 2,
+            MarkColor::Yellow => 3,
+        };
+        self.matls[index].clone()
+    }
+}
+
+/// One-time setup system: allocate the annotation assets.
+pub fn init_annotation_assets(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut mats: ResMut<Assets<StandardMaterial>>,
+) {
+    let mut matl = |color: Color| {
+        mats.add(StandardMaterial {
+            base_color: color.with_alpha(ANNOTATION_ALPHA),
+            unlit: true,
+            alpha_mode: AlphaMode::Blend,
+            ..default()
+        })
+    };
+    let matls = [
+        matl(Color::srgb(0.08, 0.6, 0.2)),
+        matl(Color::srgb(0.85, 0.15, 0.1)),
+        matl(Color::srgb(0.1, 0.45, 0.9)),
+        matl(Color::srgb(0.95, 0.75, 0.05)),
+    ];
+    commands.insert_resource(AnnotationAssets {
+        shaft: meshes.add(Cuboid::new(1.0, 0.015, 0.14)),
+        head: meshes.add(Cone {
+            radius: 0.2,
+            height: 0.3,
+        }),
+        ring: meshes.add(Torus::new(0.38, 0.46)),
+        matls,
+    });
+}
+
+/// Redraws the annotations whenever they change.
+pub fn update_annotation_marks_system(
+    mut commands: Commands,
+    annotations: Res<Annotations>,
+    marks: Query<Entity, With<AnnotationMark>>,
+    assets: Option<Res<AnnotationAssets>>,
+) {
+    if !annotations.is_changed() {
+        return;
+    }
+    let Some(assets) = assets else { return };
+
+    for entity in marks.iter() {
+        commands.entity(entity).despawn();
+    }
+
+    let mut spawn_mark = |mesh: &Handle<Mesh>, color: MarkColor, transform: Transform| {
+        commands.spawn((
+            Mesh3d(mesh.clone()),
+            MeshMaterial3d(assets.matl(color)),
+            transform,
+            AnnotationMark,
+            bevy::picking::Pickable::IGNORE,
+            Name::new("Annotation"),
+            crate::core::DespawnOnExit(crate::core::GameState::InGame),
+            bevy::camera::visibility::RenderLayers::layer(
+                crate::game::systems::camera::BOARD_LAYER,
+            ),
+        ));
+    };
+
+    // Above the last-move arrow and engine hint, which may share a square.
+    const Y: f32 = 0.04;
+    for square in &annotations.squares {
+        let (x, z) = square.square;
+        spawn_mark(
+            &assets.ring,
+            square.color,
+            Transform::from_xyz(file_world_x(x), Y, z as f32).with_scale(Vec3::new(1.0, 0.05, 1.0)),
+        );
+    }
+    for arrow in &annotations.arrows {
+        let src = Vec3::new(file_world_x(arrow.from.0), Y, arrow.from.1 as f32);
+        let dst = Vec3::new(file_world_x(arrow.to.0), Y, arrow.to.1 as f32);
+        let dir = (dst - src).normalize_or_zero();
+        // Start off the centre of the first square and stop the head short
+        // of the centre of the last, as on a 2D board.
+        let start = src + dir * 0.25;
+        let tip = dst - dir * 0.1;
+        let base = tip - dir * 0.3;
+        let shaft = base - start;
+
+        spawn_mark(
+            &assets.shaft,
+            arrow.color,
+            Transform {
+                translation: (start + base) * 0.5,
+                rotation: Quat::from_rotation_y(-shaft.xz().to_angle()),
+                scale: Vec3::new(shaft.length(), 1.0, 1.0),
+            },
+        );
+        spawn_mark(
+            &assets.head,
+            arrow.color,
+            Transform {
+                translation: (base + tip) * 0.5,
+                // Lay the cone along +X, then turn it like the shaft. Its
+                // local X ends up vertical, so squashing that flattens it
+                // into a triangle on the board.
+                rotation: Quat::from_rotation_y(-dir.xz().to_angle())
+                    * Quat::from_rotation_z(-std::f32::consts::FRAC_PI_2),
+                scale: Vec3::new(0.1, 1.0, 1.0),
+            },
+        );
+    }
+}
@@ -1,9 +1,12 @@
-//! Pulsing red point light on the king square when in check.
+//! Pulsing red point light on the king square when in check, dimmed by the
+//! highlight opacity setting.
 
+use crate::game::board_coords::file_world_x;
 use crate::game::components::GamePhase;
 use crate::game::resources::CurrentGamePhase;
 use crate::game::resources::CurrentTurn;
 use crate::rendering::pieces::{Piece, PieceType};
+use crate::ui::styles::UiColors;
 use bevy::prelude::*;
 
 /// Marker component for the check highlight point light entity.
@@ -15,6 +18,7 @@ pub fn update_check_highlight_system(
     mut commands: Commands,
     game_phase: Res<CurrentGamePhase>,
     current_turn: Res<CurrentTurn>,
+    settings: Res<crate::core::GameSettings>,
     pieces: Query<&Piece>,
     existing: Query<Entity, With<CheckHighlightLight>>,
     time: Res<Time>,
@@ -35,7 +39,7 @@ pub fn update_check_highlight_system(
     let king_pos = pieces
         .iter()
         .find(|p| p.piece_type == PieceType::King && p.color == king_color)
-        .map(|p| Vec3::new(7.0 - p.x as f32, 1.2, p.y as f32));
+        .map(|p| Vec3::new(file_world_x(p.x), 1.2, p.y as f32));
 
     let Some(pos) = king_pos else { return };
 
@@ -43,8 +47,8 @@ pub fn update_check_highlight_system(
         // Spawn the light
         commands.spawn((
             PointLight {
-                color: Color::srgb(1.0, 0.1, 0.1),
-                intensity: 20_000.0,
+                color: UiColors::to_bevy(UiColors::CHECK_HIGHLIGHT),
+                intensity: 20_000.0 * settings.highlight_opacity,
                 radius: 1.5,
                 range: 3.0,
                 shadow_maps_enabled: false,
@@ -61,7 +65,7 @@ pub fn update_check_highlight_system(
     } else {
         // Update position and pulse intensity
         let pulse = (time.elapsed_secs() * 4.0).sin() * 0.5 + 0.5;
-        let intensity = 8_000.0 + pulse * 24_000.0;
+        let intensity = (8_000.0 + pulse * 24_000.0) * settings.highlight_opacity;
         for (mut light, mut tf) in lights.iter_mut() {
             light.intensity = intensity;
             tf.translation = pos;
@@ -0,0 +1,47 @@
+//! Highlight for the engine's suggested move.
+
+use crate::core::GameSettings;
+use crate::game::ai::hint::EngineHint;
+use crate::game::board_coords::file_world_x;
+use crate::rendering::effects::spawn_square_overlay;
+use crate::rendering::utils::SquareMaterials;
+use bevy::prelude::*;
+
+/// Marker component for the squares of the hinted move
+#[derive(Component)]
+pub struct EngineHintHighlight;
+
+/// Shows the hinted move's squares in their own colour. Runs only when the
+/// hint or the settings change.
+pub fn update_engine_hint_highlight_system(
+    mut commands: Commands,
+    settings: Res<GameSettings>,
+    hint: Res<EngineHint>,
+    highlight_query: Query<Entity, With<EngineHintHighlight>>,
+    materials: Res<SquareMaterials>,
+) {
+    if !hint.is_changed() && !settings.is_changed() {
+        return;
+    }
+
+    for entity in highlight_query.iter() {
+        commands.entity(entity).despawn();
+    }
+
+    let Some(shown) = hint.shown else {
+        return;
+    };
+    for (x, y) in [shown.from, shown.to] {
+        spawn_square_overlay(
+            &mut commands,
+            &materials,
+            settings.highlight_style,
+            &materials.engine_hint_matl,
+            // Just above the last-move highlight, which may share the square.
+            Vec3::new(file_world_x(x), 0.025, y as f32),
+            |entity| {
+                entity.insert((EngineHintHighlight, Name::new("Engine Hint Highlight")));
+            },
+        );
+    }
+}
@@ -0,0 +1,168 @@
+//! Strength and shape of the square overlays.
+//!
+//! The overlay materials are shared, so the opacity setting is applied once
+//! to the materials rather than per entity; the check light is dimmed by the
+//! same factor. Squares are drawn filled, outlined or as corner dots
+//! depending on [`HighlightStyle`].
+
+use crate::core::{GameSettings, HighlightStyle};
+use crate::rendering::effects::ArrowAssets;
+use crate::rendering::utils::SquareMaterials;
+use bevy::ecs::system::EntityCommands;
+use bevy::prelude::*;
+
+/// Lowest opacity the settings allow, so highlights never vanish entirely.
+pub const HIGHLIGHT_OPACITY_MIN: f32 = 0.2;
+
+/// Alpha of the move-hint and hover material at full opacity.
+pub const MOVE_HINT_ALPHA: f32 = 0.82;
+/// Alpha of the selected-square material at full opacity.
+pub const SELECTED_SQUARE_ALPHA: f32 = 0.75;
+/// Alpha of the capture-ring material at full opacity.
+pub const CAPTURE_HINT_ALPHA: f32 = 0.85;
+/// Alpha of the last-move arrow at full opacity.
+pub const LAST_MOVE_ARROW_ALPHA: f32 = 0.75;
+/// Alpha of the engine hint squares at full opacity.
+pub const ENGINE_HINT_ALPHA: f32 = 0.8;
+/// Alpha of the premove squares at full opacity.
+pub const PREMOVE_ALPHA: f32 = 0.7;
+/// Alpha of the check square at full opacity, at the top of its pulse.
+pub const CHECK_SQUARE_ALPHA: f32 = 0.85;
+
+/// Half the side of a square highlight, matching `highlight_mesh`.
+const HALF_SIDE: f32 = 0.46;
+
+/// Re-applies `highlight_opacity` to the shared overlay materials whenever
+/// the settings change.
+pub fn apply_highlight_opacity_system(
+    settings: Res<GameSettings>,
+    square_materials: Res<SquareMaterials>,
+    arrow_assets: Option<Res<ArrowAssets>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    if !settings.is_changed() && !square_materials.is_added() {
+        return;
+    }
+
+    let opacity = settings.highlight_opacity;
+    let mut targets = vec![
+        (&square_materials.hover_matl, MOVE_HINT_ALPHA),
+        (
+            &square_materials.selected_border_matl,
+            SELECTED_SQUARE_ALPHA,
+        ),
+        (&square_materials.capture_hint_matl, CAPTURE_HINT_ALPHA),
+        (&square_materials.engine_hint_matl, ENGINE_HINT_ALPHA),
+        (&square_materials.premove_matl, PREMOVE_ALPHA),
+    ];
+    if let Some(arrow) = arrow_assets.as_ref() {
+        targets.push((&arrow.matl, LAST_MOVE_ARROW_ALPHA));
+    }
+    for (handle, alpha) in targets {
+        if let Some(mut material) = materials.get_mut(handle) {
+            material.base_color.set_alpha(alpha * opacity);
+        }
+    }
+}
+
+/// Spawn a highlight for the square centred on `center` in the chosen
+/// style. `tag` is called on every part so callers can add their marker and
+/// name; outlines and corner dots are several entities.
+pub fn spawn_square_overlay(
+    commands: &mut Commands,
+    square_materials: &SquareMaterials,
+    style: HighlightStyle,
+    material: &Handle<StandardMaterial>,
+    center: Vec3,
+    mut tag: impl FnMut(&mut EntityCommands),
+) {
+    let flat = Quat::from_rotation_x(-std::f32::consts::FRAC_PI_2);
+    let parts: Vec<(Handle<Mesh>, Transform)> = match style {
+        HighlightStyle::Solid => vec![(
+            square_materials.highlight_mesh.clone(),
+            Transform::from_translation(center),
+        )],
+        HighlightStyle::Outline => {
+            let across = Quat::from_rotation_y(std::f32::consts::FRAC_PI_2);
+            [
+                (Vec3::Z * HALF_SIDE, Quat::IDENTITY),
+                (Vec3::NEG_Z * HALF_SIDE, Quat::IDENTITY),
+                (Vec3::X * HALF_SIDE, across),
+                (Vec3::NEG_X * HALF_SIDE, across),
+            ]
+            .into_iter()
+            .map(|(offset, rotation)| {
+                (
+                    square_materials.outline_edge_mesh.clone(),
+                    Transform::from_translation(center + offset).with_rotation(rotation),
+                )
+            })
+            .collect()
+        }
+        HighlightStyle::CornerDots => [(1.0, 1.0), (1.0, -1.0), (-1.0, 1.0), (-1.0, -1.0)]
+            .into_iter()
+            .map(|(x, z)| {
+                let offset = Vec3::new(x, 0.0, z) * (HALF_SIDE - 0.08);
+                (
+                    square_materials.corner_dot_mesh.clone(),
+                    Transform::from_translation(center + offset).with_rotation(flat),
+                )
+            })
+            .collect(),
+    };
+
+    for (mesh, transform) in parts {
+        let mut entity = commands.spawn((
+            Mesh3d(mesh),
+            MeshMaterial3d(material.clone()),
+            transform,
+            bevy::picking::Pickable::IGNORE,
+            crate::core::DespawnOnExit(crate::core::GameState::InGame),
+            bevy::camera::visibility::RenderLayers::layer(
+                crate::game::systems::camera::BOARD_LAYER,
+            ),
+        ));
+        tag(&mut entity);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::system::RunSystemOnce;
+
+    #[test]
+    fn test_opacity_scales_hint_material_alpha() {
+        //! Halving the opacity setting halves the alpha of the move-hint material
+
+        let mut world = World::new();
+        world.insert_resource(Assets::<StandardMaterial>::default());
+        world.insert_resource(Assets::<Mesh>::default());
+        world.init_resource::<SquareMaterials>();
+        world.insert_resource(GameSettings {
+            highlight_opacity: 0.5,
+            ..default()
+        });
+
+        world
+            .run_system_once(apply_highlight_opacity_system)
+            .unwrap();
+
+        let hint_alpha = |world: &World| {
+            let handle = &world.resource::<SquareMaterials>().hover_matl;
+            world
+                .resource::<Assets<StandardMaterial>>()
+                .get(handle)
+                .unwrap()
+                .base_color
+                .alpha()
+        };
+        assert!((hint_alpha(&world) - MOVE_HINT_ALPHA * 0.5).abs() < 1e-6);
+
+        world.resource_mut::<GameSettings>().highlight_opacity = 1.0;
+        world
+            .run_system_once(apply_highlight_opacity_system)
+            .unwrap();
+        assert!((hint_alpha(&world) - MOVE_HINT_ALPHA).abs() < 1e-6);
+    }
+}
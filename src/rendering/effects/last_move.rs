@@ -1,7 +1,9 @@
 //! Last move highlighting system.
 
 use crate::core::GameSettings;
+use crate::game::board_coords::file_world_x;
 use crate::game::resources::MoveHistory;
+use crate::rendering::effects::{spawn_square_overlay, LAST_MOVE_ARROW_ALPHA};
 use crate::rendering::utils::SquareMaterials;
 use bevy::prelude::*;
 
@@ -30,7 +32,7 @@ pub fn init_arrow_assets(
     // Unit-length cuboid — actual length set via Transform.scale.x each move.
     let mesh = meshes.add(Cuboid::new(1.0, 0.015, 0.12));
     let matl = mats.add(StandardMaterial {
-        base_color: Color::srgba(1.0, 0.85, 0.1, 0.75),
+        base_color: Color::srgb(1.0, 0.85, 0.1).with_alpha(LAST_MOVE_ARROW_ALPHA),
         unlit: true,
         alpha_mode: AlphaMode::Blend,
         ..default()
@@ -69,22 +71,24 @@ pub fn update_last_move_highlight_system(
     let Some(assets) = arrow_assets else { return };
 
     for (x, y) in [last_move.from, last_move.to] {
-        commands.spawn((
-            Mesh3d(materials.highlight_mesh.clone()),
-            MeshMaterial3d(materials.hover_matl.clone()),
-            Transform::from_translation(Vec3::new(7.0 - x as f32, 0.02, y as f32)),
-            LastMoveHighlight,
-            bevy::picking::Pickable::IGNORE,
-            Name::new("Last Move Highlight"),
-            crate::core::DespawnOnExit(crate::core::GameState::InGame),
-            bevy::camera::visibility::RenderLayers::layer(
-                crate::game::systems::camera::BOARD_LAYER,
-            ),
-        ));
+        spawn_square_overlay(
+            &mut commands,
+            &materials,
+            settings.highlight_style,
+            &materials.hover_matl,
+            Vec3::new(file_world_x(x), 0.02, y as f32),
+            |entity| {
+                entity.insert((LastMoveHighlight, Name::new("Last Move Highlight")));
+            },
+        );
     }
 
-    let src = Vec3::new(7.0 - last_move.from.0 as f32, 0.03, last_move.from.1 as f32);
-    let dst = Vec3::new(7.0 - last_move.to.0 as f32, 0.03, last_move.to.1 as f32);
+    let src = Vec3::new(
+        file_world_x(last_move.from.0),
+        0.03,
+        last_move.from.1 as f32,
+    );
+    let dst = Vec3::new(file_world_x(last_move.to.0), 0.03, last_move.to.1 as f32);
     let dir = dst - src;
     let length = dir.length();
     if length > 0.01 {
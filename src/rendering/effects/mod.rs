@@ -2,18 +2,28 @@
 //!
 //! Manages move hints and last move highlighting effects.
 
+pub mod annotations;
 pub mod check_highlight;
 pub mod dynamic_lighting;
+pub mod engine_hint;
+pub mod highlight_style;
 pub mod last_move;
 pub mod move_hints;
+pub mod premove;
 pub mod sky;
 
 // Re-export all public items
+pub use annotations::{
+    init_annotation_assets, update_annotation_marks_system, AnnotationAssets, AnnotationMark,
+};
 pub use check_highlight::*;
 pub use dynamic_lighting::DynamicLightingPlugin;
+pub use engine_hint::{update_engine_hint_highlight_system, EngineHintHighlight};
+pub use highlight_style::*;
 pub use last_move::{
     init_arrow_assets, update_last_move_highlight_system, ArrowAssets, LastMoveArrow3D,
     LastMoveHighlight,
 };
 pub use move_hints::*;
+pub use premove::{update_premove_highlight_system, PremoveHighlight};
 pub use sky::SkyPlugin;
@@ -2,10 +2,12 @@
 //!
 //! Highlights valid move squares when a piece is selected and show_hints is enabled.
 //! Regular moves: green dot. Captures: orange annular ring (Lichess style).
+//! With hover preview on, the same hints follow the hovered piece until one is selected.
 
 use crate::core::GameSettings;
+use crate::game::board_coords::file_world_x;
 use crate::game::components::Piece;
-use crate::game::resources::Selection;
+use crate::game::resources::{CurrentTurn, DragTarget, HoverPreview, Selection};
 use crate::rendering::utils::SquareMaterials;
 use bevy::prelude::*;
 
@@ -13,17 +15,27 @@ use bevy::prelude::*;
 #[derive(Component)]
 pub struct MoveHint;
 
-/// System that shows/hides move hints based on selection and settings.
+/// Marker component for the square highlight under a dragged piece
+#[derive(Component)]
+pub struct DragTargetHighlight;
+
+/// System that shows/hides move hints based on selection, hover preview and settings.
 /// Uses green dots for regular moves and orange rings for capture targets.
 pub fn update_move_hints_system(
     mut commands: Commands,
     settings: Res<GameSettings>,
     selection: Res<Selection>,
+    preview: Res<HoverPreview>,
+    current_turn: Res<CurrentTurn>,
     hint_query: Query<Entity, With<MoveHint>>,
     materials: Res<SquareMaterials>,
     pieces: Query<&Piece>,
 ) {
-    if !selection.is_changed() && !settings.is_changed() {
+    if !selection.is_changed()
+        && !settings.is_changed()
+        && !preview.is_changed()
+        && !current_turn.is_changed()
+    {
         return;
     }
 
@@ -31,12 +43,22 @@ pub fn update_move_hints_system(
         commands.entity(entity).despawn();
     }
 
-    if settings.show_hints && selection.is_selected() {
+    let moves = if selection.is_selected() {
+        &selection.possible_moves
+    } else if settings.preview_moves_on_hover
+        && preview_is_current(&preview, &current_turn, &pieces)
+    {
+        &preview.moves
+    } else {
+        return;
+    };
+
+    if settings.show_hints {
         // Build a set of occupied squares for O(1) capture detection
         let occupied: std::collections::HashSet<(u8, u8)> =
             pieces.iter().map(|p| (p.x, p.y)).collect();
 
-        for &(x, y) in &selection.possible_moves {
+        for &(x, y) in moves {
             let is_capture = occupied.contains(&(x, y));
             let (mesh, matl) = if is_capture {
                 (
@@ -49,7 +71,7 @@ pub fn update_move_hints_system(
             commands.spawn((
                 Mesh3d(mesh),
                 MeshMaterial3d(matl),
-                Transform::from_translation(Vec3::new(7.0 - x as f32, 0.051, y as f32))
+                Transform::from_translation(Vec3::new(file_world_x(x), 0.051, y as f32))
                     .with_rotation(Quat::from_rotation_x(-std::f32::consts::FRAC_PI_2)),
                 MoveHint,
                 bevy::picking::Pickable::IGNORE,
@@ -66,3 +88,46 @@ pub fn update_move_hints_system(
         }
     }
 }
+
+/// A preview is stale once its piece has moved or the turn has passed, since
+/// the pointer may never leave a piece that was just played.
+fn preview_is_current(
+    preview: &HoverPreview,
+    current_turn: &CurrentTurn,
+    pieces: &Query<&Piece>,
+) -> bool {
+    let (Some(entity), Some(square)) = (preview.entity, preview.square) else {
+        return false;
+    };
+    pieces
+        .get(entity)
+        .is_ok_and(|p| (p.x, p.y) == square && p.color == current_turn.color)
+}
+
+/// Highlights the square a dragged piece would drop onto. Runs when
+/// [`DragTarget`] changes and clears once the drag ends.
+pub fn update_drag_target_highlight_system(
+    mut commands: Commands,
+    settings: Res<GameSettings>,
+    drag_target: Res<DragTarget>,
+    highlight_query: Query<Entity, With<DragTargetHighlight>>,
+    materials: Res<SquareMaterials>,
+) {
+    for entity in highlight_query.iter() {
+        commands.entity(entity).despawn();
+    }
+
+    let Some((x, y)) = drag_target.square else {
+        return;
+    };
+    super::spawn_square_overlay(
+        &mut commands,
+        &materials,
+        settings.highlight_style,
+        &materials.hover_matl,
+        Vec3::new(file_world_x(x), 0.025, y as f32),
+        |entity| {
+            entity.insert((DragTargetHighlight, Name::new("Drag Target Highlight")));
+        },
+    );
+}
@@ -0,0 +1,44 @@
+//! Highlight for a queued premove.
+
+use crate::core::GameSettings;
+use crate::game::board_coords::file_world_x;
+use crate::game::premove::Premove;
+use crate::rendering::effects::spawn_square_overlay;
+use crate::rendering::utils::SquareMaterials;
+use bevy::prelude::*;
+
+/// Marker component for the squares of the queued premove
+#[derive(Component)]
+pub struct PremoveHighlight;
+
+/// Shows the premove's squares, the first as soon as it is clicked, in
+/// their own colour. Runs only when the premove or the settings change.
+pub fn update_premove_highlight_system(
+    mut commands: Commands,
+    settings: Res<GameSettings>,
+    premove: Res<Premove>,
+    highlight_query: Query<Entity, With<PremoveHighlight>>,
+    materials: Res<SquareMaterials>,
+) {
+    if !premove.is_changed() && !settings.is_changed() {
+        return;
+    }
+
+    for entity in highlight_query.iter() {
+        commands.entity(entity).despawn();
+    }
+
+    for (x, y) in [premove.from, premove.to].into_iter().flatten() {
+        spawn_square_overlay(
+            &mut commands,
+            &materials,
+            settings.highlight_style,
+            &materials.premove_matl,
+            // Level with the engine hint; the two are never shown together.
+            Vec3::new(file_world_x(x), 0.025, y as f32),
+            |entity| {
+                entity.insert((PremoveHighlight, Name::new("Premove Highlight")));
+            },
+        );
+    }
+}
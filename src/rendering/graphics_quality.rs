@@ -1,32 +1,48 @@
 //! Graphics quality settings application
 //!
-//! Applies graphics quality presets to cameras and lights based on GameSettings.
+//! Applies graphics quality presets to cameras and lights based on GameSettings,
+//! along with the anti-aliasing, render-scale, bloom and fog settings the
+//! presets seed.
 
-use crate::core::GameSettings;
-use bevy::pbr::ScreenSpaceAmbientOcclusion;
+use crate::core::{GameSettings, GraphicsQuality, MsaaLevel};
+use crate::game::systems::camera::BoardCamera;
+use crate::game::systems::STANDARD_CLEAR_COLOR;
+use bevy::pbr::{DistanceFog, FogFalloff, ScreenSpaceAmbientOcclusion};
 use bevy::post_process::bloom::Bloom;
 use bevy::prelude::*;
+use bevy::render::camera::MainPassResolutionOverride;
+
+/// Lowest render scale the settings allow.
+pub const RENDER_SCALE_MIN: f32 = 0.5;
+
+/// Strongest bloom the settings allow; past this the board washes out.
+pub const BLOOM_INTENSITY_MAX: f32 = 0.5;
+
+/// Thinnest distance fog the settings allow.
+pub const FOG_DENSITY_MIN: f32 = 0.005;
+/// Thickest distance fog the settings allow; the far edge of the board
+/// stays readable from the highest camera.
+pub const FOG_DENSITY_MAX: f32 = 0.08;
+
+/// SSAO only when the preset asks for it and MSAA is off; Bevy's SSAO does
+/// not run on multisampled views.
+fn ssao_enabled(settings: &GameSettings) -> bool {
+    settings.graphics_quality.ambient_occlusion_enabled() && settings.msaa == MsaaLevel::Off
+}
 
 /// System that applies graphics quality settings to cameras
 ///
-/// Watches for changes to `GameSettings.graphics_quality` and updates:
-/// - Bloom component on cameras
-/// - ScreenSpaceAmbientOcclusion component on cameras
+/// Watches for changes to `GameSettings.graphics_quality` and adds the
+/// ScreenSpaceAmbientOcclusion component to cameras. Bloom and fog are
+/// handled by [`apply_post_processing_system`].
 pub fn apply_graphics_quality_camera_system(
     settings: Res<GameSettings>,
-    mut camera_query: Query<
-        Entity,
-        (
-            With<Camera3d>,
-            Without<Bloom>,
-            Without<ScreenSpaceAmbientOcclusion>,
-        ),
-    >,
+    mut camera_query: Query<Entity, (With<Camera3d>, Without<ScreenSpaceAmbientOcclusion>)>,
     mut commands: Commands,
-    mut last_quality: Local<Option<crate::core::GraphicsQuality>>,
+    mut last_quality: Local<Option<(GraphicsQuality, MsaaLevel)>>,
 ) {
     // Check if quality changed
-    let current_quality = settings.graphics_quality;
+    let current_quality = (settings.graphics_quality, settings.msaa);
     if let Some(prev_quality) = *last_quality {
         if prev_quality == current_quality {
             return; // No change
@@ -34,14 +50,10 @@ pub fn apply_graphics_quality_camera_system(
     }
     *last_quality = Some(current_quality);
 
-    let bloom_enabled = settings.graphics_quality.bloom_enabled();
-    let ssao_enabled = settings.graphics_quality.ambient_occlusion_enabled();
+    let ssao_enabled = ssao_enabled(&settings);
 
     // Apply settings to all cameras
     for entity in camera_query.iter_mut() {
-        if bloom_enabled {
-            commands.entity(entity).insert(Bloom::NATURAL);
-        }
         if ssao_enabled {
             commands
                 .entity(entity)
@@ -50,25 +62,23 @@ pub fn apply_graphics_quality_camera_system(
     }
 
     info!(
-        "[GRAPHICS] Applied quality preset: {:?} (Bloom: {}, SSAO: {})",
+        "[GRAPHICS] Applied quality preset: {:?} (SSAO: {})",
         settings.graphics_quality.description(),
-        bloom_enabled,
         ssao_enabled
     );
 }
 
 /// System that updates graphics quality settings on existing cameras
 ///
-/// Updates cameras that already have Bloom or SSAO components when quality changes.
+/// Updates cameras that already have SSAO when quality changes.
 pub fn update_graphics_quality_camera_system(
     settings: Res<GameSettings>,
-    mut bloom_query: Query<Entity, (With<Camera3d>, With<Bloom>)>,
     mut ssao_query: Query<Entity, (With<Camera3d>, With<ScreenSpaceAmbientOcclusion>)>,
     mut commands: Commands,
-    mut last_quality: Local<Option<crate::core::GraphicsQuality>>,
+    mut last_quality: Local<Option<(GraphicsQuality, MsaaLevel)>>,
 ) {
     // Check if quality changed
-    let current_quality = settings.graphics_quality;
+    let current_quality = (settings.graphics_quality, settings.msaa);
     if let Some(prev_quality) = *last_quality {
         if prev_quality == current_quality {
             return; // No change
@@ -76,17 +86,7 @@ pub fn update_graphics_quality_camera_system(
     }
     *last_quality = Some(current_quality);
 
-    let bloom_enabled = settings.graphics_quality.bloom_enabled();
-    let ssao_enabled = settings.graphics_quality.ambient_occlusion_enabled();
-
-    // Remove or add Bloom based on quality
-    if !bloom_enabled {
-        for entity in bloom_query.iter_mut() {
-            commands.entity(entity).remove::<Bloom>();
-        }
-    } else {
-        // Add to cameras that don't have it (handled by apply system)
-    }
+    let ssao_enabled = ssao_enabled(&settings);
 
     // Remove or add SSAO based on quality
     if !ssao_enabled {
@@ -98,19 +98,123 @@ pub fn update_graphics_quality_camera_system(
     }
 
     info!(
-        "[GRAPHICS] Updated quality preset: {:?} (Bloom: {}, SSAO: {})",
+        "[GRAPHICS] Updated quality preset: {:?} (SSAO: {})",
         settings.graphics_quality.description(),
-        bloom_enabled,
         ssao_enabled
     );
 }
 
-/// System that applies shadow settings to lights based on graphics quality
+/// The bloom `settings` ask for, if any.
+pub fn bloom_for(settings: &GameSettings) -> Option<Bloom> {
+    settings.bloom.then(|| Bloom {
+        intensity: settings.bloom_intensity.clamp(0.0, BLOOM_INTENSITY_MAX),
+        ..Bloom::NATURAL
+    })
+}
+
+/// The distance fog `settings` ask for, if any. It fades to the clear colour
+/// so the board's surroundings dissolve into the background.
+pub fn fog_for(settings: &GameSettings) -> Option<DistanceFog> {
+    settings.fog.then(|| DistanceFog {
+        color: STANDARD_CLEAR_COLOR,
+        falloff: FogFalloff::ExponentialSquared {
+            density: settings.fog_density.clamp(FOG_DENSITY_MIN, FOG_DENSITY_MAX),
+        },
+        ..default()
+    })
+}
+
+/// System that keeps bloom on every 3D camera and distance fog on the board
+/// camera in step with the settings, including cameras spawned since the
+/// settings last changed.
+pub fn apply_post_processing_system(
+    settings: Res<GameSettings>,
+    cameras: Query<(Entity, Has<BoardCamera>, Ref<Camera3d>)>,
+    mut commands: Commands,
+) {
+    let changed = settings.is_changed();
+    let bloom = bloom_for(&settings);
+    let fog = fog_for(&settings);
+    for (entity, is_board, camera) in cameras.iter() {
+        if !changed && !camera.is_added() {
+            continue;
+        }
+        let mut entity = commands.entity(entity);
+        match bloom.clone() {
+            Some(bloom) => entity.insert(bloom),
+            None => entity.remove::<Bloom>(),
+        };
+        match fog.clone().filter(|_| is_board) {
+            Some(fog) => entity.insert(fog),
+            None => entity.remove::<DistanceFog>(),
+        };
+    }
+}
+
+/// Bevy's MSAA component for a setting.
+pub fn msaa_for(level: MsaaLevel) -> Msaa {
+    match level {
+        MsaaLevel::Off => Msaa::Off,
+        MsaaLevel::X2 => Msaa::Sample2,
+        MsaaLevel::X4 => Msaa::Sample4,
+    }
+}
+
+/// System that keeps every 3D camera's MSAA at the chosen level, including
+/// cameras spawned after the setting last changed.
+pub fn apply_msaa_system(
+    settings: Res<GameSettings>,
+    mut cameras: Query<&mut Msaa, With<Camera3d>>,
+) {
+    let wanted = msaa_for(settings.msaa);
+    for mut msaa in cameras.iter_mut() {
+        msaa.set_if_neq(wanted);
+    }
+}
+
+/// System that draws the 3D view's main pass at `render_scale` of the
+/// camera's target, for speed on weak GPUs. Follows window resizes; at a
+/// scale of 1 the override is removed.
+pub fn apply_render_scale_system(
+    settings: Res<GameSettings>,
+    cameras: Query<(Entity, &Camera, Option<&MainPassResolutionOverride>), With<Camera3d>>,
+    mut commands: Commands,
+) {
+    let scale = settings.render_scale.clamp(RENDER_SCALE_MIN, 1.0);
+    for (entity, camera, current) in cameras.iter() {
+        let wanted = camera
+            .physical_target_size()
+            .filter(|_| scale < 1.0)
+            .map(|size| (size.as_vec2() * scale).round().as_uvec2().max(UVec2::ONE));
+        match (wanted, current) {
+            (Some(size), Some(current)) if current.0 == size => {}
+            (Some(size), _) => {
+                commands
+                    .entity(entity)
+                    .insert(MainPassResolutionOverride(size));
+            }
+            (None, Some(_)) => {
+                commands
+                    .entity(entity)
+                    .remove::<MainPassResolutionOverride>();
+            }
+            (None, None) => {}
+        }
+    }
+}
+
+/// Marker for the scene's key lights (the overhead board light in game and
+/// menu) whose shadows follow [`GameSettings::shadows_enabled`].
+///
+/// Fill, highlight and orbital lights are deliberately left untagged — they
+/// are shadowless by design or have their own toggle.
+#[derive(Component, Debug, Default)]
+pub struct ShadowCastingLight;
+
+/// System that applies shadow bias to point lights based on graphics quality
 pub fn apply_graphics_quality_lights_system(
     settings: Res<GameSettings>,
-    mut directional_lights: Query<&mut DirectionalLight>,
     mut point_lights: Query<&mut PointLight>,
-    mut spot_lights: Query<&mut SpotLight>,
     mut last_quality: Local<Option<crate::core::GraphicsQuality>>,
 ) {
     let current_quality = settings.graphics_quality;
@@ -121,23 +225,165 @@ pub fn apply_graphics_quality_lights_system(
     }
     *last_quality = Some(current_quality);
 
-    let shadows_enabled = settings.graphics_quality.shadow_enabled();
     let shadow_size = settings.graphics_quality.shadow_map_size();
 
-    for mut light in directional_lights.iter_mut() {
-        light.shadow_maps_enabled = shadows_enabled;
-    }
     for mut light in point_lights.iter_mut() {
-        light.shadow_maps_enabled = shadows_enabled;
         // Bias scales with map resolution: smaller map → more bias to hide aliasing
         light.shadow_depth_bias = 4096.0 / shadow_size as f32 * 0.05;
     }
-    for mut light in spot_lights.iter_mut() {
-        light.shadow_maps_enabled = shadows_enabled;
-    }
 
     info!(
-        "[GRAPHICS] Shadow quality: {:?} (map {}px, enabled: {})",
-        current_quality, shadow_size, shadows_enabled
+        "[GRAPHICS] Shadow quality: {:?} (map {}px)",
+        current_quality, shadow_size
     );
 }
+
+/// System that switches shadows on [`ShadowCastingLight`]s on or off.
+///
+/// Runs when `GameSettings` changes (shadow toggle or quality preset) and for
+/// lights spawned since the last run, so lights created on entering a state
+/// pick up the current setting instead of their spawn-time default.
+pub fn apply_shadow_setting_system(
+    settings: Res<GameSettings>,
+    mut directional_lights: Query<(&mut DirectionalLight, Ref<ShadowCastingLight>)>,
+    mut point_lights: Query<(&mut PointLight, Ref<ShadowCastingLight>)>,
+    mut spot_lights: Query<(&mut SpotLight, Ref<ShadowCastingLight>)>,
+) {
+    let changed = settings.is_changed();
+    let enabled = settings.shadows_enabled();
+
+    for (mut light, marker) in directional_lights.iter_mut() {
+        if (changed || marker.is_added()) && light.shadow_maps_enabled != enabled {
+            light.shadow_maps_enabled = enabled;
+        }
+    }
+    for (mut light, marker) in point_lights.iter_mut() {
+        if (changed || marker.is_added()) && light.shadow_maps_enabled != enabled {
+            light.shadow_maps_enabled = enabled;
+        }
+    }
+    for (mut light, marker) in spot_lights.iter_mut() {
+        if (changed || marker.is_added()) && light.shadow_maps_enabled != enabled {
+            light.shadow_maps_enabled = enabled;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::system::RunSystemOnce;
+
+    fn spawn_lights(world: &mut World) -> (Entity, Entity) {
+        let main = world
+            .spawn((
+                DirectionalLight {
+                    shadow_maps_enabled: true,
+                    ..default()
+                },
+                ShadowCastingLight,
+            ))
+            .id();
+        let fill = world
+            .spawn(PointLight {
+                shadow_maps_enabled: false,
+                ..default()
+            })
+            .id();
+        (main, fill)
+    }
+
+    #[test]
+    fn test_disabling_shadows_turns_off_main_light_shadows() {
+        //! The shadows toggle switches the key directional light off at runtime
+
+        let mut world = World::new();
+        world.insert_resource(GameSettings::default());
+        let (main, fill) = spawn_lights(&mut world);
+
+        world.run_system_once(apply_shadow_setting_system).unwrap();
+        assert!(
+            world
+                .get::<DirectionalLight>(main)
+                .unwrap()
+                .shadow_maps_enabled
+        );
+
+        world.resource_mut::<GameSettings>().shadows = false;
+        world.run_system_once(apply_shadow_setting_system).unwrap();
+
+        assert!(
+            !world
+                .get::<DirectionalLight>(main)
+                .unwrap()
+                .shadow_maps_enabled
+        );
+        assert!(!world.get::<PointLight>(fill).unwrap().shadow_maps_enabled);
+    }
+
+    #[test]
+    fn test_presets_set_msaa_samples() {
+        //! Ultra turns on 4x MSAA and Low turns it off; the cameras follow
+
+        let mut world = World::new();
+        world.insert_resource(GameSettings::default());
+        let camera = world.spawn((Camera3d::default(), Msaa::Sample4)).id();
+
+        let mut settings = world.resource_mut::<GameSettings>();
+        settings.select_graphics_quality(GraphicsQuality::Ultra);
+        assert_eq!(settings.msaa.samples(), 4);
+        settings.select_graphics_quality(GraphicsQuality::Low);
+        assert_eq!(settings.msaa.samples(), 1);
+
+        world.run_system_once(apply_msaa_system).unwrap();
+        assert_eq!(*world.get::<Msaa>(camera).unwrap(), Msaa::Off);
+    }
+
+    #[test]
+    fn test_low_preset_drops_bloom_and_fog() {
+        //! High brings bloom to every camera and fog to the board camera; Low takes both away
+
+        let mut world = World::new();
+        world.insert_resource(GameSettings::default());
+        let board = world.spawn((Camera3d::default(), BoardCamera)).id();
+        let menu = world.spawn(Camera3d::default()).id();
+
+        world
+            .resource_mut::<GameSettings>()
+            .select_graphics_quality(GraphicsQuality::High);
+        world.resource_mut::<GameSettings>().bloom_intensity = 9.0;
+        world.run_system_once(apply_post_processing_system).unwrap();
+        assert_eq!(
+            world.get::<Bloom>(board).unwrap().intensity,
+            BLOOM_INTENSITY_MAX
+        );
+        assert!(world.get::<DistanceFog>(board).is_some());
+        assert!(world.get::<Bloom>(menu).is_some());
+        assert!(world.get::<DistanceFog>(menu).is_none());
+
+        world
+            .resource_mut::<GameSettings>()
+            .select_graphics_quality(GraphicsQuality::Low);
+        world.run_system_once(apply_post_processing_system).unwrap();
+        assert!(world.get::<Bloom>(board).is_none());
+        assert!(world.get::<DistanceFog>(board).is_none());
+    }
+
+    #[test]
+    fn test_shadow_toggle_is_independent_of_high_preset() {
+        //! High quality with shadows off keeps shadows off
+
+        let settings = GameSettings {
+            graphics_quality: crate::core::GraphicsQuality::High,
+            shadows: false,
+            ..default()
+        };
+        assert!(!settings.shadows_enabled());
+
+        let settings = GameSettings {
+            shadows: true,
+            ..settings
+        };
+        assert!(settings.shadows_enabled());
+    }
+}
@@ -34,6 +34,10 @@ impl Plugin for RenderingPlugin {
                 graphics_quality::apply_graphics_quality_camera_system,
                 graphics_quality::update_graphics_quality_camera_system,
                 graphics_quality::apply_graphics_quality_lights_system,
+                graphics_quality::apply_shadow_setting_system,
+                graphics_quality::apply_msaa_system,
+                graphics_quality::apply_render_scale_system,
+                graphics_quality::apply_post_processing_system,
             ),
         );
     }
@@ -0,0 +1,169 @@
+//! Stand-in piece assets for meshes or materials lost mid-game
+//!
+//! The piece models are checked once before the board is spawned, but an
+//! asset can still drop out afterwards (a hot-reload of a broken GLB, or an
+//! asset being removed). A piece whose mesh is gone renders as nothing and
+//! can no longer be clicked, so [`replace_missing_piece_assets`] swaps in a
+//! simple procedural shape and a plain material instead, warning once.
+
+use super::{
+    black_piece_material, white_piece_material, Piece, Piece3DVisual, PieceColor, PieceType,
+};
+use bevy::prelude::*;
+
+/// Procedural meshes and materials shared by every piece that needs one,
+/// created on first use.
+#[derive(Resource, Default)]
+pub struct FallbackPieceAssets {
+    meshes: [Option<Handle<Mesh>>; 6],
+    white_material: Option<Handle<StandardMaterial>>,
+    black_material: Option<Handle<StandardMaterial>>,
+}
+
+impl FallbackPieceAssets {
+    fn mesh(&mut self, piece_type: PieceType, meshes: &mut Assets<Mesh>) -> Handle<Mesh> {
+        let slot = &mut self.meshes[piece_type_index(piece_type)];
+        if let Some(handle) = slot.as_ref().filter(|h| meshes.contains(h.id())) {
+            return handle.clone();
+        }
+        let handle = meshes.add(fallback_piece_mesh(piece_type));
+        *slot = Some(handle.clone());
+        handle
+    }
+
+    fn material(
+        &mut self,
+        color: PieceColor,
+        materials: &mut Assets<StandardMaterial>,
+    ) -> Handle<StandardMaterial> {
+        let (slot, make): (_, fn() -> StandardMaterial) = match color {
+            PieceColor::White => (&mut self.white_material, white_piece_material),
+            PieceColor::Black => (&mut self.black_material, black_piece_material),
+        };
+        if let Some(handle) = slot.as_ref().filter(|h| materials.contains(h.id())) {
+            return handle.clone();
+        }
+        let handle = materials.add(make());
+        *slot = Some(handle.clone());
+        handle
+    }
+}
+
+fn piece_type_index(piece_type: PieceType) -> usize {
+    match piece_type {
+        PieceType::Pawn => 0,
+        PieceType::Knight => 1,
+        PieceType::Bishop => 2,
+        PieceType::Rook => 3,
+        PieceType::Queen => 4,
+        PieceType::King => 5,
+    }
+}
+
+/// A cylinder standing on the origin, taller for more valuable pieces so the
+/// position stays readable.
+pub fn fallback_piece_mesh(piece_type: PieceType) -> Mesh {
+    let (radius, height) = match piece_type {
+        PieceType::Pawn => (0.18, 0.45),
+        PieceType::Knight => (0.22, 0.60),
+        PieceType::Bishop => (0.22, 0.70),
+        PieceType::Rook => (0.25, 0.55),
+        PieceType::Queen => (0.25, 0.85),
+        PieceType::King => (0.26, 0.95),
+    };
+    Mesh::from(Cylinder {
+        radius,
+        half_height: height * 0.5,
+    })
+    .translated_by(Vec3::Y * height * 0.5)
+}
+
+/// Replace any piece visual whose mesh or material is no longer loaded with
+/// the procedural fallback.
+pub fn replace_missing_piece_assets(
+    pieces: Query<(&Piece, &Children)>,
+    mut visuals: Query<(&mut Mesh3d, &mut MeshMaterial3d<StandardMaterial>), With<Piece3DVisual>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut fallback: ResMut<FallbackPieceAssets>,
+    mut warned: Local<bool>,
+) {
+    for (piece, children) in &pieces {
+        for child in children.iter() {
+            let Ok((mut mesh, mut material)) = visuals.get_mut(child) else {
+                continue;
+            };
+            let mesh_missing = !meshes.contains(mesh.0.id());
+            let material_missing = !materials.contains(material.0.id());
+            if !mesh_missing && !material_missing {
+                continue;
+            }
+
+            if !*warned {
+                warn!(
+                    "[PIECES] {:?} {:?} lost its {}; drawing a fallback shape",
+                    piece.color,
+                    piece.piece_type,
+                    if mesh_missing { "mesh" } else { "material" }
+                );
+                *warned = true;
+            }
+            if mesh_missing {
+                mesh.0 = fallback.mesh(piece.piece_type, &mut meshes);
+            }
+            if material_missing {
+                material.0 = fallback.material(piece.color, &mut materials);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::system::RunSystemOnce;
+
+    #[test]
+    fn test_missing_mesh_uses_fallback() {
+        //! A piece whose mesh handle points at nothing gets a real mesh, shared on later runs
+
+        let mut world = World::new();
+        world.init_resource::<Assets<Mesh>>();
+        world.init_resource::<Assets<StandardMaterial>>();
+        world.init_resource::<FallbackPieceAssets>();
+
+        let material = world
+            .resource_mut::<Assets<StandardMaterial>>()
+            .add(white_piece_material());
+        let mut visual = Entity::PLACEHOLDER;
+        world
+            .spawn(Piece::new(PieceColor::White, PieceType::Queen, 3, 0))
+            .with_children(|parent| {
+                visual = parent
+                    .spawn((
+                        Mesh3d(Handle::default()),
+                        MeshMaterial3d(material.clone()),
+                        Piece3DVisual,
+                    ))
+                    .id();
+            });
+
+        world
+            .run_system_once(replace_missing_piece_assets)
+            .expect("system runs");
+        let mesh = world.get::<Mesh3d>(visual).unwrap().0.clone();
+        assert!(world.resource::<Assets<Mesh>>().contains(mesh.id()));
+        assert_eq!(
+            world
+                .get::<MeshMaterial3d<StandardMaterial>>(visual)
+                .unwrap()
+                .0,
+            material
+        );
+
+        world
+            .run_system_once(replace_missing_piece_assets)
+            .expect("system runs");
+        assert_eq!(world.resource::<Assets<Mesh>>().len(), 1);
+    }
+}
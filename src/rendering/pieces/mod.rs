@@ -2,6 +2,7 @@
 //!
 //! Manages 3D piece models and piece-related types.
 
+pub mod fallback;
 pub mod pieces;
 
 // Re-export all public items
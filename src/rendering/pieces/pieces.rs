@@ -4,10 +4,14 @@
 //! [`PieceType`] live in [`crate::game::components::piece_types`].
 //! This module re-exports them for backward compatibility.
 
+use crate::core::{GameSettings, PieceFinish};
+use crate::game::annotations::{on_annotation_press, on_annotation_release};
+use crate::game::board_coords::file_world_x;
 use crate::game::components::HasMoved;
 use crate::game::systems::input::{
     on_piece_click, on_piece_drag, on_piece_drag_end, on_piece_drag_start,
 };
+use crate::game::systems::visual::SelectionTint;
 use crate::input::pointer::{on_piece_hover, on_piece_unhover};
 use bevy::color::Color;
 
@@ -47,26 +51,146 @@ pub struct PiecesSpawned {
     pub spawned: bool,
 }
 
-/// Ivory/cream piece with low roughness — specular highlights define the silhouette.
-pub fn white_piece_material() -> StandardMaterial {
-    StandardMaterial {
-        base_color: Color::srgb(0.92, 0.89, 0.82), // warm ivory, not pure white
-        perceptual_roughness: 0.25,
+/// Colour and surface of one side's pieces.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PieceSurface {
+    pub color: Color,
+    pub perceptual_roughness: f32,
+    pub reflectance: f32,
+}
+
+impl PieceSurface {
+    const fn new(color: Color, perceptual_roughness: f32, reflectance: f32) -> Self {
+        Self {
+            color,
+            perceptual_roughness,
+            reflectance,
+        }
+    }
+}
+
+/// Material parameters for one [`PieceFinish`]: a surface per side and the
+/// settings both sides share.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PieceMaterialPreset {
+    pub white: PieceSurface,
+    pub black: PieceSurface,
+    pub metallic: f32,
+    /// Fraction of the base colour the piece gives off as light.
+    pub emissive: f32,
+    /// Opacity; below 1 the pieces are alpha-blended.
+    pub alpha: f32,
+}
+
+impl PieceMaterialPreset {
+    /// Ivory and charcoal with low roughness — specular highlights define the
+    /// silhouette. Neither is pure white or black, so light still picks out
+    /// the edges; the black pieces are a touch glossier to keep them readable.
+    pub const POLISHED: Self = Self {
+        white: PieceSurface::new(Color::srgb(0.92, 0.89, 0.82), 0.25, 0.55),
+        black: PieceSurface::new(Color::srgb(0.10, 0.08, 0.07), 0.20, 0.50),
         metallic: 0.0,
-        reflectance: 0.55,
-        ..default()
+        emissive: 0.0,
+        alpha: 1.0,
+    };
+
+    pub fn for_finish(finish: PieceFinish) -> Self {
+        match finish {
+            PieceFinish::Polished => Self::POLISHED,
+            PieceFinish::Matte => Self {
+                white: PieceSurface::new(Color::srgb(0.90, 0.87, 0.80), 0.85, 0.3),
+                black: PieceSurface::new(Color::srgb(0.13, 0.11, 0.10), 0.85, 0.3),
+                ..Self::POLISHED
+            },
+            PieceFinish::Metallic => Self {
+                white: PieceSurface::new(Color::srgb(0.86, 0.86, 0.88), 0.3, 0.52),
+                black: PieceSurface::new(Color::srgb(0.24, 0.24, 0.27), 0.3, 0.52),
+                metallic: 1.0,
+                ..Self::POLISHED
+            },
+            PieceFinish::Glass => Self {
+                white: PieceSurface::new(Color::srgb(0.88, 0.94, 1.0), 0.05, 0.9),
+                black: PieceSurface::new(Color::srgb(0.12, 0.14, 0.18), 0.05, 0.9),
+                alpha: 0.55,
+                ..Self::POLISHED
+            },
+            PieceFinish::TempleOsNeon => Self {
+                white: PieceSurface::new(Color::srgb(1.0, 1.0, 0.33), 0.6, 0.2),
+                black: PieceSurface::new(Color::srgb(0.33, 1.0, 1.0), 0.6, 0.2),
+                emissive: 1.5,
+                ..Self::POLISHED
+            },
+        }
+    }
+
+    /// A new material for `color`'s pieces.
+    pub fn material(&self, color: PieceColor) -> StandardMaterial {
+        let mut material = StandardMaterial::default();
+        self.apply(&mut material, color);
+        material
     }
+
+    /// Overwrite `material` with this preset for `color`'s pieces.
+    pub fn apply(&self, material: &mut StandardMaterial, color: PieceColor) {
+        let surface = match color {
+            PieceColor::White => self.white,
+            PieceColor::Black => self.black,
+        };
+        material.base_color = surface.color.with_alpha(self.alpha);
+        material.alpha_mode = if self.alpha < 1.0 {
+            AlphaMode::Blend
+        } else {
+            AlphaMode::Opaque
+        };
+        material.perceptual_roughness = surface.perceptual_roughness;
+        material.metallic = self.metallic;
+        material.reflectance = surface.reflectance;
+        material.emissive = surface.color.to_linear() * self.emissive;
+    }
+}
+
+/// A white piece in the [`PieceMaterialPreset::POLISHED`] finish.
+pub fn white_piece_material() -> StandardMaterial {
+    PieceMaterialPreset::POLISHED.material(PieceColor::White)
 }
 
-/// Dark charcoal piece — not pure black so light still picks out the edges.
+/// A black piece in the [`PieceMaterialPreset::POLISHED`] finish.
 pub fn black_piece_material() -> StandardMaterial {
-    StandardMaterial {
-        base_color: Color::srgb(0.10, 0.08, 0.07), // very dark warm brown-black
-        perceptual_roughness: 0.20,
-        metallic: 0.0,
-        reflectance: 0.50,
-        ..default()
+    PieceMaterialPreset::POLISHED.material(PieceColor::Black)
+}
+
+/// Re-apply the chosen [`PieceFinish`] to every piece on the board when the
+/// setting changes. Each piece has its own material (see `create_pieces`),
+/// so they are updated in place; a selected piece's untinted material is
+/// updated too.
+pub fn apply_piece_finish_system(
+    settings: Res<GameSettings>,
+    pieces: Query<(&Piece, &Children, Option<&SelectionTint>)>,
+    visuals: Query<&MeshMaterial3d<StandardMaterial>, With<Piece3DVisual>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut last_finish: Local<Option<PieceFinish>>,
+) {
+    let finish = settings.piece_finish;
+    let changed = last_finish.is_some_and(|last| last != finish);
+    *last_finish = Some(finish);
+    if !changed {
+        return;
     }
+
+    let preset = PieceMaterialPreset::for_finish(finish);
+    // Captured pieces may be mid-fade; leave their alpha alone.
+    for (piece, children, tint) in pieces.iter().filter(|(p, ..)| p.is_on_board()) {
+        let handles = children
+            .iter()
+            .filter_map(|child| visuals.get(child).ok().map(|m| m.0.clone()))
+            .chain(tint.map(|tint| tint.original.clone()));
+        for handle in handles {
+            if let Some(mut material) = materials.get_mut(&handle) {
+                preset.apply(&mut material, piece.color);
+            }
+        }
+    }
+    info!("[PIECES] Piece finish changed to {}", finish.label());
 }
 
 /// Component marking a 3D visual element of a piece
@@ -83,6 +207,7 @@ pub struct Piece2DVisual;
 /// This pattern is cleaner, more maintainable, and easier to test than manual spawning.
 ///
 /// Reference: `reference/bevy/examples/ecs/` for data-driven entity spawning patterns
+#[allow(clippy::too_many_arguments)]
 pub fn create_pieces(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
@@ -92,6 +217,8 @@ pub fn create_pieces(
     mut pieces_spawned: ResMut<PiecesSpawned>,
     sprite_handles: Option<Res<PieceSpriteHandles>>,
     puzzle_board: Option<Res<crate::puzzle::PuzzleBoard>>,
+    save_restore: Option<Res<crate::game::save::PendingSaveRestore>>,
+    settings: Res<GameSettings>,
 ) {
     // Skip if already spawned
     if pieces_spawned.spawned {
@@ -118,6 +245,7 @@ pub fn create_pieces(
     // Use the documented constant offset to position pieces on the board surface
     // See PIECE_Y_OFFSET documentation for how to recalculate if models change
     let visual_offset = Vec3::new(0.0, PIECE_Y_OFFSET, 0.0);
+    let preset = PieceMaterialPreset::for_finish(settings.piece_finish);
 
     // Puzzle mode: spawn the position described by the FEN instead of the
     // standard starting layout.
@@ -127,6 +255,7 @@ pub fn create_pieces(
                 &mut commands,
                 &piece_meshes,
                 &mut materials,
+                &preset,
                 &pb.fen,
                 visual_offset,
                 &sprite_handles,
@@ -137,6 +266,25 @@ pub fn create_pieces(
         }
     }
 
+    // Restoring a save slot: same as puzzles, the board comes from its FEN.
+    if let Some(restore) = save_restore.as_ref() {
+        spawn_pieces_from_fen(
+            &mut commands,
+            &piece_meshes,
+            &mut materials,
+            &preset,
+            &restore.0.fen,
+            visual_offset,
+            &sprite_handles,
+        );
+        pieces_spawned.spawned = true;
+        info!(
+            "[PIECES] Spawned saved position '{}' from FEN",
+            restore.0.name
+        );
+        return;
+    }
+
     // Each piece will get its own unique material to prevent color bleeding
     // during capture animations. This ensures fade effects don't affect other pieces.
 
@@ -154,7 +302,7 @@ pub fn create_pieces(
 
     // Spawn white pieces (rank 0 in chess coordinates = rank 1 on board)
     for (file, &piece_type) in BACK_ROW.iter().enumerate() {
-        let piece_material = materials.add(white_piece_material());
+        let piece_material = materials.add(preset.material(PieceColor::White));
         spawn_piece_at(
             &mut commands,
             &piece_meshes,
@@ -169,7 +317,7 @@ pub fn create_pieces(
 
     // Spawn white pawns (rank 1 in chess coordinates = rank 2 on board)
     for file in 0..8 {
-        let piece_material = materials.add(white_piece_material());
+        let piece_material = materials.add(preset.material(PieceColor::White));
         spawn_piece_at(
             &mut commands,
             &piece_meshes,
@@ -184,7 +332,7 @@ pub fn create_pieces(
 
     // Spawn black pieces (rank 7 in chess coordinates = rank 8 on board)
     for (file, &piece_type) in BACK_ROW.iter().enumerate() {
-        let piece_material = materials.add(black_piece_material());
+        let piece_material = materials.add(preset.material(PieceColor::Black));
         spawn_piece_at(
             &mut commands,
             &piece_meshes,
@@ -199,7 +347,7 @@ pub fn create_pieces(
 
     // Spawn black pawns (rank 6 in chess coordinates = rank 7 on board)
     for file in 0..8 {
-        let piece_material = materials.add(black_piece_material());
+        let piece_material = materials.add(preset.material(PieceColor::Black));
         spawn_piece_at(
             &mut commands,
             &piece_meshes,
@@ -224,6 +372,7 @@ fn spawn_pieces_from_fen(
     commands: &mut Commands,
     meshes: &PieceMeshes,
     materials: &mut Assets<StandardMaterial>,
+    preset: &PieceMaterialPreset,
     fen: &str,
     visual_offset: Vec3,
     sprite_handles: &Option<Res<PieceSpriteHandles>>,
@@ -258,11 +407,7 @@ fn spawn_pieces_from_fen(
                 }
             };
             if file < 8 {
-                let material = if color == PieceColor::White {
-                    materials.add(white_piece_material())
-                } else {
-                    materials.add(black_piece_material())
-                };
+                let material = materials.add(preset.material(color));
                 spawn_piece_at(
                     commands,
                     meshes,
@@ -480,8 +625,8 @@ pub fn spawn_piece_at(
     sprite_handles: &Option<Res<PieceSpriteHandles>>,
 ) {
     let (file, rank) = position;
-    // World position: X = 7-file (mirrored so a-file is left from White camera), Y = board surface, Z = rank
-    let world_pos = Vec3::new(7.0 - file as f32, PIECE_ON_BOARD_Y, rank as f32);
+    // World position: X from file (see board_coords::file_world_x), Y = board surface, Z = rank
+    let world_pos = Vec3::new(file_world_x(file), PIECE_ON_BOARD_Y, rank as f32);
 
     // Reference assets are already centered - no offsets needed
     let offset = Vec3::ZERO;
@@ -607,6 +752,16 @@ fn knight_rotation(color: PieceColor) -> Quat {
     }
 }
 
+/// The rotation a piece of `piece_type` is spawned with, for putting one
+/// back on the board.
+pub fn resting_rotation(piece_type: PieceType, color: PieceColor) -> Quat {
+    if piece_type == PieceType::Knight {
+        knight_rotation(color)
+    } else {
+        piece_rotation(color)
+    }
+}
+
 /// Helper function to generate piece name for inspector
 ///
 /// # Arguments
@@ -677,6 +832,8 @@ pub fn spawn_king(
         .observe(on_piece_drag_end)
         .observe(on_piece_hover)
         .observe(on_piece_unhover)
+        .observe(on_annotation_press)
+        .observe(on_annotation_release)
         .with_children(|parent| {
             spawn_piece_visual!(parent, mesh, material, Vec3::ZERO);
             // Also append 2D visual
@@ -718,6 +875,8 @@ pub fn spawn_knight(
         .observe(on_piece_drag_end)
         .observe(on_piece_hover)
         .observe(on_piece_unhover)
+        .observe(on_annotation_press)
+        .observe(on_annotation_release)
         .with_children(|parent| {
             spawn_piece_visual!(parent, mesh, material, Vec3::ZERO);
             // Also append 2D visual
@@ -759,6 +918,8 @@ pub fn spawn_queen(
         .observe(on_piece_drag_end)
         .observe(on_piece_hover)
         .observe(on_piece_unhover)
+        .observe(on_annotation_press)
+        .observe(on_annotation_release)
         .with_children(|parent| {
             spawn_piece_visual!(parent, mesh, material, Vec3::ZERO);
             // Also append 2D visual
@@ -800,6 +961,8 @@ pub fn spawn_bishop(
         .observe(on_piece_drag_end)
         .observe(on_piece_hover)
         .observe(on_piece_unhover)
+        .observe(on_annotation_press)
+        .observe(on_annotation_release)
         .with_children(|parent| {
             spawn_piece_visual!(parent, mesh, material, Vec3::ZERO);
             // Also append 2D visual
@@ -841,6 +1004,8 @@ pub fn spawn_rook(
         .observe(on_piece_drag_end)
         .observe(on_piece_hover)
         .observe(on_piece_unhover)
+        .observe(on_annotation_press)
+        .observe(on_annotation_release)
         .with_children(|parent| {
             spawn_piece_visual!(parent, mesh, material, Vec3::ZERO);
             // Also append 2D visual
@@ -882,6 +1047,8 @@ pub fn spawn_pawn(
         .observe(on_piece_drag_end)
         .observe(on_piece_hover)
         .observe(on_piece_unhover)
+        .observe(on_annotation_press)
+        .observe(on_annotation_release)
         .with_children(|parent| {
             spawn_piece_visual!(parent, mesh, material, Vec3::ZERO);
             // Also append 2D visual
@@ -964,7 +1131,19 @@ impl Plugin for PiecePlugin {
         app.init_resource::<PiecesSpawned>();
         app.add_systems(Startup, (load_piece_meshes, init_piece_picking_assets));
         app.add_systems(Update, create_pieces.run_if(in_state(GameState::InGame)));
+        app.add_systems(
+            Update,
+            apply_piece_finish_system.run_if(in_state(GameState::InGame)),
+        );
         app.add_systems(OnExit(GameState::InGame), reset_pieces_spawned);
+        // Swap in procedural stand-ins if a piece mesh or material is lost mid-game.
+        app.init_resource::<super::fallback::FallbackPieceAssets>();
+        app.add_systems(
+            Update,
+            super::fallback::replace_missing_piece_assets.run_if(in_state(GameState::InGame).and(
+                on_message::<AssetEvent<Mesh>>.or(on_message::<AssetEvent<StandardMaterial>>),
+            )),
+        );
         // Apply the current view mode's visibility on game entry (idempotent),
         // then keep it applied whenever the mode changes or pieces (re)spawn.
         // `ViewMode` is the single source of truth, so this can never desync.
@@ -1038,3 +1217,33 @@ fn reset_pieces_spawned(mut pieces_spawned: ResMut<PiecesSpawned>) {
     pieces_spawned.spawned = false;
     info!("[PIECES] Reset spawn flag for next game");
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_presets_set_blend_and_glow() {
+        //! Glass pieces are alpha-blended, neon ones glow, and switching back clears both
+
+        let mut material = white_piece_material();
+        assert_eq!(material.alpha_mode, AlphaMode::Opaque);
+
+        PieceMaterialPreset::for_finish(PieceFinish::Glass).apply(&mut material, PieceColor::White);
+        assert_eq!(material.alpha_mode, AlphaMode::Blend);
+        assert!(material.base_color.alpha() < 1.0);
+
+        PieceMaterialPreset::for_finish(PieceFinish::TempleOsNeon)
+            .apply(&mut material, PieceColor::Black);
+        assert_eq!(material.alpha_mode, AlphaMode::Opaque);
+        assert!(material.emissive.green > 1.0);
+
+        PieceMaterialPreset::POLISHED.apply(&mut material, PieceColor::Black);
+        assert_eq!(
+            material.base_color,
+            PieceMaterialPreset::POLISHED.black.color
+        );
+        assert_eq!(material.perceptual_roughness, 0.20);
+        assert_eq!(material.emissive.green, 0.0);
+    }
+}
@@ -32,6 +32,11 @@
 //! - `reference/bevy/examples/3d/3d_shapes.rs` - Material creation and usage
 //! - `reference/bevy/examples/asset/hot_asset_reloading.rs` - Handle management
 
+use crate::rendering::effects::{
+    CAPTURE_HINT_ALPHA, CHECK_SQUARE_ALPHA, ENGINE_HINT_ALPHA, MOVE_HINT_ALPHA, PREMOVE_ALPHA,
+    SELECTED_SQUARE_ALPHA,
+};
+use crate::ui::styles::UiColors;
 use bevy::prelude::*;
 
 /// Represents a square on the chess board.
@@ -75,8 +80,19 @@ pub struct SquareMaterials {
     pub capture_hint_mesh: Handle<Mesh>,
     /// Material for capture-target hints (red/orange ring)
     pub capture_hint_matl: Handle<StandardMaterial>,
+    /// Material for the squares of the engine's suggested move (blue)
+    pub engine_hint_matl: Handle<StandardMaterial>,
+    /// Material for the squares of a queued premove (cyan)
+    pub premove_matl: Handle<StandardMaterial>,
+    /// Material for the square of a king in check, pulsed by
+    /// `pulse_check_highlight`
+    pub check_matl: Handle<StandardMaterial>,
     /// Shared mesh for last move highlights (prevent per-frame allocation)
     pub highlight_mesh: Handle<Mesh>,
+    /// One side of an outlined square highlight
+    pub outline_edge_mesh: Handle<Mesh>,
+    /// One corner of a corner-dots square highlight
+    pub corner_dot_mesh: Handle<Mesh>,
 }
 
 impl FromWorld for SquareMaterials {
@@ -103,19 +119,38 @@ impl FromWorld for SquareMaterials {
             black_color: materials.add(light_color), // Light squares
             white_color: materials.add(dark_color),  // Dark squares
             hover_matl: materials.add(StandardMaterial {
-                base_color: Color::srgba(0.18, 0.78, 0.35, 0.82), // Vivid green (Lichess-style move dots)
+                base_color: Color::srgb(0.18, 0.78, 0.35).with_alpha(MOVE_HINT_ALPHA), // Vivid green (Lichess-style move dots)
                 alpha_mode: AlphaMode::Blend,
                 unlit: true,
                 ..default()
             }),
             selected_border_matl: materials.add(StandardMaterial {
-                base_color: Color::srgba(0.95, 0.85, 0.1, 0.75), // Bright gold for selected square
+                base_color: Color::srgb(0.95, 0.85, 0.1).with_alpha(SELECTED_SQUARE_ALPHA), // Bright gold for selected square
                 alpha_mode: AlphaMode::Blend,
                 unlit: true,
                 ..default()
             }),
             capture_hint_matl: materials.add(StandardMaterial {
-                base_color: Color::srgba(0.90, 0.25, 0.08, 0.85),
+                base_color: Color::srgb(0.90, 0.25, 0.08).with_alpha(CAPTURE_HINT_ALPHA),
+                alpha_mode: AlphaMode::Blend,
+                unlit: true,
+                ..default()
+            }),
+            engine_hint_matl: materials.add(StandardMaterial {
+                base_color: Color::srgb(0.2, 0.5, 0.95).with_alpha(ENGINE_HINT_ALPHA),
+                alpha_mode: AlphaMode::Blend,
+                unlit: true,
+                ..default()
+            }),
+            premove_matl: materials.add(StandardMaterial {
+                base_color: Color::srgb(0.0, 0.78, 0.86).with_alpha(PREMOVE_ALPHA),
+                alpha_mode: AlphaMode::Blend,
+                unlit: true,
+                ..default()
+            }),
+            check_matl: materials.add(StandardMaterial {
+                base_color: UiColors::to_bevy(UiColors::CHECK_HIGHLIGHT)
+                    .with_alpha(CHECK_SQUARE_ALPHA),
                 alpha_mode: AlphaMode::Blend,
                 unlit: true,
                 ..default()
@@ -127,6 +162,10 @@ impl FromWorld for SquareMaterials {
             highlight_mesh: world
                 .resource_mut::<Assets<Mesh>>()
                 .add(Plane3d::default().mesh().size(0.92, 0.92)),
+            outline_edge_mesh: world
+                .resource_mut::<Assets<Mesh>>()
+                .add(Plane3d::default().mesh().size(0.92, 0.06)),
+            corner_dot_mesh: world.resource_mut::<Assets<Mesh>>().add(Circle::new(0.07)),
         }
     }
 }
@@ -8,6 +8,7 @@ use crate::core::GameState;
 use crate::game::camera_modes::{CameraViewMode, CinematicSequence};
 use crate::game::resources::{GameOverState, MoveHistory};
 use crate::game::view_mode::ViewMode;
+use crate::rendering::pieces::PieceColor;
 use crate::ui::menus::game_over_popup::GameOverPopupPlugin;
 use bevy::prelude::*;
 
@@ -90,15 +91,32 @@ fn reset_camera_on_exit(
 }
 
 /// Record game statistics when entering game over state
+///
+/// Games against the computer also count towards the record for its
+/// difficulty level and the player's colour. Games played at a target ELO
+/// rather than a level only count towards the totals.
 fn record_game_stats(
     game_over: Res<GameOverState>,
     move_history: Res<MoveHistory>,
     mut stats: ResMut<crate::core::GameStatistics>,
+    core_mode: Res<crate::core::GameMode>,
+    ai_config: Res<crate::game::ai::ChessAIResource>,
 ) {
     let winner = game_over.winner();
     let moves = move_history.len() as u32;
 
     stats.record_game(winner, moves);
+    if let (crate::core::GameMode::SinglePlayer, crate::game::ai::GameMode::VsAI { ai_color }) =
+        (*core_mode, ai_config.mode)
+    {
+        if ai_config.target_elo.is_none() {
+            let player_color = match ai_color {
+                PieceColor::White => PieceColor::Black,
+                PieceColor::Black => PieceColor::White,
+            };
+            stats.record_vs_ai(ai_config.difficulty.to_u8(), player_color, winner);
+        }
+    }
     info!(
         "[GAME_OVER] Game statistics recorded: winner={:?}, moves={}",
         winner, moves
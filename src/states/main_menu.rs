@@ -43,7 +43,9 @@ pub mod new_menu;
 #[path = "main_menu/screens.rs"]
 mod screens;
 
-use modals::{render_ai_setup_modal, render_controls_popup, render_pgn_input_modal};
+use modals::{
+    render_ai_setup_modal, render_ai_vs_ai_modal, render_controls_popup, render_pgn_input_modal,
+};
 pub use new_menu::NewMenuPanel;
 use new_menu::{
     menu_escape_system, orbit_camera_system, purge_stale_lights, render_new_style_panel,
@@ -66,6 +68,7 @@ impl Plugin for MainMenuPlugin {
             .init_resource::<music::MenuMusic>()
             .init_resource::<WalletBridgePoller>()
             .init_resource::<FontsLoaded>()
+            .init_resource::<crate::core::keybindings::KeyRebind>()
             .add_systems(
                 OnEnter(GameState::MainMenu),
                 (
@@ -86,6 +89,10 @@ impl Plugin for MainMenuPlugin {
                 )
                     .chain(),
             )
+            .add_systems(
+                OnExit(GameState::MainMenu),
+                crate::core::keybindings::cancel_key_rebind,
+            )
             .add_systems(
                 OnExit(GameState::MainMenu),
                 |mut spawned: ResMut<new_menu::MenuBgPiecesSpawned>,
@@ -161,7 +168,12 @@ impl Plugin for MainMenuPlugin {
                     // Ambient Immortal-Zugzwang replay on the full-size MenuBg board.
                     // Self-arms once `spawn_menu_bg_pieces` populates the animator.
                     board_animation::animate_ambient_board,
-                    menu_escape_system,
+                    // Shortcuts wait while the controls popup is rebinding a key.
+                    (
+                        menu_escape_system,
+                        crate::core::keybindings::capture_rebind_key,
+                    )
+                        .chain(),
                     // Menu playlist: fades between tracks, advances on end / skip.
                     music::drive_menu_music,
                 )
@@ -329,8 +341,16 @@ pub struct CompetitiveMenuState {
     pub lobby_filter: LobbyFilter,
     /// Whether the AI setup modal is currently open.
     pub show_ai_setup: bool,
+    /// Whether the computer-vs-computer setup modal is currently open.
+    pub show_ai_vs_ai_setup: bool,
+    /// Levels (1-8) for White and Black in a computer-vs-computer game.
+    pub ai_vs_ai_levels: (u8, u8),
     /// Selected AI difficulty level (1-8).
     pub ai_difficulty: u8,
+    /// Approximate ELO to play at instead of `ai_difficulty`, if chosen.
+    pub ai_target_elo: Option<u16>,
+    /// Whether the AI may play book moves; follows the level until changed.
+    pub ai_opening_book: bool,
     /// Selected player side (Black, Random, White).
     pub ai_side: AISide,
     /// Selected time control for AI games.
@@ -364,7 +384,11 @@ impl Default for CompetitiveMenuState {
         Self {
             lobby_filter: LobbyFilter::default(),
             show_ai_setup: false,
+            show_ai_vs_ai_setup: false,
+            ai_vs_ai_levels: (4, 4),
             ai_difficulty: 4,
+            ai_target_elo: None,
+            ai_opening_book: true,
             ai_side: AISide::default(),
             ai_time_control: crate::game::time_control::TimeControl::Blitz,
             show_spectator_popup: false,
@@ -1460,6 +1484,8 @@ fn render_website_menu(ctx: &egui::Context, ctx_menu: &mut MainMenuUIContext) {
     render_wallet_hud(ctx, ctx_menu);
 
     if ctx_menu.competitive_menu.show_ai_setup {
+        // The think-time slider edits a copy; see the controls popup below.
+        let mut settings = ctx_menu.settings.clone();
         render_ai_setup_modal(
             ctx,
             &mut ctx_menu.competitive_menu,
@@ -1467,6 +1493,19 @@ fn render_website_menu(ctx: &egui::Context, ctx_menu: &mut MainMenuUIContext) {
             &mut ctx_menu.core_mode,
             &mut ctx_menu.next_state,
             &mut ctx_menu.active_time_control,
+            &mut settings,
+        );
+        ctx_menu.settings.set_if_neq(settings);
+    }
+
+    if ctx_menu.competitive_menu.show_ai_vs_ai_setup {
+        render_ai_vs_ai_modal(
+            ctx,
+            &mut ctx_menu.competitive_menu,
+            &mut ctx_menu.ai_config,
+            &mut ctx_menu.core_mode,
+            &mut ctx_menu.next_state,
+            &mut ctx_menu.active_time_control,
         );
     }
 
@@ -1494,7 +1533,18 @@ fn render_website_menu(ctx: &egui::Context, ctx_menu: &mut MainMenuUIContext) {
     }
 
     if ctx_menu.competitive_menu.show_controls_popup {
-        render_controls_popup(ctx, &mut ctx_menu.competitive_menu);
+        // Edited on a copy so the settings only count as changed (and get
+        // saved) when a binding actually changes.
+        let mut key_bindings = ctx_menu.settings.key_bindings.clone();
+        render_controls_popup(
+            ctx,
+            &mut ctx_menu.competitive_menu,
+            &mut key_bindings,
+            &mut ctx_menu.key_rebind,
+        );
+        if key_bindings != ctx_menu.settings.key_bindings {
+            ctx_menu.settings.key_bindings = key_bindings;
+        }
     }
 
     if ctx_menu.competitive_menu.show_pgn_input {
@@ -1,12 +1,14 @@
 //! Modal popups owned by the main menu.
 //!
-//! Renders the two purely local-state popups reached from the website-style
+//! Renders the purely local-state popups reached from the website-style
 //! main menu: the AI setup modal (strength / time-control / side picker that
-//! immediately starts a Vs-Computer game) and the controls cheat-sheet opened
-//! from the navbar. Both take the bare resources they need so they can be
-//! called without the full `MainMenuUIContext`.
+//! immediately starts a Vs-Computer game), its computer-vs-computer
+//! counterpart, and the controls cheat-sheet opened from the navbar. Each
+//! takes the bare resources it needs so it can be called without the full
+//! `MainMenuUIContext`.
 
 use super::*;
+use crate::core::keybindings::{KeyBindings, KeyRebind};
 use crate::core::{GameMode as CoreGameMode, GameState};
 use crate::game::ai::GameMode;
 use crate::ui::styles::*;
@@ -25,12 +27,13 @@ pub(super) fn render_ai_setup_modal(
     core_mode: &mut CoreGameMode,
     next_state: &mut NextState<GameState>,
     active_tc: &mut crate::game::resources::active_time_control::ActiveTimeControl,
+    settings: &mut crate::core::GameSettings,
 ) {
     egui::Window::new("Game Setup")
         .collapsible(false)
         .resizable(false)
         .title_bar(false)
-        .fixed_size(egui::Vec2::new(380.0, 420.0))
+        .fixed_size(egui::Vec2::new(380.0, 470.0))
         .anchor(egui::Align2::CENTER_CENTER, egui::Vec2::ZERO)
         .frame(StyledPanel::popup())
         .show(ctx, |ui| {
@@ -83,6 +86,8 @@ pub(super) fn render_ai_setup_modal(
 
                         if response.clicked() {
                             competitive.ai_difficulty = lvl;
+                            competitive.ai_opening_book =
+                                crate::game::ai::resource::AIDifficulty::from_u8(lvl).plays_book();
                         }
                         ui.add_space(4.0);
                     }
@@ -101,6 +106,26 @@ pub(super) fn render_ai_setup_modal(
                 );
             });
 
+            // Optional target ELO, overriding the level for the built-in engine
+            ui.add_space(6.0);
+            ui.horizontal(|ui| {
+                use crate::game::ai::resource::{ELO_MAX, ELO_MIN, ELO_STEP};
+                let mut use_elo = competitive.ai_target_elo.is_some();
+                if ui.checkbox(&mut use_elo, "Target ELO").changed() {
+                    competitive.ai_target_elo = use_elo.then_some(1200);
+                }
+                if let Some(elo) = competitive.ai_target_elo.as_mut() {
+                    ui.add(egui::Slider::new(elo, ELO_MIN..=ELO_MAX).step_by(ELO_STEP as f64))
+                        .on_hover_text(
+                            "Approximate. Caps search depth and adds occasional mistakes; built-in engine only.",
+                        );
+                }
+            });
+            ui.checkbox(&mut competitive.ai_opening_book, "Opening book")
+                .on_hover_text(
+                    "Let the computer play known opening lines. Off by default at levels 1 and 2.",
+                );
+
             ui.add_space(16.0);
 
             // ── Time Control ─────────────────────────────────────────────────
@@ -165,6 +190,26 @@ pub(super) fn render_ai_setup_modal(
                 });
             });
 
+            ui.add_space(10.0);
+
+            // Upper bound on the engine's per-move budget; the strength level's
+            // own time only applies below it.
+            ui.horizontal(|ui| {
+                ui.label(
+                    egui::RichText::new("Max think time")
+                        .size(13.0)
+                        .color(UiColors::TEXT_POPUP_BODY),
+                );
+                ui.add(
+                    egui::Slider::new(&mut settings.max_ai_think_secs, 0.1..=10.0)
+                        .suffix(" s")
+                        .step_by(0.1),
+                )
+                .on_hover_text(
+                    "Longest the computer may think per move. Lower is faster but weaker.",
+                );
+            });
+
             ui.add_space(16.0);
 
             // Side selection (buttons are self-explanatory)
@@ -211,6 +256,8 @@ pub(super) fn render_ai_setup_modal(
                     );
                     ai_config.difficulty =
                         crate::game::ai::resource::AIDifficulty::from_u8(competitive.ai_difficulty);
+                    ai_config.target_elo = competitive.ai_target_elo;
+                    ai_config.opening_book = competitive.ai_opening_book;
                     ai_config.mode = GameMode::VsAI {
                         ai_color: match competitive.ai_side {
                             AISide::Black => crate::rendering::pieces::PieceColor::White,
@@ -235,12 +282,131 @@ pub(super) fn render_ai_setup_modal(
         });
 }
 
+/// Render the computer-vs-computer setup: a strength for each side, then
+/// start a game the AI plays on both sides.
+pub(super) fn render_ai_vs_ai_modal(
+    ctx: &egui::Context,
+    competitive: &mut CompetitiveMenuState,
+    ai_config: &mut crate::game::ai::resource::ChessAIResource,
+    core_mode: &mut CoreGameMode,
+    next_state: &mut NextState<GameState>,
+    active_tc: &mut crate::game::resources::active_time_control::ActiveTimeControl,
+) {
+    use crate::game::ai::resource::AIDifficulty;
+
+    egui::Window::new("Computer vs Computer")
+        .collapsible(false)
+        .resizable(false)
+        .title_bar(false)
+        .fixed_size(egui::Vec2::new(380.0, 300.0))
+        .anchor(egui::Align2::CENTER_CENTER, egui::Vec2::ZERO)
+        .frame(StyledPanel::popup())
+        .show(ctx, |ui| {
+            ui.horizontal(|ui| {
+                ui.label(TextStyle::popup_title("COMPUTER VS COMPUTER"));
+                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                    if ui
+                        .add(
+                            egui::Button::new(
+                                egui::RichText::new("X")
+                                    .size(12.0)
+                                    .color(UiColors::TEXT_POPUP_BODY),
+                            )
+                            .fill(egui::Color32::TRANSPARENT)
+                            .stroke(egui::Stroke::NONE),
+                        )
+                        .clicked()
+                    {
+                        competitive.show_ai_vs_ai_setup = false;
+                    }
+                });
+            });
+
+            ui.add_space(14.0);
+
+            let (white, black) = &mut competitive.ai_vs_ai_levels;
+            for (side, level) in [("White", white), ("Black", black)] {
+                ui.label(
+                    egui::RichText::new(side)
+                        .size(13.0)
+                        .color(UiColors::TEXT_POPUP_BODY),
+                );
+                ui.add_space(6.0);
+                ui.vertical_centered(|ui| {
+                    ui.horizontal(|ui| {
+                        for lvl in 1..=8 {
+                            let response = StyledButton::chip(
+                                ui,
+                                &format!("{}", lvl),
+                                *level == lvl,
+                                egui::Vec2::new(32.0, 32.0),
+                            )
+                            .on_hover_text(AIDifficulty::from_u8(lvl).tooltip());
+                            if response.clicked() {
+                                *level = lvl;
+                            }
+                            ui.add_space(4.0);
+                        }
+                    });
+                });
+                ui.label(
+                    egui::RichText::new(AIDifficulty::from_u8(*level).description())
+                        .size(11.0)
+                        .color(egui::Color32::from_rgb(150, 150, 150)),
+                );
+                ui.add_space(12.0);
+            }
+
+            ui.add_space(12.0);
+
+            ui.vertical_centered(|ui| {
+                let start_btn = egui::Button::new(
+                    egui::RichText::new("WATCH")
+                        .size(18.0)
+                        .color(egui::Color32::WHITE)
+                        .strong(),
+                )
+                .fill(egui::Color32::from_rgb(45, 100, 45))
+                .corner_radius(6.0)
+                .min_size(egui::Vec2::new(ui.available_width() * 0.8, 44.0));
+
+                if ui.add(start_btn).clicked() {
+                    let (white, black) = competitive.ai_vs_ai_levels;
+                    info!(
+                        "[MENU] Computer vs computer started: level {} vs level {}",
+                        white, black
+                    );
+                    ai_config.mode = GameMode::AIvsAI {
+                        white: AIDifficulty::from_u8(white),
+                        black: AIDifficulty::from_u8(black),
+                    };
+                    ai_config.target_elo = None;
+                    ai_config.opening_book = true;
+                    ai_config.engine = crate::game::ai::resource::AIEngine::XFChessEngine;
+                    *core_mode = CoreGameMode::SinglePlayer;
+                    active_tc.control = crate::game::time_control::TimeControl::Unlimited;
+                    active_tc.ai_game = true;
+                    next_state.set(GameState::InGame);
+                    competitive.show_ai_vs_ai_setup = false;
+                }
+            });
+        });
+}
+
 /// Render the controls / keybindings popup reached from the navbar.
-pub(super) fn render_controls_popup(ctx: &egui::Context, competitive: &mut CompetitiveMenuState) {
+///
+/// The mouse controls are fixed; every hotkey can be rebound here by
+/// clicking it and pressing the new key.
+pub(super) fn render_controls_popup(
+    ctx: &egui::Context,
+    competitive: &mut CompetitiveMenuState,
+    key_bindings: &mut KeyBindings,
+    rebind: &mut KeyRebind,
+) {
     egui::Window::new("Controls")
         .collapsible(false)
         .resizable(false)
-        .fixed_size(egui::Vec2::new(420.0, 380.0))
+        .fixed_size(egui::Vec2::new(420.0, 480.0))
         .anchor(egui::Align2::CENTER_CENTER, egui::Vec2::ZERO)
         .title_bar(false)
         .frame(StyledPanel::popup())
@@ -261,6 +427,7 @@ pub(super) fn render_controls_popup(ctx: &egui::Context, competitive: &mut Compe
                         .clicked()
                     {
                         competitive.show_controls_popup = false;
+                        rebind.action = None;
                     }
                 });
             });
@@ -268,21 +435,18 @@ pub(super) fn render_controls_popup(ctx: &egui::Context, competitive: &mut Compe
             ui.add_space(14.0);
             ui.add_space(6.0);
 
-            let rows: [(&str, &str); 8] = [
+            let rows: [(&str, &str); 5] = [
                 ("Left Click", "Select piece / confirm move"),
-                ("Right Click", "Deselect / cancel"),
+                ("Left Drag", "Drag a piece to its square"),
+                ("Right Drag", "Draw arrow / circle square"),
+                ("Right Drag (off board)", "Orbit camera"),
                 ("Mouse Wheel", "Zoom camera"),
-                ("Middle Drag", "Orbit camera"),
-                ("Esc", "Pause / back to menu"),
-                ("R", "Reset camera view"),
-                ("F", "Flip board"),
-                ("U", "Undo last move (local only)"),
             ];
 
             for (key, desc) in rows {
                 ui.horizontal(|ui| {
                     ui.add_sized(
-                        [130.0, 20.0],
+                        [150.0, 20.0],
                         egui::Label::new(
                             egui::RichText::new(key)
                                 .size(13.0)
@@ -298,6 +462,23 @@ pub(super) fn render_controls_popup(ctx: &egui::Context, competitive: &mut Compe
                 });
                 ui.add_space(4.0);
             }
+
+            ui.add_space(10.0);
+            egui::ScrollArea::vertical()
+                .max_height(260.0)
+                .show(ui, |ui| {
+                    crate::states::settings::key_bindings_editor(ui, key_bindings, rebind);
+                });
+            ui.add_space(6.0);
+            ui.horizontal(|ui| {
+                ui.label(TextStyle::popup_body(
+                    "Click a key, then press the new one; Esc cancels",
+                ));
+                if ui.button("Default Keys").clicked() {
+                    *key_bindings = KeyBindings::default();
+                    rebind.action = None;
+                }
+            });
         });
 }
 
@@ -329,7 +510,7 @@ pub(super) fn render_pgn_input_modal(
 
             ui.add_space(10.0);
             ui.label(
-                egui::RichText::new("Paste PGN below and click Load to replay the game.")
+                egui::RichText::new("Paste PGN below, or open a .pgn file, and click Load to replay the game.")
                     .size(11.0)
                     .color(egui::Color32::from_rgb(160, 170, 190)),
             );
@@ -372,12 +553,14 @@ pub(super) fn render_pgn_input_modal(
                 );
 
                 if load_btn.clicked() {
-                    match nimzovich_engine::parse_pgn(&competitive.pgn_input_text) {
-                        Ok(pgn) => {
-                            info!("[PGN] Loaded game: {} moves", pgn.moves.len());
+                    // Replayed once here so an illegal move is reported now,
+                    // not halfway through the replay.
+                    match crate::game::pgn::import_pgn(&competitive.pgn_input_text) {
+                        Ok(imported) => {
+                            info!("[PGN] Loaded game: {} moves", imported.records.len());
                             commands.insert_resource(
                                 crate::game::replay::ParsedPgnGameResource {
-                                    inner: pgn,
+                                    inner: imported.game,
                                     show_eval_graph: false,
                                     puzzle_mode: false,
                                     puzzle_revealed: false,
@@ -389,7 +572,34 @@ pub(super) fn render_pgn_input_modal(
                             competitive.pgn_input_error = None;
                         }
                         Err(e) => {
-                            competitive.pgn_input_error = Some(format!("{:?}", e));
+                            competitive.pgn_input_error = Some(e.to_string());
+                        }
+                    }
+                }
+
+                ui.add_space(8.0);
+                if ui
+                    .add(
+                        egui::Button::new(egui::RichText::new("Open File…").size(13.0))
+                            .fill(egui::Color32::from_rgba_unmultiplied(80, 80, 80, 200))
+                            .corner_radius(4.0)
+                            .min_size(egui::Vec2::new(100.0, 32.0)),
+                    )
+                    .clicked()
+                {
+                    let picked = rfd::FileDialog::new()
+                        .add_filter("PGN", &["pgn"])
+                        .pick_file();
+                    if let Some(path) = picked {
+                        match std::fs::read_to_string(&path) {
+                            Ok(text) => {
+                                competitive.pgn_input_text = text;
+                                competitive.pgn_input_error = None;
+                            }
+                            Err(e) => {
+                                competitive.pgn_input_error =
+                                    Some(format!("could not read {}: {}", path.display(), e));
+                            }
                         }
                     }
                 }
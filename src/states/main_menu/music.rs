@@ -7,14 +7,16 @@
 //!   the next one is spawned and fades in. Skipping fades the current track out
 //!   while the next fades in (a true crossfade).
 //! - Volume is driven per-voice in [`drive_menu_music`] toward a target, so mute
-//!   and crossfades are all just "move current volume toward target".
+//!   and crossfades are all just "move current volume toward target". The
+//!   result is scaled by the music volume in the settings.
 //! - Voices carry `DespawnOnExit(MainMenu)`, so music is strictly menu-only and
 //!   stops the instant a game starts.
 
 use bevy::audio::{AudioSinkPlayback, PlaybackMode, Volume};
 use bevy::prelude::*;
 
-use crate::core::{DespawnOnExit, GameState};
+use crate::core::{DespawnOnExit, GameSettings, GameState};
+use crate::presentation::audio::MusicChannel;
 
 /// Default background volume (linear). Quiet — it sits under the menu.
 const BASE_VOLUME: f32 = 0.30;
@@ -96,6 +98,7 @@ fn spawn_voice(commands: &mut Commands, music: &MenuMusic, index: usize) {
             outgoing: false,
             has_played: false,
         },
+        MusicChannel,
         DespawnOnExit(GameState::MainMenu),
         Name::new("MenuMusicVoice"),
     ));
@@ -116,6 +119,7 @@ pub fn start_menu_music(
 /// Drives fades, advances to the next track on natural end, and handles skips.
 pub fn drive_menu_music(
     time: Res<Time>,
+    settings: Res<GameSettings>,
     mut commands: Commands,
     mut music: ResMut<MenuMusic>,
     mut voices: Query<(Entity, &mut MenuMusicVoice, &mut AudioSink)>,
@@ -138,7 +142,9 @@ pub fn drive_menu_music(
         } else if voice.current > voice.target {
             voice.current = (voice.current - step).max(voice.target);
         }
-        sink.set_volume(Volume::Linear(voice.current));
+        sink.set_volume(Volume::Linear(
+            voice.current * settings.music_output_volume(),
+        ));
 
         if !sink.empty() {
             voice.has_played = true;
@@ -38,7 +38,10 @@ pub(super) fn menu_click_sound(
         return;
     };
     if ctx.is_pointer_over_area() {
-        commands.spawn(bevy::audio::AudioPlayer::new(sounds.menu_click.clone()));
+        commands.spawn((
+            bevy::audio::AudioPlayer::new(sounds.menu_click.clone()),
+            crate::presentation::audio::SfxChannel,
+        ));
     }
 }
 
@@ -76,6 +79,7 @@ pub enum NewMenuPanel {
     HowToPlay,
     Settings,
     Profile,
+    Statistics,
 }
 
 impl NewMenuPanel {
@@ -91,6 +95,7 @@ impl NewMenuPanel {
             Self::HowToPlay => 7,
             Self::Settings => 8,
             Self::Profile => 9,
+            Self::Statistics => 10,
         }
     }
 }
@@ -330,6 +335,7 @@ pub fn spawn_menu_bg_lights(
             ..default()
         },
         Transform::from_xyz(3.5, 20.0, 3.5),
+        crate::rendering::graphics_quality::ShadowCastingLight,
         MenuBg,
         DespawnOnExit(GameState::MainMenu),
         Name::new("MenuBg-OverheadLight"),
@@ -413,13 +419,18 @@ pub fn orbit_camera_system(
 }
 
 /// Handle keyboard shortcuts on the main menu.
-/// H → Guide, G → Settings, L → toggle Learn focus mode, ESC → back-navigate / exit.
+/// H → Help, G → Settings, L → toggle Learn focus mode, ESC → back-navigate / exit.
 pub fn menu_escape_system(
     keyboard: Res<ButtonInput<KeyCode>>,
     mut panel: ResMut<NewMenuPanel>,
     mut exit_confirm: ResMut<MenuExitConfirm>,
     mut focus_mode: ResMut<MenuFocusMode>,
+    rebind: Res<crate::core::keybindings::KeyRebind>,
 ) {
+    // The key is being bound to a shortcut, not used as one.
+    if rebind.action.is_some() {
+        return;
+    }
     if keyboard.just_pressed(KeyCode::KeyL) {
         focus_mode.active = !focus_mode.active;
         return;
@@ -562,6 +573,7 @@ pub fn render_new_style_panel(ctx: &egui::Context, cx: &mut MainMenuUIContext) {
                 NewMenuPanel::HowToPlay => render_how_to_play_panel(ui, cx),
                 NewMenuPanel::Settings => render_settings_panel(ui, cx),
                 NewMenuPanel::Profile => render_profile_panel(ui, cx),
+                NewMenuPanel::Statistics => render_statistics_panel(ui, cx),
                 NewMenuPanel::SolanaMultiplayer => {}
             }
 
@@ -919,6 +931,61 @@ fn render_main_panel(ui: &mut egui::Ui, cx: &mut MainMenuUIContext) {
     }
     ui.add_space(SP);
 
+    if item_tip(
+        ui,
+        "Watch Computer vs Computer",
+        "Let the engine play itself, with a difficulty for each side.",
+        W,
+    ) {
+        play_click(&mut cx.commands, snd);
+        cx.competitive_menu.show_ai_vs_ai_setup = true;
+    }
+    ui.add_space(SP);
+
+    if item_tip(
+        ui,
+        "Saved Games",
+        "Continue an offline game from a save slot, auto-save or quick-save, or start one from a pasted FEN.",
+        W,
+    ) {
+        play_click(&mut cx.commands, snd);
+        cx.save_browser.show();
+    }
+    ui.add_space(SP);
+
+    if item_tip(
+        ui,
+        "Studies",
+        "Open a saved position with its arrows, circled squares and notes.",
+        W,
+    ) {
+        play_click(&mut cx.commands, snd);
+        cx.study_browser.show();
+    }
+    ui.add_space(SP);
+
+    if item_tip(
+        ui,
+        "Load PGN",
+        "Replay a game from a .pgn file or pasted PGN, stepping through it move by move.",
+        W,
+    ) {
+        play_click(&mut cx.commands, snd);
+        cx.competitive_menu.show_pgn_input = true;
+    }
+    ui.add_space(SP);
+
+    if item_expandable_tip(
+        ui,
+        "Statistics",
+        "Your results over every finished game: wins, draws, game lengths and your record against the computer.",
+        W,
+    ) {
+        play_click(&mut cx.commands, snd);
+        *cx.new_menu_panel = NewMenuPanel::Statistics;
+    }
+    ui.add_space(SP);
+
     if item_expandable_tip(
         ui,
         "Play Online",
@@ -947,6 +1014,17 @@ fn render_main_panel(ui: &mut egui::Ui, cx: &mut MainMenuUIContext) {
         ui.add_space(SP);
     }
 
+    if item_expandable_tip(
+        ui,
+        "Help & Rules",
+        "Controls, how each piece moves, and what every game mode is (H).",
+        W,
+    ) {
+        play_click(&mut cx.commands, snd);
+        *cx.new_menu_panel = NewMenuPanel::HowToPlay;
+    }
+    ui.add_space(SP);
+
     if item_tip(
         ui,
         "XFChess.com",
@@ -1347,7 +1425,7 @@ fn render_how_to_play_panel(ui: &mut egui::Ui, cx: &mut MainMenuUIContext) {
         }
         ui.add_space(6.0);
         ui.label(
-            egui::RichText::new("How to Play")
+            egui::RichText::new("Help & Rules")
                 .size(16.5)
                 .color(egui::Color32::WHITE)
                 .strong(),
@@ -1355,30 +1433,8 @@ fn render_how_to_play_panel(ui: &mut egui::Ui, cx: &mut MainMenuUIContext) {
     });
     ui.add_space(10.0);
 
-    egui::ScrollArea::vertical()
-        .max_height(420.0)
-        .auto_shrink([false, false])
-        .show(ui, |ui| {
-            ui.set_width(280.0);
-
-            section(ui, "XFChess Modes");
-            bullet(
-                ui,
-                "Play vs Computer — Choose difficulty 1–8 and time control.",
-            );
-            bullet(ui, "Play Online — Host or join a P2P lobby with a friend.");
-            bullet(ui, "Tournaments — Compete in Swiss-format brackets.");
-            bullet(
-                ui,
-                "Solana Multiplayer — Wager SOL on the outcome. Connect a wallet to unlock.",
-            );
-
-            ui.add_space(8.0);
-            section(ui, "Controls");
-            bullet(ui, "Left-click — Select and move pieces.");
-            bullet(ui, "K — Toggle between 3D board menu and classic menu.");
-            bullet(ui, "Escape — Return to menu from a game.");
-        });
+    ui.set_width(320.0);
+    crate::ui::menus::help::render_help(ui, &cx.settings.key_bindings, 420.0);
 }
 
 fn render_settings_panel(ui: &mut egui::Ui, cx: &mut MainMenuUIContext) {
@@ -1420,6 +1476,394 @@ fn render_settings_panel(ui: &mut egui::Ui, cx: &mut MainMenuUIContext) {
         cx.competitive_menu.show_controls_popup = true;
     }
 
+    ui.add_space(14.0);
+    section(ui, "Graphics");
+
+    let shadows_label = if cx.settings.shadows {
+        "Shadows: On"
+    } else {
+        "Shadows: Off"
+    };
+    if item_tip(
+        ui,
+        shadows_label,
+        "Board and piece shadows. Turning them off is the biggest single performance win.",
+        W,
+    ) {
+        play_click(&mut cx.commands, snd);
+        cx.settings.shadows = !cx.settings.shadows;
+    }
+
+    if item_tip(
+        ui,
+        &format!("Display: {}", cx.settings.display_mode.label()),
+        "Windowed, borderless or fullscreen. F11 also switches between windowed and fullscreen.",
+        W,
+    ) {
+        play_click(&mut cx.commands, snd);
+        let modes = crate::core::DisplayMode::ALL;
+        let next = modes
+            .iter()
+            .position(|mode| *mode == cx.settings.display_mode)
+            .map_or(0, |i| (i + 1) % modes.len());
+        cx.settings.display_mode = modes[next];
+    }
+
+    if cx.settings.display_mode == crate::core::DisplayMode::Windowed {
+        use crate::core::window_config::RESOLUTIONS;
+        let [width, height] = cx.settings.window_resolution;
+        if item_tip(
+            ui,
+            &format!("Window Size: {width} × {height}"),
+            "Size of the game window. Sizes larger than your screen fall back to 1280 × 720.",
+            W,
+        ) {
+            play_click(&mut cx.commands, snd);
+            cx.settings.window_resolution = RESOLUTIONS
+                .into_iter()
+                .find(|[w, h]| (*w, *h) > (width, height))
+                .unwrap_or(RESOLUTIONS[0]);
+        }
+    }
+
+    const UI_SCALE_STEPS: [f32; 5] = [0.75, 1.0, 1.25, 1.5, 2.0];
+    if item_tip(
+        ui,
+        &format!("Interface Scale: {:.2}×", cx.settings.ui_scale),
+        "Enlarge menus and panels for high-DPI or 4K screens.",
+        W,
+    ) {
+        play_click(&mut cx.commands, snd);
+        cx.settings.ui_scale = UI_SCALE_STEPS
+            .into_iter()
+            .find(|step| *step > cx.settings.ui_scale + 0.01)
+            .unwrap_or(UI_SCALE_STEPS[0]);
+    }
+
+    ui.add_space(14.0);
+    section(ui, "Audio");
+
+    const VOLUME_STEPS: [f32; 5] = [0.0, 0.25, 0.5, 0.75, 1.0];
+    // Borrowed without change detection so drawing the items doesn't mark
+    // the settings changed every frame; a click marks them below.
+    let mut stepped = false;
+    let settings = cx.settings.bypass_change_detection();
+    for (label, tip, volume) in [
+        (
+            "Master Volume",
+            "Loudness of everything the game plays.",
+            &mut settings.master_volume,
+        ),
+        (
+            "Sound Effects",
+            "Moves, captures and clicks, as a share of the master volume.",
+            &mut settings.sfx_volume,
+        ),
+        (
+            "Music",
+            "Menu music, as a share of the master volume.",
+            &mut settings.music_volume,
+        ),
+    ] {
+        if item_tip(ui, &format!("{label}: {:.0}%", *volume * 100.0), tip, W) {
+            play_click(&mut cx.commands, snd);
+            *volume = VOLUME_STEPS
+                .into_iter()
+                .find(|step| *step > *volume + 0.01)
+                .unwrap_or(VOLUME_STEPS[0]);
+            stepped = true;
+        }
+    }
+    if stepped {
+        cx.settings.set_changed();
+    }
+
+    ui.add_space(14.0);
+    section(ui, "Game");
+
+    let summary_label = if cx.settings.show_game_start_summary {
+        "Start Summary: On"
+    } else {
+        "Start Summary: Off"
+    };
+    if item_tip(
+        ui,
+        summary_label,
+        "Briefly show the mode, difficulty, time control and your colour when a game starts.",
+        W,
+    ) {
+        play_click(&mut cx.commands, snd);
+        cx.settings.show_game_start_summary = !cx.settings.show_game_start_summary;
+    }
+
+    let clock_label = match cx.settings.clock_format {
+        crate::core::ClockFormat::MinutesSeconds => "Clock: m:ss",
+        crate::core::ClockFormat::TenthsWhenLow => "Clock: Tenths Under 10s",
+    };
+    if item_tip(
+        ui,
+        clock_label,
+        "Show tenths of a second once a clock drops below ten seconds.",
+        W,
+    ) {
+        play_click(&mut cx.commands, snd);
+        cx.settings.clock_format = match cx.settings.clock_format {
+            crate::core::ClockFormat::MinutesSeconds => crate::core::ClockFormat::TenthsWhenLow,
+            crate::core::ClockFormat::TenthsWhenLow => crate::core::ClockFormat::MinutesSeconds,
+        };
+    }
+
+    if item_tip(
+        ui,
+        &format!("Increment: {}", cx.settings.increment_mode.label()),
+        "Fischer adds the full increment each move; delay modes never let the clock grow. Offline games only.",
+        W,
+    ) {
+        play_click(&mut cx.commands, snd);
+        cx.settings.increment_mode = match cx.settings.increment_mode {
+            crate::core::IncrementMode::Fischer => crate::core::IncrementMode::Bronstein,
+            crate::core::IncrementMode::Bronstein => crate::core::IncrementMode::SimpleDelay,
+            crate::core::IncrementMode::SimpleDelay => crate::core::IncrementMode::Fischer,
+        };
+    }
+
+    if item_tip(
+        ui,
+        &format!("Auto-Promote: {}", cx.settings.auto_promotion.label()),
+        "Promote without the dialog in bullet and blitz. Hold Alt as the pawn lands to pick another piece.",
+        W,
+    ) {
+        play_click(&mut cx.commands, snd);
+        cx.settings.auto_promotion = cx.settings.auto_promotion.next();
+    }
+
+    let realistic_label = if cx.settings.realistic_promotion {
+        "Realistic Promotion: On"
+    } else {
+        "Realistic Promotion: Off"
+    };
+    if item_tip(
+        ui,
+        realistic_label,
+        "A pawn may only promote to a piece its side has lost, as with a physical set. The engine plays normal rules.",
+        W,
+    ) {
+        play_click(&mut cx.commands, snd);
+        cx.settings.realistic_promotion = !cx.settings.realistic_promotion;
+    }
+
+    let blunder_label = if cx.settings.confirm_blunders {
+        "Blunder Check: On"
+    } else {
+        "Blunder Check: Off"
+    };
+    if item_tip(
+        ui,
+        blunder_label,
+        "Ask \"are you sure?\" before a move a quick engine check says loses material. Offline games only.",
+        W,
+    ) {
+        play_click(&mut cx.commands, snd);
+        cx.settings.confirm_blunders = !cx.settings.confirm_blunders;
+    }
+
+    let trade_label = if cx.settings.confirm_losing_captures {
+        "Trade Check: On"
+    } else {
+        "Trade Check: Off"
+    };
+    if item_tip(
+        ui,
+        trade_label,
+        "Ask before a capture that loses material once the other side recaptures. Offline games only.",
+        W,
+    ) {
+        play_click(&mut cx.commands, snd);
+        cx.settings.confirm_losing_captures = !cx.settings.confirm_losing_captures;
+    }
+
+    let touch_label = if cx.settings.touch_move {
+        "Touch-Move: On"
+    } else {
+        "Touch-Move: Off"
+    };
+    if item_tip(
+        ui,
+        touch_label,
+        "Once you pick up a piece that has a legal move, you have to move it, as over the board.",
+        W,
+    ) {
+        play_click(&mut cx.commands, snd);
+        cx.settings.touch_move = !cx.settings.touch_move;
+    }
+
+    let mirror_label = if cx.settings.mirror_files {
+        "Files: h-a"
+    } else {
+        "Files: a-h"
+    };
+    if item_tip(
+        ui,
+        mirror_label,
+        "Mirror the file order left to right; ranks stay as they are. Applies from the next game.",
+        W,
+    ) {
+        play_click(&mut cx.commands, snd);
+        cx.settings.mirror_files = !cx.settings.mirror_files;
+    }
+
+    let effects_label = if cx.settings.sound_effects {
+        "Sound Effects: On"
+    } else {
+        "Sound Effects: Off"
+    };
+    if item_tip(
+        ui,
+        effects_label,
+        "Play move, capture, check and click sounds. Off silences them; music keeps playing.",
+        W,
+    ) {
+        play_click(&mut cx.commands, snd);
+        cx.settings.sound_effects = !cx.settings.sound_effects;
+    }
+
+    let pan_label = if cx.settings.spatial_move_sounds {
+        "Sound Panning: On"
+    } else {
+        "Sound Panning: Off"
+    };
+    if item_tip(
+        ui,
+        pan_label,
+        "Play move and capture sounds from the side of the board they happen on. Off plays them in mono.",
+        W,
+    ) {
+        play_click(&mut cx.commands, snd);
+        cx.settings.spatial_move_sounds = !cx.settings.spatial_move_sounds;
+    }
+
+    if crate::core::SoundTheme::PICKABLE.len() > 1
+        && item_tip(
+            ui,
+            &format!("Sounds: {}", cx.settings.sound_theme.label()),
+            "Switch the move, capture and check sounds. Retro matches the TempleOS view.",
+            W,
+        )
+    {
+        play_click(&mut cx.commands, snd);
+        cx.settings.sound_theme = cx.settings.sound_theme.next();
+    }
+
+    if item_tip(
+        ui,
+        &format!("Highlights: {}", cx.settings.highlight_style.label()),
+        "How the selected and last-move squares are marked. Corner dots keep the pieces clear.",
+        W,
+    ) {
+        play_click(&mut cx.commands, snd);
+        cx.settings.highlight_style = cx.settings.highlight_style.next();
+    }
+
+    if item_tip(
+        ui,
+        &format!("Captures: {}", cx.settings.capture_animation.label()),
+        "What happens to a taken piece: it sinks away, slides to a tray beside the board, or appears there at once.",
+        W,
+    ) {
+        play_click(&mut cx.commands, snd);
+        cx.settings.capture_animation = cx.settings.capture_animation.next();
+    }
+
+    if item_tip(
+        ui,
+        &format!("Starting View: {}", cx.settings.starting_view.label()),
+        "Where the camera looks from when a game starts. Angled and Top-Down follow the colour you play.",
+        W,
+    ) {
+        play_click(&mut cx.commands, snd);
+        cx.settings.starting_view = cx.settings.starting_view.next();
+    }
+
+    if item_tip(
+        ui,
+        &format!("Highlight Strength: {:.0}%", cx.settings.highlight_opacity * 100.0),
+        "How strongly move hints and highlights are tinted. Lower values let the pieces show through.",
+        W,
+    ) {
+        play_click(&mut cx.commands, snd);
+        // Step down in quarters, wrapping back to full strength.
+        cx.settings.highlight_opacity = if cx.settings.highlight_opacity <= 0.25 + f32::EPSILON {
+            1.0
+        } else {
+            (cx.settings.highlight_opacity - 0.25).max(0.25)
+        };
+    }
+
+    let preview_label = if cx.settings.preview_moves_on_hover {
+        "Hover Preview: On"
+    } else {
+        "Hover Preview: Off"
+    };
+    if item_tip(
+        ui,
+        preview_label,
+        "Show where a piece can go while the pointer is over it, before clicking it.",
+        W,
+    ) {
+        play_click(&mut cx.commands, snd);
+        cx.settings.preview_moves_on_hover = !cx.settings.preview_moves_on_hover;
+    }
+
+    let variety_label = if cx.settings.ai_opening_variety {
+        "AI Openings: Varied"
+    } else {
+        "AI Openings: Best Move"
+    };
+    if item_tip(
+        ui,
+        variety_label,
+        "Varied picks a different book opening each game; Best Move always plays the engine's top choice.",
+        W,
+    ) {
+        play_click(&mut cx.commands, snd);
+        cx.settings.ai_opening_variety = !cx.settings.ai_opening_variety;
+    }
+
+    let resign_label = if cx.settings.ai_resigns {
+        "AI Resigns: On"
+    } else {
+        "AI Resigns: Off"
+    };
+    if item_tip(
+        ui,
+        resign_label,
+        "Let the computer resign once its position stays hopeless for several moves.",
+        W,
+    ) {
+        play_click(&mut cx.commands, snd);
+        cx.settings.ai_resigns = !cx.settings.ai_resigns;
+    }
+
+    let max_threads = crate::core::max_search_threads();
+    if max_threads > 1
+        && item_tip(
+            ui,
+            &format!("Search Threads: {}", cx.settings.search_threads),
+            "Threads the engine thinks with at the difficulty levels. More use more of your CPU and play stronger.",
+            W,
+        )
+    {
+        play_click(&mut cx.commands, snd);
+        cx.settings.search_threads = if cx.settings.search_threads >= max_threads {
+            1
+        } else {
+            (cx.settings.search_threads * 2).min(max_threads)
+        };
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    render_settings_transfer(ui, cx, W);
+
     if cx.player_identity.username.is_some() {
         ui.add_space(14.0);
         section(ui, "Account");
@@ -1457,6 +1901,82 @@ fn render_settings_panel(ui: &mut egui::Ui, cx: &mut MainMenuUIContext) {
     }
 }
 
+/// Export and import of settings and profile records, with the outcome of
+/// the last attempt shown underneath.
+#[cfg(not(target_arch = "wasm32"))]
+fn render_settings_transfer(ui: &mut egui::Ui, cx: &mut MainMenuUIContext, width: f32) {
+    use crate::core::settings_transfer::{export_to_file, import_from_file, EXPORT_FILE_NAME};
+
+    let status_id = egui::Id::new("settings_transfer_status");
+    let snd = cx.menu_sounds.as_deref();
+
+    ui.add_space(14.0);
+    section(ui, "Transfer");
+
+    if item_tip(
+        ui,
+        "Export Settings",
+        "Save your settings and records to a file you can copy to another machine.",
+        width,
+    ) {
+        play_click(&mut cx.commands, snd);
+        let picked = rfd::FileDialog::new()
+            .set_file_name(EXPORT_FILE_NAME)
+            .add_filter("JSON", &["json"])
+            .save_file();
+        if let Some(path) = picked {
+            let status = match export_to_file(&path, &cx.settings, &cx.stats) {
+                Ok(()) => format!("Exported to {}", path.display()),
+                Err(e) => {
+                    warn!("[SETTINGS] Export to {:?} failed: {}", path, e);
+                    format!("Export failed: {}", e)
+                }
+            };
+            ui.data_mut(|d| d.insert_temp(status_id, status));
+        }
+    }
+
+    if item_tip(
+        ui,
+        "Import Settings",
+        "Load settings and records from an exported file, replacing the current ones.",
+        width,
+    ) {
+        play_click(&mut cx.commands, snd);
+        let picked = rfd::FileDialog::new()
+            .add_filter("JSON", &["json"])
+            .pick_file();
+        if let Some(path) = picked {
+            let status = match import_from_file(&path) {
+                Ok(imported) => {
+                    *cx.settings = imported.settings;
+                    if let Some(stats) = imported.statistics {
+                        *cx.stats = stats;
+                    }
+                    if imported.repaired.is_empty() {
+                        "Settings imported".to_string()
+                    } else {
+                        format!("Imported; reset: {}", imported.repaired.join(", "))
+                    }
+                }
+                Err(e) => {
+                    warn!("[SETTINGS] Import from {:?} failed: {}", path, e);
+                    format!("Import failed: {}", e)
+                }
+            };
+            ui.data_mut(|d| d.insert_temp(status_id, status));
+        }
+    }
+
+    if let Some(status) = ui.data(|d| d.get_temp::<String>(status_id)) {
+        ui.label(
+            egui::RichText::new(status)
+                .size(10.1)
+                .color(egui::Color32::from_rgba_unmultiplied(180, 180, 200, 160)),
+        );
+    }
+}
+
 fn section(ui: &mut egui::Ui, title: &str) {
     ui.label(
         egui::RichText::new(title)
@@ -1467,22 +1987,6 @@ fn section(ui: &mut egui::Ui, title: &str) {
     ui.add_space(3.0);
 }
 
-fn bullet(ui: &mut egui::Ui, text: &str) {
-    ui.horizontal_wrapped(|ui| {
-        ui.label(
-            egui::RichText::new("·")
-                .size(9.8)
-                .color(egui::Color32::from_rgb(100, 140, 200)),
-        );
-        ui.label(
-            egui::RichText::new(text)
-                .size(9.4)
-                .color(egui::Color32::from_rgb(200, 200, 210)),
-        );
-    });
-    ui.add_space(2.0);
-}
-
 fn render_solana_connect_panel(ui: &mut egui::Ui, cx: &mut MainMenuUIContext) {
     // Back button + header
     ui.horizontal(|ui| {
@@ -1989,6 +2493,227 @@ fn render_profile_panel(ui: &mut egui::Ui, cx: &mut MainMenuUIContext) {
             *cx.new_menu_panel = NewMenuPanel::PlayOnline;
         }
     }
+
+    render_vs_ai_records(ui, &cx.stats);
+}
+
+/// Offline record against each computer level the player has faced, split by
+/// the colour they played. Wallet-independent, so shown either way.
+fn render_vs_ai_records(ui: &mut egui::Ui, stats: &crate::core::GameStatistics) {
+    use crate::game::ai::resource::AIDifficulty;
+    use crate::rendering::pieces::PieceColor;
+
+    let levels: Vec<u8> = (1..=crate::core::AI_LEVELS as u8)
+        .filter(|&level| {
+            stats.vs_ai_record(level, PieceColor::White).games()
+                + stats.vs_ai_record(level, PieceColor::Black).games()
+                > 0
+        })
+        .collect();
+    if levels.is_empty() {
+        return;
+    }
+
+    ui.add_space(16.0);
+    ui.label(
+        egui::RichText::new("VS COMPUTER")
+            .size(9.0)
+            .color(egui::Color32::from_rgb(120, 140, 170)),
+    );
+    ui.add_space(4.0);
+    for level in levels {
+        ui.label(
+            egui::RichText::new(AIDifficulty::from_u8(level).description())
+                .size(10.5)
+                .color(egui::Color32::from_rgb(200, 210, 230)),
+        );
+        ui.label(
+            egui::RichText::new(format!(
+                "as White {}   as Black {}",
+                stats.vs_ai_record(level, PieceColor::White),
+                stats.vs_ai_record(level, PieceColor::Black)
+            ))
+            .size(9.5)
+            .color(egui::Color32::from_rgb(160, 170, 190))
+            .monospace(),
+        );
+        ui.add_space(2.0);
+    }
+}
+
+/// Totals from [`GameStatistics`](crate::core::GameStatistics), which are
+/// recorded at every game over and saved by `stats_persistence`, with a
+/// reset behind a confirmation.
+fn render_statistics_panel(ui: &mut egui::Ui, cx: &mut MainMenuUIContext) {
+    const W: f32 = 280.0;
+
+    ui.horizontal(|ui| {
+        if ui
+            .add(
+                egui::Button::new(
+                    egui::RichText::new("‹ Back")
+                        .size(10.0)
+                        .color(egui::Color32::from_rgba_unmultiplied(180, 180, 200, 160)),
+                )
+                .fill(egui::Color32::TRANSPARENT)
+                .stroke(egui::Stroke::NONE),
+            )
+            .clicked()
+        {
+            play_click(&mut cx.commands, cx.menu_sounds.as_deref());
+            *cx.new_menu_panel = NewMenuPanel::Main;
+        }
+        ui.add_space(6.0);
+        ui.label(
+            egui::RichText::new("Statistics")
+                .size(16.5)
+                .color(egui::Color32::WHITE)
+                .strong(),
+        );
+    });
+    ui.add_space(10.0);
+
+    let stats = cx.stats.clone();
+    if stats.games_played == 0 {
+        ui.label(
+            egui::RichText::new("No finished games yet. Results appear here after each game.")
+                .size(10.5)
+                .color(egui::Color32::from_rgb(160, 170, 190)),
+        );
+        return;
+    }
+
+    egui::ScrollArea::vertical()
+        .max_height(420.0)
+        .auto_shrink([false, false])
+        .show(ui, |ui| {
+            ui.set_width(W);
+
+            let vs_ai = stats.vs_ai_total();
+            let mut rows = vec![("Games played", stats.games_played.to_string())];
+            if vs_ai.games() > 0 {
+                rows.extend([
+                    ("Wins", vs_ai.wins.to_string()),
+                    ("Losses", vs_ai.losses.to_string()),
+                    ("Draws", vs_ai.draws.to_string()),
+                    ("Win rate", format!("{:.0}%", vs_ai.win_rate())),
+                ]);
+            }
+            rows.extend([
+                (
+                    "White wins",
+                    format!("{} ({:.0}%)", stats.white_wins, stats.win_rate_white()),
+                ),
+                (
+                    "Black wins",
+                    format!("{} ({:.0}%)", stats.black_wins, stats.win_rate_black()),
+                ),
+                ("All draws", stats.draws.to_string()),
+                (
+                    "Average length",
+                    format!("{:.1} moves", stats.average_moves()),
+                ),
+                ("Longest game", format!("{} moves", stats.longest_game)),
+                ("Shortest game", format!("{} moves", stats.shortest_game)),
+            ]);
+
+            section(ui, "RESULTS");
+            egui::Grid::new("xfc_statistics_grid")
+                .num_columns(2)
+                .spacing([24.0, 4.0])
+                .show(ui, |ui| {
+                    for (label, value) in rows {
+                        ui.label(
+                            egui::RichText::new(label)
+                                .size(10.5)
+                                .color(egui::Color32::from_rgb(160, 170, 190)),
+                        );
+                        ui.label(
+                            egui::RichText::new(value)
+                                .size(10.5)
+                                .color(egui::Color32::from_rgb(200, 210, 230))
+                                .monospace(),
+                        );
+                        ui.end_row();
+                    }
+                });
+            if vs_ai.games() > 0 {
+                ui.add_space(4.0);
+                ui.label(
+                    egui::RichText::new(
+                        "Wins, losses and win rate are from your games against the computer.",
+                    )
+                    .size(9.0)
+                    .color(egui::Color32::from_rgb(120, 130, 150)),
+                );
+            }
+
+            render_vs_ai_records(ui, &stats);
+        });
+
+    ui.add_space(12.0);
+    let confirm_id = egui::Id::new("xfc_reset_statistics_confirm");
+    let confirming = ui.ctx().data(|d| d.get_temp(confirm_id).unwrap_or(false));
+    if !confirming {
+        if ui
+            .add_sized(
+                [W, 30.0],
+                egui::Button::new(
+                    egui::RichText::new("Reset Statistics")
+                        .size(10.5)
+                        .color(egui::Color32::from_rgb(230, 160, 160)),
+                )
+                .fill(egui::Color32::from_rgba_unmultiplied(120, 40, 40, 60))
+                .corner_radius(6.0),
+            )
+            .clicked()
+        {
+            play_click(&mut cx.commands, cx.menu_sounds.as_deref());
+            ui.ctx().data_mut(|d| d.insert_temp(confirm_id, true));
+        }
+        return;
+    }
+
+    ui.label(
+        egui::RichText::new("Erase every recorded game? This cannot be undone.")
+            .size(10.5)
+            .color(egui::Color32::WHITE),
+    );
+    ui.add_space(6.0);
+    ui.horizontal(|ui| {
+        if ui
+            .add_sized(
+                [110.0, 30.0],
+                egui::Button::new(
+                    egui::RichText::new("Reset")
+                        .size(11.0)
+                        .color(egui::Color32::WHITE)
+                        .strong(),
+                )
+                .fill(egui::Color32::from_rgb(160, 50, 50))
+                .corner_radius(4.0),
+            )
+            .clicked()
+        {
+            play_click(&mut cx.commands, cx.menu_sounds.as_deref());
+            *cx.stats = crate::core::GameStatistics::default();
+            ui.ctx().data_mut(|d| d.insert_temp(confirm_id, false));
+            info!("[STATS] Statistics reset from the menu");
+        }
+        ui.add_space(12.0);
+        if ui
+            .add_sized(
+                [110.0, 30.0],
+                egui::Button::new(egui::RichText::new("Cancel").size(11.0))
+                    .fill(egui::Color32::from_rgba_unmultiplied(70, 70, 70, 220))
+                    .corner_radius(4.0),
+            )
+            .clicked()
+        {
+            play_click(&mut cx.commands, cx.menu_sounds.as_deref());
+            ui.ctx().data_mut(|d| d.insert_temp(confirm_id, false));
+        }
+    });
 }
 
 /// Full-screen Solana splash: pure black background, two logos bottom-right.
@@ -1,9 +1,11 @@
 //! Pause menu plugin
 //!
 //! Displayed when pressing ESC during gameplay.
-//! Allows resuming, accessing settings, or returning to main menu.
+//! Allows resuming, reading the help, or returning to main menu.
 
-use crate::core::{GameState, PreviousState};
+use crate::core::keybindings::key_label;
+use crate::core::{GameAction, GameSettings, GameState, PreviousState};
+use crate::game::view_mode::ViewMode;
 use crate::ui::styles::*;
 use bevy::prelude::*;
 use bevy_egui::{egui, EguiContexts, EguiPrimaryContextPass};
@@ -13,7 +15,11 @@ pub struct PausePlugin;
 
 impl Plugin for PausePlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(OnEnter(GameState::Paused), setup_pause_camera)
+        app.init_resource::<PauseMenu>()
+            .add_systems(
+                OnEnter(GameState::Paused),
+                (setup_pause_camera, reset_pause_menu),
+            )
             .add_systems(
                 EguiPrimaryContextPass,
                 pause_ui_wrapper.run_if(in_state(GameState::Paused)),
@@ -25,13 +31,34 @@ impl Plugin for PausePlugin {
     }
 }
 
+/// Which view of the pause menu is showing.
+#[derive(Resource, Default)]
+struct PauseMenu {
+    show_help: bool,
+}
+
+/// Open the pause menu on its main view, not where it was last left.
+fn reset_pause_menu(mut menu: ResMut<PauseMenu>) {
+    *menu = PauseMenu::default();
+}
+
 /// Wrapper for pause_ui that handles Result
 fn pause_ui_wrapper(
     contexts: EguiContexts,
     next_state: ResMut<NextState<GameState>>,
     previous_state: ResMut<PreviousState>,
+    view_mode: ResMut<ViewMode>,
+    settings: Res<GameSettings>,
+    menu: ResMut<PauseMenu>,
 ) {
-    let _ = pause_ui(contexts, next_state, previous_state);
+    let _ = pause_ui(
+        contexts,
+        next_state,
+        previous_state,
+        view_mode,
+        settings,
+        menu,
+    );
 }
 
 /// Marker component for pause camera
@@ -90,13 +117,17 @@ fn setup_pause_camera(
     info!("[PAUSE] Camera setup complete");
 }
 
-/// Handle ESC key - return to main menu from pause
+/// Handle the leave-game key (ESC by default) - return to main menu from pause
 fn handle_pause_input(
     keyboard: Res<ButtonInput<KeyCode>>,
+    settings: Res<GameSettings>,
     mut next_state: ResMut<NextState<GameState>>,
 ) {
-    if keyboard.just_pressed(KeyCode::Escape) {
-        info!("[PAUSE] ESC pressed, returning to main menu");
+    if settings
+        .key_bindings
+        .just_pressed(&keyboard, GameAction::LeaveGame)
+    {
+        info!("[PAUSE] Leave key pressed, returning to main menu");
         next_state.set(GameState::MainMenu);
     }
 }
@@ -106,9 +137,34 @@ fn pause_ui(
     mut contexts: EguiContexts,
     mut next_state: ResMut<NextState<GameState>>,
     _previous_state: ResMut<PreviousState>,
+    mut view_mode: ResMut<ViewMode>,
+    settings: Res<GameSettings>,
+    mut menu: ResMut<PauseMenu>,
 ) -> Result<(), bevy::ecs::query::QuerySingleError> {
     let ctx = contexts.ctx_mut()?;
 
+    if menu.show_help {
+        egui::CentralPanel::default()
+            .frame(StyledPanel::overlay())
+            .show(ctx, |ui| {
+                ui.vertical_centered(|ui| {
+                    Layout::small_space(ui);
+                    ui.heading(TextStyle::heading("Help & Rules", TextSize::MD));
+                    Layout::small_space(ui);
+                    if ModernButton::secondary(ui, "Back").clicked() {
+                        menu.show_help = false;
+                    }
+                });
+                Layout::small_space(ui);
+                let height = ui.available_height();
+                ui.vertical_centered(|ui| {
+                    ui.set_max_width(640.0);
+                    crate::ui::menus::help::render_help(ui, &settings.key_bindings, height);
+                });
+            });
+        return Ok(());
+    }
+
     // Semi-transparent overlay
     egui::CentralPanel::default()
         .frame(StyledPanel::overlay())
@@ -128,6 +184,24 @@ fn pause_ui(
 
                 Layout::item_space(ui);
 
+                // TempleOS only: back to the standard board. Only the view
+                // changes; "Resume Game" goes back to the game, so the
+                // OnEnter(InGame) setup isn't run a second time.
+                if view_mode.is_templeos() {
+                    if ModernButton::secondary(ui, "Standard View").clicked() {
+                        info!("[PAUSE] Leaving TempleOS view");
+                        *view_mode = ViewMode::Standard3D;
+                    }
+
+                    Layout::item_space(ui);
+                }
+
+                if ModernButton::secondary(ui, "Help & Rules").clicked() {
+                    menu.show_help = true;
+                }
+
+                Layout::item_space(ui);
+
                 // Main Menu
                 if ModernButton::secondary(ui, "Main Menu").clicked() {
                     info!("[PAUSE] Returning to main menu");
@@ -136,7 +210,10 @@ fn pause_ui(
 
                 Layout::section_space(ui);
 
-                ui.label(TextStyle::caption("Press ESC to return to main menu"));
+                ui.label(TextStyle::caption(format!(
+                    "Press {} to return to main menu",
+                    key_label(settings.key_bindings.key(GameAction::LeaveGame))
+                )));
             });
         });
 
@@ -5,9 +5,31 @@
 //! - Graphics quality
 //! - Board theme
 //! - Game preferences
+//!
+//! "Restore Defaults" resets every setting after a confirmation; the usual
+//! change detection then re-applies them and writes them to disk.
+//!
+//! In the keyboard shortcuts list, clicking an action's key waits for the
+//! next key press and binds it (Escape cancels). Actions sharing a key are
+//! listed as conflicts underneath.
 
-use crate::core::{GameSettings, GameState, GraphicsQuality, PreviousState};
+use crate::core::keybindings::{
+    cancel_key_rebind, capture_rebind_key, key_label, GameAction, KeyBindings, KeyRebind,
+};
+use crate::core::window_config::RESOLUTIONS;
+use crate::core::{
+    AutoPromotion, BoardTheme, CameraZoomSettings, CaptureAnimation, ClockFormat, DisplayMode,
+    GameSettings, GameState, GraphicsQuality, HighlightStyle, IncrementMode, MsaaLevel,
+    PieceFinish, PreviousState, SoundTheme, StartingView,
+};
+use crate::game::components::{EFFECT_ANIMATION_SECS_MAX, EFFECT_ANIMATION_SECS_MIN};
+use crate::game::resources::ANIMATION_SCALE_MAX;
+use crate::rendering::effects::HIGHLIGHT_OPACITY_MIN;
+use crate::rendering::graphics_quality::{
+    BLOOM_INTENSITY_MAX, FOG_DENSITY_MAX, FOG_DENSITY_MIN, RENDER_SCALE_MIN,
+};
 use crate::ui::styles::*;
+use crate::ui::ui_scale::{UI_SCALE_MAX, UI_SCALE_MIN};
 use bevy::prelude::*;
 use bevy_egui::{egui, EguiContexts, EguiPrimaryContextPass};
 
@@ -16,14 +38,18 @@ pub struct SettingsPlugin;
 
 impl Plugin for SettingsPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(OnEnter(GameState::Settings), setup_settings_camera)
+        app.init_resource::<KeyRebind>()
+            .add_systems(OnEnter(GameState::Settings), setup_settings_camera)
+            .add_systems(OnExit(GameState::Settings), cancel_key_rebind)
             .add_systems(
                 EguiPrimaryContextPass,
                 settings_ui_wrapper.run_if(in_state(GameState::Settings)),
             )
             .add_systems(
                 Update,
-                handle_settings_escape.run_if(in_state(GameState::Settings)),
+                (handle_settings_escape, capture_rebind_key)
+                    .chain()
+                    .run_if(in_state(GameState::Settings)),
             );
     }
 }
@@ -34,9 +60,18 @@ fn settings_ui_wrapper(
     next_state: ResMut<NextState<GameState>>,
     previous_state: Res<PreviousState>,
     settings: ResMut<GameSettings>,
+    mut rebind: ResMut<KeyRebind>,
+    mut confirm_restore: Local<bool>,
 ) {
     info!("[SETTINGS] UI wrapper called!");
-    if let Err(e) = settings_ui(contexts, next_state, previous_state, settings) {
+    if let Err(e) = settings_ui(
+        contexts,
+        next_state,
+        previous_state,
+        settings,
+        &mut rebind,
+        &mut confirm_restore,
+    ) {
         error!("[SETTINGS] UI rendering failed: {:?}", e);
     } else {
         info!("[SETTINGS] UI rendered successfully!");
@@ -102,9 +137,14 @@ fn settings_ui(
     mut contexts: EguiContexts,
     mut next_state: ResMut<NextState<GameState>>,
     previous_state: Res<PreviousState>,
-    mut settings: ResMut<GameSettings>,
+    mut stored: ResMut<GameSettings>,
+    rebind: &mut KeyRebind,
+    confirm_restore: &mut bool,
 ) -> Result<(), bevy::ecs::query::QuerySingleError> {
     let ctx = contexts.ctx_mut()?;
+    // The widgets take `&mut` every frame; edit a copy so the resource is
+    // only marked changed (and saved, and re-applied) when a value moves.
+    let mut settings = stored.clone();
 
     egui::CentralPanel::default()
         .frame(egui::Frame {
@@ -125,26 +165,157 @@ fn settings_ui(
                     Layout::item_space(ui);
 
                     ui.horizontal(|ui| {
-                        ui.radio_value(&mut settings.graphics_quality, GraphicsQuality::Low, "Low");
-                        ui.radio_value(
-                            &mut settings.graphics_quality,
-                            GraphicsQuality::Medium,
-                            "Medium",
-                        );
-                        ui.radio_value(
-                            &mut settings.graphics_quality,
-                            GraphicsQuality::High,
-                            "High",
-                        );
-                        ui.radio_value(
-                            &mut settings.graphics_quality,
-                            GraphicsQuality::Ultra,
-                            "Ultra",
-                        );
+                        for (quality, label) in [
+                            (GraphicsQuality::Low, "Low"),
+                            (GraphicsQuality::Medium, "Medium"),
+                            (GraphicsQuality::High, "High"),
+                            (GraphicsQuality::Ultra, "Ultra"),
+                        ] {
+                            // A preset also resets anti-aliasing, bloom and fog to
+                            // its own levels.
+                            if ui
+                                .radio(settings.graphics_quality == quality, label)
+                                .clicked()
+                            {
+                                settings.select_graphics_quality(quality);
+                            }
+                        }
                     });
 
                     Layout::small_space(ui);
                     ui.label(TextStyle::caption(settings.graphics_quality.description()));
+
+                    Layout::item_space(ui);
+                    ui.horizontal(|ui| {
+                        ui.label(TextStyle::body("Display"));
+                        for mode in DisplayMode::ALL {
+                            ui.radio_value(&mut settings.display_mode, mode, mode.label());
+                        }
+                    });
+                    ui.add_enabled_ui(settings.display_mode == DisplayMode::Windowed, |ui| {
+                        let [width, height] = settings.window_resolution;
+                        egui::ComboBox::from_label("Window size")
+                            .selected_text(format!("{width} × {height}"))
+                            .show_ui(ui, |ui| {
+                                for resolution in RESOLUTIONS {
+                                    ui.selectable_value(
+                                        &mut settings.window_resolution,
+                                        resolution,
+                                        format!("{} × {}", resolution[0], resolution[1]),
+                                    );
+                                }
+                            });
+                    });
+                    ui.label(TextStyle::caption(
+                        "F11 also switches between windowed and fullscreen",
+                    ));
+
+                    Layout::item_space(ui);
+                    ui.horizontal(|ui| {
+                        ui.label(TextStyle::body("Anti-aliasing"));
+                        for level in [MsaaLevel::Off, MsaaLevel::X2, MsaaLevel::X4] {
+                            ui.radio_value(&mut settings.msaa, level, level.label());
+                        }
+                    });
+                    ui.label(TextStyle::body("Render scale"));
+                    ui.add(
+                        egui::Slider::new(&mut settings.render_scale, RENDER_SCALE_MIN..=1.0)
+                            .step_by(0.05)
+                            .suffix("×"),
+                    );
+                    ui.label(TextStyle::caption(
+                        "Below 1 draws the board at a lower resolution for speed",
+                    ));
+
+                    Layout::item_space(ui);
+                    ui.checkbox(&mut settings.shadows, "Shadows");
+
+                    Layout::item_space(ui);
+                    ui.checkbox(&mut settings.bloom, "Bloom");
+                    ui.add_enabled(
+                        settings.bloom,
+                        egui::Slider::new(&mut settings.bloom_intensity, 0.0..=BLOOM_INTENSITY_MAX)
+                            .step_by(0.01)
+                            .text("Intensity"),
+                    );
+                    ui.checkbox(&mut settings.fog, "Distance fog (3D board)");
+                    ui.add_enabled(
+                        settings.fog,
+                        egui::Slider::new(
+                            &mut settings.fog_density,
+                            FOG_DENSITY_MIN..=FOG_DENSITY_MAX,
+                        )
+                        .step_by(0.005)
+                        .text("Density"),
+                    );
+
+                    Layout::item_space(ui);
+                    ui.label(TextStyle::body("Interface scale"));
+                    ui.add(
+                        egui::Slider::new(&mut settings.ui_scale, UI_SCALE_MIN..=UI_SCALE_MAX)
+                            .step_by(0.05)
+                            .suffix("×"),
+                    );
+
+                    Layout::item_space(ui);
+                    ui.label(TextStyle::body("Camera zoom (3D)"));
+                    let zoom = &mut settings.camera_zoom;
+                    ui.add(
+                        egui::Slider::new(&mut zoom.min, CameraZoomSettings::RANGE).text("closest"),
+                    );
+                    ui.add(
+                        egui::Slider::new(&mut zoom.max, CameraZoomSettings::RANGE)
+                            .text("farthest"),
+                    );
+                    ui.add(
+                        egui::Slider::new(&mut zoom.initial, CameraZoomSettings::RANGE)
+                            .text("starting height"),
+                    );
+                    if zoom.max < zoom.min {
+                        zoom.max = zoom.min;
+                    }
+                    ui.label(TextStyle::caption(
+                        "Starting height applies from the next game or camera reset (N)",
+                    ));
+                    ui.horizontal(|ui| {
+                        ui.label(TextStyle::body("Starting view"));
+                        for view in [
+                            StartingView::Angled,
+                            StartingView::WhiteSide,
+                            StartingView::BlackSide,
+                            StartingView::TopDown,
+                        ] {
+                            ui.radio_value(&mut settings.starting_view, view, view.label());
+                        }
+                    });
+                    ui.horizontal(|ui| {
+                        ui.label(TextStyle::body("Board theme (3D)"));
+                        egui::ComboBox::from_id_salt("board_theme_3d")
+                            .selected_text(settings.board_theme_3d.label())
+                            .show_ui(ui, |ui| {
+                                for theme in BoardTheme::ALL {
+                                    ui.selectable_value(
+                                        &mut settings.board_theme_3d,
+                                        theme,
+                                        theme.label(),
+                                    );
+                                }
+                            });
+                    });
+                    ui.horizontal(|ui| {
+                        ui.label(TextStyle::body("Piece material (3D)"));
+                        egui::ComboBox::from_id_salt("piece_finish")
+                            .selected_text(settings.piece_finish.label())
+                            .show_ui(ui, |ui| {
+                                for finish in PieceFinish::ALL {
+                                    ui.selectable_value(
+                                        &mut settings.piece_finish,
+                                        finish,
+                                        finish.label(),
+                                    );
+                                }
+                            });
+                    });
                 });
 
                 Layout::item_space(ui);
@@ -155,7 +326,73 @@ fn settings_ui(
                     Layout::item_space(ui);
 
                     ui.checkbox(&mut settings.show_hints, "Show move hints");
+                    ui.add_enabled(
+                        settings.show_hints,
+                        egui::Checkbox::new(
+                            &mut settings.preview_moves_on_hover,
+                            "Preview moves on hover",
+                        ),
+                    );
                     ui.checkbox(&mut settings.highlight_last_move, "Highlight last move");
+                    ui.checkbox(
+                        &mut settings.mirror_files,
+                        "Mirror files (h–a left to right, next game)",
+                    );
+                    ui.checkbox(&mut settings.show_coordinates, "Show board coordinates");
+                    ui.checkbox(
+                        &mut settings.confirm_blunders,
+                        "Ask before playing a blunder (offline)",
+                    );
+                    ui.checkbox(
+                        &mut settings.confirm_losing_captures,
+                        "Ask before a capture that loses material (offline)",
+                    );
+                    ui.checkbox(
+                        &mut settings.touch_move,
+                        "Touch-move (a touched piece must be moved)",
+                    );
+                    ui.horizontal(|ui| {
+                        ui.label(TextStyle::body("Clock"));
+                        for format in [ClockFormat::MinutesSeconds, ClockFormat::TenthsWhenLow] {
+                            ui.radio_value(&mut settings.clock_format, format, format.label());
+                        }
+                    });
+                    ui.horizontal(|ui| {
+                        ui.label(TextStyle::body("Increment"));
+                        for mode in [
+                            IncrementMode::Fischer,
+                            IncrementMode::Bronstein,
+                            IncrementMode::SimpleDelay,
+                        ] {
+                            ui.radio_value(&mut settings.increment_mode, mode, mode.label());
+                        }
+                    });
+                    ui.label(TextStyle::caption(
+                        "Offline games only; online clocks use Fischer",
+                    ));
+                    ui.horizontal(|ui| {
+                        ui.label(TextStyle::body("Auto-promote"));
+                        for choice in [
+                            AutoPromotion::Off,
+                            AutoPromotion::Queen,
+                            AutoPromotion::Rook,
+                            AutoPromotion::Bishop,
+                            AutoPromotion::Knight,
+                        ] {
+                            ui.radio_value(&mut settings.auto_promotion, choice, choice.label());
+                        }
+                    });
+                    ui.label(TextStyle::caption(
+                        "Bullet and blitz only; hold Alt as the pawn lands to choose",
+                    ));
+                    ui.checkbox(
+                        &mut settings.realistic_promotion,
+                        "Realistic promotion (only to pieces already lost)",
+                    );
+                    ui.checkbox(
+                        &mut settings.show_game_start_summary,
+                        "Show game settings summary at start",
+                    );
                     ui.checkbox(
                         &mut settings.use_vps_relay,
                         "Use VPS relay for P2P (reliable NAT traversal)",
@@ -163,12 +400,165 @@ fn settings_ui(
 
                     Layout::item_space(ui);
 
+                    ui.label(TextStyle::body("Drag lift height"));
+                    ui.add(egui::Slider::new(&mut settings.drag_lift_height, 0.0..=1.5));
+
+                    ui.label(TextStyle::body("Highlight opacity"));
+                    ui.add(egui::Slider::new(
+                        &mut settings.highlight_opacity,
+                        HIGHLIGHT_OPACITY_MIN..=1.0,
+                    ));
+                    ui.horizontal(|ui| {
+                        ui.label(TextStyle::body("Highlight style"));
+                        for style in [
+                            HighlightStyle::Solid,
+                            HighlightStyle::Outline,
+                            HighlightStyle::CornerDots,
+                        ] {
+                            ui.radio_value(&mut settings.highlight_style, style, style.label());
+                        }
+                    });
+                    ui.horizontal(|ui| {
+                        ui.label(TextStyle::body("Captured pieces"));
+                        for animation in [
+                            CaptureAnimation::Fade,
+                            CaptureAnimation::Slide,
+                            CaptureAnimation::Instant,
+                        ] {
+                            ui.radio_value(
+                                &mut settings.capture_animation,
+                                animation,
+                                animation.label(),
+                            );
+                        }
+                    });
+                    ui.label(TextStyle::body("Animation speed"));
+                    ui.add(
+                        egui::Slider::new(&mut settings.animation_scale, 0.0..=ANIMATION_SCALE_MAX)
+                            .step_by(0.25)
+                            .custom_formatter(|scale, _| match scale {
+                                s if s <= 0.0 => "Instant".to_string(),
+                                s if s == 1.0 => "Normal".to_string(),
+                                s if s >= ANIMATION_SCALE_MAX as f64 => "Slow".to_string(),
+                                s => format!("{:.2}×", s),
+                            }),
+                    );
+                    ui.label(TextStyle::caption(
+                        "Length of moves, captures and promotions; instant skips them",
+                    ));
+                    ui.label(TextStyle::body("Capture and promotion animation"));
+                    ui.add(
+                        egui::Slider::new(
+                            &mut settings.effect_animation_secs,
+                            EFFECT_ANIMATION_SECS_MIN..=EFFECT_ANIMATION_SECS_MAX,
+                        )
+                        .step_by(0.05)
+                        .suffix(" s"),
+                    );
+
+                    Layout::item_space(ui);
+
                     ui.label(TextStyle::body("Master Volume"));
                     ui.add(egui::Slider::new(&mut settings.master_volume, 0.0..=1.0));
+                    ui.add(
+                        egui::Slider::new(&mut settings.sfx_volume, 0.0..=1.0)
+                            .text("Sound effects"),
+                    );
+                    ui.add(egui::Slider::new(&mut settings.music_volume, 0.0..=1.0).text("Music"));
+                    ui.checkbox(&mut settings.sound_effects, "Play sound effects");
+                    ui.label(TextStyle::caption(
+                        "Effects and music play at their share of the master volume",
+                    ));
+                    ui.checkbox(
+                        &mut settings.spatial_move_sounds,
+                        "Pan move sounds towards their square",
+                    );
+                    if SoundTheme::PICKABLE.len() > 1 {
+                        ui.horizontal(|ui| {
+                            ui.label(TextStyle::body("Sound theme"));
+                            for &theme in SoundTheme::PICKABLE {
+                                ui.radio_value(&mut settings.sound_theme, theme, theme.label());
+                            }
+                        });
+                    }
+
+                    Layout::item_space(ui);
+
+                    ui.label(TextStyle::body("Auto-save interval"));
+                    ui.add(
+                        egui::Slider::new(&mut settings.autosave_interval_secs, 0..=600)
+                            .suffix(" s"),
+                    );
+                    ui.label(TextStyle::caption("0 turns auto-save off"));
+
+                    Layout::item_space(ui);
+
+                    ui.checkbox(&mut settings.ai_opening_variety, "Vary AI openings");
+                    ui.checkbox(&mut settings.ai_resigns, "AI resigns lost positions");
+                    ui.add_enabled_ui(settings.ai_resigns, |ui| {
+                        ui.add(
+                            egui::Slider::new(&mut settings.ai_resign_threshold_cp, 300..=3000)
+                                .text("threshold")
+                                .suffix(" cp"),
+                        );
+                        ui.add(
+                            egui::Slider::new(&mut settings.ai_resign_streak, 1..=10)
+                                .text("moves in a row"),
+                        );
+                    });
+                    let max_threads = crate::core::max_search_threads();
+                    if max_threads > 1 {
+                        ui.add(
+                            egui::Slider::new(&mut settings.search_threads, 1..=max_threads)
+                                .text("search threads"),
+                        );
+                        ui.label(TextStyle::caption(
+                            "More threads make Hard stronger; target ELO always uses one",
+                        ));
+                    }
                 });
 
                 Layout::section_space(ui);
 
+                // Keyboard shortcuts
+                StyledPanel::card().show(ui, |ui| {
+                    ui.heading(TextStyle::heading("Keyboard Shortcuts", TextSize::MD));
+                    Layout::item_space(ui);
+
+                    key_bindings_editor(ui, &mut settings.key_bindings, rebind);
+                    ui.label(TextStyle::caption(
+                        "Click a key, then press the new one; Esc cancels",
+                    ));
+                    if StyledButton::secondary(ui, "Default Keys").clicked() {
+                        settings.key_bindings = KeyBindings::default();
+                        rebind.action = None;
+                    }
+                });
+
+                Layout::section_space(ui);
+
+                // Restore defaults, behind a confirmation
+                if *confirm_restore {
+                    StyledPanel::card().show(ui, |ui| {
+                        ui.label(TextStyle::body("Reset every setting to its default?"));
+                        Layout::item_space(ui);
+                        ui.horizontal(|ui| {
+                            if StyledButton::danger(ui, "Restore").clicked() {
+                                restore_defaults(&mut settings);
+                                *confirm_restore = false;
+                            }
+                            if StyledButton::secondary(ui, "Cancel").clicked() {
+                                *confirm_restore = false;
+                            }
+                        });
+                    });
+                    Layout::item_space(ui);
+                } else if StyledButton::secondary(ui, "Restore Defaults").clicked() {
+                    *confirm_restore = true;
+                }
+
+                Layout::item_space(ui);
+
                 // Back button
                 if StyledButton::secondary(ui, "Back").clicked() {
                     next_state.set(previous_state.state);
@@ -176,15 +566,71 @@ fn settings_ui(
             });
         });
 
+    stored.set_if_neq(settings);
     Ok(())
 }
 
-/// Handle escape key to return to previous state
+/// Every action with a button showing its key; clicking one waits for the
+/// next key press (see [`capture_rebind_key`]). Actions sharing a key are
+/// listed underneath as conflicts.
+pub(crate) fn key_bindings_editor(
+    ui: &mut egui::Ui,
+    key_bindings: &mut KeyBindings,
+    rebind: &mut KeyRebind,
+) {
+    egui::Grid::new("key_bindings")
+        .num_columns(2)
+        .show(ui, |ui| {
+            for action in GameAction::ALL {
+                ui.label(TextStyle::body(action.label()));
+                let waiting = rebind.action == Some(action);
+                let key = if waiting {
+                    "Press a key…".to_string()
+                } else {
+                    key_label(key_bindings.key(action))
+                };
+                let response = ui.button(key);
+                if response.clicked() {
+                    rebind.action = (!waiting).then_some(action);
+                    // Keep Enter/Space free for binding
+                    response.surrender_focus();
+                }
+                ui.end_row();
+            }
+        });
+    for (first, second, key) in key_bindings.conflicts() {
+        ui.label(
+            egui::RichText::new(format!(
+                "{} and {} are both on {}",
+                first.label(),
+                second.label(),
+                key_label(key)
+            ))
+            .color(UiColors::WARNING),
+        );
+    }
+}
+
+/// Reset every setting to its default
+///
+/// Overwriting the resource marks it changed, so the graphics, audio and
+/// theme systems re-apply it and `save_settings_system` writes it to disk.
+pub fn restore_defaults(settings: &mut GameSettings) {
+    info!("[SETTINGS] Restoring default settings");
+    *settings = GameSettings::default();
+}
+
+/// Handle escape key to return to previous state, unless it is cancelling
+/// a key rebind
 fn handle_settings_escape(
     keyboard: Res<ButtonInput<KeyCode>>,
     mut next_state: ResMut<NextState<GameState>>,
     previous_state: Res<PreviousState>,
+    rebind: Res<KeyRebind>,
 ) {
+    if rebind.action.is_some() {
+        return;
+    }
     if keyboard.just_pressed(KeyCode::Escape) {
         info!(
             "[SETTINGS] Escape pressed, returning to {:?}",
@@ -193,3 +639,34 @@ fn handle_settings_escape(
         next_state.set(previous_state.state);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_restore_defaults_resets_every_setting() {
+        //! After a restore the settings equal `GameSettings::default()`
+
+        let mut settings = GameSettings {
+            master_volume: 0.1,
+            muted: true,
+            show_hints: false,
+            graphics_quality: GraphicsQuality::Ultra,
+            shadows: false,
+            board_theme: 3,
+            board_theme_3d: BoardTheme::Wood,
+            clock_format: ClockFormat::MinutesSeconds,
+            preview_moves_on_hover: true,
+            ui_scale: 1.75,
+            increment_mode: IncrementMode::SimpleDelay,
+            ..Default::default()
+        };
+        settings.dynamic_lighting.enabled = !settings.dynamic_lighting.enabled;
+        assert_ne!(settings, GameSettings::default());
+
+        restore_defaults(&mut settings);
+
+        assert_eq!(settings, GameSettings::default());
+    }
+}
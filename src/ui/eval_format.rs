@@ -0,0 +1,62 @@
+//! Engine evaluations as text
+//!
+//! Scores are White-relative centipawns. Ordinary scores are shown in pawns
+//! with a sign ("+1.35", "-0.20"); scores past [`SURE_CHECKMATE`] are forced
+//! mates, which the engine encodes as `KING_VALUE` minus the plies to mate,
+//! and are shown as moves to mate ("M3" when White mates, "-M2" when Black
+//! does).
+
+use nimzovich_engine::{KING_VALUE, SURE_CHECKMATE};
+
+/// Moves until mate for a mate score, negative when Black is mating.
+/// `None` for an ordinary score.
+pub fn mate_in(score_cp: i64) -> Option<i64> {
+    if score_cp.abs() <= SURE_CHECKMATE as i64 {
+        return None;
+    }
+    let plies = (KING_VALUE as i64 - score_cp.abs()).max(0);
+    // The mating side moves on odd plies from its own point of view, so
+    // mate in N is 2N - 1 plies for it and 2N for the side being mated.
+    let moves = ((plies + 1) / 2).max(1);
+    Some(moves * score_cp.signum())
+}
+
+/// The engine's score for "mate in `moves`" as reported over UCI
+/// (`score mate N`), from the reporting side's point of view.
+pub fn mate_score(moves: i64) -> i64 {
+    let plies = if moves > 0 { 2 * moves - 1 } else { 2 * -moves };
+    (KING_VALUE as i64 - plies) * if moves < 0 { -1 } else { 1 }
+}
+
+/// Format a White-relative score as "+1.35", "-0.20", "M3" or "-M2".
+pub fn format_eval(score_cp: i64) -> String {
+    match mate_in(score_cp) {
+        Some(moves) if moves < 0 => format!("-M{}", -moves),
+        Some(moves) => format!("M{}", moves),
+        None => format!("{:+.2}", score_cp as f64 / 100.0),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn centipawn_and_mate_scores_read_from_whites_side() {
+        //! Centipawns become signed pawns and mate scores become moves to mate, positive for White
+
+        assert_eq!(format_eval(135), "+1.35");
+        assert_eq!(format_eval(-20), "-0.20");
+        assert_eq!(format_eval(0), "+0.00");
+
+        // White mates on the fifth ply: mate in 3.
+        assert_eq!(format_eval(KING_VALUE as i64 - 5), "M3");
+        // Black mates, White being mated on the fourth ply: mate in 2.
+        assert_eq!(format_eval(-(KING_VALUE as i64 - 4)), "-M2");
+
+        for moves in [1, 4, -1, -3] {
+            assert_eq!(mate_in(mate_score(moves)), Some(moves));
+        }
+        assert_eq!(mate_in(SURE_CHECKMATE as i64), None);
+    }
+}
@@ -0,0 +1,54 @@
+//! Blunder confirmation UI
+//!
+//! Asks before playing a move the blunder check flagged (see
+//! [`crate::game::blunder_check`]).
+
+use crate::engine::board_state::ChessEngine;
+use crate::game::blunder_check::{BlunderCheck, HoldReason};
+use crate::ui::styles::*;
+use bevy::prelude::*;
+use bevy_egui::{egui, EguiContexts};
+
+/// System to display the "are you sure?" dialog for a flagged move
+pub fn blunder_confirm_ui_system(mut contexts: EguiContexts, mut check: ResMut<BlunderCheck>) {
+    let Some((target, reason)) = check.awaiting_confirmation() else {
+        return;
+    };
+
+    let Ok(ctx) = contexts.ctx_mut() else {
+        return;
+    };
+
+    egui::Window::new("Blunder Check")
+        .collapsible(false)
+        .resizable(false)
+        .title_bar(false)
+        .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+        .frame(StyledPanel::popup())
+        .show(ctx, |ui| {
+            ui.vertical_centered(|ui| {
+                ui.label(TextStyle::popup_title("ARE YOU SURE?"));
+                ui.add_space(10.0);
+                let square = ChessEngine::coords_to_uci(target.0, target.1);
+                ui.label(TextStyle::popup_body(match reason {
+                    HoldReason::Blunder => {
+                        format!("Moving to {square} looks like it loses material.")
+                    }
+                    HoldReason::LosingCapture => {
+                        format!("This capture on {square} loses material — proceed?")
+                    }
+                }));
+                ui.add_space(15.0);
+
+                ui.horizontal(|ui| {
+                    if StyledButton::secondary(ui, "Take it back").clicked() {
+                        check.cancel();
+                    }
+                    ui.add_space(8.0);
+                    if StyledButton::danger(ui, "Play it anyway").clicked() {
+                        check.confirm();
+                    }
+                });
+            });
+        });
+}
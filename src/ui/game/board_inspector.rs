@@ -0,0 +1,185 @@
+//! Engine board inspector (F1 unless remapped)
+//!
+//! Draws the engine's internal board next to the one the ECS `Piece`
+//! entities describe, with squares that disagree shown in red, so a desync
+//! between the two representations is visible at a glance. Nothing is built
+//! while the panel is closed.
+
+use crate::core::{GameAction, GameSettings};
+use crate::engine::board_state::ChessEngine;
+use crate::game::components::{Captured, FadingCapture, Piece};
+use crate::ui::styles::*;
+use bevy::prelude::*;
+use bevy_egui::{egui, EguiContexts};
+use nimzovich_engine::{BISHOP_ID, KING_ID, KNIGHT_ID, PAWN_ID, QUEEN_ID, ROOK_ID};
+
+/// Whether the inspector panel is showing.
+#[derive(Resource, Default)]
+pub struct BoardInspector {
+    pub open: bool,
+}
+
+/// Flip the inspector on the [`GameAction::BoardInspector`] key.
+pub fn toggle_board_inspector(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    settings: Res<GameSettings>,
+    mut inspector: ResMut<BoardInspector>,
+) {
+    if settings
+        .key_bindings
+        .just_pressed(&keyboard, GameAction::BoardInspector)
+    {
+        inspector.open = !inspector.open;
+        info!(
+            "[INSPECTOR] Engine board inspector {}",
+            if inspector.open { "opened" } else { "closed" }
+        );
+    }
+}
+
+/// The board the ECS pieces describe, in the engine's layout and codes.
+/// Pieces off the board or on their way out are left off.
+pub fn ecs_board<'a>(pieces: impl IntoIterator<Item = &'a Piece>) -> [i8; 64] {
+    let mut board = [0i8; 64];
+    for piece in pieces {
+        if piece.x > 7 || piece.y > 7 {
+            continue;
+        }
+        board[ChessEngine::square_to_index(piece.x, piece.y) as usize] =
+            ChessEngine::piece_code(piece.color, piece.piece_type);
+    }
+    board
+}
+
+/// FEN letter for an engine piece code, '.' for an empty square.
+fn piece_char(code: i8) -> char {
+    let c = match code.abs() {
+        PAWN_ID => 'p',
+        KNIGHT_ID => 'n',
+        BISHOP_ID => 'b',
+        ROOK_ID => 'r',
+        QUEEN_ID => 'q',
+        KING_ID => 'k',
+        0 => return '.',
+        _ => return '?',
+    };
+    if code > 0 {
+        c.to_ascii_uppercase()
+    } else {
+        c
+    }
+}
+
+/// One 8×8 grid, White at the bottom, squares in `mismatched` in red.
+fn board_grid(ui: &mut egui::Ui, id: &str, board: &[i8; 64], mismatched: &[bool; 64]) {
+    egui::Grid::new(id).spacing([6.0, 2.0]).show(ui, |ui| {
+        for rank in (0..8u8).rev() {
+            ui.label(
+                egui::RichText::new((b'1' + rank) as char)
+                    .monospace()
+                    .weak(),
+            );
+            for file in 0..8u8 {
+                let sq = ChessEngine::square_to_index(file, rank) as usize;
+                let mut text = egui::RichText::new(piece_char(board[sq])).monospace();
+                if mismatched[sq] {
+                    text = text.color(UiColors::DANGER).strong();
+                }
+                ui.label(text);
+            }
+            ui.end_row();
+        }
+        ui.label("");
+        for file in 0..8u8 {
+            ui.label(
+                egui::RichText::new((b'a' + file) as char)
+                    .monospace()
+                    .weak(),
+            );
+        }
+        ui.end_row();
+    });
+}
+
+/// Draw the two boards side by side.
+pub fn board_inspector_ui(
+    mut contexts: EguiContexts,
+    mut inspector: ResMut<BoardInspector>,
+    engine: Res<ChessEngine>,
+    pieces: Query<&Piece, (Without<Captured>, Without<FadingCapture>)>,
+) {
+    let Ok(ctx) = contexts.ctx_mut() else {
+        return;
+    };
+
+    let engine_board = engine.board();
+    let ecs_board = ecs_board(&pieces);
+    let mut mismatched = [false; 64];
+    for (sq, flag) in mismatched.iter_mut().enumerate() {
+        *flag = engine_board[sq] != ecs_board[sq];
+    }
+    let mismatches = mismatched.iter().filter(|&&m| m).count();
+
+    egui::Window::new("Engine Board Inspector")
+        .open(&mut inspector.open)
+        .resizable(false)
+        .show(ctx, |ui| {
+            ui.horizontal(|ui| {
+                ui.vertical(|ui| {
+                    ui.label(TextStyle::body("Engine"));
+                    board_grid(ui, "inspector_engine_board", engine_board, &mismatched);
+                });
+                ui.add_space(16.0);
+                ui.vertical(|ui| {
+                    ui.label(TextStyle::body("ECS"));
+                    board_grid(ui, "inspector_ecs_board", &ecs_board, &mismatched);
+                });
+            });
+            ui.separator();
+            if mismatches == 0 {
+                ui.label(TextStyle::caption("Boards agree"));
+            } else {
+                ui.label(
+                    egui::RichText::new(format!("{mismatches} square(s) differ"))
+                        .color(UiColors::DANGER),
+                );
+            }
+            ui.label(TextStyle::caption(engine.current_fen()));
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::components::{PieceColor, PieceType};
+
+    #[test]
+    fn test_ecs_board_matches_engine_codes() {
+        //! The ECS board uses the engine's layout, so a synced position compares equal square for square
+
+        let mut engine = ChessEngine::default();
+        engine
+            .set_from_fen("4k3/8/8/8/8/8/4P3/4K3 w - - 0 1")
+            .unwrap();
+        let pieces = [
+            Piece::new(PieceColor::White, PieceType::King, 4, 0),
+            Piece::new(PieceColor::White, PieceType::Pawn, 4, 1),
+            Piece::new(PieceColor::Black, PieceType::King, 4, 7),
+            // Captured pieces are parked off the board
+            Piece::new(PieceColor::Black, PieceType::Queen, u8::MAX, u8::MAX),
+        ];
+        assert_eq!(&ecs_board(&pieces), engine.board());
+
+        let moved = [
+            Piece::new(PieceColor::White, PieceType::King, 4, 0),
+            Piece::new(PieceColor::White, PieceType::Pawn, 4, 3),
+            Piece::new(PieceColor::Black, PieceType::King, 4, 7),
+        ];
+        let differing: Vec<usize> = (0..64)
+            .filter(|&sq| ecs_board(&moved)[sq] != engine.board()[sq])
+            .collect();
+        assert_eq!(differing, vec![12, 28]);
+        assert_eq!(piece_char(engine.board()[12]), 'P');
+        assert_eq!(piece_char(engine.board()[60]), 'k');
+    }
+}
@@ -4,17 +4,19 @@
 //! This allows players to play chess with a traditional 2D view while maintaining
 //! full compatibility with the existing game state and networking systems.
 
-use crate::core::states::GameMode;
+use crate::game::board_coords::{files_mirrored, world_x_file};
 use crate::game::components::FadingCapture;
-use crate::game::resources::{CurrentTurn, Players};
-use crate::game::systems::camera::get_is_black_view;
+use crate::game::premove::click_premove;
+use crate::game::resources::{AnimationSettings, CurrentTurn};
+use crate::game::systems::camera::BoardOrientation;
 use crate::game::systems::input::{
-    can_move_color, clear_selection_state, is_human_turn, try_move_sequence, try_select_piece,
-    InputSystemParams,
+    can_move_color, clear_selection_state, is_human_turn, release_selection, touch_move_holds,
+    try_move_sequence, try_select_piece, InputSystemParams,
 };
 use crate::game::systems::shared::CapturedTarget;
 use crate::game::view_mode::ViewMode;
 use crate::rendering::pieces::{PieceColor, PieceType};
+use crate::ui::styles::UiColors;
 use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
 use bevy_egui::egui;
@@ -29,7 +31,6 @@ pub struct Board2DExtras<'w> {
     pub sounds: Option<Res<'w, crate::game::resources::GameSounds>>,
     pub settings: ResMut<'w, crate::core::GameSettings>,
     pub drag: ResMut<'w, DragState2D>,
-    pub premove: ResMut<'w, PremoveState>,
     pub anim: ResMut<'w, PieceAnim2D>,
     pub time: Res<'w, Time>,
     pub promotion: Res<'w, crate::game::resources::PendingPromotion>,
@@ -38,19 +39,22 @@ pub struct Board2DExtras<'w> {
     pub focus: ResMut<'w, BoardFocus>,
     pub cm_flash: Res<'w, CheckmateFlashState>,
     pub board_fade: Res<'w, BoardFadeState>,
+    pub hint: Res<'w, crate::game::ai::hint::EngineHint>,
 }
 
-// The local is_black_view function is removed in favor of the shared helper in camera.rs
+// Which side is at the bottom comes from the shared BoardOrientation in camera.rs
 
 /// Convert board (file, rank) to screen offset within the board widget.
 /// White view: a-file on left, rank 1 at bottom.
 /// Black view: h-file on left, rank 8 at bottom.
+/// Mirrored files swap left and right in either view.
 fn board_to_screen(file: u8, rank: u8, black_view: bool, square_size: f32) -> egui::Vec2 {
-    let (sx, sy) = if black_view {
-        (7 - file, rank)
+    let sx = if black_view != files_mirrored() {
+        7 - file
     } else {
-        (file, 7 - rank)
+        file
     };
+    let sy = if black_view { rank } else { 7 - rank };
     egui::Vec2::new(sx as f32 * square_size, sy as f32 * square_size)
 }
 
@@ -63,23 +67,6 @@ pub struct DragState2D {
     pub piece: Option<(PieceType, PieceColor)>,
 }
 
-/// Premove state: player can queue a move during opponent's turn.
-#[derive(Resource, Default)]
-pub struct PremoveState {
-    pub from: Option<(u8, u8)>,
-    pub to: Option<(u8, u8)>,
-}
-
-impl PremoveState {
-    pub fn is_set(&self) -> bool {
-        self.from.is_some() && self.to.is_some()
-    }
-    pub fn clear(&mut self) {
-        self.from = None;
-        self.to = None;
-    }
-}
-
 /// 2D piece slide animation state.
 #[derive(Resource, Default)]
 pub struct PieceAnim2D {
@@ -87,6 +74,8 @@ pub struct PieceAnim2D {
     pub from_px: egui::Pos2,
     pub to_px: egui::Pos2,
     pub elapsed: f32,
+    /// Length of this slide, from [`AnimationSettings`] when it started.
+    pub duration: f32,
     pub piece: Option<(PieceType, PieceColor)>,
     pub from_sq: (u8, u8),
     pub to_sq: (u8, u8),
@@ -95,9 +84,15 @@ pub struct PieceAnim2D {
 }
 
 impl PieceAnim2D {
+    /// Slide length at normal speed.
     const DURATION: f32 = 0.15;
+
+    pub fn is_finished(&self) -> bool {
+        self.elapsed >= self.duration
+    }
+
     pub fn t(&self) -> f32 {
-        (self.elapsed / Self::DURATION).min(1.0)
+        (self.elapsed / self.duration.max(f32::EPSILON)).min(1.0)
     }
     pub fn lerped_pos(&self) -> egui::Pos2 {
         let t = self.t();
@@ -113,13 +108,15 @@ pub fn trigger_piece_anim_2d(
     mut events: bevy::prelude::MessageReader<crate::game::events::MoveMadeEvent>,
     mut anim: ResMut<PieceAnim2D>,
     view_mode: Res<ViewMode>,
+    animation: Res<AnimationSettings>,
 ) {
     for ev in events.read() {
         if *view_mode != ViewMode::Standard2D {
             continue;
         }
-        anim.active = true;
+        anim.active = !animation.is_instant();
         anim.elapsed = 0.0;
+        anim.duration = animation.duration(PieceAnim2D::DURATION);
         anim.from_sq = ev.from;
         anim.to_sq = ev.to;
         anim.from_px = egui::Pos2::ZERO;
@@ -256,7 +253,8 @@ pub struct EvalHistory {
 /// Centipawn evaluation bar state (updated each move, drives the visual bar).
 #[derive(Resource, Default)]
 pub struct EvalBarState {
-    /// Centipawn score from White's perspective. Positive = White better.
+    /// Centipawn score from White's perspective. Positive = White better;
+    /// past `SURE_CHECKMATE` it is a forced mate.
     pub score: i16,
     /// Whether the bar is visible (toggled from sidebar / settings).
     pub visible: bool,
@@ -271,8 +269,9 @@ impl EvalBarState {
     }
 }
 
-/// System that recomputes the eval score whenever MoveHistory changes.
-/// Also builds per-ply eval history for move annotation chips.
+/// System that builds the per-ply static eval history for move annotation
+/// chips whenever MoveHistory changes. The bar's own score comes from a
+/// search, see [`crate::game::live_eval`].
 pub fn update_eval_bar(
     history: Res<crate::game::resources::MoveHistory>,
     mut eval: ResMut<EvalBarState>,
@@ -322,12 +321,15 @@ pub fn update_eval_bar(
         let src = rec.from.1 as i8 * 8 + rec.from.0 as i8;
         let dst = rec.to.1 as i8 * 8 + rec.to.0 as i8;
         let is_promo = rec.piece_type == PieceType::Pawn && (rec.to.1 == 7 || rec.to.1 == 0);
-        let promo: i8 = if is_promo { 5 } else { 0 };
+        let promo: i8 = match rec.promotion {
+            Some(piece) => crate::engine::board_state::ChessEngine::piece_type_to_id(piece),
+            None if is_promo => 5,
+            None => 0,
+        };
         do_move_with_promo(&mut game, src, dst, true, promo);
         eval_history.scores.push(evaluate_position(&game));
     }
     eval_history.cached_game = Some(game);
-    eval.score = eval_history.scores.last().copied().unwrap_or(0);
 }
 
 /// Color theme for the 2D board.
@@ -399,6 +401,7 @@ fn highlight_color(highlight_type: HighlightType) -> egui::Color32 {
         HighlightType::Selected => egui::Color32::from_rgba_unmultiplied(255, 255, 0, 100),
         HighlightType::LegalMove => egui::Color32::from_rgba_unmultiplied(0, 200, 0, 90),
         HighlightType::Capture => egui::Color32::from_rgba_unmultiplied(255, 80, 0, 110),
+        HighlightType::EngineHint => egui::Color32::from_rgba_unmultiplied(50, 130, 240, 110),
     }
 }
 
@@ -408,6 +411,7 @@ enum HighlightType {
     Selected,
     LegalMove,
     Capture,
+    EngineHint,
 }
 
 /// Unicode chess piece symbols.
@@ -480,8 +484,7 @@ pub fn render_2d_board(
         Res<crate::multiplayer::solana::addon::CompetitiveMatchState>,
     >,
     view_mode: Res<ViewMode>,
-    game_mode: Res<GameMode>,
-    players: Res<Players>,
+    orientation: Res<BoardOrientation>,
     current_turn: Res<CurrentTurn>,
     _hud_visibility: Res<crate::ui::game::game_ui::InGameHudVisibility>,
     fading_captures: Query<&FadingCapture>,
@@ -495,7 +498,7 @@ pub fn render_2d_board(
     // Tick piece animation — independent of board layout.
     if extras.anim.active {
         extras.anim.elapsed += extras.time.delta_secs();
-        if extras.anim.elapsed >= PieceAnim2D::DURATION {
+        if extras.anim.is_finished() {
             extras.anim.active = false;
         }
     }
@@ -524,7 +527,9 @@ pub fn render_2d_board(
         return;
     };
 
-    let black_view = get_is_black_view(&players, &current_turn, *game_mode);
+    let black_view = orientation.is_black_bottom();
+    // a-file on the right: Black's view, or White's with mirrored files
+    let files_flipped = black_view != files_mirrored();
     let is_human = is_human_turn(&input_params);
     let game_over = input_params.game_over.is_game_over();
     let piece_alpha = (extras.board_fade.alpha_mult * 255.0).clamp(0.0, 255.0) as u8;
@@ -543,11 +548,11 @@ pub fn render_2d_board(
     let mut pending_theme: Option<Board2DTheme> = None;
 
     // Collect active capture flashes: board square → animation progress (0..1).
-    // initial_pos.x = file, initial_pos.z = rank (board coord formula).
+    // initial_pos.x = world X of the file, initial_pos.z = rank (board coord formula).
     let capture_flashes: HashMap<(u8, u8), f32> = fading_captures
         .iter()
         .map(|fc| {
-            let file = fc.initial_pos.x.round() as u8;
+            let file = world_x_file(fc.initial_pos.x).round() as u8;
             let rank = fc.initial_pos.z.round() as u8;
             (file, rank, fc.timer.fraction())
         })
@@ -575,6 +580,7 @@ pub fn render_2d_board(
     let mut drag_cursor_update: Option<egui::Pos2> = None;
     // Variables filled inside the closure for premove.
     let mut premove_click: Option<(u8, u8)> = None;
+    let premove_squares = [input_params.premove.from, input_params.premove.to];
 
     egui::CentralPanel::default()
         .frame(egui::Frame::NONE.fill(egui::Color32::from_rgb(30, 30, 35)))
@@ -618,11 +624,8 @@ pub fn render_2d_board(
                     }
                     let col = (rel.x / square_size) as u8;
                     let row = (rel.y / square_size) as u8;
-                    let (file, rank) = if black_view {
-                        (7 - col, row)
-                    } else {
-                        (col, 7 - row)
-                    };
+                    let file = if files_flipped { 7 - col } else { col };
+                    let rank = if black_view { row } else { 7 - row };
                     Some((file, rank))
                 };
                 // Right-click drag start
@@ -699,10 +702,19 @@ pub fn render_2d_board(
                             }
                         }
 
+                        // Engine hint (blue tint)
+                        if let Some(shown) = extras.hint.shown {
+                            if (file, rank) == shown.from || (file, rank) == shown.to {
+                                painter.rect_filled(
+                                    sq_rect,
+                                    0.0,
+                                    highlight_color(HighlightType::EngineHint),
+                                );
+                            }
+                        }
+
                         // Premove highlight (cyan tint)
-                        let is_premove_sq = extras.premove.from == Some((file, rank))
-                            || extras.premove.to == Some((file, rank));
-                        if is_premove_sq {
+                        if premove_squares.contains(&Some((file, rank))) {
                             painter.rect_filled(
                                 sq_rect,
                                 0.0,
@@ -717,7 +729,7 @@ pub fn render_2d_board(
                                     painter.rect_filled(
                                         sq_rect,
                                         0.0,
-                                        egui::Color32::from_rgba_unmultiplied(200, 30, 30, 130),
+                                        UiColors::CHECK_HIGHLIGHT.gamma_multiply(0.5),
                                     );
                                 }
                             }
@@ -846,8 +858,11 @@ pub fn render_2d_board(
                         } // end !blindfold && !skip_piece draw
 
                         // In-board corner coordinate labels (Lichess style)
-                        let is_bottom_row = if black_view { rank == 7 } else { rank == 0 };
-                        let is_left_col = if black_view { file == 7 } else { file == 0 };
+                        let show_labels = extras.settings.show_coordinates;
+                        let is_bottom_row =
+                            show_labels && if black_view { rank == 7 } else { rank == 0 };
+                        let is_left_col =
+                            show_labels && if files_flipped { file == 7 } else { file == 0 };
                         let lc = current_theme.label_color(file, rank);
                         let font = egui::FontId::proportional(9.0);
                         if is_left_col {
@@ -1058,6 +1073,15 @@ pub fn render_2d_board(
                                 egui::FontId::proportional(font_size),
                                 piece_col,
                             );
+                            // Realistic promotion: greyed out and not clickable
+                            if !extras.promotion.allows(pt) {
+                                painter.rect_filled(
+                                    btn_rect,
+                                    4.0,
+                                    egui::Color32::from_rgba_unmultiplied(0, 0, 0, 150),
+                                );
+                                continue;
+                            }
                             // Click detection
                             let btn_id = egui::Id::new("promo_btn").with(idx as u32);
                             let btn_resp = ui.interact(btn_rect, btn_id, egui::Sense::click());
@@ -1121,21 +1145,29 @@ pub fn render_2d_board(
                 if extras.focus.active && !game_over {
                     let (mut f, mut r) = extras.focus.cursor;
                     if extras.keyboard.just_pressed(KeyCode::ArrowRight) {
-                        if black_view {
+                        if files_flipped {
                             f = f.saturating_sub(1);
                         } else {
                             f = (f + 1).min(7);
                         }
                     } else if extras.keyboard.just_pressed(KeyCode::ArrowLeft) {
-                        if black_view {
+                        if files_flipped {
                             f = (f + 1).min(7);
                         } else {
                             f = f.saturating_sub(1);
                         }
                     } else if extras.keyboard.just_pressed(KeyCode::ArrowUp) {
-                        r = (r + 1).min(7);
+                        if black_view {
+                            r = r.saturating_sub(1);
+                        } else {
+                            r = (r + 1).min(7);
+                        }
                     } else if extras.keyboard.just_pressed(KeyCode::ArrowDown) {
-                        r = r.saturating_sub(1);
+                        if black_view {
+                            r = (r + 1).min(7);
+                        } else {
+                            r = r.saturating_sub(1);
+                        }
                     }
                     extras.focus.cursor = (f, r);
                     if extras.keyboard.just_pressed(KeyCode::Enter)
@@ -1171,11 +1203,8 @@ pub fn render_2d_board(
                     // Score label
                     let abs_cp = extras.eval_bar.score.unsigned_abs();
                     if abs_cp > 20 {
-                        let label = if abs_cp >= 1000 {
-                            format!("M{}", abs_cp / 100)
-                        } else {
-                            format!("{:.1}", abs_cp as f32 / 100.0)
-                        };
+                        let label =
+                            crate::ui::eval_format::format_eval(extras.eval_bar.score as i64);
                         let label_y = if extras.eval_bar.score > 0 {
                             white_rect.min.y + 3.0
                         } else {
@@ -1426,79 +1455,20 @@ pub fn render_2d_board(
                 };
                 try_move_sequence(&mut input_params, to_sq, capture_info, "2d_drag");
             } else {
-                clear_selection_state(
-                    &mut input_params.commands,
-                    &mut input_params.selection,
-                    &input_params.selected_pieces,
-                );
+                release_selection(&mut input_params);
             }
         }
     }
 
-    // ── Premove: queue clicks during opponent's turn ─────────────────────
+    // ── Premove: queue clicks during the computer's turn ─────────────────
+    // play_premove plays the queued move once the turn comes back.
     if !is_human {
         if let Some(sq) = premove_click {
-            if extras.premove.from.is_none() {
-                // The local player's color is the opponent of whoever is currently moving.
-                let local_color = match current_turn.color {
-                    PieceColor::White => PieceColor::Black,
-                    PieceColor::Black => PieceColor::White,
-                };
-                let has_own_piece = {
-                    let q = input_params.pieces.p1();
-                    q.iter()
-                        .any(|(_, p, _, _)| p.x == sq.0 && p.y == sq.1 && p.color == local_color)
-                };
-                if has_own_piece {
-                    extras.premove.from = Some(sq);
-                }
-            } else if extras.premove.to.is_none() {
-                if Some(sq) != extras.premove.from {
-                    extras.premove.to = Some(sq);
-                } else {
-                    extras.premove.clear(); // clicked same square: cancel
-                }
-            } else {
-                extras.premove.clear(); // third click: cancel and restart
-            }
+            click_premove(&mut input_params, sq);
         }
         return;
     }
 
-    // ── Execute queued premove when it becomes our turn ──────────────────
-    if is_human && extras.premove.is_set() {
-        let from = extras.premove.from.unwrap();
-        let to = extras.premove.to.unwrap();
-        extras.premove.clear();
-        // Select the from-piece and execute the premove move.
-        let piece_at = {
-            let q = input_params.pieces.p1();
-            q.iter()
-                .find(|(_, p, _, _)| p.x == from.0 && p.y == from.1)
-                .map(|(e, p, _, _)| (e, *p))
-        };
-        if let Some((entity, piece)) = piece_at {
-            if can_move_color(&input_params, piece.color) {
-                try_select_piece(&mut input_params, entity, piece, true);
-                let updated_legal_moves = input_params.selection.possible_moves.clone();
-                if updated_legal_moves.contains(&to) {
-                    let capture_info = {
-                        let q = input_params.pieces.p1();
-                        q.iter()
-                            .find(|(_, p, _, _)| p.x == to.0 && p.y == to.1)
-                            .map(|(e, p, _, _)| CapturedTarget {
-                                entity: e,
-                                piece_type: p.piece_type,
-                                color: p.color,
-                            })
-                    };
-                    try_move_sequence(&mut input_params, to, capture_info, "2d_premove");
-                    return; // premove fired — don't process normal click
-                }
-            }
-        }
-    }
-
     // ── Normal click handling ────────────────────────────────────────────
     let Some((cf, cr)) = clicked_square else {
         return;
@@ -1530,12 +1500,14 @@ pub fn render_2d_board(
                 Some((entity, piece)) if can_move_color(&input_params, piece.color) => {
                     try_select_piece(&mut input_params, entity, piece, true);
                 }
+                _ if touch_move_holds(&input_params) => release_selection(&mut input_params),
                 _ => {
                     if let Some(ref s) = extras.sounds {
                         if !extras.settings.muted {
-                            input_params
-                                .commands
-                                .spawn(bevy::audio::AudioPlayer::new(s.illegal.clone()));
+                            input_params.commands.spawn((
+                                bevy::audio::AudioPlayer::new(s.illegal.clone()),
+                                crate::presentation::audio::SfxChannel,
+                            ));
                         }
                     }
                     clear_selection_state(
@@ -45,6 +45,48 @@ impl IncrementFlash {
     }
 }
 
+/// How long the "game started" summary stays on screen, fade included.
+const GAME_START_SUMMARY_SECS: f32 = 2.5;
+
+/// Plies without a capture or pawn move before the fifty-move count shows.
+const FIFTY_MOVE_HINT_PLIES: u32 = 40;
+
+/// Fading toast listing the settings a game was started with.
+/// Filled on entering `InGame`; see [`show_game_start_summary`].
+#[derive(Resource, Default)]
+pub struct GameStartSummary {
+    pub lines: Vec<String>,
+    pub elapsed: f32,
+    pub active: bool,
+}
+
+impl GameStartSummary {
+    pub fn show(&mut self, lines: Vec<String>) {
+        self.active = !lines.is_empty();
+        self.lines = lines;
+        self.elapsed = 0.0;
+    }
+
+    pub fn tick(&mut self, dt: f32) {
+        if self.active {
+            self.elapsed += dt;
+            if self.elapsed > GAME_START_SUMMARY_SECS {
+                self.active = false;
+            }
+        }
+    }
+
+    /// Full opacity for the first half, then a linear fade out.
+    pub fn alpha(&self) -> u8 {
+        if !self.active {
+            return 0;
+        }
+        let hold = GAME_START_SUMMARY_SECS * 0.5;
+        let t = ((self.elapsed - hold) / (GAME_START_SUMMARY_SECS - hold)).clamp(0.0, 1.0);
+        ((1.0 - t) * 230.0) as u8
+    }
+}
+
 /// Entry in the avatar cache.
 pub enum AvatarEntry {
     Loading,
@@ -167,6 +209,17 @@ pub fn reset_in_game_hud_visibility(mut hud_visibility: ResMut<InGameHudVisibili
     hud_visibility.visible = true;
 }
 
+/// Whether the in-game "Help & Rules" window is open.
+#[derive(Resource, Default)]
+pub struct InGameHelp {
+    pub open: bool,
+}
+
+/// Each game starts with the help window closed.
+pub fn close_in_game_help(mut help: ResMut<InGameHelp>) {
+    help.open = false;
+}
+
 /// Tracks which player has flagged (run out of time) for the hourglass animation.
 #[derive(Resource, Default)]
 pub struct TimeoutHourglassState {
@@ -195,9 +248,13 @@ pub fn timeout_hourglass_system(
 
 pub fn toggle_in_game_hud(
     keyboard: Res<ButtonInput<KeyCode>>,
+    settings: Res<crate::core::GameSettings>,
     mut hud_visibility: ResMut<InGameHudVisibility>,
 ) {
-    if keyboard.just_pressed(KeyCode::KeyB) {
+    if settings
+        .key_bindings
+        .just_pressed(&keyboard, crate::core::GameAction::ToggleHud)
+    {
         hud_visibility.visible = !hud_visibility.visible;
     }
 }
@@ -261,6 +318,11 @@ pub fn game_status_ui(mut params: GameUIParams) {
         } else {
             "Are you sure you want to exit this game?"
         };
+        // Offline games can be saved on the way out, to the quick-save slot.
+        let can_save = matches!(
+            *params.game_mode,
+            GameMode::SinglePlayer | GameMode::MultiplayerLocal
+        );
 
         egui::Window::new("exit_game_confirmation")
             .title_bar(false)
@@ -280,7 +342,8 @@ pub fn game_status_ui(mut params: GameUIParams) {
                     // so the button pair sits centered in the popup.
                     ui.horizontal(|ui| {
                         ui.spacing_mut().item_spacing.x = 14.0;
-                        let buttons_width = 120.0 * 2.0 + 14.0;
+                        let count = if can_save { 3.0 } else { 2.0 };
+                        let buttons_width = 120.0 * count + 14.0 * (count - 1.0);
                         ui.add_space(((ui.available_width() - buttons_width) / 2.0).max(0.0));
                         if ui
                             .add_sized(
@@ -300,6 +363,26 @@ pub fn game_status_ui(mut params: GameUIParams) {
                             params.exit_confirmation.pending_exit = false;
                         }
 
+                        if can_save
+                            && ui
+                                .add_sized(
+                                    [120.0, 40.0],
+                                    egui::Button::new(
+                                        egui::RichText::new("Save & Exit")
+                                            .size(13.0)
+                                            .color(egui::Color32::WHITE),
+                                    )
+                                    .fill(UiColors::BTN_POPUP_DARK)
+                                    .stroke(egui::Stroke::NONE)
+                                    .corner_radius(8.0),
+                                )
+                                .on_hover_text("Save to the quick-save slot, then leave")
+                                .clicked()
+                        {
+                            params.exit_confirmation.save_before_exit = true;
+                            params.exit_confirmation.pending_exit = true;
+                        }
+
                         if ui
                             .add_sized(
                                 [120.0, 40.0],
@@ -322,6 +405,18 @@ pub fn game_status_ui(mut params: GameUIParams) {
             });
     }
 
+    if params.in_game_help.open {
+        egui::Window::new("Help & Rules")
+            .open(&mut params.in_game_help.open)
+            .collapsible(false)
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .default_width(560.0)
+            .frame(StyledPanel::popup())
+            .show(&ctx, |ui| {
+                crate::ui::menus::help::render_help(ui, &params.settings.key_bindings, 420.0);
+            });
+    }
+
     // === LEFT PANEL ===
     // Game type / rated badge / time control, both players, inline chat
     // (online games only). Declared before the central board panel so the
@@ -435,6 +530,58 @@ pub fn game_status_ui(mut params: GameUIParams) {
                 inc,
             );
         });
+
+    // The 2D board draws its own bar beside the board.
+    if params.eval_bar.visible
+        && *params.view_mode != crate::game::view_mode::ViewMode::Standard2D
+        && *params.game_mode != GameMode::MultiplayerCompetitive
+    {
+        render_eval_bar(&ctx, &params.eval_bar);
+    }
+}
+
+/// Vertical evaluation bar at the left edge of the board area, White's
+/// share filling from the bottom, with the score ("+0.45", "M3") beside it.
+fn render_eval_bar(ctx: &egui::Context, eval: &crate::ui::game::game_2d::EvalBarState) {
+    let area = ctx.available_rect();
+    let bar_h = (area.height() * 0.6).max(120.0);
+    let bar_w = 16.0;
+    let score = eval.score as i64;
+
+    egui::Area::new("eval_bar".into())
+        .order(egui::Order::Foreground)
+        .interactable(false)
+        .fixed_pos(egui::pos2(area.min.x + 14.0, area.center().y - bar_h / 2.0))
+        .show(ctx, |ui| {
+            ui.horizontal(|ui| {
+                let (bar_rect, _) =
+                    ui.allocate_exact_size(egui::vec2(bar_w, bar_h), egui::Sense::hover());
+                let painter = ui.painter();
+                painter.rect_filled(bar_rect, 3.0, egui::Color32::from_rgb(40, 40, 40));
+                let white_h = bar_h * eval.white_fraction();
+                painter.rect_filled(
+                    egui::Rect::from_min_max(
+                        egui::pos2(bar_rect.min.x, bar_rect.max.y - white_h),
+                        bar_rect.max,
+                    ),
+                    3.0,
+                    egui::Color32::from_rgb(230, 230, 230),
+                );
+                // Midline, so a level game reads at a glance.
+                painter.hline(
+                    bar_rect.x_range(),
+                    bar_rect.center().y,
+                    egui::Stroke::new(1.0, egui::Color32::from_rgb(224, 96, 64)),
+                );
+
+                ui.label(
+                    egui::RichText::new(crate::ui::eval_format::format_eval(score))
+                        .size(12.0)
+                        .strong()
+                        .color(egui::Color32::from_rgb(210, 210, 210)),
+                );
+            });
+        });
 }
 
 // ── Lichess-style right panel helpers ────────────────────────────────────────
@@ -464,6 +611,16 @@ pub(crate) fn resolve_player_names(
             .map(|p| p.username.clone())
             .unwrap_or_else(|| "Black".to_string());
         black_elo = b.map(|p| format!("{}", p.rating)).unwrap_or_default();
+    } else if let (
+        crate::core::GameMode::SinglePlayer,
+        crate::game::ai::GameMode::AIvsAI { white, black },
+    ) = (*params.game_mode, params.ai_params.ai_config.mode)
+    {
+        // Computer vs computer: both sides named by their level.
+        white_name = "Computer".to_string();
+        white_elo = white.elo_label().to_string();
+        black_name = "Computer".to_string();
+        black_elo = black.elo_label().to_string();
     } else if *params.game_mode == crate::core::GameMode::SinglePlayer {
         // vs Computer: one side is the AI (named "Computer", ELO from the
         // selected difficulty), the other is the local human — identified by
@@ -475,7 +632,15 @@ pub(crate) fn resolve_player_names(
             PieceColor::Black => PieceColor::White,
         };
         let ai_name = "Computer".to_string();
-        let ai_elo = params.ai_params.ai_config.difficulty.elo_label().to_string();
+        let ai_elo = match params.ai_params.ai_config.target_elo {
+            Some(elo) => format!("~{elo}"),
+            None => params
+                .ai_params
+                .ai_config
+                .difficulty
+                .elo_label()
+                .to_string(),
+        };
         let human_name = params
             .player_identity
             .as_ref()
@@ -655,6 +820,7 @@ fn render_game_right_panel(
 
         let p = egui::Margin::symmetric(12, 6);
         let bot_is_active = !top_is_active;
+        let base_secs = params.active_time_control.control.base_seconds() as f32;
 
         // ── OPPONENT (top of panel) ───────────────────────────────────────────────
         // material tray
@@ -682,6 +848,8 @@ fn render_game_right_panel(
                 top_is_active,
                 top_flagged,
                 pulse_alpha,
+                params.settings.clock_format,
+                base_secs,
                 increment,
                 &params.increment_flash,
             );
@@ -710,11 +878,40 @@ fn render_game_right_panel(
                         StyledPanel::sidebar_row()
                             .inner_margin(egui::Margin::symmetric(12, 8))
                             .show(ui, |ui| {
-                                render_move_list_paired(ui, &params.move_history);
+                                let jumps = crate::game::systems::takeback::takebacks_allowed(
+                                    *params.game_mode,
+                                ) && !params.game_state.game_over.is_game_over();
+                                let players = jumps.then_some(&*params.players);
+                                if let Some(index) =
+                                    render_move_list_paired(ui, &params.move_history, players)
+                                {
+                                    params.jump_writer.write(
+                                        crate::game::systems::takeback::JumpToMove { index },
+                                    );
+                                }
                             });
                     });
             });
 
+        // How close the fifty-move rule is, once it starts to matter. The
+        // engine's clock counts plies since the last capture or pawn move.
+        let quiet_plies = params.engine.halfmove_clock;
+        if quiet_plies >= FIFTY_MOVE_HINT_PLIES {
+            ui.add_space(4.0);
+            ui.label(
+                egui::RichText::new(format!("Fifty-move rule: {} of 50", quiet_plies / 2))
+                    .size(11.0)
+                    .color(if quiet_plies >= 80 {
+                        UiColors::WARNING
+                    } else {
+                        UiColors::TEXT_TERTIARY
+                    }),
+            )
+            .on_hover_text(
+                "Moves by each side since the last capture or pawn move. The game is drawn at 50.",
+            );
+        }
+
         ui.add_space(4.0);
 
         // ── CONTROLS ─────────────────────────────────────────────────────────────
@@ -791,30 +988,229 @@ fn render_game_right_panel(
                                     });
                             }
                         }
+
+                        if !is_online
+                            && ui
+                                .add(
+                                    egui::Button::new(
+                                        egui::RichText::new("Saves")
+                                            .size(13.0)
+                                            .color(egui::Color32::from_gray(180)),
+                                    )
+                                    .fill(egui::Color32::TRANSPARENT)
+                                    .stroke(egui::Stroke::NONE)
+                                    .min_size(egui::Vec2::new(70.0, 28.0)),
+                                )
+                                .on_hover_text("Save or load a game (F5 quick-save, F8 quick-load)")
+                                .clicked()
+                        {
+                            params.save_browser.show();
+                        }
+
+                        if !is_online
+                            && ui
+                                .add(
+                                    egui::Button::new(
+                                        egui::RichText::new("Study")
+                                            .size(13.0)
+                                            .color(egui::Color32::from_gray(180)),
+                                    )
+                                    .fill(egui::Color32::TRANSPARENT)
+                                    .stroke(egui::Stroke::NONE)
+                                    .min_size(egui::Vec2::new(70.0, 28.0)),
+                                )
+                                .on_hover_text(
+                                    "Save this position with its arrows and notes, or open a study",
+                                )
+                                .clicked()
+                        {
+                            params.study_browser.show();
+                        }
+
+                        if ui
+                            .add(
+                                egui::Button::new(
+                                    egui::RichText::new("Help")
+                                        .size(13.0)
+                                        .color(egui::Color32::from_gray(180)),
+                                )
+                                .fill(egui::Color32::TRANSPARENT)
+                                .stroke(egui::Stroke::NONE)
+                                .min_size(egui::Vec2::new(60.0, 28.0)),
+                            )
+                            .on_hover_text("Controls, how the pieces move, and the game modes")
+                            .clicked()
+                        {
+                            params.in_game_help.open = true;
+                        }
+
+                        // Takebacks and redo are for offline games.
+                        if crate::game::systems::takeback::takebacks_allowed(*params.game_mode) {
+                            use crate::core::keybindings::key_label;
+                            use crate::core::GameAction;
+                            use crate::game::systems::takeback::{
+                                plies_to_redo, plies_to_take_back, RedoRequest, TakebackRequest,
+                            };
+                            let bindings = &params.settings.key_bindings;
+                            let buttons = [
+                                (
+                                    plies_to_take_back(&params.move_history, &params.players) > 0,
+                                    "Undo",
+                                    "Take back your last move",
+                                    GameAction::Undo,
+                                ),
+                                (
+                                    plies_to_redo(&params.move_history, &params.players) > 0,
+                                    "Redo",
+                                    "Play the move taken back again",
+                                    GameAction::Redo,
+                                ),
+                            ];
+                            let mut pressed = None;
+                            for (shown, label, tip, action) in buttons {
+                                if shown
+                                    && ui
+                                        .add(
+                                            egui::Button::new(
+                                                egui::RichText::new(label)
+                                                    .size(13.0)
+                                                    .color(egui::Color32::from_gray(180)),
+                                            )
+                                            .fill(egui::Color32::TRANSPARENT)
+                                            .stroke(egui::Stroke::NONE)
+                                            .min_size(egui::Vec2::new(60.0, 28.0)),
+                                        )
+                                        .on_hover_text(format!(
+                                            "{} ({})",
+                                            tip,
+                                            key_label(bindings.key(action))
+                                        ))
+                                        .clicked()
+                                {
+                                    pressed = Some(action);
+                                }
+                            }
+                            match pressed {
+                                Some(GameAction::Undo) => {
+                                    params.takeback_writer.write(TakebackRequest);
+                                }
+                                Some(_) => {
+                                    params.redo_writer.write(RedoRequest);
+                                }
+                                None => {}
+                            }
+                        }
+
+                        // Hints are for offline games, on a human's turn.
+                        let human_to_move =
+                            params.players.current(params.current_turn.color).is_human;
+                        if !is_online && is_waiting && human_to_move {
+                            use crate::game::ai::hint::HintRequest;
+                            let busy = params.pending_hint.is_some() || params.hint.shown.is_some();
+                            let (label, tip) = if params.pending_hint.is_some() {
+                                ("Thinking…", "Cancel the hint")
+                            } else if params.hint.shown.is_some() {
+                                ("Hide Hint", "Clear the suggested move")
+                            } else {
+                                ("Hint", "Ask the engine for the best move here")
+                            };
+                            if ui
+                                .add(
+                                    egui::Button::new(
+                                        egui::RichText::new(label)
+                                            .size(13.0)
+                                            .color(egui::Color32::from_rgb(110, 160, 240)),
+                                    )
+                                    .fill(egui::Color32::TRANSPARENT)
+                                    .stroke(egui::Stroke::NONE)
+                                    .min_size(egui::Vec2::new(70.0, 28.0)),
+                                )
+                                .on_hover_text(tip)
+                                .clicked()
+                            {
+                                params.hint_writer.write(if busy {
+                                    HintRequest::Cancel
+                                } else {
+                                    HintRequest::Show
+                                });
+                            }
+                        }
                     });
                     ui.add_space(6.0);
                 }
 
                 // View toggle
+                // TempleOS leaves for the standard 3D board mid-game.
                 let view_label = match *params.view_mode {
                     crate::game::view_mode::ViewMode::Standard3D => "2D View",
+                    _ if params.view_mode.is_templeos() => "Standard View",
                     _ => "3D View",
                 };
-                if ui
-                    .add(
-                        egui::Button::new(
-                            egui::RichText::new(view_label)
-                                .size(13.0)
-                                .color(egui::Color32::from_gray(180)),
+                ui.horizontal(|ui| {
+                    ui.spacing_mut().item_spacing.x = 8.0;
+                    if ui
+                        .add(
+                            egui::Button::new(
+                                egui::RichText::new(view_label)
+                                    .size(13.0)
+                                    .color(egui::Color32::from_gray(180)),
+                            )
+                            .fill(egui::Color32::TRANSPARENT)
+                            .stroke(egui::Stroke::NONE)
+                            .min_size(egui::Vec2::new(90.0, 26.0)),
                         )
-                        .fill(egui::Color32::TRANSPARENT)
-                        .stroke(egui::Stroke::NONE)
-                        .min_size(egui::Vec2::new(90.0, 26.0)),
-                    )
-                    .clicked()
-                {
-                    params.view_mode.toggle();
-                }
+                        .clicked()
+                    {
+                        params.view_mode.toggle();
+                    }
+
+                    if ui
+                        .add(
+                            egui::Button::new(
+                                egui::RichText::new("Flip Board")
+                                    .size(13.0)
+                                    .color(egui::Color32::from_gray(180)),
+                            )
+                            .fill(egui::Color32::TRANSPARENT)
+                            .stroke(egui::Stroke::NONE)
+                            .min_size(egui::Vec2::new(80.0, 26.0)),
+                        )
+                        .on_hover_text(format!(
+                            "See the board from the other side ({})",
+                            crate::core::keybindings::key_label(
+                                params
+                                    .settings
+                                    .key_bindings
+                                    .key(crate::core::GameAction::FlipBoard)
+                            )
+                        ))
+                        .clicked()
+                    {
+                        params
+                            .flip_writer
+                            .write(crate::game::systems::camera::FlipBoardRequest);
+                    }
+
+                    // The engine's FEN is rebuilt from the pieces after every
+                    // move, castling rights and move counters included.
+                    if ui
+                        .add(
+                            egui::Button::new(
+                                egui::RichText::new("Copy FEN")
+                                    .size(13.0)
+                                    .color(egui::Color32::from_gray(180)),
+                            )
+                            .fill(egui::Color32::TRANSPARENT)
+                            .stroke(egui::Stroke::NONE)
+                            .min_size(egui::Vec2::new(80.0, 26.0)),
+                        )
+                        .on_hover_text("Copy the current position to the clipboard")
+                        .clicked()
+                    {
+                        let fen = params.engine.current_fen().to_string();
+                        ui.output_mut(|o| o.commands.push(egui::OutputCommand::CopyText(fen)));
+                    }
+                });
             });
 
         ui.add_space(4.0);
@@ -832,6 +1228,8 @@ fn render_game_right_panel(
                 bot_is_active,
                 bot_flagged,
                 pulse_alpha,
+                params.settings.clock_format,
+                base_secs,
                 increment,
                 &params.increment_flash,
             );
@@ -915,12 +1313,15 @@ pub(crate) fn render_compact_user_row(
     });
 }
 
+#[allow(clippy::too_many_arguments)]
 fn render_clock_bar(
     ui: &mut egui::Ui,
     time_secs: f32,
     is_active: bool,
     flagged: bool,
     pulse_alpha: u8,
+    clock_format: crate::core::ClockFormat,
+    base_secs: f32,
     _increment: f32,
     _increment_flash: &IncrementFlash,
 ) {
@@ -951,9 +1352,9 @@ fn render_clock_bar(
             ui.set_min_width(ui.available_width());
             ui.vertical_centered(|ui| {
                 let time_str = if flagged {
-                    format!("⧖ {}", format_time(time_secs))
+                    format!("⧖ {}", clock_format.format(time_secs))
                 } else {
-                    format_time(time_secs)
+                    clock_format.format(time_secs)
                 };
                 let text_color = if flagged {
                     egui::Color32::from_rgba_unmultiplied(255, 130, 0, pulse_alpha)
@@ -967,29 +1368,82 @@ fn render_clock_bar(
                         .color(text_color),
                 );
             });
+            // Painted over the frame's left edge rather than laid out, so the
+            // ring never nudges the digits as it shrinks.
+            if is_active && !flagged && base_secs > 0.0 {
+                let rect = ui.min_rect();
+                let radius = rect.height() / 2.0 - 2.0;
+                let center = egui::pos2(rect.left() + radius, rect.center().y);
+                paint_time_ring(ui.painter(), center, radius, time_secs / base_secs);
+            }
         });
 }
 
+/// Countdown ring: a faint full circle with an arc from twelve o'clock
+/// covering `fraction` of it clockwise, shading from green to red as it
+/// empties.
+fn paint_time_ring(painter: &egui::Painter, center: egui::Pos2, radius: f32, fraction: f32) {
+    const SEGMENTS: usize = 64;
+    let fraction = fraction.clamp(0.0, 1.0);
+    let stroke_width = 3.0;
+    painter.circle_stroke(
+        center,
+        radius,
+        egui::Stroke::new(stroke_width, egui::Color32::from_white_alpha(20)),
+    );
+    if fraction <= 0.0 {
+        return;
+    }
+
+    let low = egui::Color32::from_rgb(230, 60, 60);
+    let high = egui::Color32::from_rgb(80, 200, 120);
+    let color = egui::Color32::from_rgb(
+        egui::lerp(low.r() as f32..=high.r() as f32, fraction) as u8,
+        egui::lerp(low.g() as f32..=high.g() as f32, fraction) as u8,
+        egui::lerp(low.b() as f32..=high.b() as f32, fraction) as u8,
+    );
+    // Always at least two points so a nearly empty ring still shows a sliver.
+    let steps = ((SEGMENTS as f32 * fraction).ceil() as usize).max(1);
+    let points = (0..=steps)
+        .map(|i| {
+            let angle = std::f32::consts::TAU * fraction * i as f32 / steps as f32;
+            center + radius * egui::vec2(angle.sin(), -angle.cos())
+        })
+        .collect();
+    painter.add(egui::Shape::line(
+        points,
+        egui::Stroke::new(stroke_width, color),
+    ));
+}
+
+/// Numbered White/Black move list, the last move played highlighted and
+/// any moves waiting for redo dimmed after it. With `players` given, moves
+/// the board can go back or forward to are clickable; returns the index of
+/// the one clicked.
 fn render_move_list_paired(
     ui: &mut egui::Ui,
     history: &crate::game::resources::history::MoveHistory,
-) {
-    if history.is_empty() {
+    players: Option<&crate::game::resources::Players>,
+) -> Option<usize> {
+    use crate::game::systems::takeback::{move_at, plies_back_to, plies_forward_to};
+
+    let total = history.len() + history.redo_moves().len();
+    if total == 0 {
         ui.label(
             egui::RichText::new("No moves yet")
                 .size(12.0)
                 .color(UiColors::TEXT_TERTIARY),
         );
-        return;
+        return None;
     }
-    let moves = &history.moves;
-    let total = moves.len();
+    let current = history.len().checked_sub(1);
+    let mut clicked = None;
     egui::Grid::new("move_list_grid")
         .num_columns(3)
         .min_col_width(44.0)
         .spacing([10.0, 4.0])
         .show(ui, |ui| {
-            for move_num in 1..=((total + 1) / 2) {
+            for move_num in 1..=total.div_ceil(2) {
                 let white_idx = (move_num - 1) * 2;
                 let black_idx = white_idx + 1;
                 ui.label(
@@ -997,51 +1451,65 @@ fn render_move_list_paired(
                         .size(13.0)
                         .color(UiColors::TEXT_TERTIARY),
                 );
-                if white_idx < total {
-                    let mv = &moves[white_idx];
-                    ui.label(
-                        egui::RichText::new(move_notation(history, white_idx, mv))
-                            .size(16.0)
-                            .color(UiColors::TEXT_PRIMARY)
-                            .strong(),
-                    );
-                } else {
-                    ui.label("");
-                }
-                if black_idx < total {
-                    let mv = &moves[black_idx];
-                    ui.label(
-                        egui::RichText::new(move_notation(history, black_idx, mv))
-                            .size(16.0)
-                            .color(UiColors::TEXT_SECONDARY)
-                            .strong(),
-                    );
-                } else if white_idx < total {
-                    ui.label(
-                        egui::RichText::new("…")
-                            .size(16.0)
-                            .color(UiColors::TEXT_TERTIARY),
-                    );
-                } else {
-                    ui.label("");
+                for index in [white_idx, black_idx] {
+                    let Some(mv) = move_at(history, index) else {
+                        if index == black_idx {
+                            ui.label(
+                                egui::RichText::new("…")
+                                    .size(16.0)
+                                    .color(UiColors::TEXT_TERTIARY),
+                            );
+                        } else {
+                            ui.label("");
+                        }
+                        continue;
+                    };
+                    let color = if index >= history.len() {
+                        UiColors::TEXT_TERTIARY
+                    } else if index == white_idx {
+                        UiColors::TEXT_PRIMARY
+                    } else {
+                        UiColors::TEXT_SECONDARY
+                    };
+                    let text = egui::RichText::new(move_notation(history, index, mv))
+                        .size(16.0)
+                        .color(color)
+                        .strong();
+                    let is_current = current == Some(index);
+                    let reachable = players.is_some_and(|players| {
+                        plies_back_to(history, players, index) > 0
+                            || plies_forward_to(history, players, index) > 0
+                    });
+                    let response = ui.selectable_label(is_current, text);
+                    if reachable && response.on_hover_text("Go to this position").clicked() {
+                        clicked = Some(index);
+                    }
                 }
                 ui.end_row();
             }
         });
+    clicked
 }
 
 /// Notation for the move at `index` — prefers the properly-disambiguated SAN
 /// recorded via `MoveHistory::add_move_with_san`, falling back to the
 /// simplified hand-rolled notation only for entries that predate/skip it
-/// (e.g. moves constructed directly in tests).
+/// (e.g. moves constructed directly in tests). Indices past the last move
+/// played are moves waiting for redo.
 fn move_notation(
     history: &crate::game::resources::history::MoveHistory,
     index: usize,
     mv: &crate::game::components::MoveRecord,
 ) -> String {
-    history
-        .san_at(index)
-        .map(str::to_string)
+    let san = match index.checked_sub(history.len()) {
+        Some(ahead) => history
+            .redo_moves()
+            .len()
+            .checked_sub(ahead + 1)
+            .and_then(|position| history.redo_san_at(position)),
+        None => history.san_at(index),
+    };
+    san.map(str::to_string)
         .unwrap_or_else(|| format_move_algebraic(mv))
 }
 
@@ -1180,6 +1648,11 @@ fn format_move_algebraic(mv: &crate::game::components::MoveRecord) -> String {
         // Destination
         notation.push(to_file);
         notation.push_str(&to_rank.to_string());
+
+        if let Some(promoted) = mv.promotion {
+            notation.push('=');
+            notation.push(promoted.san_char());
+        }
     }
 
     // Check/Checkmate
@@ -1242,14 +1715,6 @@ fn render_checkmate_banner(ctx: &egui::Context, game_state: &GameStateParams) {
         });
 }
 
-/// Format time in seconds to MM:SS format
-fn format_time(seconds: f32) -> String {
-    let total_seconds = seconds.max(0.0) as u32;
-    let minutes = total_seconds / 60;
-    let secs = total_seconds % 60;
-    format!("{:02}:{:02}", minutes, secs)
-}
-
 /// Overlay system: shows an Accept/Decline banner when the opponent has offered a draw.
 /// Fires [`DrawResponseEvent`] (remote=false) on click so the network layer forwards it.
 pub fn draw_offer_ui(
@@ -1451,7 +1916,9 @@ pub fn post_game_overlay(
         crate::game::resources::GameOverState::BlackWonByResignation => "by Resignation",
         crate::game::resources::GameOverState::WhiteWonByTime => "on Time",
         crate::game::resources::GameOverState::BlackWonByTime => "on Time",
-        crate::game::resources::GameOverState::Stalemate => "Stalemate / Draw",
+        crate::game::resources::GameOverState::Stalemate => "Stalemate – Draw",
+        crate::game::resources::GameOverState::FiftyMoveRule => "Fifty-Move Rule",
+        crate::game::resources::GameOverState::ThreefoldRepetition => "Threefold Repetition",
         crate::game::resources::GameOverState::Aborted => "White didn't move in time",
         crate::game::resources::GameOverState::WhiteWonByAbandonment
         | crate::game::resources::GameOverState::BlackWonByAbandonment => "Opponent Disconnected",
@@ -1729,28 +2196,6 @@ pub fn opponent_disconnect_ui(
         });
 }
 
-// ── Check sound cue ───────────────────────────────────────────────────────────
-
-/// Plays the check sound when the game phase transitions to Check.
-pub fn play_check_sound_system(
-    mut commands: Commands,
-    game_phase: Res<crate::game::resources::CurrentGamePhase>,
-    sounds: Option<Res<crate::game::resources::GameSounds>>,
-    settings: Res<crate::core::GameSettings>,
-) {
-    use crate::game::components::GamePhase;
-    if !game_phase.is_changed() {
-        return;
-    }
-    if settings.muted {
-        return;
-    }
-    let Some(s) = sounds else { return };
-    if game_phase.0 == GamePhase::Check {
-        commands.spawn(bevy::audio::AudioPlayer::new(s.check.clone()));
-    }
-}
-
 // ── Blindfold mode toggle ─────────────────────────────────────────────────────
 
 /// Toggle blindfold mode via Ctrl+B.
@@ -1991,6 +2436,116 @@ pub fn increment_flash_system(
     }
 }
 
+// ── Game start summary ────────────────────────────────────────────────────────
+
+/// Fills [`GameStartSummary`] with mode, difficulty, time control and colour
+/// when a game starts, unless the player has turned the summary off.
+pub fn show_game_start_summary(
+    mut summary: ResMut<GameStartSummary>,
+    settings: Res<crate::core::GameSettings>,
+    game_mode: Res<GameMode>,
+    ai_config: Res<crate::game::ai::ChessAIResource>,
+    active_tc: Res<crate::game::resources::active_time_control::ActiveTimeControl>,
+    p2p_conn: Option<Res<crate::multiplayer::network::p2p::P2PConnectionState>>,
+) {
+    if !settings.show_game_start_summary {
+        summary.active = false;
+        return;
+    }
+
+    let vs_ai = matches!(ai_config.mode, crate::game::ai::GameMode::VsAI { .. });
+    let ai_vs_ai = match ai_config.mode {
+        crate::game::ai::GameMode::AIvsAI { white, black } => Some((white, black)),
+        _ => None,
+    };
+    let mode = match *game_mode {
+        GameMode::SinglePlayer if vs_ai => "vs Computer",
+        GameMode::SinglePlayer if ai_vs_ai.is_some() => "Computer vs Computer",
+        GameMode::SinglePlayer | GameMode::MultiplayerLocal => "Local game",
+        GameMode::OnlineMultiplayer => "Online game",
+        GameMode::MultiplayerCompetitive => "Competitive match",
+        GameMode::Spectator => "Spectating",
+        GameMode::PgnReplay => return,
+    };
+
+    let mut lines = vec![mode.to_string()];
+    if vs_ai {
+        lines.push(match ai_config.target_elo {
+            Some(elo) => format!("Target ~{elo} ELO (approximate)"),
+            None => ai_config.difficulty.description().to_string(),
+        });
+    }
+    if let Some((white, black)) = ai_vs_ai {
+        lines.push(format!("White: {}", white.description()));
+        lines.push(format!("Black: {}", black.description()));
+    }
+    lines.push(if active_tc.control.base_seconds() == 0 {
+        "Unlimited time".to_string()
+    } else {
+        active_tc.control.display_name().trim().to_string()
+    });
+
+    let local_color = if vs_ai {
+        Some(match ai_config.mode.ai_color() {
+            PieceColor::White => PieceColor::Black,
+            PieceColor::Black => PieceColor::White,
+        })
+    } else {
+        p2p_conn.as_ref().and_then(|c| c.player_color)
+    };
+    if *game_mode != GameMode::Spectator {
+        match local_color {
+            Some(PieceColor::White) => lines.push("You play White".to_string()),
+            Some(PieceColor::Black) => lines.push("You play Black".to_string()),
+            None => {}
+        }
+    }
+
+    summary.show(lines);
+}
+
+/// Ticks the [`GameStartSummary`] fade.
+pub fn game_start_summary_tick_system(mut summary: ResMut<GameStartSummary>, time: Res<Time>) {
+    summary.tick(time.delta_secs());
+}
+
+/// Draws the game start summary as a centred, click-through toast.
+pub fn game_start_summary_overlay(
+    mut contexts: bevy_egui::EguiContexts,
+    summary: Res<GameStartSummary>,
+) {
+    let alpha = summary.alpha();
+    if alpha == 0 {
+        return;
+    }
+    let Ok(ctx) = contexts.ctx_mut() else { return };
+
+    let a = |c: u8| egui::Color32::from_rgba_unmultiplied(c, c, c, alpha);
+    egui::Area::new("game_start_summary".into())
+        .order(egui::Order::Foreground)
+        .interactable(false)
+        .anchor(egui::Align2::CENTER_CENTER, egui::vec2(0.0, -60.0))
+        .show(ctx, |ui| {
+            let fill = egui::Color32::from_rgba_unmultiplied(12, 12, 14, alpha / 4 * 3);
+            egui::Frame::default()
+                .fill(fill)
+                .corner_radius(10.0)
+                .inner_margin(egui::Margin::symmetric(20, 12))
+                .show(ui, |ui| {
+                    ui.vertical_centered(|ui| {
+                        for (i, line) in summary.lines.iter().enumerate() {
+                            let (size, color) = if i == 0 {
+                                (18.0, a(240))
+                            } else {
+                                (13.0, a(200))
+                            };
+                            ui.label(egui::RichText::new(line).size(size).color(color));
+                        }
+                    });
+                });
+        });
+}
+
 // ── Resign / Offer Draw buttons ───────────────────────────────────────────────
 
 /// Floating bottom-left panel with Resign and Offer Draw buttons.
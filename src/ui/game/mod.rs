@@ -1,3 +1,5 @@
+pub mod blunder_ui;
+pub mod board_inspector;
 pub mod chat_ui;
 pub mod game_2d;
 pub mod game_ui;
@@ -1,7 +1,8 @@
 //! Pawn Promotion UI
 //!
 //! Displays a dialog when a pawn reaches the end of the board,
-//! allowing the player to choose which piece to promote to.
+//! allowing the player to choose which piece to promote to. Escape takes
+//! the strongest piece allowed, normally the queen.
 
 use crate::game::resources::{PendingPromotion, PromotionSelected};
 use crate::rendering::pieces::PieceType;
@@ -13,16 +14,13 @@ use bevy_egui::{egui, EguiContexts};
 pub fn promotion_ui_system(
     mut contexts: EguiContexts,
     pending_promotion: Res<PendingPromotion>,
+    keyboard: Res<ButtonInput<KeyCode>>,
     mut promotion_messages: MessageWriter<PromotionSelected>,
 ) {
     if !pending_promotion.is_active() {
         return;
     }
 
-    let Ok(ctx) = contexts.ctx_mut() else {
-        return;
-    };
-
     let Some(entity) = pending_promotion.pawn_entity else {
         return;
     };
@@ -33,6 +31,28 @@ pub fn promotion_ui_system(
         return;
     };
 
+    if keyboard.just_pressed(KeyCode::Escape) {
+        let promoted_to = [
+            PieceType::Queen,
+            PieceType::Rook,
+            PieceType::Bishop,
+            PieceType::Knight,
+        ]
+        .into_iter()
+        .find(|&piece| pending_promotion.allows(piece))
+        .unwrap_or(PieceType::Queen);
+        promotion_messages.write(PromotionSelected {
+            entity,
+            position,
+            promoted_to,
+        });
+        return;
+    }
+
+    let Ok(ctx) = contexts.ctx_mut() else {
+        return;
+    };
+
     // Create a modal overlay
     egui::Area::new(egui::Id::new("promotion_overlay"))
         .fixed_pos(egui::pos2(0.0, 0.0))
@@ -86,7 +106,11 @@ pub fn promotion_ui_system(
                         .stroke(egui::Stroke::NONE)
                         .corner_radius(8.0);
 
-                        if ui.add(button).clicked() {
+                        // Realistic promotion greys out pieces not yet lost.
+                        if ui
+                            .add_enabled(pending_promotion.allows(piece_type), button)
+                            .clicked()
+                        {
                             promotion_messages.write(PromotionSelected {
                                 entity,
                                 position,
@@ -5,7 +5,9 @@ use bevy_egui::{egui, EguiContexts};
 
 use crate::core::GameState;
 use crate::game::components::piece_types::PieceColor;
-use crate::game::resources::{GameOverState, MoveHistory};
+use crate::game::pgn::pgn_to_string;
+use crate::game::resources::{CapturedPieces, GameOverState, MoveHistory};
+use crate::ui::eval_format::format_eval;
 use crate::ui::styles::*;
 
 // ── Payout resource ───────────────────────────────────────────────────────────
@@ -142,9 +144,9 @@ pub fn cache_pgn_on_game_over(
         Some(PieceColor::Black) => "0-1",
         None => "1/2-1/2",
     };
-    let pgn = build_pgn(&history, pgn_result);
+    let pgn = history.to_pgn(pgn_result);
     cached.pgn_string = pgn_to_string(&pgn);
-    cached.final_fen = build_final_fen(&history);
+    cached.final_fen = history.final_fen();
     cached.pgn = Some(pgn);
     // Online games wait for the authoritative Braid/VPS move log (set by
     // apply_pgn_export_result); every other mode has no such fetch coming,
@@ -157,24 +159,100 @@ pub fn cache_pgn_on_game_over(
     cached.braid_pgn_ready = !is_online;
 }
 
-// ── PGN helpers ───────────────────────────────────────────────────────────────
+// ── Game summary ─────────────────────────────────────────────────────────────
+
+/// `Time::elapsed_secs` when the current game started.
+#[derive(Resource, Default)]
+pub struct GameStartedAt(pub f32);
+
+fn mark_game_start(time: Res<Time>, mut started: ResMut<GameStartedAt>) {
+    started.0 = time.elapsed_secs();
+}
+
+/// Final facts about the game shown under the result, collected once when
+/// the game ends.
+#[derive(Resource, Debug, Clone, Default, PartialEq)]
+pub struct GameSummary {
+    /// Full sentence describing how the game ended.
+    pub result: String,
+    /// Full moves played.
+    pub moves: u32,
+    pub duration_secs: f32,
+    /// Material (in pawns) White and Black took from each other.
+    pub material_taken: (i32, i32),
+    /// The engine's last evaluation from White's side, in games against it.
+    pub ai_eval: Option<String>,
+}
+
+impl GameSummary {
+    pub fn new(
+        game_over: &GameOverState,
+        history: &MoveHistory,
+        captured: &CapturedPieces,
+        duration_secs: f32,
+        ai_eval: Option<String>,
+    ) -> Self {
+        use crate::game::resources::piece_value;
+        let taken = |pieces: &Vec<_>| -> i32 { pieces.iter().map(|p| piece_value(*p)).sum() };
+        Self {
+            result: game_over.message().to_string(),
+            moves: history.len().div_ceil(2) as u32,
+            duration_secs,
+            material_taken: (
+                taken(&captured.white_captured),
+                taken(&captured.black_captured),
+            ),
+            ai_eval,
+        }
+    }
+}
 
-/// Replay all moves and return the final position as a FEN string.
-fn build_final_fen(history: &MoveHistory) -> String {
-    use crate::game::components::PieceType;
-    use nimzovich_engine::{do_move_with_promo, game_to_fen, new_game_no_tt};
-
-    let mut game = new_game_no_tt();
-    for rec in &history.moves {
-        let src = rec.from.1 as i8 * 8 + rec.from.0 as i8;
-        let dst = rec.to.1 as i8 * 8 + rec.to.0 as i8;
-        let is_promo = rec.piece_type == PieceType::Pawn && (rec.to.1 == 7 || rec.to.1 == 0);
-        let promo: i8 = if is_promo { 5 } else { 0 };
-        do_move_with_promo(&mut game, src, dst, true, promo);
+/// `m:ss`, or `h:mm:ss` for games over an hour.
+fn format_duration(secs: f32) -> String {
+    let total = secs.max(0.0) as u64;
+    let (h, m, s) = (total / 3600, total / 60 % 60, total % 60);
+    if h > 0 {
+        format!("{}:{:02}:{:02}", h, m, s)
+    } else {
+        format!("{}:{:02}", m, s)
     }
-    game_to_fen(&game)
 }
 
+pub fn build_game_summary(
+    game_over: Res<GameOverState>,
+    history: Res<MoveHistory>,
+    captured: Res<CapturedPieces>,
+    started: Res<GameStartedAt>,
+    time: Res<Time>,
+    game_mode: Res<crate::core::GameMode>,
+    ai_config: Res<crate::game::ai::ChessAIResource>,
+    ai_stats: Res<crate::game::ai::AIStatistics>,
+    mut commands: Commands,
+) {
+    // The AI reports its score from its own side; depth 0 means it hasn't
+    // searched this game.
+    let ai_eval = match (*game_mode, ai_config.mode) {
+        (crate::core::GameMode::SinglePlayer, crate::game::ai::GameMode::VsAI { ai_color })
+            if ai_stats.last_depth > 0 =>
+        {
+            Some(format_eval(match ai_color {
+                PieceColor::White => ai_stats.last_score,
+                PieceColor::Black => -ai_stats.last_score,
+            }))
+        }
+        _ => None,
+    };
+    commands.insert_resource(GameSummary::new(
+        &game_over,
+        &history,
+        &captured,
+        time.elapsed_secs() - started.0,
+        ai_eval,
+    ));
+}
+
+// ── PGN helpers ───────────────────────────────────────────────────────────────
+
 /// Return the name of the ELO tier that an ELO value falls into.
 fn elo_tier(elo: u32) -> Option<&'static str> {
     match elo {
@@ -189,99 +267,6 @@ fn elo_tier(elo: u32) -> Option<&'static str> {
     }
 }
 
-/// Convert the game's MoveHistory to a `ParsedPgnGame` using the engine
-/// to derive proper SAN notation.  Promotions default to Queen because
-/// MoveRecord doesn't store the promoted-to piece.
-fn build_pgn(history: &MoveHistory, result_str: &str) -> nimzovich_engine::ParsedPgnGame {
-    use crate::game::components::PieceType;
-    use nimzovich_engine::{do_move_with_promo, move_to_san, new_game_no_tt};
-    use std::collections::BTreeMap;
-
-    // No search ever runs on this Game (just replay + SAN), so skip the
-    // multi-GB transposition table `new_game` would otherwise allocate.
-    let mut game = new_game_no_tt();
-    let mut san_moves: Vec<String> = Vec::with_capacity(history.moves.len());
-
-    for rec in &history.moves {
-        let src = rec.from.1 as i8 * 8 + rec.from.0 as i8;
-        let dst = rec.to.1 as i8 * 8 + rec.to.0 as i8;
-        // Default promo to queen (5); MoveRecord doesn't store promoted piece type
-        let is_promo = rec.piece_type == PieceType::Pawn && (rec.to.1 == 7 || rec.to.1 == 0);
-        let promo: i8 = if is_promo { 5 } else { 0 };
-
-        let san = move_to_san(&mut game, src, dst, promo);
-        san_moves.push(san);
-        do_move_with_promo(&mut game, src, dst, true, promo);
-    }
-
-    let mut tags = BTreeMap::new();
-    tags.insert("Event".to_string(), "XFChess Game".to_string());
-    tags.insert("Site".to_string(), "xfchess.app".to_string());
-    tags.insert("Date".to_string(), chrono_or_unknown());
-    tags.insert("Result".to_string(), result_str.to_string());
-
-    nimzovich_engine::ParsedPgnGame {
-        tags,
-        moves: san_moves,
-        result: result_str.to_string(),
-        per_ply_annotations: Vec::new(),
-    }
-}
-
-fn chrono_or_unknown() -> String {
-    // No chrono dep — use a placeholder date.
-    "????.??.??".to_string()
-}
-
-/// Render a PGN string from a `ParsedPgnGame`.
-pub fn pgn_to_string(pgn: &nimzovich_engine::ParsedPgnGame) -> String {
-    let mut out = String::new();
-    for (k, v) in &pgn.tags {
-        out.push_str(&format!("[{} \"{}\"]\n", k, v));
-    }
-    out.push('\n');
-
-    let mut line = String::new();
-    let mut ply = 0usize;
-    let mut flush = |line: &mut String, out: &mut String| {
-        if !line.is_empty() {
-            out.push_str(line.trim_end());
-            out.push('\n');
-            line.clear();
-        }
-    };
-    for mv in &pgn.moves {
-        let token = if ply % 2 == 0 {
-            format!("{}. {}", ply / 2 + 1, mv)
-        } else {
-            mv.clone()
-        };
-        if !line.is_empty() && line.len() + 1 + token.len() > 80 {
-            flush(&mut line, &mut out);
-        }
-        if !line.is_empty() {
-            line.push(' ');
-        }
-        line.push_str(&token);
-        ply += 1;
-    }
-    if !line.is_empty() {
-        if line.len() + 1 + pgn.result.len() > 80 {
-            out.push_str(line.trim_end());
-            out.push('\n');
-            out.push_str(&pgn.result);
-        } else {
-            out.push_str(line.trim_end());
-            out.push(' ');
-            out.push_str(&pgn.result);
-        }
-    } else {
-        out.push_str(&pgn.result);
-    }
-    out.push('\n');
-    out
-}
-
 // ── Main popup system ─────────────────────────────────────────────────────────
 
 pub fn game_over_popup_system(
@@ -289,6 +274,7 @@ pub fn game_over_popup_system(
     game_over: Res<GameOverState>,
     payout_info: Option<Res<GameOverPayoutInfo>>,
     cached_pgn: Res<CachedGamePgn>,
+    summary: Option<Res<GameSummary>>,
     mut next_state: ResMut<NextState<GameState>>,
     mut game_mode: ResMut<crate::core::GameMode>,
     mut anim: ResMut<PopupAnimState>,
@@ -344,7 +330,7 @@ pub fn game_over_popup_system(
     let mut trigger_rematch = false;
 
     let is_single_player = matches!(*game_mode, crate::core::GameMode::SinglePlayer);
-    let _is_online = matches!(
+    let is_online = matches!(
         *game_mode,
         crate::core::GameMode::OnlineMultiplayer | crate::core::GameMode::MultiplayerCompetitive
     );
@@ -354,7 +340,7 @@ pub fn game_over_popup_system(
         .resizable(false)
         .collapsible(false)
         .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
-        .fixed_size([380.0, 340.0])
+        .fixed_size([380.0, 460.0])
         .frame(frame)
         .show(ctx, |ui| {
             ui.set_width(348.0);
@@ -380,6 +366,46 @@ pub fn game_over_popup_system(
                     );
                 }
 
+                // ── Final position and summary ───────────────────────────────
+                if let Some(summary) = summary.as_ref() {
+                    ui.add_space(8.0);
+                    ui.horizontal(|ui| {
+                        ui.add_space(24.0);
+                        if !cached_pgn.final_fen.is_empty() {
+                            crate::game::save::paint_fen_thumbnail(
+                                ui,
+                                &cached_pgn.final_fen,
+                                112.0,
+                            );
+                            ui.add_space(12.0);
+                        }
+                        ui.vertical(|ui| {
+                            let row = |ui: &mut egui::Ui, text: String| {
+                                ui.label(egui::RichText::new(text).size(12.0).color(text_primary));
+                            };
+                            ui.label(
+                                egui::RichText::new(&summary.result)
+                                    .size(12.0)
+                                    .strong()
+                                    .color(text_primary),
+                            );
+                            row(ui, format!("Moves: {}", summary.moves));
+                            row(
+                                ui,
+                                format!("Duration: {}", format_duration(summary.duration_secs)),
+                            );
+                            let (white, black) = summary.material_taken;
+                            row(
+                                ui,
+                                format!("Material taken: White {}, Black {}", white, black),
+                            );
+                            if let Some(eval) = &summary.ai_eval {
+                                row(ui, format!("Engine eval: {}", eval));
+                            }
+                        });
+                    });
+                }
+
                 // ── ELO / rating ─────────────────────────────────────────────
                 if let Some(info) = payout_info.as_ref() {
                     if !info.is_rated {
@@ -694,7 +720,11 @@ pub fn game_over_popup_system(
                         ui.spacing_mut().item_spacing.x = spacing;
                         let pgn_loading = !cached_pgn.braid_pgn_ready && cached_pgn.pgn.is_some();
                         let review_label = if pgn_loading { "Review ⟳" } else { "Review" };
-                        let save_label = if pgn_loading { "Save ⟳" } else { "Save PGN" };
+                        let save_label = if pgn_loading {
+                            "Export ⟳"
+                        } else {
+                            "Export PGN"
+                        };
                         let dim = if pgn_loading {
                             egui::Color32::from_rgba_unmultiplied(40, 40, 44, 120)
                         } else {
@@ -755,11 +785,7 @@ pub fn game_over_popup_system(
                     ui.horizontal(|ui| {
                         ui.add_space(pad);
                         ui.spacing_mut().item_spacing.x = spacing;
-                        let play_label = if is_single_player {
-                            "Play Again"
-                        } else {
-                            "New Game"
-                        };
+                        let play_label = if is_online { "New Game" } else { "Rematch" };
                         if ui
                             .add_sized(
                                 [btn_w, 32.0],
@@ -842,7 +868,7 @@ pub fn game_over_popup_system(
         let pgn_text = cached_pgn.pgn_string.clone();
         std::thread::spawn(move || {
             let base = dirs::document_dir().unwrap_or_else(|| std::path::PathBuf::from("."));
-            let dir = base.join("xfchess");
+            let dir = base.join("xfchess").join("games");
             let _ = std::fs::create_dir_all(&dir);
             let timestamp = std::time::SystemTime::now()
                 .duration_since(std::time::UNIX_EPOCH)
@@ -1140,11 +1166,13 @@ impl Plugin for GameOverPopupPlugin {
         app.init_resource::<GameOverPayoutInfo>();
         app.init_resource::<PopupAnimState>();
         app.init_resource::<CachedGamePgn>();
+        app.init_resource::<GameStartedAt>();
 
         app.add_systems(
             OnEnter(GameState::GameOver),
-            (setup_popup_anim, cache_pgn_on_game_over),
+            (setup_popup_anim, cache_pgn_on_game_over, build_game_summary),
         );
+        app.add_systems(OnEnter(GameState::InGame), mark_game_start);
 
         app.add_systems(
             bevy_egui::EguiPrimaryContextPass,
@@ -1166,3 +1194,51 @@ impl Plugin for GameOverPopupPlugin {
         app.add_systems(OnEnter(GameState::GameOver), fetch_game_payout_info);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::components::{MoveRecord, PieceType};
+
+    #[test]
+    fn test_checkmate_summary_reports_the_mate() {
+        //! Fool's mate summarises as a two-move Black checkmate
+
+        let mut history = MoveHistory::default();
+        let plies = [
+            (PieceColor::White, PieceType::Pawn, (5, 1), (5, 2)),
+            (PieceColor::Black, PieceType::Pawn, (4, 6), (4, 4)),
+            (PieceColor::White, PieceType::Pawn, (6, 1), (6, 3)),
+            (PieceColor::Black, PieceType::Queen, (3, 7), (7, 3)),
+        ];
+        for (piece_color, piece_type, from, to) in plies {
+            history.add_move(MoveRecord {
+                piece_type,
+                piece_color,
+                from,
+                to,
+                captured: None,
+                promotion: None,
+                is_castling: false,
+                is_en_passant: false,
+                is_check: to == (7, 3),
+                is_checkmate: to == (7, 3),
+            });
+        }
+
+        let summary = GameSummary::new(
+            &GameOverState::BlackWon,
+            &history,
+            &CapturedPieces::default(),
+            75.0,
+            Some(format_eval(-(nimzovich_engine::KING_VALUE as i64 - 4))),
+        );
+
+        assert_eq!(summary.result, "Black wins by checkmate!");
+        assert_eq!(summary.moves, 2);
+        assert_eq!(summary.material_taken, (0, 0));
+        assert_eq!(summary.ai_eval.as_deref(), Some("-M2"));
+        assert_eq!(format_duration(summary.duration_secs), "1:15");
+        assert_eq!(format_eval(125), "+1.25");
+    }
+}
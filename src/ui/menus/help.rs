@@ -0,0 +1,88 @@
+//! Help screen: controls, how the pieces move, and the game modes
+//!
+//! The same scrollable sections are shown by the main menu's Help & Rules
+//! panel, the in-game HUD's Help window and the pause menu. Hotkeys are
+//! listed from the player's [`KeyBindings`], so the help always names the
+//! keys actually bound.
+
+use crate::core::keybindings::{key_label, GameAction, KeyBindings};
+use crate::ui::styles::*;
+use bevy_egui::egui;
+
+/// Moving pieces and the camera, before the rebindable hotkeys.
+const CONTROLS: &[&str] = &[
+    "Click a piece, then a highlighted square, to move it.",
+    "Or drag a piece onto its square and let go.",
+    "The camera keys below pan and turn the 3D camera, and the mouse wheel zooms.",
+    "Hold the right mouse button and drag to look around the board.",
+    "Right-drag from one square to another draws an arrow; right-click a square circles it.",
+];
+
+/// One line per piece, then the special moves.
+const RULES: &[&str] = &[
+    "King: one square in any direction, never onto an attacked square.",
+    "Queen: any distance along a rank, file or diagonal.",
+    "Rook: any distance along a rank or file.",
+    "Bishop: any distance along a diagonal.",
+    "Knight: an L shape, two squares one way and one the other. It jumps over pieces.",
+    "Pawn: one square forward, or two from its first square. It captures one square diagonally forward.",
+    "Castling: the king moves two squares towards a rook, which jumps over it. Neither may have moved, the squares between must be empty, and the king may not be in, pass through or land in check.",
+    "En passant: a pawn that has just moved two squares can be taken by an enemy pawn beside it, as if it had moved one.",
+    "Promotion: a pawn reaching the last rank becomes a queen, rook, bishop or knight.",
+];
+
+/// How a game ends.
+const ENDINGS: &[&str] = &[
+    "Checkmate: the king is in check and no move gets it out. The side giving mate wins.",
+    "Stalemate: the side to move has no legal move but is not in check. The game is drawn.",
+    "Also drawn: the same position three times, fifty moves each without a capture or pawn move, or too little material left to mate.",
+    "A player may also resign, or lose on time when playing with a clock.",
+];
+
+/// What each main menu entry starts.
+const MODES: &[&str] = &[
+    "Play Against a Computer: pick your side, one of eight difficulty levels and a time control.",
+    "Watch Computer vs Computer: the engine plays itself, with a level for each side.",
+    "Saved Games: continue an offline game, or start from a pasted FEN position.",
+    "Load PGN: replay a recorded game move by move.",
+    "Play Online: host or join a game against a friend or a matched opponent.",
+    "Solana Multiplayer: wager SOL on the result. Connect a wallet to unlock.",
+];
+
+/// Every help section, in a vertical scroll area `height` points tall.
+pub fn render_help(ui: &mut egui::Ui, key_bindings: &KeyBindings, height: f32) {
+    egui::ScrollArea::vertical()
+        .max_height(height)
+        .auto_shrink([false, false])
+        .show(ui, |ui| {
+            section(ui, "Controls", CONTROLS);
+            egui::Grid::new("xfc_help_hotkeys")
+                .num_columns(2)
+                .spacing([16.0, 2.0])
+                .show(ui, |ui| {
+                    for action in GameAction::ALL {
+                        ui.label(TextStyle::caption(key_label(key_bindings.key(action))));
+                        ui.label(TextStyle::body(action.label()));
+                        ui.end_row();
+                    }
+                });
+            ui.label(TextStyle::caption("Hotkeys can be changed in Settings."));
+
+            Layout::item_space(ui);
+            section(ui, "Moving the Pieces", RULES);
+            Layout::item_space(ui);
+            section(ui, "Winning and Drawing", ENDINGS);
+            Layout::item_space(ui);
+            section(ui, "Game Modes", MODES);
+        });
+}
+
+/// A heading over its lines.
+fn section(ui: &mut egui::Ui, title: &str, lines: &[&str]) {
+    ui.label(TextStyle::heading(title, TextSize::SM));
+    Layout::small_space(ui);
+    for line in lines {
+        ui.label(TextStyle::body(*line));
+        ui.add_space(2.0);
+    }
+}
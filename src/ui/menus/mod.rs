@@ -1,6 +1,7 @@
 pub mod compliance_modal;
 // pub mod inspector;
 pub mod game_over_popup;
+pub mod help;
 pub mod multiplayer_menu;
 pub mod popup;
 pub mod stats;
@@ -2,13 +2,24 @@
 //!
 //! Add a `GamePopup` to the `GamePopupQueue` resource from any system and it
 //! will be rendered as a floating toast in the bottom-right corner while the
-//! player is in-game. Popups auto-dismiss after a configurable timeout and
-//! can carry an optional copy-to-clipboard string and an optional URL button.
+//! player is in-game. Popups auto-dismiss after a configurable timeout, fading
+//! out over their last moments, and can carry an optional copy-to-clipboard
+//! string and an optional URL button.
+//!
+//! Short status messages ("Saved", "Computer resigned") use
+//! [`GamePopup::notice`]. Pushing a popup identical to one still showing
+//! restarts its timer instead of stacking a second copy.
 
 use bevy::prelude::*;
 use bevy_egui::egui;
 use bevy_egui::{EguiContexts, EguiPrimaryContextPass};
 
+/// Seconds a [`GamePopup::notice`] stays up.
+pub const NOTICE_LIFETIME_SECS: f32 = 3.0;
+
+/// Seconds over which a timed popup fades out before it expires.
+const FADE_SECS: f32 = 0.6;
+
 // ---------------------------------------------------------------------------
 // Data types
 // ---------------------------------------------------------------------------
@@ -47,6 +58,12 @@ impl GamePopup {
         }
     }
 
+    /// A short status message that clears itself after
+    /// [`NOTICE_LIFETIME_SECS`].
+    pub fn notice(title: impl Into<String>, message: impl Into<String>) -> Self {
+        Self::warning(title, message).with_lifetime(NOTICE_LIFETIME_SECS)
+    }
+
     pub fn with_copy(mut self, text: impl Into<String>) -> Self {
         self.copy_text = Some(text.into());
         self
@@ -60,11 +77,27 @@ impl GamePopup {
         self
     }
 
+    pub fn with_lifetime(mut self, secs: f32) -> Self {
+        self.lifetime = secs;
+        self.remaining = secs;
+        self
+    }
+
     pub fn persistent(mut self) -> Self {
         self.lifetime = f32::INFINITY;
         self.remaining = f32::INFINITY;
         self
     }
+
+    /// 1 while showing, falling to 0 over the last [`FADE_SECS`] of a timed
+    /// popup's life.
+    pub fn opacity(&self) -> f32 {
+        if self.remaining.is_finite() {
+            (self.remaining / FADE_SECS).clamp(0.0, 1.0)
+        } else {
+            1.0
+        }
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -79,7 +112,17 @@ pub struct GamePopupQueue {
 
 impl GamePopupQueue {
     pub fn push(&mut self, popup: GamePopup) {
-        self.entries.push(popup);
+        let showing = self
+            .entries
+            .iter_mut()
+            .find(|p| !p.dismissed && p.title == popup.title && p.message == popup.message);
+        match showing {
+            Some(existing) => {
+                existing.lifetime = popup.lifetime;
+                existing.remaining = popup.remaining;
+            }
+            None => self.entries.push(popup),
+        }
     }
 }
 
@@ -120,8 +163,9 @@ fn render_popups(
     let count = queue.entries.len();
     for i in (0..count).rev() {
         let popup = &queue.entries[i];
+        let opacity = popup.opacity();
 
-        let accent = egui::Color32::from_rgb(244, 187, 68); // gold
+        let accent = egui::Color32::from_rgb(244, 187, 68).gamma_multiply(opacity); // gold
 
         let mut open = true;
         let title = popup.title.clone();
@@ -135,8 +179,9 @@ fn render_popups(
         let remaining = popup.remaining;
         let lifetime = popup.lifetime;
 
+        // Stable and unique: identical popups are merged in `push`
         let win_resp = egui::Window::new(&title)
-            .id(egui::Id::new(("popup", &title))) // Use stable ID based on title
+            .id(egui::Id::new(("popup", &title, &message)))
             .open(&mut open)
             .resizable(false)
             .collapsible(false)
@@ -144,12 +189,17 @@ fn render_popups(
             .fixed_size([width, 0.0])
             .frame(
                 egui::Frame::default()
-                    .fill(egui::Color32::from_rgba_unmultiplied(10, 33, 26, 230))
+                    .fill(
+                        egui::Color32::from_rgba_unmultiplied(10, 33, 26, 230)
+                            .gamma_multiply(opacity),
+                    )
                     .stroke(egui::Stroke::new(1.0, accent))
                     .corner_radius(12.0)
                     .inner_margin(14.0),
             )
             .show(ctx, |ui| {
+                ui.multiply_opacity(opacity);
+
                 // Accent bar at top
                 let (bar_rect, _) = ui.allocate_exact_size(
                     egui::vec2(ui.available_width(), 3.0),
@@ -250,3 +300,41 @@ impl Plugin for PopupPlugin {
             .add_systems(EguiPrimaryContextPass, render_popups);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::system::RunSystemOnce;
+    use std::time::Duration;
+
+    fn tick(world: &mut World, secs: f32) {
+        world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs_f32(secs));
+        world.run_system_once(tick_popups).unwrap();
+    }
+
+    #[test]
+    fn notice_shows_fades_and_expires() {
+        //! A pushed notice shows at full opacity, fades over its last moments and is gone once its lifetime has passed
+
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.init_resource::<GamePopupQueue>();
+        let mut queue = world.resource_mut::<GamePopupQueue>();
+        queue.push(GamePopup::notice("Saved", "Quick-saved"));
+        queue.push(GamePopup::notice("Saved", "Quick-saved"));
+
+        tick(&mut world, 0.5);
+        let queue = world.resource::<GamePopupQueue>();
+        assert_eq!(queue.entries.len(), 1, "a repeated notice stacked");
+        assert_eq!(queue.entries[0].opacity(), 1.0);
+
+        tick(&mut world, NOTICE_LIFETIME_SECS - 0.5 - FADE_SECS / 2.0);
+        let opacity = world.resource::<GamePopupQueue>().entries[0].opacity();
+        assert!(opacity > 0.0 && opacity < 1.0, "opacity {}", opacity);
+
+        tick(&mut world, FADE_SECS);
+        assert!(world.resource::<GamePopupQueue>().entries.is_empty());
+    }
+}
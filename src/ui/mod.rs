@@ -3,11 +3,13 @@
 //! Assembles all Bevy Egui plugin logic for the application.
 
 pub mod account;
+pub mod eval_format;
 pub mod game;
 pub mod menus;
 pub mod spectator_mode;
 pub mod styles;
 pub mod system_params;
+pub mod ui_scale;
 
 pub use account::auth;
 #[cfg(feature = "solana")]
@@ -39,5 +41,6 @@ impl Plugin for UiPlugin {
         app.add_plugins(stats::StatsPlugin);
         app.add_plugins(multiplayer_menu::MultiplayerMenuPlugin);
         app.add_plugins(SpectatorModePlugin);
+        app.add_plugins(ui_scale::UiScalePlugin);
     }
 }
@@ -66,4 +66,16 @@ impl UiColors {
 
     /// Dark charcoal fill for secondary/cancel buttons inside popups
     pub const BTN_POPUP_DARK: egui::Color32 = egui::Color32::from_rgb(32, 34, 46);
+
+    // === Board Highlights ===
+
+    /// King in check: the pulsing square and light on the 3D board, and the
+    /// square tint on the 2D board
+    pub const CHECK_HIGHLIGHT: egui::Color32 = egui::Color32::from_rgb(220, 35, 35);
+
+    /// `color` as a Bevy colour, for using a token on the 3D board.
+    pub fn to_bevy(color: egui::Color32) -> bevy::color::Color {
+        let [r, g, b, a] = color.to_srgba_unmultiplied();
+        bevy::color::Color::srgba_u8(r, g, b, a)
+    }
 }
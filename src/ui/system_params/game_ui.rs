@@ -67,14 +67,29 @@ pub struct GameUIParams<'w, 's> {
         Res<'w, crate::game::resources::active_time_control::ActiveTimeControl>,
     pub current_turn: Res<'w, CurrentTurn>,
     pub eval_history: Res<'w, crate::ui::game::game_2d::EvalHistory>,
+    pub eval_bar: Res<'w, crate::ui::game::game_2d::EvalBarState>,
     pub p2p_conn: Option<Res<'w, crate::multiplayer::network::p2p::P2PConnectionState>>,
     pub hourglass: Res<'w, crate::ui::game::game_ui::TimeoutHourglassState>,
     pub avatar_cache: ResMut<'w, crate::ui::game::game_ui::AvatarCache>,
     pub increment_flash: Res<'w, crate::ui::game::game_ui::IncrementFlash>,
+    pub settings: Res<'w, crate::core::GameSettings>,
     pub pending_draw: Res<'w, crate::game::systems::network_move::PendingDrawOffer>,
     pub turn_ctx: Res<'w, crate::game::resources::TurnStateContext>,
+    pub save_browser: ResMut<'w, crate::game::save::SaveSlotBrowser>,
+    pub study_browser: ResMut<'w, crate::game::study::StudyBrowser>,
+    pub in_game_help: ResMut<'w, crate::ui::game::game_ui::InGameHelp>,
+    pub engine: Res<'w, crate::engine::board_state::ChessEngine>,
     pub resign_writer: bevy::prelude::MessageWriter<'w, crate::game::events::ResignEvent>,
     pub draw_writer: bevy::prelude::MessageWriter<'w, crate::game::events::DrawOfferEvent>,
+    pub hint: Res<'w, crate::game::ai::hint::EngineHint>,
+    pub pending_hint: Option<Res<'w, crate::game::ai::hint::PendingHint>>,
+    pub hint_writer: bevy::prelude::MessageWriter<'w, crate::game::ai::hint::HintRequest>,
+    pub takeback_writer:
+        bevy::prelude::MessageWriter<'w, crate::game::systems::takeback::TakebackRequest>,
+    pub redo_writer: bevy::prelude::MessageWriter<'w, crate::game::systems::takeback::RedoRequest>,
+    pub flip_writer:
+        bevy::prelude::MessageWriter<'w, crate::game::systems::camera::FlipBoardRequest>,
+    pub jump_writer: bevy::prelude::MessageWriter<'w, crate::game::systems::takeback::JumpToMove>,
     pub first_move_deadline: Res<'w, crate::game::resources::FirstMoveDeadline>,
     pub chat_state: ResMut<'w, crate::ui::game::chat_ui::ChatState>,
     pub chat_writer:
@@ -1,5 +1,7 @@
 use crate::assets::{GameAssets, LoadingProgress};
-use crate::core::{GameMode as CoreGameMode, GameSettings, GameState, PreviousState};
+use crate::core::{
+    GameMode as CoreGameMode, GameSettings, GameState, GameStatistics, PreviousState,
+};
 use crate::game::ai::ChessAIResource;
 use crate::game::events::GameStartedEvent;
 use crate::game::resources::MenuSounds;
@@ -43,6 +45,8 @@ use bevy_egui::EguiContexts;
 /// - [`GameAssets`] - Loaded game assets
 ///
 /// - [`GameSettings`] - Game settings
+/// - [`KeyRebind`](crate::core::keybindings::KeyRebind) - Hotkey waiting to be rebound
+/// - [`GameStatistics`] - Lifetime game statistics
 /// - [`CoreGameMode`] - Core game mode selection
 /// - [`CompetitiveMenuState`] - Competitive match UI state
 /// - [`BraidSubscriptionConfig`] - legacy Braid subscription configuration
@@ -65,6 +69,8 @@ pub struct MainMenuUIContext<'w, 's> {
     pub previous_state: ResMut<'w, PreviousState>,
 
     pub settings: ResMut<'w, GameSettings>,
+    pub key_rebind: ResMut<'w, crate::core::keybindings::KeyRebind>,
+    pub stats: ResMut<'w, GameStatistics>,
     pub core_mode: ResMut<'w, CoreGameMode>,
     pub competitive_menu: ResMut<'w, CompetitiveMenuState>,
     pub braid_subscription: Option<ResMut<'w, BraidSubscriptionConfig>>,
@@ -78,6 +84,8 @@ pub struct MainMenuUIContext<'w, 's> {
     pub connect_events: Option<MessageWriter<'w, ConnectToPeerEvent>>,
     pub game_started_events: MessageWriter<'w, GameStartedEvent>,
     pub color_choice: ResMut<'w, PlayerColorChoice>,
+    pub save_browser: ResMut<'w, crate::game::save::SaveSlotBrowser>,
+    pub study_browser: ResMut<'w, crate::game::study::StudyBrowser>,
     #[cfg(feature = "solana")]
     pub wallet: Option<ResMut<'w, SolanaWallet>>,
     #[cfg(feature = "solana")]
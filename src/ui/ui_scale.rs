@@ -0,0 +1,66 @@
+//! Interface scale
+//!
+//! Multiplies egui's pixels-per-point by [`GameSettings::ui_scale`] so the
+//! menus and in-game panels stay readable on high-DPI and 4K monitors. egui
+//! lays out in points, so centred panels and side panels keep their
+//! proportions; only the physical size changes.
+
+use crate::core::GameSettings;
+use bevy::prelude::*;
+use bevy_egui::{egui, EguiContexts, EguiPrimaryContextPass};
+
+/// Smallest scale offered in the settings.
+pub const UI_SCALE_MIN: f32 = 0.75;
+/// Largest scale offered in the settings.
+pub const UI_SCALE_MAX: f32 = 2.0;
+
+pub struct UiScalePlugin;
+
+impl Plugin for UiScalePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            EguiPrimaryContextPass,
+            sync_ui_scale.run_if(resource_exists::<GameSettings>),
+        );
+    }
+}
+
+/// Set `ctx`'s zoom to `scale`, clamped to the supported range. egui folds
+/// the zoom into pixels-per-point from the next frame on.
+pub fn apply_ui_scale(ctx: &egui::Context, scale: f32) {
+    let scale = scale.clamp(UI_SCALE_MIN, UI_SCALE_MAX);
+    if ctx.zoom_factor() != scale {
+        ctx.set_zoom_factor(scale);
+    }
+}
+
+/// Keep the primary egui context at the configured scale. Checked every
+/// frame, so a context recreated after a window change picks it up too.
+fn sync_ui_scale(mut contexts: EguiContexts, settings: Res<GameSettings>) {
+    let Ok(ctx) = contexts.ctx_mut() else {
+        return;
+    };
+    apply_ui_scale(ctx, settings.ui_scale);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ui_scale_sets_pixels_per_point() {
+        //! A new scale shows up as pixels-per-point on the next frame, clamped to range
+
+        let ctx = egui::Context::default();
+        let _ = ctx.run(egui::RawInput::default(), |_| {});
+        assert_eq!(ctx.pixels_per_point(), 1.0);
+
+        apply_ui_scale(&ctx, 1.5);
+        let _ = ctx.run(egui::RawInput::default(), |_| {});
+        assert_eq!(ctx.pixels_per_point(), 1.5);
+
+        apply_ui_scale(&ctx, 4.0);
+        let _ = ctx.run(egui::RawInput::default(), |_| {});
+        assert_eq!(ctx.pixels_per_point(), UI_SCALE_MAX);
+    }
+}
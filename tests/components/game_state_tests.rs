@@ -37,6 +37,7 @@ fn example_recording_a_move() {
         from: (4, 1), // e2
         to: (4, 3),   // e4
         captured: None,
+        promotion: None,
         is_castling: false,
         is_en_passant: false,
         is_check: false,
@@ -75,6 +76,7 @@ fn example_scholars_mate_checkmate() {
         from: (3, 7),                    // d8 (assuming queen started there)
         to: (5, 6),                      // f7
         captured: Some(PieceType::Pawn), // Captures f7 pawn
+        promotion: None,
         is_castling: false,
         is_en_passant: false,
         is_check: true,
@@ -2,20 +2,24 @@
 //! Original location: src/game/resources/history/captured.rs
 
 use xfchess::game::resources::CapturedPieces;
+use xfchess::game::test_support::GameHarness;
 use xfchess::rendering::pieces::{PieceColor, PieceType};
 
 /// Test usage of CapturedPieces resource
 /// Original: CapturedPieces struct-level example
 #[test]
 fn example_captured_pieces_usage() {
-    let mut captured_pieces = CapturedPieces::default();
+    let mut game = GameHarness::builder()
+        .fen("4k3/8/8/3q4/4P3/8/8/4K3 w - - 0 1")
+        .build();
 
-    // Add a capture
-    captured_pieces.add_capture(PieceColor::Black, PieceType::Queen);
+    // White's pawn takes the queen
+    assert!(game.play("e4d5"));
 
     // Check advantage
-    let advantage = captured_pieces.material_advantage();
-    assert_eq!(advantage, 9); // +9 for White
+    let captured_pieces = game.resource::<CapturedPieces>();
+    assert_eq!(captured_pieces.white_captured, vec![PieceType::Queen]);
+    assert_eq!(captured_pieces.material_advantage(), 9); // +9 for White
 }
 
 /// Test recording a capture
@@ -3,28 +3,22 @@
 
 use xfchess::game::components::MoveRecord;
 use xfchess::game::resources::MoveHistory;
+use xfchess::game::test_support::GameHarness;
 use xfchess::rendering::pieces::{PieceColor, PieceType};
 
 /// Test recording moves
 /// Original: recording moves example
 #[test]
 fn example_execute_move_system() {
-    let mut history = MoveHistory::default();
-
-    let move_record = MoveRecord {
-        piece_type: PieceType::Pawn,
-        piece_color: PieceColor::White,
-        from: (4, 1),
-        to: (4, 3),
-        captured: None,
-        is_castling: false,
-        is_en_passant: false,
-        is_check: false,
-        is_checkmate: false,
-    };
+    let mut game = GameHarness::builder().build();
+    assert!(game.play("e2e4"));
 
-    history.add_move(move_record);
+    let history = game.resource::<MoveHistory>();
     assert_eq!(history.len(), 1);
+    let record = history.last_move().expect("e4 was recorded");
+    assert_eq!(record.piece_type, PieceType::Pawn);
+    assert_eq!(record.piece_color, PieceColor::White);
+    assert_eq!((record.from, record.to), ((4, 1), (4, 3)));
 }
 
 /// Test reviewing history
@@ -39,6 +33,7 @@ fn example_display_last_move() {
         from: (4, 1),
         to: (4, 3),
         captured: None,
+        promotion: None,
         is_castling: false,
         is_en_passant: false,
         is_check: false,
@@ -65,6 +60,7 @@ fn example_add_move_method() {
         from: (1, 7),
         to: (2, 5),
         captured: None,
+        promotion: None,
         is_castling: false,
         is_en_passant: false,
         is_check: true, // Knight gives check
@@ -88,6 +84,7 @@ fn example_last_move_en_passant_check() {
         from: (4, 1),
         to: (4, 3),
         captured: None,
+        promotion: None,
         is_castling: false,
         is_en_passant: false,
         is_check: false,
@@ -120,6 +117,7 @@ fn example_ply_count() {
             from: (0, 0),
             to: (0, 0),
             captured: None,
+            promotion: None,
             is_castling: false,
             is_en_passant: false,
             is_check: false,
@@ -157,6 +155,7 @@ fn example_clear_history() {
         from: (0, 0),
         to: (0, 0),
         captured: None,
+        promotion: None,
         is_castling: false,
         is_en_passant: false,
         is_check: false,
@@ -179,6 +178,7 @@ fn example_get_move() {
         from: (4, 1),
         to: (4, 3),
         captured: None,
+        promotion: None,
         is_castling: false,
         is_en_passant: false,
         is_check: false,
@@ -204,6 +204,7 @@ fn example_iter_moves() {
         from: (0, 0),
         to: (0, 0),
         captured: None,
+        promotion: None,
         is_castling: false,
         is_en_passant: false,
         is_check: false,
@@ -2,20 +2,26 @@
 //! Original location: src/game/resources/turn/current.rs
 
 use xfchess::game::resources::CurrentTurn;
+use xfchess::game::test_support::GameHarness;
 use xfchess::rendering::pieces::PieceColor;
 
 /// Test turn execution flow
 /// Original: turn flow example
 #[test]
 fn example_execute_move_flow() {
-    let mut current_turn = CurrentTurn::default();
+    let mut game = GameHarness::builder().build();
 
     // White moves
-    // ... execute move ...
-    current_turn.switch();
+    assert!(game.play("e2e4"));
 
     // Expect Black's turn
-    assert_eq!(current_turn.color, PieceColor::Black);
+    assert_eq!(game.resource::<CurrentTurn>().color, PieceColor::Black);
+    assert_eq!(game.resource::<CurrentTurn>().move_number, 1);
+
+    // Black replies, and the move number ticks over
+    assert!(game.play("e7e5"));
+    assert_eq!(game.resource::<CurrentTurn>().color, PieceColor::White);
+    assert_eq!(game.resource::<CurrentTurn>().move_number, 2);
 }
 
 /// Test switching turns
@@ -35,6 +35,7 @@ fn test_reset_game_resources_system() {
         from: (4, 1),
         to: (4, 3),
         captured: None,
+        promotion: None,
         is_castling: false,
         is_en_passant: false,
         is_check: false,
@@ -48,6 +49,8 @@ fn test_reset_game_resources_system() {
     app.insert_resource(TurnStateContext::default());
     app.insert_resource(ChessEngine::default()); // Mock or default engine
     app.insert_resource(ActiveTimeControl::default()); // reset_game_resources reads this to seed GameTimer
+    app.insert_resource(xfchess::core::GameSettings::default()); // ...and the increment mode from here
+    app.insert_resource(xfchess::core::GameMode::default());
 
     // Register types used in spawn
     app.register_type::<PointLight>();